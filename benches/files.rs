@@ -55,6 +55,7 @@ fn build_file_benchmark_environment(
             timezone: Default::default(),
             internal_metrics: Default::default(),
             truncate: Default::default(),
+            rotation: Default::default(),
             base_dir: None,
             confinement: Default::default(),
         },
@@ -127,6 +128,7 @@ fn benchmark_files_no_partitions(c: &mut Criterion) {
                         timezone: Default::default(),
                         internal_metrics: Default::default(),
                         truncate: Default::default(),
+                        rotation: Default::default(),
                         base_dir: None,
                         confinement: Default::default(),
                     },
@@ -0,0 +1,80 @@
+//! Support for the MessagePack [timestamp extension type][timestamp].
+//!
+//! [timestamp]: https://github.com/msgpack/msgpack/blob/master/spec.md#timestamp-extension-type
+
+use chrono::{DateTime, Utc};
+
+/// The extension type reserved by MessagePack for timestamps.
+pub const TIMESTAMP_EXT_TYPE: i8 = -1;
+
+/// Decodes the payload of a timestamp extension.
+///
+/// Returns `None` if the payload isn't one of the 32, 64 or 96 bit layouts, or if it is out of the
+/// range supported by `DateTime`.
+pub fn decode_timestamp(data: &[u8]) -> Option<DateTime<Utc>> {
+    let (seconds, nanoseconds) = match data.len() {
+        4 => (i64::from(u32::from_be_bytes(data.try_into().ok()?)), 0),
+        8 => {
+            let value = u64::from_be_bytes(data.try_into().ok()?);
+            ((value & 0x3_ffff_ffff) as i64, (value >> 34) as u32)
+        }
+        12 => (
+            i64::from_be_bytes(data[4..].try_into().ok()?),
+            u32::from_be_bytes(data[..4].try_into().ok()?),
+        ),
+        _ => return None,
+    };
+
+    if nanoseconds >= 1_000_000_000 {
+        return None;
+    }
+    DateTime::from_timestamp(seconds, nanoseconds)
+}
+
+/// Encodes a timestamp as the payload of a timestamp extension, using the smallest layout that
+/// can represent it.
+pub fn encode_timestamp(timestamp: DateTime<Utc>) -> Vec<u8> {
+    let seconds = timestamp.timestamp();
+    let nanoseconds = timestamp.timestamp_subsec_nanos();
+
+    if seconds >> 34 == 0 {
+        let value = (u64::from(nanoseconds) << 34) | seconds as u64;
+        if value >> 32 == 0 {
+            (value as u32).to_be_bytes().to_vec()
+        } else {
+            value.to_be_bytes().to_vec()
+        }
+    } else {
+        let mut data = Vec::with_capacity(12);
+        data.extend_from_slice(&nanoseconds.to_be_bytes());
+        data.extend_from_slice(&seconds.to_be_bytes());
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn timestamp_roundtrip() {
+        for (timestamp, len) in [
+            (Utc.timestamp_opt(1_700_000_000, 0).unwrap(), 4),
+            (Utc.timestamp_opt(1_700_000_000, 123_456_789).unwrap(), 8),
+            (Utc.timestamp_opt(-1, 500).unwrap(), 12),
+            (Utc.timestamp_opt(1 << 34, 0).unwrap(), 12),
+        ] {
+            let data = encode_timestamp(timestamp);
+            assert_eq!(data.len(), len, "{timestamp}");
+            assert_eq!(decode_timestamp(&data), Some(timestamp));
+        }
+    }
+
+    #[test]
+    fn invalid_timestamp() {
+        assert_eq!(decode_timestamp(&[0; 5]), None);
+        assert_eq!(decode_timestamp(&u64::MAX.to_be_bytes()), None);
+    }
+}
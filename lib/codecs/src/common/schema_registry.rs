@@ -0,0 +1,652 @@
+//! Support for [Confluent Schema Registry][schema_registry] compatible registries and the wire
+//! format used by their serializers.
+//!
+//! Payloads in the wire format start with a zero magic byte and the big-endian, 4-byte ID of the
+//! schema they were written with. Protobuf payloads then hold the indexes of the message type
+//! within the schema's file, ahead of the message itself.
+//!
+//! [schema_registry]: https://docs.confluent.io/platform/current/schema-registry/index.html
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, OnceLock, RwLock, mpsc},
+    time::{Duration, Instant},
+};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use reqwest::{Certificate, Identity, Method, Proxy, Url, header};
+use serde::{Deserialize, de::DeserializeOwned};
+use tokio::runtime::{Handle, Runtime, RuntimeFlavor};
+use vector_common::sensitive_string::SensitiveString;
+use vector_config::configurable_component;
+use vector_core::{
+    config::proxy::ProxyConfig,
+    tls::{TlsConfig, TlsSettings},
+};
+
+/// The magic byte that payloads in the wire format start with.
+pub const MAGIC_BYTE: u8 = 0;
+
+/// The length of the magic byte and schema ID that payloads in the wire format start with.
+pub const HEADER_LEN: usize = 5;
+
+const CONTENT_TYPE: &str = "application/vnd.schemaregistry.v1+json";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+// How long to wait before fetching a schema again after failing to, doubling with each failure.
+const MIN_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Options for connecting to a schema registry.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaRegistryConfig {
+    /// The URL of the schema registry.
+    #[configurable(metadata(docs::examples = "http://localhost:8081"))]
+    pub url: String,
+
+    /// Basic authentication credentials for the schema registry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<SchemaRegistryAuth>,
+
+    /// TLS options for connecting to the schema registry.
+    ///
+    /// `alpn_protocols` and `server_name` aren't supported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsConfig>,
+
+    /// Proxy options for connecting to the schema registry.
+    ///
+    /// These are merged with the `HTTP_PROXY`, `HTTPS_PROXY`, and `NO_PROXY` environment variables.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub proxy: ProxyConfig,
+}
+
+/// Basic authentication credentials for the schema registry.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaRegistryAuth {
+    /// The basic authentication username.
+    #[configurable(metadata(docs::examples = "${SCHEMA_REGISTRY_USERNAME}"))]
+    pub user: String,
+
+    /// The basic authentication password.
+    #[configurable(metadata(docs::examples = "${SCHEMA_REGISTRY_PASSWORD}"))]
+    pub password: SensitiveString,
+}
+
+/// Options for looking up or registering the schema that events are encoded with.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaRegistryEncodingConfig {
+    #[serde(flatten)]
+    #[configurable(derived)]
+    pub registry: SchemaRegistryConfig,
+
+    /// The subject that the schema is registered under.
+    ///
+    /// With the registry's default topic name strategy, this is the name of the Kafka topic
+    /// followed by `-value`.
+    #[configurable(metadata(docs::examples = "logs-value"))]
+    pub subject: String,
+
+    /// Registers the schema under the subject if it isn't registered yet.
+    ///
+    /// When disabled, the schema must already be registered under the subject.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub auto_register: bool,
+}
+
+/// A schema as returned by the registry.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisteredSchema {
+    /// The ID of the schema. Only returned when the schema is looked up by subject.
+    #[serde(default)]
+    pub id: Option<u32>,
+
+    /// The schema itself.
+    pub schema: String,
+
+    /// The type of the schema. The registry omits this for Avro schemas.
+    #[serde(default)]
+    pub schema_type: Option<String>,
+
+    /// The other schemas that this schema imports.
+    #[serde(default)]
+    pub references: Vec<SchemaReference>,
+}
+
+impl RegisteredSchema {
+    /// Returns an error unless this is a schema of the given type.
+    pub fn expect_type(&self, schema_type: &str) -> vector_common::Result<()> {
+        let actual = self.schema_type.as_deref().unwrap_or("AVRO");
+        if actual == schema_type {
+            Ok(())
+        } else {
+            Err(format!("Expected a {schema_type} schema, found a {actual} schema").into())
+        }
+    }
+}
+
+/// A reference from a schema to another schema that it imports.
+#[derive(Debug, Deserialize)]
+pub struct SchemaReference {
+    /// The name the schema is imported as.
+    pub name: String,
+
+    /// The subject that the imported schema is registered under.
+    pub subject: String,
+
+    /// The version of the imported schema.
+    pub version: i32,
+}
+
+#[derive(Deserialize)]
+struct SchemaId {
+    id: u32,
+}
+
+/// Client for the schema registry's REST API.
+///
+/// Codecs are synchronous, so requests are run on a runtime shared by all clients and the caller
+/// blocks until they complete. Callers cache what they fetch, so this happens once per schema
+/// rather than once per event.
+#[derive(Clone, Debug)]
+pub struct SchemaRegistryClient {
+    config: SchemaRegistryConfig,
+    client: reqwest::Client,
+}
+
+impl SchemaRegistryClient {
+    /// Creates a new `SchemaRegistryClient`.
+    pub fn new(config: SchemaRegistryConfig) -> vector_common::Result<Self> {
+        let mut builder = reqwest::Client::builder().timeout(REQUEST_TIMEOUT);
+
+        if let Some(tls) = &config.tls {
+            let settings = TlsSettings::from_options(Some(tls))?;
+            for authority in settings.authorities_pem() {
+                builder = builder.add_root_certificate(Certificate::from_pem(&authority)?);
+            }
+            if let Some((cert, key)) = settings.identity_pem() {
+                builder = builder.identity(Identity::from_pkcs8_pem(&cert, &key)?);
+            }
+            builder = builder
+                .danger_accept_invalid_certs(tls.verify_certificate == Some(false))
+                .danger_accept_invalid_hostnames(!settings.verify_hostname());
+        }
+
+        let proxy = ProxyConfig::from_env().merge(&config.proxy);
+        builder = if proxy.enabled {
+            builder.proxy(build_proxy(proxy)?)
+        } else {
+            builder.no_proxy()
+        };
+
+        Ok(Self {
+            config,
+            client: builder.build()?,
+        })
+    }
+
+    /// Fetches the schema with the given ID.
+    ///
+    /// If `serialized` is set, Protobuf schemas are returned as a base64 encoded
+    /// `FileDescriptorProto` rather than as `.proto` source.
+    pub fn schema_by_id(
+        &self,
+        id: u32,
+        serialized: bool,
+    ) -> vector_common::Result<RegisteredSchema> {
+        self.request(
+            Method::GET,
+            &["schemas", "ids", &id.to_string()],
+            serialized,
+            None,
+        )
+    }
+
+    /// Fetches a version of the schema registered under a subject.
+    ///
+    /// `version` is either a version number or `latest`.
+    pub fn subject_version(
+        &self,
+        subject: &str,
+        version: &str,
+        serialized: bool,
+    ) -> vector_common::Result<RegisteredSchema> {
+        self.request(
+            Method::GET,
+            &["subjects", subject, "versions", version],
+            serialized,
+            None,
+        )
+    }
+
+    /// Returns the ID of a schema that is registered under a subject.
+    pub fn lookup(
+        &self,
+        subject: &str,
+        schema: &str,
+        schema_type: &str,
+    ) -> vector_common::Result<u32> {
+        let body = serde_json::json!({ "schema": schema, "schemaType": schema_type });
+        self.request::<SchemaId>(Method::POST, &["subjects", subject], false, Some(body))
+            .map(|response| response.id)
+    }
+
+    /// Registers a schema under a subject and returns its ID.
+    ///
+    /// Registering a schema that is already registered under the subject returns its existing ID.
+    pub fn register(
+        &self,
+        subject: &str,
+        schema: &str,
+        schema_type: &str,
+    ) -> vector_common::Result<u32> {
+        let body = serde_json::json!({ "schema": schema, "schemaType": schema_type });
+        self.request::<SchemaId>(
+            Method::POST,
+            &["subjects", subject, "versions"],
+            false,
+            Some(body),
+        )
+        .map(|response| response.id)
+    }
+
+    fn request<T>(
+        &self,
+        method: Method,
+        segments: &[&str],
+        serialized: bool,
+        body: Option<serde_json::Value>,
+    ) -> vector_common::Result<T>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let mut url = Url::parse(&self.config.url)
+            .map_err(|error| format!("Invalid schema registry URL: {error}"))?;
+        url.path_segments_mut()
+            .map_err(|()| format!("Invalid schema registry URL: {}", self.config.url))?
+            .pop_if_empty()
+            .extend(segments);
+        if serialized {
+            url.query_pairs_mut().append_pair("format", "serialized");
+        }
+        let auth = self.config.auth.clone();
+        let client = self.client.clone();
+
+        block_on(async move {
+            let mut request = client
+                .request(method, url.clone())
+                .header(header::ACCEPT, CONTENT_TYPE);
+            if let Some(auth) = &auth {
+                request = request.basic_auth(&auth.user, Some(auth.password.inner()));
+            }
+            if let Some(body) = body {
+                request = request
+                    .header(header::CONTENT_TYPE, CONTENT_TYPE)
+                    .body(body.to_string());
+            }
+
+            let response = request.send().await?;
+            let status = response.status();
+            let text = response.text().await?;
+            if !status.is_success() {
+                return Err(format!(
+                    "Schema registry request to {url} failed with {status}: {text}"
+                )
+                .into());
+            }
+            Ok(serde_json::from_str(&text)?)
+        })
+    }
+}
+
+fn build_proxy(config: ProxyConfig) -> vector_common::Result<Proxy> {
+    let http = config.http.as_deref().map(Url::parse).transpose()?;
+    let https = config.https.as_deref().map(Url::parse).transpose()?;
+    Ok(Proxy::custom(move |url| {
+        let host = url.host_str()?;
+        let excluded = config.no_proxy.matches(host)
+            || url
+                .port()
+                .is_some_and(|port| config.no_proxy.matches(&format!("{host}:{port}")));
+        if excluded {
+            return None;
+        }
+        match url.scheme() {
+            "http" => http.clone(),
+            "https" => https.clone(),
+            _ => None,
+        }
+    }))
+}
+
+/// Returns the runtime that schema registry requests are run on.
+fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("schema-registry")
+            .enable_all()
+            .build()
+            .expect("Failed to start the schema registry runtime")
+    })
+}
+
+fn block_on<T, F>(future: F) -> vector_common::Result<T>
+where
+    T: Send + 'static,
+    F: Future<Output = vector_common::Result<T>> + Send + 'static,
+{
+    let (sender, receiver) = mpsc::sync_channel(1);
+    runtime().spawn(async move {
+        _ = sender.send(future.await);
+    });
+    let wait = move || {
+        receiver
+            .recv()
+            .unwrap_or_else(|_| Err("Schema registry request was cancelled".into()))
+    };
+
+    // Let the caller's runtime move its other tasks to another worker while this one waits.
+    match Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(wait)
+        }
+        _ => wait(),
+    }
+}
+
+/// Values built from registry schemas, keyed by schema ID.
+///
+/// A registered schema never changes, so entries are kept for the lifetime of the codec.
+#[derive(Debug)]
+pub struct SchemaCache<T> {
+    entries: Arc<RwLock<HashMap<u32, CacheEntry<T>>>>,
+}
+
+#[derive(Debug)]
+enum CacheEntry<T> {
+    Ready(Arc<T>),
+    Failed {
+        error: String,
+        backoff: Duration,
+        retry_at: Instant,
+    },
+}
+
+impl<T> Clone for SchemaCache<T> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: Arc::clone(&self.entries),
+        }
+    }
+}
+
+impl<T> Default for SchemaCache<T> {
+    fn default() -> Self {
+        Self {
+            entries: Default::default(),
+        }
+    }
+}
+
+impl<T> SchemaCache<T> {
+    /// Returns the entry for a schema ID, building it with `build` if there isn't one yet.
+    ///
+    /// If `build` fails, the error is returned for that schema ID without calling `build` again
+    /// until a backoff has passed. The backoff doubles with each failure, up to a minute.
+    pub fn get_or_try_insert(
+        &self,
+        id: u32,
+        build: impl FnOnce() -> vector_common::Result<T>,
+    ) -> vector_common::Result<Arc<T>> {
+        let backoff = match self.entries.read().expect("poisoned lock").get(&id) {
+            Some(CacheEntry::Ready(entry)) => return Ok(Arc::clone(entry)),
+            Some(CacheEntry::Failed {
+                error,
+                backoff,
+                retry_at,
+            }) => {
+                if Instant::now() < *retry_at {
+                    return Err(error.clone().into());
+                }
+                (*backoff * 2).min(MAX_RETRY_BACKOFF)
+            }
+            None => MIN_RETRY_BACKOFF,
+        };
+
+        let (entry, result) = match build() {
+            Ok(entry) => {
+                let entry = Arc::new(entry);
+                (CacheEntry::Ready(Arc::clone(&entry)), Ok(entry))
+            }
+            Err(error) => {
+                let entry = CacheEntry::Failed {
+                    error: error.to_string(),
+                    backoff,
+                    retry_at: Instant::now() + backoff,
+                };
+                (entry, Err(error))
+            }
+        };
+        self.entries
+            .write()
+            .expect("poisoned lock")
+            .insert(id, entry);
+        result
+    }
+}
+
+/// Splits a payload in the wire format into its schema ID and the rest of the payload.
+pub fn read_header(mut bytes: Bytes) -> vector_common::Result<(u32, Bytes)> {
+    if bytes.len() < HEADER_LEN || bytes[0] != MAGIC_BYTE {
+        return Err("Expected payload to be prefixed with a schema registry header".into());
+    }
+    bytes.advance(1);
+    let id = bytes.get_u32();
+    Ok((id, bytes))
+}
+
+/// Writes the wire format header for a schema ID.
+pub fn write_header(id: u32, buffer: &mut BytesMut) {
+    buffer.put_u8(MAGIC_BYTE);
+    buffer.put_u32(id);
+}
+
+/// Reads the indexes of a Protobuf message type within its schema's file.
+///
+/// The first index is that of a top-level message, and each following index is that of a message
+/// nested in the previous one.
+pub fn read_message_indexes(bytes: &mut Bytes) -> vector_common::Result<Vec<i32>> {
+    let count = read_varint(bytes)?;
+    // An empty list is shorthand for the first message in the file.
+    if count == 0 {
+        return Ok(vec![0]);
+    }
+    if count < 0 || count as usize > bytes.len() {
+        return Err(format!("Invalid Protobuf message index count {count}").into());
+    }
+    (0..count).map(|_| read_varint(bytes)).collect()
+}
+
+/// Writes the indexes of a Protobuf message type within its schema's file.
+pub fn write_message_indexes(indexes: &[i32], buffer: &mut BytesMut) {
+    if indexes == [0] {
+        write_varint(0, buffer);
+    } else {
+        write_varint(indexes.len() as i32, buffer);
+        for index in indexes {
+            write_varint(*index, buffer);
+        }
+    }
+}
+
+// Message indexes are zigzag encoded varints.
+fn read_varint(bytes: &mut Bytes) -> vector_common::Result<i32> {
+    let mut value = 0u32;
+    let mut shift = 0;
+    loop {
+        if !bytes.has_remaining() {
+            return Err("Unexpected end of Protobuf message indexes".into());
+        }
+        let byte = bytes.get_u8();
+        value |= u32::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+        if shift > 28 {
+            return Err("Protobuf message index is too long".into());
+        }
+    }
+    Ok((value >> 1) as i32 ^ -((value & 1) as i32))
+}
+
+fn write_varint(value: i32, buffer: &mut BytesMut) {
+    let mut value = ((value << 1) ^ (value >> 31)) as u32;
+    while value >= 0x80 {
+        buffer.put_u8(value as u8 | 0x80);
+        value >>= 7;
+    }
+    buffer.put_u8(value as u8);
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    /// Serves each of `responses` in turn to a single request, returning the registry's URL.
+    pub(crate) fn mock_registry(responses: Vec<String>) -> String {
+        recording_registry(responses).0
+    }
+
+    /// Like `mock_registry`, but also returns a receiver of the bodies of the requests.
+    pub(crate) fn recording_registry(
+        responses: Vec<String>,
+    ) -> (String, std::sync::mpsc::Receiver<Vec<u8>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (requests, received) = std::sync::mpsc::channel();
+
+        std::thread::spawn(move || {
+            for body in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                let mut request = Vec::new();
+                reader
+                    .by_ref()
+                    .take(content_length)
+                    .read_to_end(&mut request)
+                    .unwrap();
+                // The receiver is dropped by tests that don't look at the requests.
+                _ = requests.send(request);
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\ncontent-type: {CONTENT_TYPE}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        (url, received)
+    }
+
+    /// Returns the config for a registry at `url`.
+    pub(crate) fn registry_config(url: String) -> SchemaRegistryConfig {
+        SchemaRegistryConfig {
+            url,
+            auth: None,
+            tls: None,
+            proxy: ProxyConfig::default(),
+        }
+    }
+
+    #[test]
+    fn header_roundtrip() {
+        let mut buffer = BytesMut::new();
+        write_header(258, &mut buffer);
+        buffer.put_slice(b"payload");
+
+        let (id, payload) = read_header(buffer.freeze()).unwrap();
+        assert_eq!(id, 258);
+        assert_eq!(payload, "payload");
+
+        assert!(read_header(Bytes::from_static(b"\x01\0\0\0\x01payload")).is_err());
+        assert!(read_header(Bytes::from_static(b"\0\0")).is_err());
+    }
+
+    #[test]
+    fn message_indexes_roundtrip() {
+        for indexes in [vec![0], vec![1], vec![0, 2], vec![3, 1, 70]] {
+            let mut buffer = BytesMut::new();
+            write_message_indexes(&indexes, &mut buffer);
+            let mut bytes = buffer.freeze();
+            assert_eq!(read_message_indexes(&mut bytes).unwrap(), indexes);
+            assert!(bytes.is_empty());
+        }
+
+        // The first message is written as a single zero byte.
+        let mut buffer = BytesMut::new();
+        write_message_indexes(&[0], &mut buffer);
+        assert_eq!(buffer.as_ref(), [0]);
+    }
+
+    #[test]
+    fn client_caches_schemas() {
+        let url = mock_registry(vec![r#"{"schema": "\"string\""}"#.to_owned()]);
+        let client = SchemaRegistryClient::new(registry_config(url)).unwrap();
+        let cache = SchemaCache::default();
+
+        for _ in 0..2 {
+            let schema = cache
+                .get_or_try_insert(1, || {
+                    client.schema_by_id(1, false).map(|schema| schema.schema)
+                })
+                .unwrap();
+            assert_eq!(*schema, r#""string""#);
+        }
+    }
+
+    #[test]
+    fn cache_backs_off_after_failures() {
+        let cache = SchemaCache::<String>::default();
+        let mut attempts = 0;
+
+        for _ in 0..2 {
+            let error = cache
+                .get_or_try_insert(1, || {
+                    attempts += 1;
+                    Err("Schema registry is unavailable".into())
+                })
+                .unwrap_err();
+            assert_eq!(error.to_string(), "Schema registry is unavailable");
+        }
+        assert_eq!(attempts, 1);
+    }
+}
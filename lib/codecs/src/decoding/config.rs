@@ -12,6 +12,9 @@ pub struct DecodingConfig {
     decoding: DeserializerConfig,
     /// The namespace used when decoding.
     log_namespace: LogNamespace,
+    /// Whether frames that fail to parse are kept for the decode errors output.
+    #[serde(default)]
+    decode_errors: bool,
 }
 
 impl DecodingConfig {
@@ -26,9 +29,16 @@ impl DecodingConfig {
             framing,
             decoding,
             log_namespace,
+            decode_errors: false,
         }
     }
 
+    /// Sets whether frames that fail to parse are kept for the decode errors output.
+    pub const fn with_decode_errors(mut self, decode_errors: bool) -> Self {
+        self.decode_errors = decode_errors;
+        self
+    }
+
     /// Get the decoding configuration.
     pub const fn config(&self) -> &DeserializerConfig {
         &self.decoding
@@ -47,6 +57,8 @@ impl DecodingConfig {
         // Build the deserializer.
         let deserializer = self.decoding.build()?;
 
-        Ok(Decoder::new(framer, deserializer).with_log_namespace(self.log_namespace))
+        Ok(Decoder::new(framer, deserializer)
+            .with_log_namespace(self.log_namespace)
+            .with_decode_errors(self.decode_errors))
     }
 }
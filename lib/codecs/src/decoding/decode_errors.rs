@@ -0,0 +1,54 @@
+use bytes::Bytes;
+use lookup::owned_value_path;
+use vector_core::{
+    config::{DataType, LogNamespace, SourceOutput},
+    event::{Event, LogEvent, ObjectMap, Value},
+    schema,
+    source_sender::DECODE_ERRORS_OUTPUT,
+};
+use vrl::value::Kind;
+
+/// Builds the event sent to the decode errors output for a frame that failed to decode.
+///
+/// The event holds the raw bytes of the frame in `message`, the error in `error`, and the names of
+/// the framing method and codec in `framing` and `codec`.
+pub(super) fn decode_error_event(frame: Bytes, error: &str, framing: &str, codec: &str) -> Event {
+    let mut log = LogEvent::from(Value::Object(ObjectMap::from([
+        ("message".into(), Value::Bytes(frame)),
+        ("error".into(), Value::from(error)),
+        ("framing".into(), Value::from(framing)),
+        ("codec".into(), Value::from(codec)),
+    ])));
+    log.metadata_mut().set_decode_error();
+    Event::Log(log)
+}
+
+/// The schema of the events sent to the decode errors output.
+pub fn decode_errors_schema_definition(log_namespace: LogNamespace) -> schema::Definition {
+    let definition = match log_namespace {
+        LogNamespace::Legacy => schema::Definition::default_legacy_namespace(),
+        LogNamespace::Vector => {
+            schema::Definition::new_with_default_metadata(Kind::any_object(), [log_namespace])
+        }
+    };
+
+    definition
+        .with_event_field(
+            &owned_value_path!("message"),
+            Kind::bytes(),
+            Some("message"),
+        )
+        .with_event_field(&owned_value_path!("error"), Kind::bytes(), None)
+        .with_event_field(&owned_value_path!("framing"), Kind::bytes(), None)
+        .with_event_field(&owned_value_path!("codec"), Kind::bytes(), None)
+}
+
+/// The output that sources add when they're configured to route events that fail to decode to
+/// [`DECODE_ERRORS_OUTPUT`].
+pub fn decode_errors_output(log_namespace: LogNamespace) -> SourceOutput {
+    SourceOutput::new_maybe_logs(
+        DataType::Log,
+        decode_errors_schema_definition(log_namespace),
+    )
+    .with_port(DECODE_ERRORS_OUTPUT)
+}
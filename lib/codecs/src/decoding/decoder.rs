@@ -1,5 +1,5 @@
 use bytes::{Bytes, BytesMut};
-use smallvec::SmallVec;
+use smallvec::{SmallVec, smallvec};
 use vector_common::internal_event::emit;
 use vector_core::{
     config::LogNamespace,
@@ -10,6 +10,7 @@ use crate::{
     decoding::format::Deserializer as _,
     decoding::{
         BoxedFramingError, BytesDeserializer, Deserializer, Error, Framer, NewlineDelimitedDecoder,
+        decode_errors::decode_error_event,
     },
     internal_events::{DecoderDeserializeError, DecoderFramingError},
 };
@@ -26,6 +27,9 @@ pub struct Decoder {
     pub deserializer: Deserializer,
     /// The `log_namespace` being used.
     pub log_namespace: LogNamespace,
+    /// Whether frames that fail to parse are turned into events for the decode errors output
+    /// instead of being returned as errors.
+    pub decode_errors: bool,
 }
 
 impl Default for Decoder {
@@ -34,6 +38,7 @@ impl Default for Decoder {
             framer: Framer::NewlineDelimited(NewlineDelimitedDecoder::new()),
             deserializer: Deserializer::Bytes(BytesDeserializer),
             log_namespace: LogNamespace::Legacy,
+            decode_errors: false,
         }
     }
 }
@@ -47,6 +52,7 @@ impl Decoder {
             framer,
             deserializer,
             log_namespace: LogNamespace::Legacy,
+            decode_errors: false,
         }
     }
 
@@ -56,6 +62,15 @@ impl Decoder {
         self
     }
 
+    /// Sets whether frames that fail to parse are kept.
+    ///
+    /// When enabled, a frame that fails to parse is decoded into a single event holding the raw
+    /// frame and the error, which the source sender routes to the decode errors output.
+    pub const fn with_decode_errors(mut self, decode_errors: bool) -> Self {
+        self.decode_errors = decode_errors;
+        self
+    }
+
     /// Attaches a per-decode-call metadata template to the inner deserializer,
     /// allowing deserializers to read from and write to event metadata.
     pub fn with_metadata_template(mut self, metadata: EventMetadata) -> Self {
@@ -85,14 +100,28 @@ impl Decoder {
     pub fn deserializer_parse(&self, frame: Bytes) -> Result<DecodedFrame, Error> {
         let byte_size = frame.len();
 
+        // Keep a handle on the frame so it can be forwarded if it fails to parse.
+        let raw_frame = self.decode_errors.then(|| frame.clone());
+
         // Parse structured events from the byte frame.
-        self.deserializer
-            .parse(frame, self.log_namespace)
-            .map(|events| (events, byte_size))
-            .map_err(|error| {
+        match self.deserializer.parse(frame, self.log_namespace) {
+            Ok(events) => Ok((events, byte_size)),
+            Err(error) => {
                 emit(DecoderDeserializeError { error: &error });
-                Error::ParsingError(error)
-            })
+                match raw_frame {
+                    Some(frame) => {
+                        let event = decode_error_event(
+                            frame,
+                            &error.to_string(),
+                            self.framer.name(),
+                            self.deserializer.name(),
+                        );
+                        Ok((smallvec![event], byte_size))
+                    }
+                    None => Err(Error::ParsingError(error)),
+                }
+            }
+        }
     }
 }
 
@@ -152,4 +181,39 @@ mod tests {
         let event = next.unwrap().0.pop().unwrap().into_log();
         assert_eq!(event.get(event_path!("bar")).unwrap(), &Value::from(2));
     }
+
+    #[tokio::test]
+    async fn framed_read_keeps_decode_errors() {
+        let iter = stream::iter(
+            ["{ \"foo\": 1 }\n", "invalid\n"]
+                .into_iter()
+                .map(Bytes::from),
+        );
+        let stream = iter.map(Ok::<_, std::io::Error>);
+        let reader = StreamReader::new(stream);
+        let decoder = Decoder::new(
+            Framer::NewlineDelimited(NewlineDelimitedDecoder::new()),
+            Deserializer::Json(JsonDeserializer::default()),
+        )
+        .with_decode_errors(true);
+        let mut stream = DecoderFramedRead::new(reader, decoder);
+
+        let next = stream.next().await.unwrap();
+        let event = next.unwrap().0.pop().unwrap().into_log();
+        assert!(!event.metadata().is_decode_error());
+
+        let next = stream.next().await.unwrap();
+        let (mut events, byte_size) = next.unwrap();
+        assert_eq!(byte_size, 7);
+        let event = events.pop().unwrap().into_log();
+        assert!(event.metadata().is_decode_error());
+        assert_eq!(event["message"], Value::from("invalid"));
+        assert!(
+            event["error"]
+                .to_string_lossy()
+                .contains("Error parsing JSON")
+        );
+        assert_eq!(event["framing"], Value::from("newline_delimited"));
+        assert_eq!(event["codec"], Value::from("json"));
+    }
 }
@@ -12,14 +12,17 @@ use vector_core::{
 use vrl::value::KeyString;
 
 use super::Deserializer;
-use crate::encoding::AvroSerializerOptions;
+use crate::{
+    common::schema_registry::{
+        self, HEADER_LEN, MAGIC_BYTE, SchemaCache, SchemaReference, SchemaRegistryClient,
+        SchemaRegistryConfig,
+    },
+    encoding::AvroSerializerOptions,
+};
 
 type VrlValue = vrl::value::Value;
 type AvroValue = apache_avro::types::Value;
 
-const CONFLUENT_MAGIC_BYTE: u8 = 0;
-const CONFLUENT_SCHEMA_PREFIX_LEN: usize = 5;
-
 /// Config used to build a `AvroDeserializer`.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AvroDeserializerConfig {
@@ -34,18 +37,31 @@ impl AvroDeserializerConfig {
             avro_options: AvroDeserializerOptions {
                 schema,
                 strip_schema_id_prefix,
+                schema_registry: None,
             },
         }
     }
 
     /// Build the `AvroDeserializer` from this configuration.
     pub fn build(&self) -> vector_common::Result<AvroDeserializer> {
-        let schema = apache_avro::Schema::parse_str(&self.avro_options.schema)
-            .map_err(|error| format!("Failed building Avro serializer: {error}"))?;
+        let options = &self.avro_options;
+        // With a schema registry, the schema is only needed to resolve the writer's schema to.
+        let schema = if options.schema.is_empty() && options.schema_registry.is_some() {
+            None
+        } else {
+            let schema = apache_avro::Schema::parse_str(&options.schema)
+                .map_err(|error| format!("Failed building Avro serializer: {error}"))?;
+            Some(schema)
+        };
 
         Ok(AvroDeserializer {
             schema,
-            strip_schema_id_prefix: self.avro_options.strip_schema_id_prefix,
+            strip_schema_id_prefix: options.strip_schema_id_prefix,
+            registry: options
+                .schema_registry
+                .clone()
+                .map(AvroSchemaRegistry::new)
+                .transpose()?,
         })
     }
 
@@ -82,6 +98,7 @@ impl From<&AvroDeserializerOptions> for AvroSerializerOptions {
     fn from(value: &AvroDeserializerOptions) -> Self {
         Self {
             schema: value.schema.clone(),
+            schema_registry: None,
         }
     }
 }
@@ -90,6 +107,11 @@ impl From<&AvroDeserializerOptions> for AvroSerializerOptions {
 #[derive(Clone, Debug)]
 pub struct AvroDeserializerOptions {
     /// The Avro schema definition.
+    ///
+    /// When `schema_registry` is set, this is optional. If it's set, data written with any
+    /// compatible version of the schema is resolved to it, following Avro's schema evolution
+    /// rules. Otherwise, data is decoded with the schema it was written with.
+    ///
     /// **Note**: The following [`apache_avro::types::Value`] variants are *not* supported:
     /// * `Date`
     /// * `Decimal`
@@ -101,43 +123,56 @@ pub struct AvroDeserializerOptions {
         docs::additional_props_description = r#"Supports most avro data types, unsupported data types includes
         ["decimal", "duration", "local-timestamp-millis", "local-timestamp-micros"]"#,
     ))]
+    #[serde(default)]
     pub schema: String,
 
     /// For Avro datum encoded in Kafka messages, the bytes are prefixed with the schema ID.  Set this to `true` to strip the schema ID prefix, as described in [Confluent Kafka's documentation](https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format).
+    #[serde(default)]
     pub strip_schema_id_prefix: bool,
+
+    /// Fetches the schema that each datum was written with from a schema registry.
+    ///
+    /// Each datum must be in the [Confluent wire format][wire_format], which prefixes it with the
+    /// ID of its schema. Schemas are fetched the first time their ID is seen and cached after
+    /// that.
+    ///
+    /// [wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_registry: Option<SchemaRegistryConfig>,
 }
 
 /// Serializer that converts bytes to an `Event` using the Apache Avro format.
 #[derive(Debug, Clone)]
 pub struct AvroDeserializer {
-    schema: apache_avro::Schema,
+    schema: Option<apache_avro::Schema>,
     strip_schema_id_prefix: bool,
+    registry: Option<AvroSchemaRegistry>,
 }
 
 impl AvroDeserializer {
     /// Creates a new `AvroDeserializer`.
     pub const fn new(schema: apache_avro::Schema, strip_schema_id_prefix: bool) -> Self {
         Self {
-            schema,
+            schema: Some(schema),
             strip_schema_id_prefix,
+            registry: None,
         }
     }
-}
 
-impl Deserializer for AvroDeserializer {
-    fn parse(
-        &self,
-        bytes: Bytes,
-        log_namespace: LogNamespace,
-    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
-        // Avro has a `null` type which indicates no value.
-        if bytes.is_empty() {
-            return Ok(smallvec![]);
+    fn decode(&self, bytes: Bytes) -> vector_common::Result<AvroValue> {
+        if let Some(registry) = &self.registry {
+            let (id, datum) = schema_registry::read_header(bytes)?;
+            let writer_schema = registry.schema(id)?;
+            return Ok(apache_avro::from_avro_datum(
+                &writer_schema,
+                &mut datum.reader(),
+                self.schema.as_ref(),
+            )?);
         }
 
         let bytes = if self.strip_schema_id_prefix {
-            if bytes.len() >= CONFLUENT_SCHEMA_PREFIX_LEN && bytes[0] == CONFLUENT_MAGIC_BYTE {
-                bytes.slice(CONFLUENT_SCHEMA_PREFIX_LEN..)
+            if bytes.len() >= HEADER_LEN && bytes[0] == MAGIC_BYTE {
+                bytes.slice(HEADER_LEN..)
             } else {
                 return Err(vector_common::Error::from(
                     "Expected avro datum to be prefixed with schema id",
@@ -147,7 +182,82 @@ impl Deserializer for AvroDeserializer {
             bytes
         };
 
-        let value = apache_avro::from_avro_datum(&self.schema, &mut bytes.reader(), None)?;
+        let schema = self.schema.as_ref().ok_or("Avro schema is not set")?;
+        Ok(apache_avro::from_avro_datum(
+            schema,
+            &mut bytes.reader(),
+            None,
+        )?)
+    }
+}
+
+/// Fetches and caches the schemas that data was written with.
+#[derive(Debug, Clone)]
+struct AvroSchemaRegistry {
+    client: SchemaRegistryClient,
+    schemas: SchemaCache<apache_avro::Schema>,
+}
+
+impl AvroSchemaRegistry {
+    fn new(config: SchemaRegistryConfig) -> vector_common::Result<Self> {
+        Ok(Self {
+            client: SchemaRegistryClient::new(config)?,
+            schemas: SchemaCache::default(),
+        })
+    }
+
+    fn schema(&self, id: u32) -> vector_common::Result<std::sync::Arc<apache_avro::Schema>> {
+        self.schemas.get_or_try_insert(id, || {
+            let registered = self.client.schema_by_id(id, false)?;
+            registered.expect_type("AVRO")?;
+            if registered.references.is_empty() {
+                return Ok(apache_avro::Schema::parse_str(&registered.schema)?);
+            }
+
+            // Named types from referenced schemas must be parsed along with the schema itself.
+            let mut schemas = Vec::new();
+            self.referenced_schemas(&registered.references, &mut schemas)?;
+            schemas.push(registered.schema);
+            let schemas = schemas.iter().map(String::as_str).collect::<Vec<_>>();
+            apache_avro::Schema::parse_list(&schemas)?
+                .pop()
+                .ok_or_else(|| format!("Schema {id} is empty").into())
+        })
+    }
+
+    fn referenced_schemas(
+        &self,
+        references: &[SchemaReference],
+        schemas: &mut Vec<String>,
+    ) -> vector_common::Result<()> {
+        for reference in references {
+            let registered = self.client.subject_version(
+                &reference.subject,
+                &reference.version.to_string(),
+                false,
+            )?;
+            // Referenced schemas come before the schemas that use them.
+            self.referenced_schemas(&registered.references, schemas)?;
+            if !schemas.contains(&registered.schema) {
+                schemas.push(registered.schema);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Deserializer for AvroDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        // Avro has a `null` type which indicates no value.
+        if bytes.is_empty() {
+            return Ok(smallvec![]);
+        }
+
+        let value = self.decode(bytes)?;
 
         let apache_avro::types::Value::Record(fields) = value else {
             return Err(vector_common::Error::from("Expected an avro Record"));
@@ -321,6 +431,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_avro_schema_registry() {
+        // The writer's schema has gained a field with a default since the reader's schema.
+        let writer_schema = r#"{
+            "type": "record",
+            "name": "log",
+            "fields": [
+                { "name": "message", "type": "string" },
+                { "name": "level", "type": "string", "default": "info" }
+            ]
+        }"#;
+        let url = schema_registry::tests::mock_registry(vec![
+            serde_json::json!({ "schema": writer_schema }).to_string(),
+        ]);
+
+        let schema = Schema::parse_str(writer_schema).unwrap();
+        let mut record = apache_avro::types::Record::new(&schema).unwrap();
+        record.put("message", "hello from avro");
+        record.put("level", "warn");
+        let datum = apache_avro::to_avro_datum(&schema, record).unwrap();
+
+        let mut config = AvroDeserializerConfig::new(String::new(), false);
+        config.avro_options.schema_registry = Some(schema_registry::tests::registry_config(url));
+        let deserializer = config.build().unwrap();
+
+        // The schema is only fetched once.
+        for _ in 0..2 {
+            let mut bytes = BytesMut::new();
+            schema_registry::write_header(7, &mut bytes);
+            bytes.extend(&datum);
+
+            let events = deserializer
+                .parse(bytes.freeze(), LogNamespace::Vector)
+                .unwrap();
+            let log = events[0].as_log();
+            assert_eq!(log["message"], VrlValue::from("hello from avro"));
+            assert_eq!(log["level"], VrlValue::from("warn"));
+        }
+
+        // A reader's schema without the new field drops it.
+        let url = schema_registry::tests::mock_registry(vec![
+            serde_json::json!({ "schema": writer_schema }).to_string(),
+        ]);
+        let mut config = AvroDeserializerConfig::new(get_schema().canonical_form(), false);
+        config.avro_options.schema_registry = Some(schema_registry::tests::registry_config(url));
+        let deserializer = config.build().unwrap();
+
+        let mut bytes = BytesMut::new();
+        schema_registry::write_header(7, &mut bytes);
+        bytes.extend(&datum);
+        let events = deserializer
+            .parse(bytes.freeze(), LogNamespace::Vector)
+            .unwrap();
+        let log = events[0].as_log();
+        assert_eq!(log["message"], VrlValue::from("hello from avro"));
+        assert!(!log.contains(event_path!("level")));
+    }
+
     #[test]
     fn deserialize_avro_uuid() {
         let schema = get_schema();
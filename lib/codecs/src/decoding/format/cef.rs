@@ -0,0 +1,389 @@
+use std::borrow::Cow;
+
+use bytes::Bytes;
+use chrono::Utc;
+use derivative::Derivative;
+use lookup::owned_value_path;
+use smallvec::{SmallVec, smallvec};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{DataType, LogNamespace, log_schema},
+    event::{Event, LogEvent, ObjectMap, Value},
+    schema,
+};
+use vrl::value::{Kind, kind::Collection};
+
+use super::{Deserializer, default_lossy};
+
+/// The field the CEF header fields are decoded into.
+const HEADER_FIELD: &str = "cef";
+/// The names of the header fields, in the order they appear in a message.
+const HEADER_KEYS: [&str; 7] = [
+    "version",
+    "device_vendor",
+    "device_product",
+    "device_version",
+    "device_event_class_id",
+    "name",
+    "severity",
+];
+
+/// Config used to build a `CefDeserializer`.
+#[configurable_component]
+#[derive(Debug, Clone, Default)]
+pub struct CefDeserializerConfig {
+    /// CEF-specific decoding options.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub cef: CefDeserializerOptions,
+}
+
+impl CefDeserializerConfig {
+    /// Creates a new `CefDeserializerConfig`.
+    pub fn new(options: CefDeserializerOptions) -> Self {
+        Self { cef: options }
+    }
+
+    /// Build the `CefDeserializer` from this configuration.
+    pub fn build(&self) -> CefDeserializer {
+        Into::<CefDeserializer>::into(self)
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        let mut definition = schema::Definition::new_with_default_metadata(
+            Kind::object(Collection::empty()),
+            [log_namespace],
+        )
+        .with_event_field(
+            &owned_value_path!(HEADER_FIELD, "version"),
+            Kind::bytes(),
+            None,
+        )
+        .with_event_field(
+            &owned_value_path!(HEADER_FIELD, "device_vendor"),
+            Kind::bytes(),
+            None,
+        )
+        .with_event_field(
+            &owned_value_path!(HEADER_FIELD, "device_product"),
+            Kind::bytes(),
+            None,
+        )
+        .with_event_field(
+            &owned_value_path!(HEADER_FIELD, "device_version"),
+            Kind::bytes(),
+            None,
+        )
+        .with_event_field(
+            &owned_value_path!(HEADER_FIELD, "device_event_class_id"),
+            Kind::bytes(),
+            None,
+        )
+        .with_event_field(
+            &owned_value_path!(HEADER_FIELD, "name"),
+            Kind::bytes(),
+            None,
+        )
+        .with_event_field(
+            &owned_value_path!(HEADER_FIELD, "severity"),
+            Kind::integer().or_bytes(),
+            None,
+        )
+        // Extensions are decoded into the root of the event.
+        .unknown_fields(Kind::bytes());
+
+        if log_namespace == LogNamespace::Legacy
+            && let Some(timestamp_key) = log_schema().timestamp_key()
+        {
+            definition = definition.try_with_field(
+                timestamp_key,
+                // The timestamp is only inserted if there's no extension with the same name.
+                Kind::bytes().or_timestamp(),
+                Some("timestamp"),
+            );
+        }
+
+        definition
+    }
+}
+
+/// CEF-specific decoding options.
+#[configurable_component]
+#[derive(Debug, Clone, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub struct CefDeserializerOptions {
+    /// Determines whether to replace invalid UTF-8 sequences instead of failing.
+    ///
+    /// When true, invalid UTF-8 sequences are replaced with the [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// [U+FFFD]: https://en.wikipedia.org/wiki/Specials_(Unicode_block)#Replacement_character
+    #[serde(
+        default = "default_lossy",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_lossy()"))]
+    pub lossy: bool,
+}
+
+/// Deserializer that builds an `Event` from a byte frame containing a CEF message.
+///
+/// The header fields are decoded into the `cef` object, using the same names as the options of
+/// the CEF encoder, and the extensions are decoded into the root of the event. Any text before
+/// `CEF:`, such as a syslog header, is ignored.
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct CefDeserializer {
+    #[derivative(Default(value = "default_lossy()"))]
+    lossy: bool,
+}
+
+impl CefDeserializer {
+    /// Creates a new `CefDeserializer`.
+    pub fn new(lossy: bool) -> Self {
+        Self { lossy }
+    }
+}
+
+impl Deserializer for CefDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let line: Cow<str> = match self.lossy {
+            true => String::from_utf8_lossy(&bytes),
+            false => Cow::from(std::str::from_utf8(&bytes)?),
+        };
+        let line = line.trim_end_matches(['\r', '\n']);
+
+        // Empty lines are common in newline delimited input, so they're skipped without a
+        // warning.
+        if line.trim().is_empty() {
+            return Ok(smallvec![]);
+        }
+
+        let (header, mut fields) =
+            parse_cef(line).map_err(|error| format!("Error parsing CEF: {error}"))?;
+        fields.insert(HEADER_FIELD.into(), Value::Object(header));
+
+        let mut log = LogEvent::from(Value::Object(fields));
+
+        if log_namespace == LogNamespace::Legacy
+            && let Some(timestamp_key) = log_schema().timestamp_key_target_path()
+            && !log.contains(timestamp_key)
+        {
+            log.insert(timestamp_key, Utc::now());
+        }
+
+        Ok(smallvec![Event::Log(log)])
+    }
+}
+
+impl From<&CefDeserializerConfig> for CefDeserializer {
+    fn from(config: &CefDeserializerConfig) -> Self {
+        Self {
+            lossy: config.cef.lossy,
+        }
+    }
+}
+
+/// Parses a CEF message into its header fields and extensions.
+///
+/// `CEF:{version}|{device_vendor}|{device_product}|{device_version}|{device_event_class_id}|{name}|{severity}|{extensions}`
+fn parse_cef(line: &str) -> Result<(ObjectMap, ObjectMap), String> {
+    let start = line.find("CEF:").ok_or("missing \"CEF:\" prefix")?;
+    let mut rest = &line[start + "CEF:".len()..];
+
+    let mut header = ObjectMap::new();
+    for (index, key) in HEADER_KEYS.iter().enumerate() {
+        let (field, remainder) = match find_unescaped(rest, '|') {
+            Some(end) => (&rest[..end], Some(&rest[end + 1..])),
+            // The extensions, and the separator before them, are optional.
+            None if index == HEADER_KEYS.len() - 1 => (rest, None),
+            None => return Err(format!("missing header field {key:?}")),
+        };
+
+        let field = unescape(field, &['|', '\\']);
+        let value = match *key {
+            "severity" => field
+                .trim()
+                .parse::<i64>()
+                .map_or_else(|_| Value::from(field.trim()), Value::from),
+            _ => Value::from(field),
+        };
+        header.insert((*key).into(), value);
+
+        rest = remainder.unwrap_or_default();
+    }
+
+    Ok((header, parse_extensions(rest)?))
+}
+
+/// Parses the space separated `key=value` extensions of a CEF message.
+///
+/// Values may contain spaces, so a value ends where the next key begins. An `=` in a value is
+/// meant to be escaped, but it's common for it not to be, so an `=` is only taken to start a new
+/// extension if it follows something that looks like a key.
+fn parse_extensions(extensions: &str) -> Result<ObjectMap, String> {
+    let extensions = extensions.trim();
+    let mut fields = ObjectMap::new();
+    if extensions.is_empty() {
+        return Ok(fields);
+    }
+
+    // The position of each key, and of the `=` following it.
+    let mut keys = Vec::new();
+    let mut escaped = false;
+    for (i, c) in extensions.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '=' => {
+                let key_start = extensions[..i].rfind(' ').map_or(0, |space| space + 1);
+                let key = &extensions[key_start..i];
+                if !key.is_empty() && key.chars().all(is_extension_key_char) {
+                    keys.push((key_start, i));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    match keys.first() {
+        Some((0, _)) => {}
+        _ => return Err("extensions must start with a key".to_string()),
+    }
+
+    for (index, &(key_start, equals)) in keys.iter().enumerate() {
+        let value_end = keys
+            .get(index + 1)
+            .map_or(extensions.len(), |&(next_key_start, _)| next_key_start);
+        let value = extensions[equals + 1..value_end].trim_end_matches(' ');
+        fields.insert(
+            extensions[key_start..equals].into(),
+            Value::from(unescape(value, &['=', '\\'])),
+        );
+    }
+
+    Ok(fields)
+}
+
+const fn is_extension_key_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-' | '[' | ']')
+}
+
+/// Finds the first occurrence of `needle` that isn't escaped with a backslash.
+fn find_unescaped(s: &str, needle: char) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == needle => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Removes the backslash from the escaped `chars`, and turns `\n` and `\r` into line breaks.
+fn unescape(s: &str, chars: &[char]) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut iter = s.chars();
+    while let Some(c) = iter.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match iter.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(c) if chars.contains(&c) => unescaped.push(c),
+            Some(c) => {
+                unescaped.push('\\');
+                unescaped.push(c);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_core::config::log_schema;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_cef() {
+        let input = Bytes::from(
+            r"<134>Feb 14 19:04:54 host CEF:0|Security|threat\|manager|1.0|100|worm successfully stopped|10|src=10.0.0.1 dst=2.1.2.2 msg=Detected a threat. No action needed. eq=a\=b path=C:\\Windows request=http://example.com/?a=b",
+        );
+        let deserializer = CefDeserializer::default();
+
+        for namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+            let events = deserializer.parse(input.clone(), namespace).unwrap();
+            assert_eq!(events.len(), 1);
+
+            let log = events[0].as_log();
+            assert_eq!(log["cef.version"], "0".into());
+            assert_eq!(log["cef.device_vendor"], "Security".into());
+            assert_eq!(log["cef.device_product"], "threat|manager".into());
+            assert_eq!(log["cef.device_version"], "1.0".into());
+            assert_eq!(log["cef.device_event_class_id"], "100".into());
+            assert_eq!(log["cef.name"], "worm successfully stopped".into());
+            assert_eq!(log["cef.severity"], 10.into());
+            assert_eq!(log["src"], "10.0.0.1".into());
+            assert_eq!(log["dst"], "2.1.2.2".into());
+            assert_eq!(log["msg"], "Detected a threat. No action needed.".into());
+            assert_eq!(log["eq"], "a=b".into());
+            assert_eq!(log["path"], r"C:\Windows".into());
+            assert_eq!(log["request"], "http://example.com/?a=b".into());
+            assert_eq!(
+                log.get((
+                    lookup::PathPrefix::Event,
+                    log_schema().timestamp_key().unwrap()
+                ))
+                .is_some(),
+                namespace == LogNamespace::Legacy
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_cef_without_extensions() {
+        let input = Bytes::from("CEF:1|Datadog|Vector|0|Telemetry Event|Event name|High");
+        let deserializer = CefDeserializer::default();
+
+        let events = deserializer.parse(input, LogNamespace::Vector).unwrap();
+        let log = events[0].as_log();
+        assert_eq!(log["cef.version"], "1".into());
+        assert_eq!(log["cef.name"], "Event name".into());
+        assert_eq!(log["cef.severity"], "High".into());
+        assert_eq!(log.value().as_object().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn deserialize_cef_errors() {
+        let deserializer = CefDeserializer::default();
+
+        for input in [
+            "not a CEF message",
+            "CEF:0|Datadog|Vector|0",
+            "CEF:0|Datadog|Vector|0|100|name|1|no key here",
+        ] {
+            assert!(
+                deserializer
+                    .parse(Bytes::from(input), LogNamespace::Vector)
+                    .is_err(),
+                "{input}"
+            );
+        }
+    }
+}
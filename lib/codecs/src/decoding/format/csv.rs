@@ -0,0 +1,490 @@
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use chrono::Utc;
+use derivative::Derivative;
+use smallvec::{SmallVec, smallvec};
+use vector_common::internal_event::emit;
+use vector_config::configurable_component;
+use vector_core::{
+    config::{DataType, LogNamespace, log_schema},
+    event::{Event, LogEvent, ObjectMap, Value},
+    schema,
+};
+use vrl::{
+    compiler::{TimeZone, conversion::Conversion},
+    value::{Kind, kind::Collection},
+};
+
+use super::Deserializer;
+use crate::internal_events::DecoderDeserializeError;
+
+/// Config used to build a `CsvDeserializer`.
+#[configurable_component]
+#[derive(Debug, Clone, Default)]
+pub struct CsvDeserializerConfig {
+    /// CSV-specific decoding options.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub csv: CsvDeserializerOptions,
+}
+
+impl CsvDeserializerConfig {
+    /// Creates a new `CsvDeserializerConfig`.
+    pub fn new(options: CsvDeserializerOptions) -> Self {
+        Self { csv: options }
+    }
+
+    /// Build the `CsvDeserializer` from this configuration.
+    pub fn build(&self) -> vector_common::Result<CsvDeserializer> {
+        let timezone = self.csv.timezone.unwrap_or(TimeZone::Local);
+        let types = self
+            .csv
+            .types
+            .iter()
+            .map(|(column, name)| {
+                Conversion::parse(name, timezone)
+                    .map(|conversion| (column.clone(), conversion))
+                    .map_err(|error| format!("Invalid type for CSV column {column:?}: {error}"))
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+
+        Ok(CsvDeserializer {
+            delimiter: self.csv.delimiter,
+            quote: self.csv.quote,
+            escape: self.csv.escape,
+            double_quote: self.csv.double_quote,
+            columns: self.csv.columns.clone(),
+            types,
+        })
+    }
+
+    /// Returns `true` when the column names are read from a header row.
+    pub fn has_header_row(&self) -> bool {
+        self.csv.columns.is_empty()
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        let field_kind = self.field_kind();
+
+        match log_namespace {
+            LogNamespace::Legacy => {
+                let mut definition =
+                    schema::Definition::empty_legacy_namespace().unknown_fields(field_kind.clone());
+
+                if let Some(timestamp_key) = log_schema().timestamp_key() {
+                    definition = definition.try_with_field(
+                        timestamp_key,
+                        // A column may have the same name as the timestamp key, in which case the
+                        // timestamp is not inserted.
+                        field_kind.or_timestamp(),
+                        Some("timestamp"),
+                    );
+                }
+                definition
+            }
+            LogNamespace::Vector => schema::Definition::new_with_default_metadata(
+                Kind::object(Collection::from_unknown(field_kind)),
+                [log_namespace],
+            ),
+        }
+    }
+
+    /// The kind of the values of the decoded columns.
+    fn field_kind(&self) -> Kind {
+        self.csv
+            .types
+            .values()
+            .fold(Kind::bytes(), |kind, name| match name.as_str() {
+                "int" | "integer" => kind.or_integer().or_null(),
+                "float" => kind.or_float().or_null(),
+                "bool" | "boolean" => kind.or_boolean().or_null(),
+                name if name.starts_with("timestamp") => kind.or_timestamp().or_null(),
+                _ => kind,
+            })
+    }
+}
+
+/// CSV-specific decoding options.
+#[configurable_component]
+#[derive(Debug, Clone, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub struct CsvDeserializerOptions {
+    /// The names of the columns, in the order they appear in each row.
+    ///
+    /// When empty, the first row of each frame is treated as a header row holding the column
+    /// names, and every following row in the frame is decoded into an event. As the header row
+    /// must be in the same frame as the rows it describes, this requires a framing method that
+    /// keeps a whole CSV document in a single frame, such as `bytes`.
+    ///
+    /// When set, every row is decoded into an event, so a framing method that splits the input
+    /// into lines, such as `newline_delimited`, can be used. A header row in the input is then
+    /// decoded like any other row.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    #[configurable(metadata(docs::examples = "timestamp", docs::examples = "message"))]
+    pub columns: Vec<String>,
+
+    /// The types to convert the values of columns to.
+    ///
+    /// Columns without a type are decoded as strings. The value of a typed column that is
+    /// empty is decoded as `null`.
+    ///
+    /// The supported types are `string`, `integer`, `float`, `boolean`, `timestamp` (parsed as
+    /// RFC 3339), and `timestamp|<format>` (parsed with the given [`strptime` format][strptime]).
+    ///
+    /// [strptime]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html#specifiers
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    #[configurable(metadata(
+        docs::additional_props_description = "The type to convert the column's values to.",
+        docs::examples = "example_types()"
+    ))]
+    pub types: HashMap<String, String>,
+
+    /// The name of the timezone to apply to timestamp columns that do not contain an explicit
+    /// time zone. The time zone name may be any name in the [TZ database][tz_database], or `local`
+    /// to indicate system local time.
+    ///
+    /// If not set, `local` is used.
+    ///
+    /// [tz_database]: https://en.wikipedia.org/wiki/List_of_tz_database_time_zones
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<TimeZone>,
+
+    /// The field delimiter to use when reading CSV.
+    #[configurable(metadata(docs::type_override = "ascii_char"))]
+    #[serde(
+        default = "default_delimiter",
+        with = "vector_core::serde::ascii_char",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_delimiter()"))]
+    pub delimiter: u8,
+
+    /// The quote character to use when reading CSV.
+    #[configurable(metadata(docs::type_override = "ascii_char"))]
+    #[serde(
+        default = "default_quote",
+        with = "vector_core::serde::ascii_char",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_quote()"))]
+    pub quote: u8,
+
+    /// Enables double quote escapes.
+    ///
+    /// This is enabled by default, so two consecutive quotes within a quoted field are read as
+    /// a single quote. When disabled, quotes in field data are expected to be escaped with the
+    /// `escape` character instead.
+    #[serde(
+        default = "default_double_quote",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_double_quote()"))]
+    pub double_quote: bool,
+
+    /// The escape character to use when reading CSV.
+    ///
+    /// In some variants of CSV, quotes are escaped using a special escape character
+    /// like \ (instead of escaping quotes by doubling them).
+    ///
+    /// To use this, `double_quote` needs to be disabled as well; otherwise, this setting is ignored.
+    #[configurable(metadata(docs::type_override = "ascii_char"))]
+    #[serde(
+        default = "default_escape",
+        with = "vector_core::serde::ascii_char",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_escape()"))]
+    pub escape: u8,
+}
+
+const fn default_delimiter() -> u8 {
+    b','
+}
+
+const fn default_quote() -> u8 {
+    b'"'
+}
+
+const fn default_double_quote() -> bool {
+    true
+}
+
+const fn default_escape() -> u8 {
+    b'"'
+}
+
+fn example_types() -> HashMap<String, String> {
+    HashMap::<_, _>::from_iter([
+        ("status".to_string(), "integer".to_string()),
+        ("duration".to_string(), "float".to_string()),
+        ("success".to_string(), "boolean".to_string()),
+        ("timestamp".to_string(), "timestamp|%F %T".to_string()),
+    ])
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing CSV.
+#[derive(Debug, Clone)]
+pub struct CsvDeserializer {
+    delimiter: u8,
+    quote: u8,
+    escape: u8,
+    double_quote: bool,
+    columns: Vec<String>,
+    types: HashMap<String, Conversion>,
+}
+
+impl CsvDeserializer {
+    fn reader<'a>(&self, bytes: &'a [u8]) -> ::csv::Reader<&'a [u8]> {
+        ::csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .double_quote(self.double_quote)
+            .escape((!self.double_quote).then_some(self.escape))
+            .from_reader(bytes)
+    }
+
+    fn decode_row(
+        &self,
+        columns: &[String],
+        row: &::csv::StringRecord,
+    ) -> vector_common::Result<ObjectMap> {
+        if row.len() != columns.len() {
+            return Err(format!(
+                "Expected {} CSV fields, found {}.",
+                columns.len(),
+                row.len()
+            )
+            .into());
+        }
+
+        columns
+            .iter()
+            .zip(row.iter())
+            .map(|(column, field)| {
+                let value = match self.types.get(column) {
+                    Some(_) if field.is_empty() => Value::Null,
+                    Some(conversion) => conversion
+                        .convert(Bytes::copy_from_slice(field.as_bytes()))
+                        .map_err(|error| {
+                            format!("Invalid value for CSV column {column:?}: {error}")
+                        })?,
+                    None => Value::Bytes(Bytes::copy_from_slice(field.as_bytes())),
+                };
+                Ok((column.as_str().into(), value))
+            })
+            .collect()
+    }
+}
+
+impl Deserializer for CsvDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        // Empty frames are common when the input ends with a newline, so they're skipped without
+        // a warning.
+        if bytes.is_empty() {
+            return Ok(smallvec![]);
+        }
+
+        let mut reader = self.reader(&bytes);
+        let mut records = reader.records();
+
+        let header;
+        let columns = if self.columns.is_empty() {
+            header = match records.next() {
+                Some(record) => record?.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                None => return Ok(smallvec![]),
+            };
+            &header
+        } else {
+            &self.columns
+        };
+
+        // A row that can't be decoded is reported and skipped, so it doesn't drop the other rows
+        // of the frame.
+        let mut events = records
+            .filter_map(|record| {
+                match record
+                    .map_err(Into::into)
+                    .and_then(|record| self.decode_row(columns, &record))
+                {
+                    Ok(map) => Some(Event::Log(LogEvent::from(Value::Object(map)))),
+                    Err(error) => {
+                        emit(DecoderDeserializeError { error: &error });
+                        None
+                    }
+                }
+            })
+            .collect::<SmallVec<[Event; 1]>>();
+
+        if log_namespace == LogNamespace::Legacy {
+            let timestamp = Utc::now();
+
+            if let Some(timestamp_key) = log_schema().timestamp_key_target_path() {
+                for event in &mut events {
+                    let log = event.as_mut_log();
+                    if !log.contains(timestamp_key) {
+                        log.insert(timestamp_key, timestamp);
+                    }
+                }
+            }
+        }
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_core::config::log_schema;
+
+    use super::*;
+
+    fn deserializer(options: CsvDeserializerOptions) -> CsvDeserializer {
+        CsvDeserializerConfig::new(options).build().unwrap()
+    }
+
+    #[test]
+    fn deserialize_csv_with_header_row() {
+        let input = Bytes::from("host,message\nfoo,\"hello, world\"\nbar,bye\n");
+        let deserializer = deserializer(CsvDeserializerOptions::default());
+
+        for namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+            let events = deserializer.parse(input.clone(), namespace).unwrap();
+            assert_eq!(events.len(), 2);
+
+            let log = events[0].as_log();
+            assert_eq!(log["host"], "foo".into());
+            assert_eq!(log["message"], "hello, world".into());
+            assert_eq!(
+                log.get((
+                    lookup::PathPrefix::Event,
+                    log_schema().timestamp_key().unwrap()
+                ))
+                .is_some(),
+                namespace == LogNamespace::Legacy
+            );
+
+            let log = events[1].as_log();
+            assert_eq!(log["host"], "bar".into());
+            assert_eq!(log["message"], "bye".into());
+        }
+    }
+
+    #[test]
+    fn deserialize_csv_header_only() {
+        let input = Bytes::from("host,message\n");
+        let deserializer = deserializer(CsvDeserializerOptions::default());
+
+        let events = deserializer.parse(input, LogNamespace::Vector).unwrap();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn deserialize_csv_with_columns() {
+        let input = Bytes::from("foo;'it''s'");
+        let deserializer = deserializer(CsvDeserializerOptions {
+            columns: vec!["host".to_string(), "message".to_string()],
+            delimiter: b';',
+            quote: b'\'',
+            ..Default::default()
+        });
+
+        let events = deserializer.parse(input, LogNamespace::Vector).unwrap();
+        assert_eq!(events.len(), 1);
+
+        let log = events[0].as_log();
+        assert_eq!(log["host"], "foo".into());
+        assert_eq!(log["message"], "it's".into());
+    }
+
+    #[test]
+    fn deserialize_csv_with_escape() {
+        let input = Bytes::from(r#""say \"hi\"""#);
+        let deserializer = deserializer(CsvDeserializerOptions {
+            columns: vec!["message".to_string()],
+            double_quote: false,
+            escape: b'\\',
+            ..Default::default()
+        });
+
+        let events = deserializer.parse(input, LogNamespace::Vector).unwrap();
+        assert_eq!(events[0].as_log()["message"], r#"say "hi""#.into());
+    }
+
+    #[test]
+    fn deserialize_csv_with_types() {
+        let input = Bytes::from("status,duration,success,note\n200,1.5,true,ok\n,,,\n");
+        let deserializer = deserializer(CsvDeserializerOptions {
+            types: HashMap::from_iter([
+                ("status".to_string(), "integer".to_string()),
+                ("duration".to_string(), "float".to_string()),
+                ("success".to_string(), "bool".to_string()),
+            ]),
+            ..Default::default()
+        });
+
+        let events = deserializer.parse(input, LogNamespace::Vector).unwrap();
+        assert_eq!(events.len(), 2);
+
+        let log = events[0].as_log();
+        assert_eq!(log["status"], 200.into());
+        assert_eq!(log["duration"], 1.5.into());
+        assert_eq!(log["success"], true.into());
+        assert_eq!(log["note"], "ok".into());
+
+        let log = events[1].as_log();
+        assert_eq!(log["status"], Value::Null);
+        assert_eq!(log["duration"], Value::Null);
+        assert_eq!(log["success"], Value::Null);
+        assert_eq!(log["note"], "".into());
+    }
+
+    #[test]
+    fn deserialize_csv_invalid_value() {
+        let input = Bytes::from("status\n200\nnope\n404\n");
+        let deserializer = deserializer(CsvDeserializerOptions {
+            types: HashMap::from_iter([("status".to_string(), "integer".to_string())]),
+            ..Default::default()
+        });
+
+        let events = deserializer.parse(input, LogNamespace::Vector).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].as_log()["status"], 200.into());
+        assert_eq!(events[1].as_log()["status"], 404.into());
+    }
+
+    #[test]
+    fn deserialize_csv_wrong_field_count() {
+        let input = Bytes::from("a,b,c\nd,e\n");
+        let deserializer = deserializer(CsvDeserializerOptions {
+            columns: vec!["a".to_string(), "b".to_string()],
+            ..Default::default()
+        });
+
+        let events = deserializer.parse(input, LogNamespace::Vector).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].as_log()["a"], "d".into());
+        assert_eq!(events[0].as_log()["b"], "e".into());
+    }
+
+    #[test]
+    fn build_with_invalid_type() {
+        let config = CsvDeserializerConfig::new(CsvDeserializerOptions {
+            types: HashMap::from_iter([("status".to_string(), "nope".to_string())]),
+            ..Default::default()
+        });
+
+        assert!(config.build().is_err());
+    }
+}
@@ -0,0 +1,277 @@
+use std::borrow::Cow;
+
+use bytes::Bytes;
+use chrono::Utc;
+use derivative::Derivative;
+use smallvec::{SmallVec, smallvec};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{DataType, LogNamespace, log_schema},
+    event::{Event, LogEvent, ObjectMap, Value},
+    schema,
+};
+use vrl::value::{Kind, kind::Collection};
+
+use super::{Deserializer, default_lossy};
+
+/// Config used to build a `LogfmtDeserializer`.
+#[configurable_component]
+#[derive(Debug, Clone, Default)]
+pub struct LogfmtDeserializerConfig {
+    /// Logfmt-specific decoding options.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub logfmt: LogfmtDeserializerOptions,
+}
+
+impl LogfmtDeserializerConfig {
+    /// Creates a new `LogfmtDeserializerConfig`.
+    pub fn new(options: LogfmtDeserializerOptions) -> Self {
+        Self { logfmt: options }
+    }
+
+    /// Build the `LogfmtDeserializer` from this configuration.
+    pub fn build(&self) -> LogfmtDeserializer {
+        Into::<LogfmtDeserializer>::into(self)
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        // Keys without a value are decoded as `true`.
+        let field_kind = Kind::bytes().or_boolean();
+
+        match log_namespace {
+            LogNamespace::Legacy => {
+                let mut definition =
+                    schema::Definition::empty_legacy_namespace().unknown_fields(field_kind.clone());
+
+                if let Some(timestamp_key) = log_schema().timestamp_key() {
+                    definition = definition.try_with_field(
+                        timestamp_key,
+                        // The timestamp is only inserted if the message doesn't have a key with
+                        // the same name.
+                        field_kind.or_timestamp(),
+                        Some("timestamp"),
+                    );
+                }
+                definition
+            }
+            LogNamespace::Vector => schema::Definition::new_with_default_metadata(
+                Kind::object(Collection::from_unknown(field_kind)),
+                [log_namespace],
+            ),
+        }
+    }
+}
+
+/// Logfmt-specific decoding options.
+#[configurable_component]
+#[derive(Debug, Clone, PartialEq, Eq, Derivative)]
+#[derivative(Default)]
+pub struct LogfmtDeserializerOptions {
+    /// Determines whether to replace invalid UTF-8 sequences instead of failing.
+    ///
+    /// When true, invalid UTF-8 sequences are replaced with the [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// [U+FFFD]: https://en.wikipedia.org/wiki/Specials_(Unicode_block)#Replacement_character
+    #[serde(
+        default = "default_lossy",
+        skip_serializing_if = "vector_core::serde::is_default"
+    )]
+    #[derivative(Default(value = "default_lossy()"))]
+    pub lossy: bool,
+}
+
+/// Deserializer that builds an `Event` from a byte frame containing a [logfmt][logfmt] message.
+///
+/// Every key becomes a field of the event. Keys are used as-is, so a key such as `a.b` produces a
+/// field named `a.b` rather than a nested object. Keys without a value are decoded as `true`,
+/// matching how the logfmt encoder writes boolean values.
+///
+/// [logfmt]: https://brandur.org/logfmt
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default)]
+pub struct LogfmtDeserializer {
+    #[derivative(Default(value = "default_lossy()"))]
+    lossy: bool,
+}
+
+impl LogfmtDeserializer {
+    /// Creates a new `LogfmtDeserializer`.
+    pub fn new(lossy: bool) -> Self {
+        Self { lossy }
+    }
+}
+
+impl Deserializer for LogfmtDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let line: Cow<str> = match self.lossy {
+            true => String::from_utf8_lossy(&bytes),
+            false => Cow::from(std::str::from_utf8(&bytes)?),
+        };
+
+        // Empty lines are common in newline delimited input, so they're skipped without a
+        // warning.
+        if line.trim().is_empty() {
+            return Ok(smallvec![]);
+        }
+
+        let fields =
+            parse_logfmt(&line).map_err(|error| format!("Error parsing logfmt: {error}"))?;
+        let mut log = LogEvent::from(Value::Object(fields));
+
+        if log_namespace == LogNamespace::Legacy
+            && let Some(timestamp_key) = log_schema().timestamp_key_target_path()
+            && !log.contains(timestamp_key)
+        {
+            log.insert(timestamp_key, Utc::now());
+        }
+
+        Ok(smallvec![Event::Log(log)])
+    }
+}
+
+impl From<&LogfmtDeserializerConfig> for LogfmtDeserializer {
+    fn from(config: &LogfmtDeserializerConfig) -> Self {
+        Self {
+            lossy: config.logfmt.lossy,
+        }
+    }
+}
+
+/// Parses a line of `key=value` pairs separated by whitespace.
+///
+/// Values containing whitespace are enclosed in double quotes, in which `\"` and `\\` are used to
+/// escape quotes and backslashes.
+fn parse_logfmt(line: &str) -> Result<ObjectMap, String> {
+    let mut fields = ObjectMap::new();
+    let mut chars = line.char_indices().peekable();
+
+    loop {
+        while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+        let Some(&(start, _)) = chars.peek() else {
+            break;
+        };
+
+        let mut end = line.len();
+        while let Some(&(i, c)) = chars.peek() {
+            if c == '=' || c.is_whitespace() {
+                end = i;
+                break;
+            }
+            chars.next();
+        }
+        let key = &line[start..end];
+        if key.is_empty() {
+            return Err(format!("missing key before '=' at position {start}"));
+        }
+
+        let value = if chars.next_if(|(_, c)| *c == '=').is_none() {
+            Value::Boolean(true)
+        } else if chars.next_if(|(_, c)| *c == '"').is_some() {
+            let mut value = String::new();
+            loop {
+                match chars.next() {
+                    Some((_, '"')) => break,
+                    Some((_, '\\')) => match chars.next() {
+                        Some((_, c @ ('"' | '\\'))) => value.push(c),
+                        Some((_, c)) => {
+                            value.push('\\');
+                            value.push(c);
+                        }
+                        None => value.push('\\'),
+                    },
+                    Some((_, c)) => value.push(c),
+                    None => return Err(format!("unterminated quoted value for key {key:?}")),
+                }
+            }
+            Value::from(value)
+        } else {
+            let mut value = String::new();
+            while let Some((_, c)) = chars.next_if(|(_, c)| !c.is_whitespace()) {
+                value.push(c);
+            }
+            Value::from(value)
+        };
+
+        fields.insert(key.into(), value);
+    }
+
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_core::config::log_schema;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_logfmt() {
+        let input = Bytes::from(r#"level=info msg="hello \"world\"" path=/a=b empty= debug"#);
+        let deserializer = LogfmtDeserializer::default();
+
+        for namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+            let events = deserializer.parse(input.clone(), namespace).unwrap();
+            assert_eq!(events.len(), 1);
+
+            let log = events[0].as_log();
+            assert_eq!(log["level"], "info".into());
+            assert_eq!(log["msg"], r#"hello "world""#.into());
+            assert_eq!(log["path"], "/a=b".into());
+            assert_eq!(log["empty"], "".into());
+            assert_eq!(log["debug"], true.into());
+            assert_eq!(
+                log.get((
+                    lookup::PathPrefix::Event,
+                    log_schema().timestamp_key().unwrap()
+                ))
+                .is_some(),
+                namespace == LogNamespace::Legacy
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_logfmt_dotted_keys() {
+        let input = Bytes::from("a.b=1 c[0]=2");
+        let deserializer = LogfmtDeserializer::default();
+
+        let events = deserializer.parse(input, LogNamespace::Vector).unwrap();
+        let fields = events[0].as_log().value().as_object().unwrap();
+        assert_eq!(fields["a.b"], "1".into());
+        assert_eq!(fields["c[0]"], "2".into());
+    }
+
+    #[test]
+    fn deserialize_logfmt_empty_line() {
+        let deserializer = LogfmtDeserializer::default();
+
+        let events = deserializer
+            .parse(Bytes::from("  "), LogNamespace::Vector)
+            .unwrap();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn deserialize_logfmt_errors() {
+        let deserializer = LogfmtDeserializer::default();
+
+        for input in [r#"msg="unterminated"#, "=value"] {
+            assert!(
+                deserializer
+                    .parse(Bytes::from(input), LogNamespace::Vector)
+                    .is_err(),
+                "{input}"
+            );
+        }
+    }
+}
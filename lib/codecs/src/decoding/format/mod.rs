@@ -5,9 +5,13 @@
 
 mod avro;
 mod bytes;
+mod cef;
+mod csv;
 mod gelf;
 mod influxdb;
 mod json;
+mod logfmt;
+mod msgpack;
 mod native;
 mod native_json;
 #[cfg(feature = "opentelemetry")]
@@ -23,6 +27,8 @@ use dyn_clone::DynClone;
 pub use gelf::{GelfDeserializer, GelfDeserializerConfig, GelfDeserializerOptions};
 pub use influxdb::{InfluxdbDeserializer, InfluxdbDeserializerConfig};
 pub use json::{JsonDeserializer, JsonDeserializerConfig, JsonDeserializerOptions};
+pub use logfmt::{LogfmtDeserializer, LogfmtDeserializerConfig, LogfmtDeserializerOptions};
+pub use msgpack::{MsgpackDeserializer, MsgpackDeserializerConfig};
 pub use native::{NativeDeserializer, NativeDeserializerConfig};
 pub use native_json::{
     NativeJsonDeserializer, NativeJsonDeserializerConfig, NativeJsonDeserializerOptions,
@@ -37,6 +43,8 @@ use vector_core::{config::LogNamespace, event::Event};
 
 pub use self::{
     bytes::{BytesDeserializer, BytesDeserializerConfig},
+    cef::{CefDeserializer, CefDeserializerConfig, CefDeserializerOptions},
+    csv::{CsvDeserializer, CsvDeserializerConfig, CsvDeserializerOptions},
     vrl::{VrlDeserializer, VrlDeserializerConfig, VrlDeserializerOptions},
 };
 
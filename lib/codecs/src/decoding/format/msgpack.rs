@@ -0,0 +1,269 @@
+use bytes::{Buf, Bytes};
+use chrono::Utc;
+use ordered_float::NotNan;
+use serde::{Deserialize, Serialize};
+use smallvec::{SmallVec, smallvec};
+use vector_core::{
+    config::{DataType, LogNamespace, log_schema},
+    event::{Event, LogEvent, ObjectMap, Value},
+    schema,
+};
+use vrl::value::Kind;
+
+use super::Deserializer;
+use crate::common::msgpack::{TIMESTAMP_EXT_TYPE, decode_timestamp};
+
+/// Config used to build a `MsgpackDeserializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct MsgpackDeserializerConfig;
+
+impl MsgpackDeserializerConfig {
+    /// Build the `MsgpackDeserializer` from this configuration.
+    pub const fn build(&self) -> MsgpackDeserializer {
+        MsgpackDeserializer
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        match log_namespace {
+            LogNamespace::Legacy => {
+                let mut definition =
+                    schema::Definition::empty_legacy_namespace().unknown_fields(Kind::any());
+
+                if let Some(timestamp_key) = log_schema().timestamp_key() {
+                    definition = definition.try_with_field(
+                        timestamp_key,
+                        // The timestamp is only inserted if the message doesn't have a key with
+                        // the same name.
+                        Kind::any(),
+                        Some("timestamp"),
+                    );
+                }
+                definition
+            }
+            LogNamespace::Vector => {
+                schema::Definition::new_with_default_metadata(Kind::any(), [log_namespace])
+            }
+        }
+    }
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing [MessagePack][msgpack] data.
+///
+/// The frame may contain several concatenated MessagePack values, and a top-level array is split
+/// into one event per element. Values are mapped as follows:
+///
+/// - strings and binary data are decoded as bytes,
+/// - the timestamp extension type is decoded as a timestamp,
+/// - other extension types are decoded as an object containing `msgpack_extension_code` and
+///   `bytes`,
+/// - unsigned integers that don't fit in an `i64` are decoded as strings,
+/// - map entries with non-string keys are dropped.
+///
+/// [msgpack]: https://msgpack.org/
+#[derive(Debug, Clone, Default)]
+pub struct MsgpackDeserializer;
+
+impl Deserializer for MsgpackDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let mut events = SmallVec::new();
+        let mut reader = bytes.reader();
+
+        while !reader.get_ref().is_empty() {
+            let value = rmpv::decode::read_value(&mut reader)
+                .map_err(|error| format!("Error parsing MessagePack: {error}"))?;
+
+            match value {
+                rmpv::Value::Array(values) => {
+                    for value in values {
+                        events.push(to_event(value, log_namespace)?);
+                    }
+                }
+                value => events.push(to_event(value, log_namespace)?),
+            }
+        }
+
+        if log_namespace == LogNamespace::Legacy
+            && let Some(timestamp_key) = log_schema().timestamp_key_target_path()
+        {
+            let timestamp = Utc::now();
+            for event in &mut events {
+                let log = event.as_mut_log();
+                if !log.contains(timestamp_key) {
+                    log.insert(timestamp_key, timestamp);
+                }
+            }
+        }
+
+        Ok(events)
+    }
+}
+
+fn to_event(value: rmpv::Value, log_namespace: LogNamespace) -> vector_common::Result<Event> {
+    let value = to_value(value);
+    match (log_namespace, value) {
+        (LogNamespace::Vector, value) | (LogNamespace::Legacy, value @ Value::Object(_)) => {
+            Ok(Event::Log(LogEvent::from(value)))
+        }
+        (LogNamespace::Legacy, _) => {
+            Err("Attempted to convert non-Object MessagePack into an Event.".into())
+        }
+    }
+}
+
+fn to_value(value: rmpv::Value) -> Value {
+    match value {
+        rmpv::Value::Nil => Value::Null,
+        rmpv::Value::Boolean(boolean) => Value::Boolean(boolean),
+        rmpv::Value::Integer(integer) => match integer.as_i64() {
+            Some(integer) => Value::Integer(integer),
+            // The integer is larger than an `i64` can hold, so it's kept as a string.
+            None => Value::from(integer.to_string()),
+        },
+        rmpv::Value::F32(float) => NotNan::new(f64::from(float)).map_or(Value::Null, Value::Float),
+        rmpv::Value::F64(float) => NotNan::new(float).map_or(Value::Null, Value::Float),
+        rmpv::Value::String(string) => Value::Bytes(string.into_bytes().into()),
+        rmpv::Value::Binary(bytes) => Value::Bytes(bytes.into()),
+        rmpv::Value::Array(values) => Value::Array(values.into_iter().map(to_value).collect()),
+        rmpv::Value::Map(entries) => Value::Object(
+            entries
+                .into_iter()
+                .filter_map(|(key, value)| match key {
+                    rmpv::Value::String(key) => {
+                        key.into_str().map(|key| (key.into(), to_value(value)))
+                    }
+                    _ => None,
+                })
+                .collect::<ObjectMap>(),
+        ),
+        rmpv::Value::Ext(TIMESTAMP_EXT_TYPE, data) => match decode_timestamp(&data) {
+            Some(timestamp) => Value::Timestamp(timestamp),
+            None => ext_value(TIMESTAMP_EXT_TYPE, data),
+        },
+        rmpv::Value::Ext(code, data) => ext_value(code, data),
+    }
+}
+
+fn ext_value(code: i8, data: Vec<u8>) -> Value {
+    Value::Object(ObjectMap::from([
+        ("msgpack_extension_code".into(), Value::Integer(code.into())),
+        ("bytes".into(), Value::Bytes(data.into())),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use vrl::btreemap;
+
+    use super::*;
+    use crate::common::msgpack::encode_timestamp;
+
+    fn encode(value: &rmpv::Value) -> Bytes {
+        let mut buf = Vec::new();
+        rmpv::encode::write_value(&mut buf, value).unwrap();
+        buf.into()
+    }
+
+    #[test]
+    fn deserialize_msgpack() {
+        let timestamp = Utc.timestamp_opt(1_700_000_000, 123).unwrap();
+        let input = encode(&rmpv::Value::Map(vec![
+            ("message".into(), "hello".into()),
+            ("count".into(), 3.into()),
+            ("big".into(), u64::MAX.into()),
+            ("ratio".into(), 0.5.into()),
+            ("raw".into(), rmpv::Value::Binary(vec![0, 159])),
+            (
+                "at".into(),
+                rmpv::Value::Ext(TIMESTAMP_EXT_TYPE, encode_timestamp(timestamp)),
+            ),
+            ("ext".into(), rmpv::Value::Ext(7, vec![1, 2])),
+            (1.into(), "dropped".into()),
+        ]));
+        let deserializer = MsgpackDeserializer;
+
+        for namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+            let events = deserializer.parse(input.clone(), namespace).unwrap();
+            assert_eq!(events.len(), 1);
+
+            let log = events[0].as_log();
+            assert_eq!(log["message"], "hello".into());
+            assert_eq!(log["count"], 3.into());
+            assert_eq!(log["big"], u64::MAX.to_string().into());
+            assert_eq!(log["ratio"], 0.5.into());
+            assert_eq!(log["raw"], Value::Bytes(Bytes::from_static(&[0, 159])));
+            assert_eq!(log["at"], timestamp.into());
+            assert_eq!(
+                log["ext"],
+                Value::Object(btreemap! {
+                    "msgpack_extension_code" => 7,
+                    "bytes" => Value::Bytes(Bytes::from_static(&[1, 2])),
+                })
+            );
+            assert_eq!(
+                log.get((
+                    lookup::PathPrefix::Event,
+                    log_schema().timestamp_key().unwrap()
+                ))
+                .is_some(),
+                namespace == LogNamespace::Legacy
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_msgpack_multiple_values() {
+        let mut input = encode(&rmpv::Value::Array(vec![
+            rmpv::Value::Map(vec![("a".into(), 1.into())]),
+            rmpv::Value::Map(vec![("a".into(), 2.into())]),
+        ]))
+        .to_vec();
+        input.extend_from_slice(&encode(&rmpv::Value::Map(vec![("a".into(), 3.into())])));
+
+        let events = MsgpackDeserializer
+            .parse(input.into(), LogNamespace::Legacy)
+            .unwrap();
+        let values = events
+            .iter()
+            .map(|event| event.as_log()["a"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![1.into(), 2.into(), 3.into()]);
+    }
+
+    #[test]
+    fn deserialize_msgpack_non_object() {
+        let input = encode(&"hello".into());
+
+        let events = MsgpackDeserializer
+            .parse(input.clone(), LogNamespace::Vector)
+            .unwrap();
+        assert_eq!(events[0].as_log().value(), &Value::from("hello"));
+
+        assert!(
+            MsgpackDeserializer
+                .parse(input, LogNamespace::Legacy)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn deserialize_msgpack_truncated() {
+        let input = encode(&rmpv::Value::Map(vec![("a".into(), "abc".into())]));
+
+        assert!(
+            MsgpackDeserializer
+                .parse(input.slice(..input.len() - 1), LogNamespace::Vector)
+                .is_err()
+        );
+    }
+}
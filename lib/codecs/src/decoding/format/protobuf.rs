@@ -1,9 +1,13 @@
 use std::path::PathBuf;
 
+use base64::prelude::{BASE64_STANDARD, Engine as _};
 use bytes::Bytes;
 use chrono::Utc;
 use derivative::Derivative;
-use prost_reflect::{DynamicMessage, MessageDescriptor};
+use prost_reflect::{
+    DescriptorPool, DynamicMessage, FileDescriptor, MessageDescriptor, prost::Message as _,
+    prost_types::FileDescriptorProto,
+};
 use smallvec::{SmallVec, smallvec};
 use vector_config::configurable_component;
 use vector_core::{
@@ -20,6 +24,9 @@ use vrl::{
 };
 
 use super::Deserializer;
+use crate::common::schema_registry::{
+    self, SchemaCache, SchemaReference, SchemaRegistryClient, SchemaRegistryConfig,
+};
 
 /// Config used to build a `ProtobufDeserializer`.
 #[configurable_component]
@@ -76,10 +83,16 @@ pub struct ProtobufDeserializerOptions {
     /// This file is the output of `protoc -I <include path> -o <desc output path> <proto>`.
     ///
     /// For more information, see [How Buf images work](https://buf.build/docs/reference/images/#how-buf-images-work).
+    ///
+    /// Not used when `schema_registry` is set.
+    #[serde(default)]
     pub desc_file: PathBuf,
 
     /// The name of the message type to use for serializing.
+    ///
+    /// Not used when `schema_registry` is set, as each message names its type in its header.
     #[configurable(metadata(docs::examples = "package.Message"))]
+    #[serde(default)]
     pub message_type: String,
 
     /// Use JSON field names (camelCase) instead of protobuf field names (snake_case).
@@ -91,21 +104,34 @@ pub struct ProtobufDeserializerOptions {
     /// when interfacing with systems that use JSON naming conventions.
     #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
     pub use_json_names: bool,
+
+    /// Fetches the schema that each message was written with from a schema registry.
+    ///
+    /// Each message must be in the [Confluent wire format][wire_format], which prefixes it with
+    /// the ID of its schema and the indexes of its message type within the schema. Schemas are
+    /// fetched the first time their ID is seen and cached after that, so messages written with
+    /// newer versions of a schema are decoded with the fields of that version.
+    ///
+    /// [wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_registry: Option<SchemaRegistryConfig>,
 }
 
 /// Deserializer that builds `Event`s from a byte frame containing protobuf.
 #[derive(Debug, Clone)]
 pub struct ProtobufDeserializer {
-    message_descriptor: MessageDescriptor,
+    message_descriptor: Option<MessageDescriptor>,
     options: Options,
+    registry: Option<ProtobufSchemaRegistry>,
 }
 
 impl ProtobufDeserializer {
     /// Creates a new `ProtobufDeserializer`.
     pub fn new(message_descriptor: MessageDescriptor) -> Self {
         Self {
-            message_descriptor,
+            message_descriptor: Some(message_descriptor),
             options: Default::default(),
+            registry: None,
         }
     }
 
@@ -117,12 +143,100 @@ impl ProtobufDeserializer {
     ) -> vector_common::Result<Self> {
         let message_descriptor = get_message_descriptor_from_bytes(desc_bytes, message_type)?;
         Ok(Self {
-            message_descriptor,
+            message_descriptor: Some(message_descriptor),
             options,
+            registry: None,
         })
     }
 }
 
+/// Fetches and caches the schemas that messages were written with.
+#[derive(Debug, Clone)]
+struct ProtobufSchemaRegistry {
+    client: SchemaRegistryClient,
+    files: SchemaCache<FileDescriptor>,
+}
+
+impl ProtobufSchemaRegistry {
+    fn new(config: SchemaRegistryConfig) -> vector_common::Result<Self> {
+        Ok(Self {
+            client: SchemaRegistryClient::new(config)?,
+            files: SchemaCache::default(),
+        })
+    }
+
+    /// Splits a message in the wire format into the message itself and its descriptor.
+    fn message(&self, bytes: Bytes) -> vector_common::Result<(Bytes, MessageDescriptor)> {
+        let (id, mut bytes) = schema_registry::read_header(bytes)?;
+        let indexes = schema_registry::read_message_indexes(&mut bytes)?;
+        let file = self.files.get_or_try_insert(id, || self.fetch_file(id))?;
+        let message = message_at(&file, &indexes)
+            .ok_or_else(|| format!("Schema {id} has no message type at indexes {indexes:?}"))?;
+        Ok((bytes, message))
+    }
+
+    fn fetch_file(&self, id: u32) -> vector_common::Result<FileDescriptor> {
+        let registered = self.client.schema_by_id(id, true)?;
+        registered.expect_type("PROTOBUF")?;
+
+        // The well-known types aren't registered as references, so start from a pool that holds
+        // them.
+        let mut pool = DescriptorPool::global();
+        self.add_references(&registered.references, &mut pool)?;
+
+        let mut file = decode_file_descriptor(&registered.schema)?;
+        let name = match file.name() {
+            "" => format!("schema_{id}.proto"),
+            name => name.to_owned(),
+        };
+        file.name = Some(name.clone());
+        pool.add_file_descriptor_proto(file)?;
+        pool.get_file_by_name(&name)
+            .ok_or_else(|| format!("Schema {id} was not added to the descriptor pool").into())
+    }
+
+    fn add_references(
+        &self,
+        references: &[SchemaReference],
+        pool: &mut DescriptorPool,
+    ) -> vector_common::Result<()> {
+        for reference in references {
+            if pool.get_file_by_name(&reference.name).is_some() {
+                continue;
+            }
+            let registered = self.client.subject_version(
+                &reference.subject,
+                &reference.version.to_string(),
+                true,
+            )?;
+            // Imported files must be added before the files that import them.
+            self.add_references(&registered.references, pool)?;
+
+            let mut file = decode_file_descriptor(&registered.schema)?;
+            file.name = Some(reference.name.clone());
+            pool.add_file_descriptor_proto(file)?;
+        }
+        Ok(())
+    }
+}
+
+fn decode_file_descriptor(schema: &str) -> vector_common::Result<FileDescriptorProto> {
+    let bytes = BASE64_STANDARD.decode(schema)?;
+    Ok(FileDescriptorProto::decode(bytes.as_slice())?)
+}
+
+/// Finds a message type by its indexes, as written in the wire format, within a file.
+fn message_at(file: &FileDescriptor, indexes: &[i32]) -> Option<MessageDescriptor> {
+    let (first, nested) = indexes.split_first()?;
+    let mut message = file.messages().nth(usize::try_from(*first).ok()?)?;
+    for index in nested {
+        message = message
+            .child_messages()
+            .nth(usize::try_from(*index).ok()?)?;
+    }
+    Some(message)
+}
+
 fn extract_vrl_value(
     bytes: Bytes,
     message_descriptor: &MessageDescriptor,
@@ -144,7 +258,16 @@ impl Deserializer for ProtobufDeserializer {
         bytes: Bytes,
         log_namespace: LogNamespace,
     ) -> vector_common::Result<SmallVec<[Event; 1]>> {
-        let vrl_value = extract_vrl_value(bytes, &self.message_descriptor, &self.options)?;
+        let (bytes, message_descriptor) = match &self.registry {
+            Some(registry) => registry.message(bytes)?,
+            None => (
+                bytes,
+                self.message_descriptor
+                    .clone()
+                    .ok_or("Protobuf message type is not set")?,
+            ),
+        };
+        let vrl_value = extract_vrl_value(bytes, &message_descriptor, &self.options)?;
         let mut event = Event::Log(LogEvent::from(vrl_value));
 
         let event = match log_namespace {
@@ -168,13 +291,23 @@ impl Deserializer for ProtobufDeserializer {
 impl TryFrom<&ProtobufDeserializerConfig> for ProtobufDeserializer {
     type Error = vector_common::Error;
     fn try_from(config: &ProtobufDeserializerConfig) -> vector_common::Result<Self> {
-        let message_descriptor =
-            get_message_descriptor(&config.protobuf.desc_file, &config.protobuf.message_type)?;
+        let options = &config.protobuf;
+        let (message_descriptor, registry) = match &options.schema_registry {
+            Some(registry) => (None, Some(ProtobufSchemaRegistry::new(registry.clone())?)),
+            None => (
+                Some(get_message_descriptor(
+                    &options.desc_file,
+                    &options.message_type,
+                )?),
+                None,
+            ),
+        };
         Ok(Self {
             message_descriptor,
             options: Options {
-                use_json_names: config.protobuf.use_json_names,
+                use_json_names: options.use_json_names,
             },
+            registry,
         })
     }
 }
@@ -294,6 +427,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_protobuf_schema_registry() {
+        let desc = fs::read(test_data_dir().join("protos/test_protobuf3.desc")).unwrap();
+        let file = prost_reflect::prost_types::FileDescriptorSet::decode(desc.as_slice())
+            .unwrap()
+            .file
+            .into_iter()
+            .find(|file| file.name() == "test_protobuf3.proto")
+            .unwrap();
+        let url = schema_registry::tests::mock_registry(vec![
+            serde_json::json!({
+                "schemaType": "PROTOBUF",
+                "schema": BASE64_STANDARD.encode(file.encode_to_vec()),
+            })
+            .to_string(),
+        ]);
+
+        let config = ProtobufDeserializerConfig {
+            protobuf: ProtobufDeserializerOptions {
+                schema_registry: Some(schema_registry::tests::registry_config(url)),
+                ..Default::default()
+            },
+        };
+        let deserializer = config.build().unwrap();
+
+        let message = fs::read(test_data_dir().join("pbs/person_someone3.pb")).unwrap();
+        // The schema is only fetched once.
+        for _ in 0..2 {
+            let mut bytes = bytes::BytesMut::new();
+            schema_registry::write_header(3, &mut bytes);
+            schema_registry::write_message_indexes(&[0], &mut bytes);
+            bytes.extend_from_slice(&message);
+
+            let events = deserializer
+                .parse(bytes.freeze(), LogNamespace::Vector)
+                .unwrap();
+            let log = events[0].as_log();
+            assert_eq!(log["name"], "someone".into());
+        }
+    }
+
     #[test]
     fn deserialize_error_invalid_protobuf() {
         let input = Bytes::from("{ foo");
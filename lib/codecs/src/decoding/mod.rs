@@ -2,6 +2,7 @@
 //! bytes into events.
 
 mod config;
+mod decode_errors;
 mod decoder;
 mod decompression;
 mod error;
@@ -12,16 +13,20 @@ use std::fmt::Debug;
 
 use bytes::{Bytes, BytesMut};
 pub use config::DecodingConfig;
+pub use decode_errors::{decode_errors_output, decode_errors_schema_definition};
 pub use decoder::Decoder;
 pub use decompression::{DecompressionAlgorithm, DecompressionConfig, Decompressor};
 pub use error::StreamDecodingError;
 pub use format::{
-    BoxedDeserializer, BytesDeserializer, BytesDeserializerConfig, GelfDeserializer,
-    GelfDeserializerConfig, GelfDeserializerOptions, InfluxdbDeserializer,
-    InfluxdbDeserializerConfig, JsonDeserializer, JsonDeserializerConfig, JsonDeserializerOptions,
-    NativeDeserializer, NativeDeserializerConfig, NativeJsonDeserializer,
-    NativeJsonDeserializerConfig, NativeJsonDeserializerOptions, ProtobufDeserializer,
-    ProtobufDeserializerConfig, ProtobufDeserializerOptions,
+    BoxedDeserializer, BytesDeserializer, BytesDeserializerConfig, CefDeserializer,
+    CefDeserializerConfig, CefDeserializerOptions, CsvDeserializer, CsvDeserializerConfig,
+    CsvDeserializerOptions, GelfDeserializer, GelfDeserializerConfig, GelfDeserializerOptions,
+    InfluxdbDeserializer, InfluxdbDeserializerConfig, JsonDeserializer, JsonDeserializerConfig,
+    JsonDeserializerOptions, LogfmtDeserializer, LogfmtDeserializerConfig,
+    LogfmtDeserializerOptions, MsgpackDeserializer, MsgpackDeserializerConfig, NativeDeserializer,
+    NativeDeserializerConfig, NativeJsonDeserializer, NativeJsonDeserializerConfig,
+    NativeJsonDeserializerOptions, ProtobufDeserializer, ProtobufDeserializerConfig,
+    ProtobufDeserializerOptions,
 };
 #[cfg(feature = "opentelemetry")]
 pub use format::{OtlpDeserializer, OtlpDeserializerConfig, OtlpSignalType};
@@ -201,6 +206,22 @@ pub enum Framer {
     VarintLengthDelimited(VarintLengthDelimitedDecoder),
 }
 
+impl Framer {
+    /// The name of the framing method, as used in the `framing.method` option.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Framer::Bytes(_) => "bytes",
+            Framer::CharacterDelimited(_) => "character_delimited",
+            Framer::LengthDelimited(_) => "length_delimited",
+            Framer::NewlineDelimited(_) => "newline_delimited",
+            Framer::OctetCounting(_) => "octet_counting",
+            Framer::Boxed(_) => "custom",
+            Framer::ChunkedGelf(_) => "chunked_gelf",
+            Framer::VarintLengthDelimited(_) => "varint_length_delimited",
+        }
+    }
+}
+
 impl tokio_util::codec::Decoder for Framer {
     type Item = Bytes;
     type Error = BoxedFramingError;
@@ -247,6 +268,37 @@ pub enum DeserializerConfig {
     /// [json]: https://www.json.org/
     Json(JsonDeserializerConfig),
 
+    /// Decodes the raw bytes as [CSV (Comma Separated Values)][csv].
+    ///
+    /// Each row is decoded into a log event, with a field for each column.
+    ///
+    /// [csv]: https://datatracker.ietf.org/doc/html/rfc4180
+    Csv(CsvDeserializerConfig),
+
+    /// Decodes the raw bytes as [logfmt][logfmt].
+    ///
+    /// Each key becomes a field of the event. Keys without a value are decoded as `true`.
+    ///
+    /// [logfmt]: https://brandur.org/logfmt
+    Logfmt(LogfmtDeserializerConfig),
+
+    /// Decodes the raw bytes as a [CEF (Common Event Format)][cef] message.
+    ///
+    /// The header fields are decoded into the `cef` object, and the extensions into the root of
+    /// the event. Any text before `CEF:`, such as a syslog header, is ignored.
+    ///
+    /// [cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors-8.3/cef-implementation-standard/Content/CEF/Chapter%201%20What%20is%20CEF.htm
+    Cef(CefDeserializerConfig),
+
+    /// Decodes the raw bytes as [MessagePack][msgpack].
+    ///
+    /// Binary values are decoded as bytes, and the timestamp extension type is decoded as a
+    /// timestamp. Other extension types are decoded as an object with `msgpack_extension_code` and
+    /// `bytes` fields.
+    ///
+    /// [msgpack]: https://msgpack.org/
+    Msgpack,
+
     /// Decodes the raw bytes as [protobuf][protobuf].
     ///
     /// [protobuf]: https://protobuf.dev/
@@ -340,6 +392,24 @@ impl From<JsonDeserializerConfig> for DeserializerConfig {
     }
 }
 
+impl From<CsvDeserializerConfig> for DeserializerConfig {
+    fn from(config: CsvDeserializerConfig) -> Self {
+        Self::Csv(config)
+    }
+}
+
+impl From<LogfmtDeserializerConfig> for DeserializerConfig {
+    fn from(config: LogfmtDeserializerConfig) -> Self {
+        Self::Logfmt(config)
+    }
+}
+
+impl From<CefDeserializerConfig> for DeserializerConfig {
+    fn from(config: CefDeserializerConfig) -> Self {
+        Self::Cef(config)
+    }
+}
+
 #[cfg(feature = "syslog")]
 impl From<SyslogDeserializerConfig> for DeserializerConfig {
     fn from(config: SyslogDeserializerConfig) -> Self {
@@ -353,6 +423,12 @@ impl From<GelfDeserializerConfig> for DeserializerConfig {
     }
 }
 
+impl From<MsgpackDeserializerConfig> for DeserializerConfig {
+    fn from(_: MsgpackDeserializerConfig) -> Self {
+        Self::Msgpack
+    }
+}
+
 impl From<NativeDeserializerConfig> for DeserializerConfig {
     fn from(_: NativeDeserializerConfig) -> Self {
         Self::Native
@@ -383,6 +459,12 @@ impl DeserializerConfig {
             )),
             DeserializerConfig::Bytes => Ok(Deserializer::Bytes(BytesDeserializerConfig.build())),
             DeserializerConfig::Json(config) => Ok(Deserializer::Json(config.build())),
+            DeserializerConfig::Csv(config) => Ok(Deserializer::Csv(config.build()?)),
+            DeserializerConfig::Logfmt(config) => Ok(Deserializer::Logfmt(config.build())),
+            DeserializerConfig::Cef(config) => Ok(Deserializer::Cef(config.build())),
+            DeserializerConfig::Msgpack => {
+                Ok(Deserializer::Msgpack(MsgpackDeserializerConfig.build()))
+            }
             DeserializerConfig::Protobuf(config) => Ok(Deserializer::Protobuf(config.build()?)),
             #[cfg(feature = "opentelemetry")]
             DeserializerConfig::Otlp(config) => Ok(Deserializer::Otlp(config.build())),
@@ -402,13 +484,20 @@ impl DeserializerConfig {
     pub fn default_stream_framing(&self) -> FramingConfig {
         match self {
             DeserializerConfig::Avro { .. } => FramingConfig::Bytes,
-            DeserializerConfig::Native => FramingConfig::LengthDelimited(Default::default()),
+            DeserializerConfig::Native | DeserializerConfig::Msgpack => {
+                FramingConfig::LengthDelimited(Default::default())
+            }
             DeserializerConfig::Bytes
             | DeserializerConfig::Json(_)
             | DeserializerConfig::Influxdb(_)
+            | DeserializerConfig::Logfmt(_)
+            | DeserializerConfig::Cef(_)
             | DeserializerConfig::NativeJson(_) => {
                 FramingConfig::NewlineDelimited(Default::default())
             }
+            // A header row must be in the same frame as the rows it describes.
+            DeserializerConfig::Csv(config) if config.has_header_row() => FramingConfig::Bytes,
+            DeserializerConfig::Csv(_) => FramingConfig::NewlineDelimited(Default::default()),
             DeserializerConfig::Protobuf(_) => FramingConfig::Bytes,
             #[cfg(feature = "opentelemetry")]
             DeserializerConfig::Otlp(_) => FramingConfig::Bytes,
@@ -444,6 +533,10 @@ impl DeserializerConfig {
             .output_type(),
             DeserializerConfig::Bytes => BytesDeserializerConfig.output_type(),
             DeserializerConfig::Json(config) => config.output_type(),
+            DeserializerConfig::Csv(config) => config.output_type(),
+            DeserializerConfig::Logfmt(config) => config.output_type(),
+            DeserializerConfig::Cef(config) => config.output_type(),
+            DeserializerConfig::Msgpack => MsgpackDeserializerConfig.output_type(),
             DeserializerConfig::Protobuf(config) => config.output_type(),
             #[cfg(feature = "opentelemetry")]
             DeserializerConfig::Otlp(config) => config.output_type(),
@@ -466,6 +559,12 @@ impl DeserializerConfig {
             .schema_definition(log_namespace),
             DeserializerConfig::Bytes => BytesDeserializerConfig.schema_definition(log_namespace),
             DeserializerConfig::Json(config) => config.schema_definition(log_namespace),
+            DeserializerConfig::Csv(config) => config.schema_definition(log_namespace),
+            DeserializerConfig::Logfmt(config) => config.schema_definition(log_namespace),
+            DeserializerConfig::Cef(config) => config.schema_definition(log_namespace),
+            DeserializerConfig::Msgpack => {
+                MsgpackDeserializerConfig.schema_definition(log_namespace)
+            }
             DeserializerConfig::Protobuf(config) => config.schema_definition(log_namespace),
             #[cfg(feature = "opentelemetry")]
             DeserializerConfig::Otlp(config) => config.schema_definition(log_namespace),
@@ -503,6 +602,8 @@ impl DeserializerConfig {
                 "application/octet-stream"
             }
             (DeserializerConfig::Protobuf(_), _) => "application/octet-stream",
+            (DeserializerConfig::Csv(_), _) => "text/csv",
+            (DeserializerConfig::Msgpack, _) => "application/msgpack",
             #[cfg(feature = "opentelemetry")]
             (DeserializerConfig::Otlp(_), _) => "application/x-protobuf",
             (
@@ -511,6 +612,8 @@ impl DeserializerConfig {
                 | DeserializerConfig::Bytes
                 | DeserializerConfig::Gelf(_)
                 | DeserializerConfig::Influxdb(_)
+                | DeserializerConfig::Logfmt(_)
+                | DeserializerConfig::Cef(_)
                 | DeserializerConfig::Vrl(_),
                 _,
             ) => "text/plain",
@@ -530,6 +633,14 @@ pub enum Deserializer {
     Bytes(BytesDeserializer),
     /// Uses a `JsonDeserializer` for deserialization.
     Json(JsonDeserializer),
+    /// Uses a `CsvDeserializer` for deserialization.
+    Csv(CsvDeserializer),
+    /// Uses a `LogfmtDeserializer` for deserialization.
+    Logfmt(LogfmtDeserializer),
+    /// Uses a `CefDeserializer` for deserialization.
+    Cef(CefDeserializer),
+    /// Uses a `MsgpackDeserializer` for deserialization.
+    Msgpack(MsgpackDeserializer),
     /// Uses a `ProtobufDeserializer` for deserialization.
     Protobuf(ProtobufDeserializer),
     #[cfg(feature = "opentelemetry")]
@@ -561,6 +672,30 @@ impl Deserializer {
             other => other,
         }
     }
+
+    /// The name of the codec, as used in the `decoding.codec` option.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Deserializer::Avro(_) => "avro",
+            Deserializer::Bytes(_) => "bytes",
+            Deserializer::Json(_) => "json",
+            Deserializer::Csv(_) => "csv",
+            Deserializer::Logfmt(_) => "logfmt",
+            Deserializer::Msgpack(_) => "msgpack",
+            Deserializer::Cef(_) => "cef",
+            Deserializer::Protobuf(_) => "protobuf",
+            #[cfg(feature = "opentelemetry")]
+            Deserializer::Otlp(_) => "otlp",
+            #[cfg(feature = "syslog")]
+            Deserializer::Syslog(_) => "syslog",
+            Deserializer::Native(_) => "native",
+            Deserializer::NativeJson(_) => "native_json",
+            Deserializer::Boxed(_) => "custom",
+            Deserializer::Gelf(_) => "gelf",
+            Deserializer::Influxdb(_) => "influxdb",
+            Deserializer::Vrl(_) => "vrl",
+        }
+    }
 }
 
 impl format::Deserializer for Deserializer {
@@ -573,6 +708,10 @@ impl format::Deserializer for Deserializer {
             Deserializer::Avro(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Bytes(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Json(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Csv(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Logfmt(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Msgpack(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Cef(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Protobuf(deserializer) => deserializer.parse(bytes, log_namespace),
             #[cfg(feature = "opentelemetry")]
             Deserializer::Otlp(deserializer) => deserializer.parse(bytes, log_namespace),
@@ -607,4 +746,23 @@ mod tests {
             })
         ));
     }
+
+    #[test]
+    fn csv_stream_default_framing_depends_on_header_row() {
+        let deserializer_config = DeserializerConfig::from(CsvDeserializerConfig::default());
+        assert!(matches!(
+            deserializer_config.default_stream_framing(),
+            FramingConfig::Bytes
+        ));
+
+        let deserializer_config =
+            DeserializerConfig::from(CsvDeserializerConfig::new(CsvDeserializerOptions {
+                columns: vec!["message".to_string()],
+                ..Default::default()
+            }));
+        assert!(matches!(
+            deserializer_config.default_stream_framing(),
+            FramingConfig::NewlineDelimited(_)
+        ));
+    }
 }
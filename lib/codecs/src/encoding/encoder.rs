@@ -238,6 +238,7 @@ impl Encoder<Framer> {
                 }),
             ) => "application/json",
             (Serializer::Native(_), _) | (Serializer::Protobuf(_), _) => "application/octet-stream",
+            (Serializer::Msgpack(_), _) => "application/msgpack",
             (
                 Serializer::Avro(_)
                 | Serializer::Cef(_)
@@ -4,7 +4,10 @@ use tokio_util::codec::Encoder;
 use vector_config::configurable_component;
 use vector_core::{config::DataType, event::Event, schema};
 
-use crate::encoding::BuildError;
+use crate::{
+    common::schema_registry::{self, SchemaRegistryClient, SchemaRegistryEncodingConfig},
+    encoding::BuildError,
+};
 
 /// Config used to build a `AvroSerializer`.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -17,7 +20,10 @@ impl AvroSerializerConfig {
     /// Creates a new `AvroSerializerConfig`.
     pub const fn new(schema: String) -> Self {
         Self {
-            avro: AvroSerializerOptions { schema },
+            avro: AvroSerializerOptions {
+                schema,
+                schema_registry: None,
+            },
         }
     }
 
@@ -25,7 +31,13 @@ impl AvroSerializerConfig {
     pub fn build(&self) -> Result<AvroSerializer, BuildError> {
         let schema = apache_avro::Schema::parse_str(&self.avro.schema)
             .map_err(|error| format!("Failed building Avro serializer: {error}"))?;
-        Ok(AvroSerializer { schema })
+        let schema_id = self
+            .avro
+            .schema_registry
+            .as_ref()
+            .map(|config| registered_schema_id(config, &self.avro.schema))
+            .transpose()?;
+        Ok(AvroSerializer { schema, schema_id })
     }
 
     /// The data type of events that are accepted by `AvroSerializer`.
@@ -50,21 +62,58 @@ pub struct AvroSerializerOptions {
     ))]
     #[configurable(metadata(docs::human_name = "Schema JSON"))]
     pub schema: String,
+
+    /// Prefixes each datum with the ID of the schema in a schema registry.
+    ///
+    /// The output is in the [Confluent wire format][wire_format], which is what consumers that
+    /// read schemas from the registry expect. The schema's ID is looked up, or registered, when
+    /// the component starts.
+    ///
+    /// [wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_registry: Option<SchemaRegistryEncodingConfig>,
 }
 
 /// Serializer that converts an `Event` to bytes using the Apache Avro format.
 #[derive(Debug, Clone)]
 pub struct AvroSerializer {
     schema: apache_avro::Schema,
+    schema_id: Option<u32>,
 }
 
 impl AvroSerializer {
     /// Creates a new `AvroSerializer`.
     pub const fn new(schema: apache_avro::Schema) -> Self {
-        Self { schema }
+        Self {
+            schema,
+            schema_id: None,
+        }
     }
 }
 
+/// Looks up, or registers, the ID that the schema is registered with.
+///
+/// The schema is sent as it's configured, rather than in its canonical form, which would drop
+/// the defaults, docs, aliases, and logical types that the registry's consumers may rely on.
+fn registered_schema_id(
+    config: &SchemaRegistryEncodingConfig,
+    schema: &str,
+) -> vector_common::Result<u32> {
+    let client = SchemaRegistryClient::new(config.registry.clone())?;
+    let id = if config.auto_register {
+        client.register(&config.subject, schema, "AVRO")
+    } else {
+        client.lookup(&config.subject, schema, "AVRO")
+    };
+    id.map_err(|error| {
+        format!(
+            "Failed resolving the schema ID for subject {}: {error}",
+            config.subject
+        )
+        .into()
+    })
+}
+
 impl Encoder<Event> for AvroSerializer {
     type Error = vector_common::Error;
 
@@ -73,6 +122,9 @@ impl Encoder<Event> for AvroSerializer {
         let value = apache_avro::to_value(log)?;
         let value = value.resolve(&self.schema)?;
         let bytes = apache_avro::to_avro_datum(&self.schema, value)?;
+        if let Some(id) = self.schema_id {
+            schema_registry::write_header(id, buffer);
+        }
         buffer.put_slice(&bytes);
         Ok(())
     }
@@ -113,4 +165,42 @@ mod tests {
 
         assert_eq!(bytes.freeze(), b"\0\x06bar".as_slice());
     }
+
+    #[test]
+    fn serialize_avro_schema_registry() {
+        let (url, requests) =
+            schema_registry::tests::recording_registry(vec![r#"{"id": 12}"#.to_owned()]);
+        let schema = indoc! {r#"
+            {
+                "type": "record",
+                "name": "Log",
+                "doc": "A log event.",
+                "fields": [{ "name": "foo", "type": "string", "default": "" }]
+            }
+        "#}
+        .to_owned();
+        let mut config = AvroSerializerConfig::new(schema.clone());
+        config.avro.schema_registry = Some(SchemaRegistryEncodingConfig {
+            registry: schema_registry::tests::registry_config(url),
+            subject: "logs-value".to_owned(),
+            auto_register: true,
+        });
+        // The schema ID is looked up once, when the serializer is built, rather than by each of
+        // the clones that sinks encode batches with.
+        let serializer = config.build().unwrap();
+
+        // The schema is registered as it's written, keeping its docs and defaults.
+        let request: serde_json::Value = serde_json::from_slice(&requests.recv().unwrap()).unwrap();
+        assert_eq!(request["schema"], schema.as_str());
+        assert_eq!(request["schemaType"], "AVRO");
+
+        for _ in 0..2 {
+            let event = Event::Log(LogEvent::from(btreemap! {
+                "foo" => Value::from("bar")
+            }));
+            let mut bytes = BytesMut::new();
+            serializer.clone().encode(event, &mut bytes).unwrap();
+            assert_eq!(bytes.freeze(), b"\0\0\0\0\x0c\x06bar".as_slice());
+        }
+    }
 }
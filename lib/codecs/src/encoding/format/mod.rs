@@ -12,6 +12,7 @@ mod csv;
 mod gelf;
 mod json;
 mod logfmt;
+mod msgpack;
 mod native;
 mod native_json;
 #[cfg(feature = "opentelemetry")]
@@ -36,11 +37,12 @@ pub use arrow::{
     find_null_non_nullable_fields,
 };
 pub use avro::{AvroSerializer, AvroSerializerConfig, AvroSerializerOptions};
-pub use cef::{CefSerializer, CefSerializerConfig};
+pub use cef::{CefSerializer, CefSerializerConfig, CefSerializerOptions};
 use dyn_clone::DynClone;
 pub use gelf::{GelfSerializer, GelfSerializerConfig};
 pub use json::{JsonSerializer, JsonSerializerConfig, JsonSerializerOptions};
 pub use logfmt::{LogfmtSerializer, LogfmtSerializerConfig};
+pub use msgpack::{MsgpackSerializer, MsgpackSerializerConfig};
 pub use native::{NativeSerializer, NativeSerializerConfig};
 pub use native_json::{NativeJsonSerializer, NativeJsonSerializerConfig};
 #[cfg(feature = "opentelemetry")]
@@ -52,7 +54,7 @@ pub use syslog::{SyslogSerializer, SyslogSerializerConfig};
 pub use text::{TextSerializer, TextSerializerConfig};
 use vector_core::event::Event;
 
-pub use self::csv::{CsvSerializer, CsvSerializerConfig};
+pub use self::csv::{CsvSerializer, CsvSerializerConfig, CsvSerializerOptions};
 
 /// Serialize a structured event into a byte frame.
 pub trait Serializer:
@@ -0,0 +1,120 @@
+use bytes::{BufMut, BytesMut};
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Encoder;
+use vector_core::{config::DataType, event::Event, schema};
+use vrl::value::Value;
+
+use crate::common::msgpack::{TIMESTAMP_EXT_TYPE, encode_timestamp};
+
+/// Config used to build a `MsgpackSerializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct MsgpackSerializerConfig;
+
+impl MsgpackSerializerConfig {
+    /// Creates a new `MsgpackSerializerConfig`.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Build the `MsgpackSerializer` from this configuration.
+    pub const fn build(&self) -> MsgpackSerializer {
+        MsgpackSerializer
+    }
+
+    /// The data type of events that are accepted by `MsgpackSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        schema::Requirement::empty()
+    }
+}
+
+/// Serializer that converts an `Event` to bytes using the [MessagePack][msgpack] format.
+///
+/// Bytes are encoded as strings when they are valid UTF-8 and as binary otherwise, and timestamps
+/// are encoded using the timestamp extension type.
+///
+/// [msgpack]: https://msgpack.org/
+#[derive(Debug, Clone)]
+pub struct MsgpackSerializer;
+
+impl Encoder<Event> for MsgpackSerializer {
+    type Error = vector_common::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let value = from_value(event.as_log().value());
+        rmpv::encode::write_value(&mut buffer.writer(), &value)?;
+
+        Ok(())
+    }
+}
+
+fn from_value(value: &Value) -> rmpv::Value {
+    match value {
+        Value::Bytes(bytes) => match std::str::from_utf8(bytes) {
+            Ok(string) => rmpv::Value::from(string),
+            Err(_) => rmpv::Value::Binary(bytes.to_vec()),
+        },
+        Value::Regex(regex) => rmpv::Value::from(regex.as_str()),
+        Value::Integer(integer) => rmpv::Value::from(*integer),
+        Value::Float(float) => rmpv::Value::F64(float.into_inner()),
+        Value::Boolean(boolean) => rmpv::Value::Boolean(*boolean),
+        Value::Timestamp(timestamp) => {
+            rmpv::Value::Ext(TIMESTAMP_EXT_TYPE, encode_timestamp(*timestamp))
+        }
+        Value::Object(fields) => rmpv::Value::Map(
+            fields
+                .iter()
+                .map(|(key, value)| (rmpv::Value::from(key.as_str()), from_value(value)))
+                .collect(),
+        ),
+        Value::Array(values) => rmpv::Value::Array(values.iter().map(from_value).collect()),
+        Value::Null => rmpv::Value::Nil,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Bytes, BytesMut};
+    use chrono::{TimeZone, Utc};
+    use vector_core::event::LogEvent;
+    use vrl::btreemap;
+
+    use super::*;
+
+    #[test]
+    fn serialize_msgpack() {
+        let timestamp = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "message" => "hello",
+            "raw" => Value::Bytes(Bytes::from_static(&[0, 159])),
+            "count" => 3,
+            "at" => timestamp,
+            "tags" => vec![Value::Null, Value::Boolean(true)],
+        }));
+        let mut bytes = BytesMut::new();
+
+        MsgpackSerializer.encode(event, &mut bytes).unwrap();
+
+        let value = rmpv::decode::read_value(&mut bytes.as_ref()).unwrap();
+        assert_eq!(
+            value,
+            rmpv::Value::Map(vec![
+                (
+                    "at".into(),
+                    rmpv::Value::Ext(TIMESTAMP_EXT_TYPE, encode_timestamp(timestamp))
+                ),
+                ("count".into(), 3.into()),
+                ("message".into(), "hello".into()),
+                ("raw".into(), rmpv::Value::Binary(vec![0, 159])),
+                (
+                    "tags".into(),
+                    rmpv::Value::Array(vec![rmpv::Value::Nil, true.into()])
+                ),
+            ])
+        );
+    }
+}
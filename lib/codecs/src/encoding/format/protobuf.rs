@@ -1,6 +1,9 @@
 use std::path::PathBuf;
 
-use crate::encoding::BuildError;
+use crate::{
+    common::schema_registry::{self, SchemaRegistryClient, SchemaRegistryEncodingConfig},
+    encoding::BuildError,
+};
 use bytes::BytesMut;
 use prost_reflect::{MessageDescriptor, prost::Message as _};
 use tokio_util::codec::Encoder;
@@ -28,12 +31,19 @@ impl ProtobufSerializerConfig {
     pub fn build(&self) -> Result<ProtobufSerializer, BuildError> {
         let message_descriptor =
             get_message_descriptor(&self.protobuf.desc_file, &self.protobuf.message_type)?;
+        let header = self
+            .protobuf
+            .schema_registry
+            .as_ref()
+            .map(|config| WireFormatHeader::resolve(config, &message_descriptor))
+            .transpose()?;
         Ok(ProtobufSerializer {
             message_descriptor,
             options: Options {
                 use_json_names: self.protobuf.use_json_names,
                 allow_lossy_string_coercion: true,
             },
+            header,
         })
     }
 
@@ -75,6 +85,17 @@ pub struct ProtobufSerializerOptions {
     /// when interfacing with systems that use JSON naming conventions.
     #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
     pub use_json_names: bool,
+
+    /// Prefixes each message with the ID of its schema in a schema registry.
+    ///
+    /// The output is in the [Confluent wire format][wire_format], which is what consumers that
+    /// read schemas from the registry expect. The ID of the latest version of the schema
+    /// registered under the subject is looked up when the component starts. Protobuf schemas
+    /// must be registered ahead of time, so `auto_register` isn't supported.
+    ///
+    /// [wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_registry: Option<SchemaRegistryEncodingConfig>,
 }
 
 /// Serializer that converts an `Event` to bytes using the Protobuf format.
@@ -83,6 +104,7 @@ pub struct ProtobufSerializer {
     /// The protobuf message definition to use for serialization.
     message_descriptor: MessageDescriptor,
     options: Options,
+    header: Option<WireFormatHeader>,
 }
 
 impl ProtobufSerializer {
@@ -91,6 +113,7 @@ impl ProtobufSerializer {
         Self {
             message_descriptor,
             options: Options::default(),
+            header: None,
         }
     }
 
@@ -104,6 +127,7 @@ impl ProtobufSerializer {
         Ok(Self {
             message_descriptor,
             options: options.clone(),
+            header: None,
         })
     }
 
@@ -113,6 +137,68 @@ impl ProtobufSerializer {
     }
 }
 
+/// The schema ID and message indexes that prefix each message in the wire format.
+#[derive(Debug, Clone)]
+struct WireFormatHeader {
+    schema_id: u32,
+    message_indexes: Vec<i32>,
+}
+
+impl WireFormatHeader {
+    /// Looks up the ID of the latest schema registered under the subject.
+    fn resolve(
+        config: &SchemaRegistryEncodingConfig,
+        message_descriptor: &MessageDescriptor,
+    ) -> Result<Self, BuildError> {
+        if config.auto_register {
+            return Err("Protobuf schemas can't be registered automatically, register the schema ahead of time and disable `auto_register`".into());
+        }
+
+        let client = SchemaRegistryClient::new(config.registry.clone())?;
+        let registered = client
+            .subject_version(&config.subject, "latest", false)
+            .map_err(|error| {
+                format!(
+                    "Failed resolving the schema ID for subject {}: {error}",
+                    config.subject
+                )
+            })?;
+        registered.expect_type("PROTOBUF")?;
+        let schema_id = registered
+            .id
+            .ok_or_else(|| format!("Schema registry returned no ID for {}", config.subject))?;
+        Ok(Self {
+            schema_id,
+            message_indexes: message_indexes(message_descriptor),
+        })
+    }
+}
+
+/// Returns the indexes of a message type within its file, as written in the wire format.
+fn message_indexes(message: &MessageDescriptor) -> Vec<i32> {
+    let mut indexes = Vec::new();
+    let mut message = message.clone();
+    loop {
+        let parent = message.parent_message();
+        let position = match &parent {
+            Some(parent) => parent
+                .child_messages()
+                .position(|sibling| sibling.full_name() == message.full_name()),
+            None => message
+                .parent_file()
+                .messages()
+                .position(|sibling| sibling.full_name() == message.full_name()),
+        };
+        indexes.push(position.unwrap_or_default() as i32);
+        match parent {
+            Some(parent) => message = parent,
+            None => break,
+        }
+    }
+    indexes.reverse();
+    indexes
+}
+
 impl Encoder<Event> for ProtobufSerializer {
     type Error = vector_common::Error;
 
@@ -128,6 +214,36 @@ impl Encoder<Event> for ProtobufSerializer {
                 &self.options,
             ),
         }?;
+        if let Some(header) = &self.header {
+            schema_registry::write_header(header.schema_id, buffer);
+            schema_registry::write_message_indexes(&header.message_indexes, buffer);
+        }
         message.encode(buffer).map_err(Into::into)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{env, path::PathBuf};
+
+    use super::*;
+
+    #[test]
+    fn message_indexes_within_file() {
+        let desc_file = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap())
+            .join("tests/data/protobuf/protos/test_protobuf3.desc");
+
+        for (message_type, indexes) in [
+            ("test_protobuf3.Person", vec![0]),
+            ("test_protobuf3.Person.PhoneNumber", vec![0, 0]),
+            ("test_protobuf3.AddressBook", vec![1]),
+        ] {
+            let message_descriptor = get_message_descriptor(&desc_file, message_type).unwrap();
+            assert_eq!(
+                message_indexes(&message_descriptor),
+                indexes,
+                "{message_type}"
+            );
+        }
+    }
+}
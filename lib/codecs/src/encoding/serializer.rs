@@ -18,9 +18,10 @@ use super::{
         AvroSerializer, AvroSerializerConfig, AvroSerializerOptions, CefSerializer,
         CefSerializerConfig, CsvSerializer, CsvSerializerConfig, GelfSerializer,
         GelfSerializerConfig, JsonSerializer, JsonSerializerConfig, LogfmtSerializer,
-        LogfmtSerializerConfig, NativeJsonSerializer, NativeJsonSerializerConfig, NativeSerializer,
-        NativeSerializerConfig, ProtobufSerializer, ProtobufSerializerConfig, RawMessageSerializer,
-        RawMessageSerializerConfig, TextSerializer, TextSerializerConfig,
+        LogfmtSerializerConfig, MsgpackSerializer, MsgpackSerializerConfig, NativeJsonSerializer,
+        NativeJsonSerializerConfig, NativeSerializer, NativeSerializerConfig, ProtobufSerializer,
+        ProtobufSerializerConfig, RawMessageSerializer, RawMessageSerializerConfig, TextSerializer,
+        TextSerializerConfig,
     },
     framing::{
         CharacterDelimitedEncoderConfig, FramingConfig, LengthDelimitedEncoderConfig,
@@ -83,6 +84,14 @@ pub enum SerializerConfig {
     /// [logfmt]: https://brandur.org/logfmt
     Logfmt,
 
+    /// Encodes an event as [MessagePack][msgpack].
+    ///
+    /// Bytes that aren't valid UTF-8 are encoded as binary values, and timestamps are encoded
+    /// using the timestamp extension type.
+    ///
+    /// [msgpack]: https://msgpack.org/
+    Msgpack,
+
     /// Encodes an event in the [native Protocol Buffers format][vector_native_protobuf].
     ///
     /// This codec is **[experimental][experimental]**.
@@ -241,6 +250,12 @@ impl From<JsonSerializerConfig> for SerializerConfig {
     }
 }
 
+impl From<MsgpackSerializerConfig> for SerializerConfig {
+    fn from(_: MsgpackSerializerConfig) -> Self {
+        Self::Msgpack
+    }
+}
+
 impl From<LogfmtSerializerConfig> for SerializerConfig {
     fn from(_: LogfmtSerializerConfig) -> Self {
         Self::Logfmt
@@ -289,13 +304,14 @@ impl SerializerConfig {
     pub fn build(&self) -> Result<Serializer, Box<dyn std::error::Error + Send + Sync + 'static>> {
         match self {
             SerializerConfig::Avro { avro } => Ok(Serializer::Avro(
-                AvroSerializerConfig::new(avro.schema.clone()).build()?,
+                AvroSerializerConfig { avro: avro.clone() }.build()?,
             )),
             SerializerConfig::Cef(config) => Ok(Serializer::Cef(config.build()?)),
             SerializerConfig::Csv(config) => Ok(Serializer::Csv(config.build()?)),
             SerializerConfig::Gelf(config) => Ok(Serializer::Gelf(config.build())),
             SerializerConfig::Json(config) => Ok(Serializer::Json(config.build())),
             SerializerConfig::Logfmt => Ok(Serializer::Logfmt(LogfmtSerializerConfig.build())),
+            SerializerConfig::Msgpack => Ok(Serializer::Msgpack(MsgpackSerializerConfig.build())),
             SerializerConfig::Native => Ok(Serializer::Native(NativeSerializerConfig.build())),
             SerializerConfig::NativeJson => {
                 Ok(Serializer::NativeJson(NativeJsonSerializerConfig.build()))
@@ -328,7 +344,9 @@ impl SerializerConfig {
             // we should do so accurately, even if practically it doesn't need to be.
             //
             // [1]: https://avro.apache.org/docs/1.11.1/specification/_print/#message-framing
-            SerializerConfig::Avro { .. } | SerializerConfig::Native => {
+            SerializerConfig::Avro { .. }
+            | SerializerConfig::Msgpack
+            | SerializerConfig::Native => {
                 FramingConfig::LengthDelimited(LengthDelimitedEncoderConfig::default())
             }
             #[cfg(feature = "opentelemetry")]
@@ -362,6 +380,7 @@ impl SerializerConfig {
             SerializerConfig::Gelf(config) => config.input_type(),
             SerializerConfig::Json(config) => config.input_type(),
             SerializerConfig::Logfmt => LogfmtSerializerConfig.input_type(),
+            SerializerConfig::Msgpack => MsgpackSerializerConfig.input_type(),
             SerializerConfig::Native => NativeSerializerConfig.input_type(),
             SerializerConfig::NativeJson => NativeJsonSerializerConfig.input_type(),
             #[cfg(feature = "opentelemetry")]
@@ -385,6 +404,7 @@ impl SerializerConfig {
             SerializerConfig::Gelf(config) => config.schema_requirement(),
             SerializerConfig::Json(config) => config.schema_requirement(),
             SerializerConfig::Logfmt => LogfmtSerializerConfig.schema_requirement(),
+            SerializerConfig::Msgpack => MsgpackSerializerConfig.schema_requirement(),
             SerializerConfig::Native => NativeSerializerConfig.schema_requirement(),
             SerializerConfig::NativeJson => NativeJsonSerializerConfig.schema_requirement(),
             #[cfg(feature = "opentelemetry")]
@@ -413,6 +433,8 @@ pub enum Serializer {
     Json(JsonSerializer),
     /// Uses a `LogfmtSerializer` for serialization.
     Logfmt(LogfmtSerializer),
+    /// Uses a `MsgpackSerializer` for serialization.
+    Msgpack(MsgpackSerializer),
     /// Uses a `NativeSerializer` for serialization.
     Native(NativeSerializer),
     /// Uses a `NativeJsonSerializer` for serialization.
@@ -440,6 +462,7 @@ impl Serializer {
             | Serializer::Cef(_)
             | Serializer::Csv(_)
             | Serializer::Logfmt(_)
+            | Serializer::Msgpack(_)
             | Serializer::Text(_)
             | Serializer::Native(_)
             | Serializer::Protobuf(_)
@@ -466,6 +489,7 @@ impl Serializer {
             | Serializer::Cef(_)
             | Serializer::Csv(_)
             | Serializer::Logfmt(_)
+            | Serializer::Msgpack(_)
             | Serializer::Text(_)
             | Serializer::Native(_)
             | Serializer::Protobuf(_)
@@ -499,6 +523,7 @@ impl Serializer {
         match self {
             Serializer::RawMessage(_)
             | Serializer::Avro(_)
+            | Serializer::Msgpack(_)
             | Serializer::Native(_)
             | Serializer::Protobuf(_) => true,
             #[cfg(feature = "opentelemetry")]
@@ -546,6 +571,12 @@ impl From<JsonSerializer> for Serializer {
     }
 }
 
+impl From<MsgpackSerializer> for Serializer {
+    fn from(serializer: MsgpackSerializer) -> Self {
+        Self::Msgpack(serializer)
+    }
+}
+
 impl From<LogfmtSerializer> for Serializer {
     fn from(serializer: LogfmtSerializer) -> Self {
         Self::Logfmt(serializer)
@@ -606,6 +637,7 @@ impl tokio_util::codec::Encoder<Event> for Serializer {
             Serializer::Gelf(serializer) => serializer.encode(event, buffer),
             Serializer::Json(serializer) => serializer.encode(event, buffer),
             Serializer::Logfmt(serializer) => serializer.encode(event, buffer),
+            Serializer::Msgpack(serializer) => serializer.encode(event, buffer),
             Serializer::Native(serializer) => serializer.encode(event, buffer),
             Serializer::NativeJson(serializer) => serializer.encode(event, buffer),
             #[cfg(feature = "opentelemetry")]
@@ -36,6 +36,7 @@ fn build_serializer_pair(
             desc_file: desc_file.clone(),
             message_type: message_type.clone(),
             use_json_names,
+            schema_registry: None,
         },
     }
     .build()
@@ -45,6 +46,7 @@ fn build_serializer_pair(
             desc_file,
             message_type,
             use_json_names,
+            schema_registry: None,
         },
     }
     .build()
@@ -10,7 +10,7 @@ use criterion::{
     criterion_main, measurement::WallTime,
 };
 use tokio::runtime::{Handle, Runtime};
-use vector_buffers::{BufferType, MemoryBufferSize, WhenFull};
+use vector_buffers::{BufferType, DiskBufferCompression, MemoryBufferSize, WhenFull};
 
 use crate::common::{init_instrumentation, war_measurement, wtr_measurement};
 
@@ -81,6 +81,9 @@ fn create_disk_v2_variant(_max_events: usize, max_size: u64) -> BufferType {
     BufferType::DiskV2 {
         max_size: NonZeroU64::new(max_size).unwrap(),
         when_full: WhenFull::DropNewest,
+        compression: DiskBufferCompression::None,
+        encryption: None,
+        max_age: None,
     }
 }
 
@@ -94,6 +97,7 @@ fn create_in_memory_variant(bound_by: BoundBy, max_events: usize, max_size: u64)
     BufferType::Memory {
         size,
         when_full: WhenFull::DropNewest,
+        max_age: None,
     }
 }
 
@@ -16,7 +16,7 @@ use tokio::{select, sync::oneshot, task, time};
 use tracing::{Span, debug, info};
 use tracing_subscriber::EnvFilter;
 use vector_buffers::{
-    BufferType, Bufferable, EventCount, MemoryBufferSize, WhenFull,
+    BufferType, Bufferable, DiskBufferCompression, EventCount, MemoryBufferSize, WhenFull,
     encoding::FixedEncodable,
     topology::{
         builder::TopologyBuilder,
@@ -274,6 +274,7 @@ where
             BufferType::Memory {
                 size: MemoryBufferSize::MaxEvents(max_size_events),
                 when_full,
+                max_age: None,
             }
         }
         "disk-v2" => {
@@ -284,6 +285,9 @@ where
             BufferType::DiskV2 {
                 max_size: max_size_bytes,
                 when_full,
+                compression: DiskBufferCompression::None,
+                encryption: None,
+                max_age: None,
             }
         }
         s => panic!(
@@ -19,6 +19,22 @@ use crate::{
 /// always used a "relaxed" ordering when updating them.
 const ORDERING: Ordering = Ordering::Relaxed;
 
+/// The reason that a buffer stage dropped events.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DropReason {
+    /// The events couldn't be processed, such as when they were corrupted.
+    Unprocessable,
+
+    /// The buffer was full and configured to drop the incoming events.
+    DropNewest,
+
+    /// The buffer was full and configured to drop the oldest buffered events.
+    DropOldest,
+
+    /// The events were buffered for longer than the configured maximum age.
+    Expired,
+}
+
 /// Snapshot of category metrics.
 #[derive(Clone, Copy, Debug, Default)]
 struct CategorySnapshot {
@@ -194,14 +210,16 @@ impl BufferUsageHandle {
         &self,
         count: u64,
         byte_size: u64,
-        intentional: bool,
+        reason: DropReason,
     ) {
         if count > 0 || byte_size > 0 {
-            if intentional {
-                self.state.dropped_intentional.increment(count, byte_size);
-            } else {
-                self.state.dropped.increment(count, byte_size);
-            }
+            let category = match reason {
+                DropReason::Unprocessable => &self.state.dropped,
+                DropReason::DropNewest => &self.state.dropped_newest,
+                DropReason::DropOldest => &self.state.dropped_oldest,
+                DropReason::Expired => &self.state.dropped_expired,
+            };
+            category.increment(count, byte_size);
         }
     }
 }
@@ -212,7 +230,9 @@ struct BufferUsageData {
     received: CategoryMetrics,
     sent: CategoryMetrics,
     dropped: CategoryMetrics,
-    dropped_intentional: CategoryMetrics,
+    dropped_newest: CategoryMetrics,
+    dropped_oldest: CategoryMetrics,
+    dropped_expired: CategoryMetrics,
     max_size: CategoryMetrics,
 }
 
@@ -228,7 +248,11 @@ impl BufferUsageData {
         let received = self.received.get();
         let sent = self.sent.get();
         let dropped = self.dropped.get();
-        let dropped_intentional = self.dropped_intentional.get();
+        let dropped_intentional = [
+            self.dropped_newest.get(),
+            self.dropped_oldest.get(),
+            self.dropped_expired.get(),
+        ];
         let max_size = self.max_size.get();
 
         BufferUsageSnapshot {
@@ -238,8 +262,14 @@ impl BufferUsageData {
             sent_byte_size: sent.event_byte_size,
             dropped_event_count: dropped.event_count,
             dropped_event_byte_size: dropped.event_byte_size,
-            dropped_event_count_intentional: dropped_intentional.event_count,
-            dropped_event_byte_size_intentional: dropped_intentional.event_byte_size,
+            dropped_event_count_intentional: dropped_intentional
+                .iter()
+                .map(|dropped| dropped.event_count)
+                .sum(),
+            dropped_event_byte_size_intentional: dropped_intentional
+                .iter()
+                .map(|dropped| dropped.event_byte_size)
+                .sum(),
             max_size_bytes: max_size.event_byte_size,
             max_size_events: max_size
                 .event_count
@@ -273,8 +303,14 @@ impl BufferUsageData {
         let dropped = self.dropped.consume();
         current_metrics.add_left(dropped);
 
-        let dropped_intentional = self.dropped_intentional.consume();
-        current_metrics.add_left(dropped_intentional);
+        let dropped_intentional = [
+            ("drop_newest", self.dropped_newest.consume()),
+            ("drop_oldest", self.dropped_oldest.consume()),
+            ("expired", self.dropped_expired.consume()),
+        ];
+        for (_, dropped) in &dropped_intentional {
+            current_metrics.add_left(*dropped);
+        }
 
         let current = current_metrics.current();
 
@@ -313,17 +349,19 @@ impl BufferUsageData {
             });
         }
 
-        if dropped_intentional.has_updates() {
-            emit(BufferEventsDropped {
-                buffer_id: buffer_id.to_string(),
-                idx: self.idx,
-                intentional: true,
-                reason: "drop_newest",
-                count: dropped_intentional.event_count,
-                byte_size: dropped_intentional.event_byte_size,
-                total_count: current.event_count,
-                total_byte_size: current.event_byte_size,
-            });
+        for (reason, dropped) in dropped_intentional {
+            if dropped.has_updates() {
+                emit(BufferEventsDropped {
+                    buffer_id: buffer_id.to_string(),
+                    idx: self.idx,
+                    intentional: true,
+                    reason,
+                    count: dropped.event_count,
+                    byte_size: dropped.event_byte_size,
+                    total_count: current.event_count,
+                    total_byte_size: current.event_byte_size,
+                });
+            }
         }
     }
 }
@@ -498,7 +536,8 @@ mod tests {
         data.received.increment(20, 2000);
         data.sent.increment(5, 500);
         data.dropped.increment(3, 300);
-        data.dropped_intentional.increment(2, 200);
+        data.dropped_newest.increment(1, 100);
+        data.dropped_expired.increment(1, 100);
 
         data.report(&mut metrics, "test");
         let current = metrics.current();
@@ -3,12 +3,16 @@ use std::{
     num::{NonZeroU64, NonZeroUsize},
     path::{Path, PathBuf},
     slice,
+    time::Duration,
 };
 
+use base64::prelude::{BASE64_STANDARD, Engine as _};
 use serde::{Deserialize, Deserializer, Serialize, de};
 use snafu::{ResultExt, Snafu};
 use tracing::Span;
-use vector_common::{config::ComponentKey, finalization::Finalizable};
+use vector_common::{
+    config::ComponentKey, finalization::Finalizable, sensitive_string::SensitiveString,
+};
 use vector_config::configurable_component;
 
 use crate::{
@@ -17,7 +21,10 @@ use crate::{
         builder::{TopologyBuilder, TopologyError},
         channel::{BufferReceiver, BufferSender},
     },
-    variants::{DiskV2Buffer, MemoryBuffer},
+    variants::{
+        DiskV2Buffer, MemoryBuffer,
+        disk_v2::{ENCRYPTION_KEY_LEN, RecordCipher},
+    },
 };
 
 #[derive(Debug, Snafu)]
@@ -28,6 +35,8 @@ pub enum BufferBuildError {
     FailedToBuildTopology { source: TopologyError },
     #[snafu(display("`max_events` must be greater than zero"))]
     InvalidMaxEvents,
+    #[snafu(display("invalid disk buffer encryption key: {}", reason))]
+    InvalidEncryptionKey { reason: String },
 }
 
 #[derive(Deserialize, Serialize)]
@@ -38,7 +47,15 @@ enum BufferTypeKind {
     DiskV2,
 }
 
-const ALL_FIELDS: [&str; 4] = ["type", "max_events", "max_size", "when_full"];
+const ALL_FIELDS: [&str; 7] = [
+    "type",
+    "max_events",
+    "max_size",
+    "when_full",
+    "compression",
+    "encryption",
+    "max_age",
+];
 
 struct BufferTypeVisitor;
 
@@ -51,6 +68,9 @@ impl BufferTypeVisitor {
         let mut max_events: Option<NonZeroUsize> = None;
         let mut max_size: Option<NonZeroU64> = None;
         let mut when_full: Option<WhenFull> = None;
+        let mut compression: Option<DiskBufferCompression> = None;
+        let mut encryption: Option<DiskBufferEncryption> = None;
+        let mut max_age: Option<NonZeroU64> = None;
         while let Some(key) = map.next_key::<String>()? {
             match key.as_str() {
                 "type" => {
@@ -77,6 +97,24 @@ impl BufferTypeVisitor {
                     }
                     when_full = Some(map.next_value()?);
                 }
+                "compression" => {
+                    if compression.is_some() {
+                        return Err(de::Error::duplicate_field("compression"));
+                    }
+                    compression = Some(map.next_value()?);
+                }
+                "encryption" => {
+                    if encryption.is_some() {
+                        return Err(de::Error::duplicate_field("encryption"));
+                    }
+                    encryption = Some(map.next_value()?);
+                }
+                "max_age" => {
+                    if max_age.is_some() {
+                        return Err(de::Error::duplicate_field("max_age"));
+                    }
+                    max_age = Some(map.next_value()?);
+                }
                 other => {
                     return Err(de::Error::unknown_field(other, &ALL_FIELDS));
                 }
@@ -86,11 +124,23 @@ impl BufferTypeVisitor {
         let when_full = when_full.unwrap_or_default();
         match kind {
             BufferTypeKind::Memory => {
+                if compression.is_some() {
+                    return Err(de::Error::unknown_field(
+                        "compression",
+                        &["type", "max_events", "max_size", "when_full", "max_age"],
+                    ));
+                }
+                if encryption.is_some() {
+                    return Err(de::Error::unknown_field(
+                        "encryption",
+                        &["type", "max_events", "max_size", "when_full", "max_age"],
+                    ));
+                }
                 let size = match (max_events, max_size) {
                     (Some(_), Some(_)) => {
                         return Err(de::Error::unknown_field(
                             "max_events",
-                            &["type", "max_size", "when_full"],
+                            &["type", "max_size", "when_full", "max_age"],
                         ));
                     }
                     (_, Some(max_size)) => {
@@ -111,18 +161,32 @@ impl BufferTypeVisitor {
                         max_events.unwrap_or_else(memory_buffer_default_max_events),
                     ),
                 };
-                Ok(BufferType::Memory { size, when_full })
+                Ok(BufferType::Memory {
+                    size,
+                    when_full,
+                    max_age,
+                })
             }
             BufferTypeKind::DiskV2 => {
                 if max_events.is_some() {
                     return Err(de::Error::unknown_field(
                         "max_events",
-                        &["type", "max_size", "when_full"],
+                        &[
+                            "type",
+                            "max_size",
+                            "when_full",
+                            "compression",
+                            "encryption",
+                            "max_age",
+                        ],
                     ));
                 }
                 Ok(BufferType::DiskV2 {
                     max_size: max_size.ok_or_else(|| de::Error::missing_field("max_size"))?,
                     when_full,
+                    compression: compression.unwrap_or_default(),
+                    encryption,
+                    max_age,
                 })
             }
         }
@@ -211,9 +275,90 @@ pub enum MemoryBufferSize {
     MaxSize(#[configurable(metadata(docs::type_unit = "bytes"))] NonZeroUsize),
 }
 
+/// Compression applied to records written to a disk buffer.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiskBufferCompression {
+    /// Records are not compressed.
+    #[default]
+    None,
+
+    /// Records are compressed with [Zstandard][zstd].
+    ///
+    /// This provides a better compression ratio than `lz4`, at the cost of more CPU time.
+    ///
+    /// [zstd]: https://facebook.github.io/zstd/
+    Zstd,
+
+    /// Records are compressed with [LZ4][lz4].
+    ///
+    /// This is faster than `zstd`, but compresses less.
+    ///
+    /// [lz4]: https://lz4.org/
+    Lz4,
+}
+
+/// Encryption at rest for a disk buffer.
+///
+/// Records are encrypted with XChaCha20-Poly1305 before being written to disk.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct DiskBufferEncryption {
+    /// The key used to encrypt records written to the buffer.
+    ///
+    /// Must be a 32-byte key, encoded as base64. Rather than writing the key into the
+    /// configuration, it should be retrieved with [secrets management][secrets].
+    ///
+    /// [secrets]: https://vector.dev/docs/reference/configuration/secrets/
+    #[configurable(metadata(docs::examples = "SECRET[backend.buffer_key]"))]
+    pub key: SensitiveString,
+
+    /// Keys that were previously used to encrypt records in the buffer.
+    ///
+    /// New records are always encrypted with `key`, but records encrypted with any of these keys
+    /// can still be read. When rotating keys, move the previous key here, and only remove it once
+    /// the records written with it have been read from the buffer.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "SECRET[backend.previous_buffer_key]"))]
+    pub previous_keys: Vec<SensitiveString>,
+}
+
+impl DiskBufferEncryption {
+    /// Builds the cipher used to encrypt and decrypt records.
+    ///
+    /// # Errors
+    ///
+    /// If any of the keys is not valid base64, or does not decode to exactly 32 bytes, an error
+    /// variant will be returned.
+    pub fn build_cipher(&self) -> Result<RecordCipher, BufferBuildError> {
+        let decode = |key: &SensitiveString| {
+            BASE64_STANDARD.decode(key.inner().trim()).map_err(|error| {
+                BufferBuildError::InvalidEncryptionKey {
+                    reason: format!("keys must be encoded as base64: {error}"),
+                }
+            })
+        };
+
+        let current_key = decode(&self.key)?;
+        let previous_keys = self
+            .previous_keys
+            .iter()
+            .map(decode)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        RecordCipher::new(current_key, previous_keys).map_err(|error| {
+            BufferBuildError::InvalidEncryptionKey {
+                reason: format!("{error} (keys must be {ENCRYPTION_KEY_LEN} bytes once decoded)"),
+            }
+        })
+    }
+}
+
 /// A specific type of buffer stage.
 #[configurable_component(no_deser)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[serde(rename_all = "snake_case", tag = "type")]
 #[configurable(metadata(docs::enum_tag_description = "The type of buffer to use."))]
 pub enum BufferType {
@@ -230,6 +375,14 @@ pub enum BufferType {
         #[configurable(derived)]
         #[serde(default)]
         when_full: WhenFull,
+
+        /// The maximum age of events in the buffer, in seconds.
+        ///
+        /// Events that have been in the buffer for longer than this are skipped when read, and are
+        /// counted as discarded rather than being sent to the sink.
+        #[configurable(metadata(docs::type_unit = "seconds"))]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_age: Option<NonZeroU64>,
     },
 
     /// A buffer stage backed by disk.
@@ -253,6 +406,23 @@ pub enum BufferType {
         #[configurable(derived)]
         #[serde(default)]
         when_full: WhenFull,
+
+        #[configurable(derived)]
+        #[serde(default)]
+        compression: DiskBufferCompression,
+
+        #[configurable(derived)]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        encryption: Option<DiskBufferEncryption>,
+
+        /// The maximum age of events in the buffer, in seconds.
+        ///
+        /// Events that have been in the buffer for longer than this are skipped when read, and are
+        /// counted as discarded rather than being sent to the sink. Data files whose events have all
+        /// expired are deleted without being read.
+        #[configurable(metadata(docs::type_unit = "seconds"))]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_age: Option<NonZeroU64>,
     },
 }
 
@@ -311,16 +481,37 @@ impl BufferType {
     where
         T: Bufferable + Clone + Finalizable,
     {
-        match *self {
-            BufferType::Memory { size, when_full } => {
-                builder.stage(MemoryBuffer::new(size), when_full);
+        match self {
+            BufferType::Memory {
+                size,
+                when_full,
+                max_age,
+            } => {
+                builder.stage(
+                    MemoryBuffer::new(*size)
+                        .with_max_age(max_age.map(|secs| Duration::from_secs(secs.get()))),
+                    *when_full,
+                );
             }
             BufferType::DiskV2 {
                 when_full,
                 max_size,
+                compression,
+                encryption,
+                max_age,
             } => {
                 let data_dir = data_dir.ok_or(BufferBuildError::RequiresDataDir)?;
-                builder.stage(DiskV2Buffer::new(id, data_dir, max_size), when_full);
+                let encryption = encryption
+                    .as_ref()
+                    .map(DiskBufferEncryption::build_cipher)
+                    .transpose()?;
+                builder.stage(
+                    DiskV2Buffer::new(id, data_dir, *max_size)
+                        .with_compression(*compression)
+                        .with_encryption(encryption)
+                        .with_max_age(max_age.map(|secs| Duration::from_secs(secs.get()))),
+                    *when_full,
+                );
             }
         }
 
@@ -369,6 +560,7 @@ impl Default for BufferConfig {
         Self::Single(BufferType::Memory {
             size: MemoryBufferSize::MaxEvents(memory_buffer_default_max_events()),
             when_full: WhenFull::default(),
+            max_age: None,
         })
     }
 }
@@ -428,7 +620,10 @@ impl BufferConfig {
 mod test {
     use std::num::{NonZeroU64, NonZeroUsize};
 
-    use crate::{BufferConfig, BufferType, MemoryBufferSize, WhenFull};
+    use crate::{
+        BufferConfig, BufferType, DiskBufferCompression, MemoryBufferSize, WhenFull,
+        config::{BufferBuildError, DiskBufferEncryption},
+    };
 
     fn check_single_stage(source: &str, expected: BufferType) {
         let config: BufferConfig = serde_yaml::from_str(source).unwrap();
@@ -480,6 +675,7 @@ max_events: 42
             BufferType::Memory {
                 size: MemoryBufferSize::MaxEvents(NonZeroUsize::new(100).unwrap()),
                 when_full: WhenFull::Block,
+                max_age: None,
             },
         );
     }
@@ -493,6 +689,7 @@ max_events: 42
             BufferType::Memory {
                 size: MemoryBufferSize::MaxSize(NonZeroUsize::new(4096).unwrap()),
                 when_full: WhenFull::Block,
+                max_age: None,
             },
         );
     }
@@ -509,10 +706,12 @@ max_events: 42
                 BufferType::Memory {
                     size: MemoryBufferSize::MaxEvents(NonZeroUsize::new(42).unwrap()),
                     when_full: WhenFull::Block,
+                    max_age: None,
                 },
                 BufferType::Memory {
                     size: MemoryBufferSize::MaxEvents(NonZeroUsize::new(100).unwrap()),
                     when_full: WhenFull::DropNewest,
+                    max_age: None,
                 },
             ],
         );
@@ -527,6 +726,7 @@ max_events: 42
             BufferType::Memory {
                 size: MemoryBufferSize::MaxEvents(NonZeroUsize::new(500).unwrap()),
                 when_full: WhenFull::Block,
+                max_age: None,
             },
         );
 
@@ -538,6 +738,7 @@ max_events: 42
             BufferType::Memory {
                 size: MemoryBufferSize::MaxEvents(NonZeroUsize::new(100).unwrap()),
                 when_full: WhenFull::Block,
+                max_age: None,
             },
         );
 
@@ -549,6 +750,7 @@ max_events: 42
             BufferType::Memory {
                 size: MemoryBufferSize::MaxEvents(NonZeroUsize::new(500).unwrap()),
                 when_full: WhenFull::DropNewest,
+                max_age: None,
             },
         );
 
@@ -560,18 +762,132 @@ max_events: 42
             BufferType::Memory {
                 size: MemoryBufferSize::MaxEvents(NonZeroUsize::new(500).unwrap()),
                 when_full: WhenFull::Overflow,
+                max_age: None,
+            },
+        );
+
+        check_single_stage(
+            r"
+          type: disk
+          max_size: 1024
+          ",
+            BufferType::DiskV2 {
+                max_size: NonZeroU64::new(1024).unwrap(),
+                when_full: WhenFull::Block,
+                compression: DiskBufferCompression::None,
+                encryption: None,
+                max_age: None,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_disk_with_compression() {
+        check_single_stage(
+            r"
+          type: disk
+          max_size: 1024
+          compression: lz4
+          ",
+            BufferType::DiskV2 {
+                max_size: NonZeroU64::new(1024).unwrap(),
+                when_full: WhenFull::Block,
+                compression: DiskBufferCompression::Lz4,
+                encryption: None,
+                max_age: None,
             },
         );
+    }
 
+    #[test]
+    fn parse_disk_with_encryption() {
         check_single_stage(
             r"
           type: disk
           max_size: 1024
+          encryption:
+            key: AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=
+            previous_keys:
+              - HxwdHhsaGRgXFhUUExIREA8ODQwLCgkIBwYFBAMCAQA=
           ",
             BufferType::DiskV2 {
                 max_size: NonZeroU64::new(1024).unwrap(),
                 when_full: WhenFull::Block,
+                compression: DiskBufferCompression::None,
+                encryption: Some(DiskBufferEncryption {
+                    key: "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8="
+                        .to_string()
+                        .into(),
+                    previous_keys: vec![
+                        "HxwdHhsaGRgXFhUUExIREA8ODQwLCgkIBwYFBAMCAQA="
+                            .to_string()
+                            .into(),
+                    ],
+                }),
+                max_age: None,
             },
         );
     }
+
+    #[test]
+    fn parse_max_age() {
+        check_single_stage(
+            r"
+          max_events: 100
+          max_age: 60
+          ",
+            BufferType::Memory {
+                size: MemoryBufferSize::MaxEvents(NonZeroUsize::new(100).unwrap()),
+                when_full: WhenFull::Block,
+                max_age: NonZeroU64::new(60),
+            },
+        );
+
+        check_single_stage(
+            r"
+          type: disk
+          max_size: 1024
+          max_age: 3600
+          ",
+            BufferType::DiskV2 {
+                max_size: NonZeroU64::new(1024).unwrap(),
+                when_full: WhenFull::Block,
+                compression: DiskBufferCompression::None,
+                encryption: None,
+                max_age: NonZeroU64::new(3600),
+            },
+        );
+    }
+
+    #[test]
+    fn parse_memory_with_encryption_fails() {
+        let source = r"
+          type: memory
+          encryption:
+            key: AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=
+          ";
+        assert!(serde_yaml::from_str::<BufferConfig>(source).is_err());
+    }
+
+    #[test]
+    fn encryption_key_must_be_32_bytes_of_base64() {
+        let encryption = DiskBufferEncryption {
+            key: "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8="
+                .to_string()
+                .into(),
+            previous_keys: Vec::new(),
+        };
+        assert!(encryption.build_cipher().is_ok());
+
+        for key in ["not base64!", "AAECAwQFBgcI"] {
+            let encryption = DiskBufferEncryption {
+                key: key.to_string().into(),
+                previous_keys: Vec::new(),
+            };
+            assert!(matches!(
+                encryption.build_cipher(),
+                Err(BufferBuildError::InvalidEncryptionKey { .. })
+            ));
+        }
+    }
 }
@@ -17,7 +17,7 @@ extern crate tracing;
 mod buffer_usage_data;
 
 pub mod config;
-pub use config::{BufferConfig, BufferType, MemoryBufferSize};
+pub use config::{BufferConfig, BufferType, DiskBufferCompression, MemoryBufferSize};
 use encoding::Encodable;
 pub(crate) use vector_common::Result;
 use vector_config::configurable_component;
@@ -31,6 +31,7 @@ pub mod test;
 pub mod topology;
 
 pub(crate) mod variants;
+pub use variants::disk_v2::{BufferInspector, InspectedRecord};
 
 /// `disk_v2`'s write-buffer size, re-exported under `test` so the harness can
 /// size payloads against the real value instead of hardcoding it.
@@ -66,6 +67,18 @@ pub enum WhenFull {
     /// slowdown in the acceptance/consumption of events.
     DropNewest,
 
+    /// Drops the oldest events in the buffer to make room for the event.
+    ///
+    /// The evicted events will be intentionally dropped. This mode is typically used when the most
+    /// recent data is the most valuable, such as during a prolonged outage of the downstream
+    /// service, and it is preferable to lose older events rather than cause a slowdown in the
+    /// acceptance/consumption of events.
+    ///
+    /// Disk buffers evict whole data files at a time, so `max_size` should be several times larger
+    /// than the size of a single data file (128 MiB) for this mode to be effective. If no data
+    /// file can be evicted, the event itself is dropped instead.
+    DropOldest,
+
     /// Overflows to the next stage in the buffer topology.
     ///
     /// If the current buffer stage is full, attempt to send this event to the next buffer stage.
@@ -40,7 +40,7 @@ pub enum TopologyError {
     #[snafu(display("buffer topology cannot be empty"))]
     EmptyTopology,
     #[snafu(display(
-        "stage {} configured with block/drop newest behavior in front of subsequent stage",
+        "stage {} configured with block/drop newest/drop oldest behavior in front of subsequent stage",
         stage_idx
     ))]
     NextStageNotUsed { stage_idx: usize },
@@ -76,15 +76,15 @@ impl<T: Bufferable> TopologyBuilder<T> {
     /// an overflow buffer is added to the topology after this, then the specified "when full"
     /// behavior will be ignored and will be set to "overflow" mode.
     ///
-    /// Callers can configure what to do when a buffer is full by setting `when_full`.  Three modes
-    /// are available -- block, drop newest, and overflow -- which are documented in more detail by
-    /// [`BufferSender`].
+    /// Callers can configure what to do when a buffer is full by setting `when_full`.  Four modes
+    /// are available -- block, drop newest, drop oldest, and overflow -- which are documented in
+    /// more detail by [`BufferSender`].
     ///
     /// Two notes about what modes are not valid in certain scenarios:
     /// - the innermost stage (the last stage given to the builder) cannot be set to "overflow" mode,
     ///   as there is no other stage to overflow to
-    /// - a stage cannot use the "block" or "drop newest" mode when there is a subsequent stage, and
-    ///   must user the "overflow" mode
+    /// - a stage cannot use the "block", "drop newest", or "drop oldest" mode when there is a
+    ///   subsequent stage, and must use the "overflow" mode
     ///
     /// Any occurrence of either of these scenarios will result in an error during build.
     pub fn stage<S>(&mut self, stage: S, when_full: WhenFull) -> &mut Self
@@ -122,9 +122,9 @@ impl<T: Bufferable> TopologyBuilder<T> {
                         return Err(TopologyError::OverflowWhenLast);
                     }
                 }
-                // If there's already an inner stage, then blocking or dropping the newest events
-                // doesn't no sense.  Overflowing is the only valid transition to another stage.
-                WhenFull::Block | WhenFull::DropNewest => {
+                // If there's already an inner stage, then blocking or dropping events doesn't make
+                // sense.  Overflowing is the only valid transition to another stage.
+                WhenFull::Block | WhenFull::DropNewest | WhenFull::DropOldest => {
                     if current_stage.is_some() {
                         return Err(TopologyError::NextStageNotUsed { stage_idx });
                     }
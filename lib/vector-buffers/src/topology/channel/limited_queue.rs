@@ -7,7 +7,7 @@ use std::{
         Arc,
         atomic::{AtomicUsize, Ordering},
     },
-    time::Instant,
+    time::{Duration, Instant},
 };
 
 #[cfg(test)]
@@ -22,7 +22,11 @@ use vector_common::internal_event::{GaugeName, HistogramName};
 use vector_common::stats::TimeEwmaGauge;
 use vector_common::{gauge, histogram};
 
-use crate::{InMemoryBufferable, config::MemoryBufferSize};
+use crate::{
+    InMemoryBufferable,
+    buffer_usage_data::{BufferUsageHandle, DropReason},
+    config::MemoryBufferSize,
+};
 
 pub const DEFAULT_EWMA_HALF_LIFE_SECONDS: f64 = 5.0;
 
@@ -231,7 +235,7 @@ impl Metrics {
 
 #[derive(Debug)]
 struct Inner<T> {
-    data: Arc<dyn QueueImpl<(OwnedSemaphorePermit, T)>>,
+    data: Arc<dyn QueueImpl<(OwnedSemaphorePermit, tokio::time::Instant, T)>>,
     limit: MemoryBufferSize,
     limiter: Arc<Semaphore>,
     read_waker: Arc<Notify>,
@@ -294,7 +298,8 @@ impl<T: Send + Sync + Debug + 'static> Inner<T> {
             let utilization = size.max(self.used_capacity());
             metrics.record(utilization, Instant::now());
         }
-        self.data.push((permits, item));
+        // The send time is taken from the Tokio clock, so that tests of expiry can advance it.
+        self.data.push((permits, tokio::time::Instant::now(), item));
         self.read_waker.notify_one();
     }
 }
@@ -304,8 +309,9 @@ impl<T> Inner<T> {
         self.capacity.get() - self.limiter.available_permits()
     }
 
-    fn pop_and_record(&self) -> Option<T> {
-        self.data.pop().map(|(permit, item)| {
+    /// Pops the oldest item, along with the time it was sent.
+    fn pop_and_record(&self) -> Option<(tokio::time::Instant, T)> {
+        self.data.pop().map(|(permit, sent_at, item)| {
             if let Some(metrics) = &self.metrics {
                 // Compute remaining utilization from the semaphore state. Since our permits haven't
                 // been released yet, used_capacity is stable against racing senders acquiring those
@@ -316,7 +322,7 @@ impl<T> Inner<T> {
             // Release permits after recording so a waiting sender cannot enqueue a new item
             // before this pop's utilization measurement is taken.
             drop(permit);
-            item
+            (sent_at, item)
         })
     }
 }
@@ -401,6 +407,41 @@ impl<T: InMemoryBufferable> LimitedSender<T> {
             Err(TryAcquireError::Closed) => Err(TrySendError::Disconnected(item)),
         }
     }
+
+    /// Sends an item into the channel, evicting the oldest items if there is insufficient capacity.
+    ///
+    /// The evicted items are returned to the caller, which is responsible for accounting for them.
+    /// If there is still insufficient capacity once the channel is empty, which can happen when
+    /// other senders are concurrently holding capacity, this waits for capacity like
+    /// [`LimitedSender::send`].
+    ///
+    /// # Errors
+    ///
+    /// If the receiver has disconnected (does not exist anymore), then `Err(SendError)` be returned
+    /// with the given `item`.
+    pub async fn send_dropping_oldest(&mut self, item: T) -> Result<Vec<T>, SendError<T>> {
+        let (size, permits_required) = self.calc_required_permits(&item);
+        let mut evicted = Vec::new();
+        loop {
+            match self
+                .inner
+                .limiter
+                .clone()
+                .try_acquire_many_owned(permits_required)
+            {
+                Ok(permits) => {
+                    self.inner.send_with_permits(size, permits, item);
+                    trace!(evicted = evicted.len(), "Sent item.");
+                    return Ok(evicted);
+                }
+                Err(TryAcquireError::NoPermits) => match self.inner.pop_and_record() {
+                    Some((_, oldest)) => evicted.push(oldest),
+                    None => return self.send(item).await.map(|()| evicted),
+                },
+                Err(TryAcquireError::Closed) => return Err(SendError(item)),
+            }
+        }
+    }
 }
 
 impl<T> Clone for LimitedSender<T> {
@@ -424,9 +465,47 @@ impl<T> Drop for LimitedSender<T> {
     }
 }
 
+/// Discards items that have been in a channel for longer than a maximum age.
+#[derive(Debug)]
+struct Expiry<T> {
+    max_age: Duration,
+    usage_handle: BufferUsageHandle,
+    measure: fn(&T) -> (u64, u64),
+}
+
+impl<T> Expiry<T> {
+    fn discard(&self, item: &T) {
+        let (event_count, byte_size) = (self.measure)(item);
+        trace!(event_count, "Discarding expired item.");
+        self.usage_handle
+            .increment_dropped_event_count_and_byte_size(
+                event_count,
+                byte_size,
+                DropReason::Expired,
+            );
+    }
+}
+
 #[derive(Debug)]
 pub struct LimitedReceiver<T> {
     inner: Inner<T>,
+    expiry: Option<Expiry<T>>,
+}
+
+impl<T: InMemoryBufferable> LimitedReceiver<T> {
+    /// Configures this receiver to discard items that have been in the channel for longer than
+    /// `max_age`, rather than returning them.
+    ///
+    /// Discarded items are tracked as intentionally dropped with the given usage handle.
+    #[must_use]
+    pub fn with_max_age(mut self, max_age: Duration, usage_handle: BufferUsageHandle) -> Self {
+        self.expiry = Some(Expiry {
+            max_age,
+            usage_handle,
+            measure: |item| (item.event_count() as u64, item.size_of() as u64),
+        });
+        self
+    }
 }
 
 impl<T: Send + 'static> LimitedReceiver<T> {
@@ -437,7 +516,13 @@ impl<T: Send + 'static> LimitedReceiver<T> {
 
     pub async fn next(&mut self) -> Option<T> {
         loop {
-            if let Some(item) = self.inner.pop_and_record() {
+            if let Some((sent_at, item)) = self.inner.pop_and_record() {
+                if let Some(expiry) = &self.expiry
+                    && sent_at.elapsed() > expiry.max_age
+                {
+                    expiry.discard(&item);
+                    continue;
+                }
                 return Some(item);
             }
 
@@ -490,14 +575,17 @@ pub fn limited<T: InMemoryBufferable + fmt::Debug>(
         inner: inner.clone(),
         sender_count: Arc::new(AtomicUsize::new(1)),
     };
-    let receiver = LimitedReceiver { inner };
+    let receiver = LimitedReceiver {
+        inner,
+        expiry: None,
+    };
 
     (sender, receiver)
 }
 
 #[cfg(test)]
 mod tests {
-    use std::num::NonZeroUsize;
+    use std::{num::NonZeroUsize, time::Duration};
 
     use rand::{RngExt as _, SeedableRng as _, rngs::SmallRng};
     use tokio_test::{assert_pending, assert_ready, task::spawn};
@@ -508,6 +596,7 @@ mod tests {
     };
     use crate::{
         MemoryBufferSize,
+        buffer_usage_data::BufferUsageHandle,
         test::MultiEventRecord,
         topology::{channel::limited_queue::SendError, test_util::Sample},
     };
@@ -879,6 +968,82 @@ mod tests {
         assert_eq!(None, assert_ready!(recv.poll()));
     }
 
+    #[tokio::test]
+    async fn send_dropping_oldest_evicts_oldest_items() {
+        let limit = MemoryBufferSize::MaxEvents(NonZeroUsize::new(2).unwrap());
+        let (mut tx, mut rx) = limited(limit, None, None);
+
+        assert_eq!(
+            Ok(Vec::new()),
+            tx.send_dropping_oldest(Sample::new(1)).await
+        );
+        assert_eq!(
+            Ok(Vec::new()),
+            tx.send_dropping_oldest(Sample::new(2)).await
+        );
+        assert_eq!(0, tx.available_capacity());
+
+        // The channel is full, so the oldest item should be evicted to make room.
+        assert_eq!(
+            Ok(vec![Sample::new(1)]),
+            tx.send_dropping_oldest(Sample::new(3)).await
+        );
+        assert_eq!(0, tx.available_capacity());
+
+        drop(tx);
+        assert_eq!(Some(Sample::new(2)), rx.next().await);
+        assert_eq!(Some(Sample::new(3)), rx.next().await);
+        assert_eq!(None, rx.next().await);
+    }
+
+    #[tokio::test]
+    async fn send_dropping_oldest_evicts_enough_items_for_large_item() {
+        let limit = MemoryBufferSize::MaxEvents(NonZeroUsize::new(3).unwrap());
+        let (mut tx, mut rx) = limited(limit, None, None);
+
+        for _ in 0..3 {
+            let evicted = tx
+                .send_dropping_oldest(MultiEventRecord::new(1))
+                .await
+                .expect("send should succeed");
+            assert!(evicted.is_empty());
+        }
+
+        // An item needing most of the capacity of the channel evicts as many items as necessary.
+        let evicted = tx
+            .send_dropping_oldest(MultiEventRecord::new(2))
+            .await
+            .expect("send should succeed");
+        assert_eq!(2, evicted.len());
+
+        drop(tx);
+        assert_eq!(Some(MultiEventRecord::new(1)), rx.next().await);
+        assert_eq!(Some(MultiEventRecord::new(2)), rx.next().await);
+        assert_eq!(None, rx.next().await);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn receiver_discards_expired_items() {
+        let limit = MemoryBufferSize::MaxEvents(NonZeroUsize::new(4).unwrap());
+        let usage_handle = BufferUsageHandle::noop();
+        let (mut tx, rx) = limited(limit, None, None);
+        let mut rx = rx.with_max_age(Duration::from_millis(50), usage_handle.clone());
+
+        tx.send(Sample::new(1)).await.expect("send should succeed");
+        tx.send(Sample::new(2)).await.expect("send should succeed");
+        tokio::time::advance(Duration::from_millis(100)).await;
+        tx.send(Sample::new(3)).await.expect("send should succeed");
+
+        // Only the item sent after the first two expired should be returned.
+        drop(tx);
+        assert_eq!(Some(Sample::new(3)), rx.next().await);
+        assert_eq!(None, rx.next().await);
+
+        let snapshot = usage_handle.snapshot();
+        assert_eq!(2, snapshot.dropped_event_count_intentional);
+        assert_eq!(0, snapshot.dropped_event_count);
+    }
+
     #[test]
     fn oversized_send_allowed_when_empty() {
         let limit = MemoryBufferSize::MaxEvents(NonZeroUsize::new(1).unwrap());
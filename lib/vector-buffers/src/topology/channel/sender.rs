@@ -7,12 +7,15 @@ use async_recursion::async_recursion;
 use derivative::Derivative;
 use tokio::sync::Mutex;
 use tracing::Span;
-use vector_common::internal_event::{InternalEventHandle, Registered, register};
+use vector_common::{
+    byte_size_of::ByteSizeOf,
+    internal_event::{InternalEventHandle, Registered, register},
+};
 
 use super::limited_queue::LimitedSender;
 use crate::{
-    BufferInstrumentation, Bufferable, WhenFull,
-    buffer_usage_data::BufferUsageHandle,
+    BufferInstrumentation, Bufferable, EventCount, WhenFull,
+    buffer_usage_data::{BufferUsageHandle, DropReason},
     internal_events::BufferSendDuration,
     variants::disk_v2::{self, ProductionFilesystem, TryWriteOutcome},
 };
@@ -143,6 +146,49 @@ where
         }
     }
 
+    /// Sends an item, evicting the oldest items in the buffer if there is insufficient capacity.
+    ///
+    /// Returns the items evicted from the buffer, if any, which the caller is responsible for
+    /// accounting for. Disk stages evict whole data files, and instrument those evictions
+    /// themselves, so they never return any items.
+    pub(crate) async fn send_dropping_oldest(
+        &mut self,
+        item: T,
+    ) -> crate::Result<(TryWriteOutcome<T>, Vec<T>)> {
+        match self {
+            Self::InMemory(tx) => tx
+                .send_dropping_oldest(item)
+                .await
+                .map(|evicted| (TryWriteOutcome::Written, evicted))
+                .map_err(Into::into),
+            Self::DiskV2(writer) => {
+                let pre_count = item.event_count() as u64;
+                let pre_size = item.size_of() as u64;
+                let mut writer = writer.lock().await;
+
+                let Some(item) = item.filter_unencodable() else {
+                    writer.track_dropped(pre_count, pre_size);
+                    return Ok((TryWriteOutcome::Dropped, Vec::new()));
+                };
+                if item.event_count() as u64 != pre_count {
+                    let dropped_events = pre_count - item.event_count() as u64;
+                    let dropped_bytes = pre_size.saturating_sub(item.size_of() as u64);
+                    writer.track_dropped(dropped_events, dropped_bytes);
+                }
+
+                writer
+                    .write_record_dropping_oldest(item)
+                    .await
+                    .map(|outcome| (outcome, Vec::new()))
+                    .map_err(|e| {
+                        error!("Disk buffer writer has encountered an unrecoverable error.");
+
+                        e.into()
+                    })
+            }
+        }
+    }
+
     pub(crate) async fn flush(&mut self) -> crate::Result<()> {
         match self {
             Self::InMemory(_) => Ok(()),
@@ -169,6 +215,10 @@ where
 enum UsageAccounting {
     Accepted,
     DroppedNewest,
+    DroppedOldest {
+        event_count: usize,
+        byte_size: usize,
+    },
     NotAccepted,
 }
 
@@ -185,7 +235,21 @@ impl UsageAccounting {
                 instrumentation.increment_dropped_event_count_and_byte_size(
                     item_count as u64,
                     item_size as u64,
-                    true,
+                    DropReason::DropNewest,
+                );
+            }
+            Self::DroppedOldest {
+                event_count,
+                byte_size,
+            } => {
+                instrumentation.increment_received_event_count_and_byte_size(
+                    item_count as u64,
+                    item_size as u64,
+                );
+                instrumentation.increment_dropped_event_count_and_byte_size(
+                    event_count as u64,
+                    byte_size as u64,
+                    DropReason::DropOldest,
                 );
             }
             Self::NotAccepted => {}
@@ -199,15 +263,17 @@ impl UsageAccounting {
 /// events when the internal channel is full.
 ///
 /// When creating a buffer sender/receiver pair, callers can specify the "when full" behavior of the
-/// sender.  This controls how events are handled when the internal channel is full.  Three modes
+/// sender.  This controls how events are handled when the internal channel is full.  Four modes
 /// are possible:
 /// - block
 /// - drop newest
+/// - drop oldest
 /// - overflow
 ///
 /// In "block" mode, callers are simply forced to wait until the channel has enough capacity to
 /// accept the event.  In "drop newest" mode, any event being sent when the channel is full will be
-/// dropped and proceed no further. In "overflow" mode, events will be sent to another buffer
+/// dropped and proceed no further. In "drop oldest" mode, the oldest events in the channel are
+/// dropped to make room for the event being sent. In "overflow" mode, events will be sent to another buffer
 /// sender.  Callers can specify the overflow sender to use when constructing their buffers initially.
 ///
 /// TODO: We should eventually rework `BufferSender`/`BufferReceiver` so that they contain a vector
@@ -316,6 +382,16 @@ impl<T: Bufferable> BufferSender<T> {
                 TryWriteOutcome::Full(_) => UsageAccounting::DroppedNewest,
                 TryWriteOutcome::Dropped => UsageAccounting::NotAccepted,
             },
+            WhenFull::DropOldest => match self.base.send_dropping_oldest(item).await? {
+                (TryWriteOutcome::Written, evicted) => UsageAccounting::DroppedOldest {
+                    event_count: evicted.iter().map(EventCount::event_count).sum(),
+                    byte_size: evicted.iter().map(ByteSizeOf::size_of).sum(),
+                },
+                (TryWriteOutcome::Full(_), _) => {
+                    unreachable!("sends dropping the oldest items never report a full buffer")
+                }
+                (TryWriteOutcome::Dropped, _) => UsageAccounting::NotAccepted,
+            },
             WhenFull::Overflow => {
                 // An item the base stage can never encode is routed to the overflow stage
                 // intact, whatever the current occupancy. Deciding this here, rather than
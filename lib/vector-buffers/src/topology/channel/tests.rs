@@ -134,6 +134,28 @@ async fn test_sender_drop_newest() {
     assert_eq!(results, vec![1, 2, 3]);
 }
 
+#[tokio::test]
+async fn test_sender_drop_oldest() {
+    // Get a non-overflow buffer in "drop oldest" mode with a capacity of 3.
+    let (mut tx, rx, _) = build_buffer(3, WhenFull::DropOldest, None);
+
+    // We should be able to send three messages through unimpeded.
+    assert_current_send_capacity(&mut tx, Some(3), None);
+    assert_send_ok_with_capacities(&mut tx, 1, Some(2), None).await;
+    assert_send_ok_with_capacities(&mut tx, 2, Some(1), None).await;
+    assert_send_ok_with_capacities(&mut tx, 3, Some(0), None).await;
+
+    // Then, since we're in "drop oldest" mode, we could continue to send without issue or being
+    // blocked, but we would expect the oldest items in the buffer to be dropped to make room.
+    assert_send_ok_with_capacities(&mut tx, 7, Some(0), None).await;
+    assert_send_ok_with_capacities(&mut tx, 8, Some(0), None).await;
+
+    // Then, when we collect all of the messages from the receiver, we should only get back the
+    // last three of them.
+    let results: Vec<u64> = drain_receiver(tx, rx).await;
+    assert_eq!(results, vec![3, 7, 8]);
+}
+
 #[tokio::test]
 async fn test_sender_overflow_block() {
     // Get an overflow buffer, where the overflow buffer is in blocking mode, and both the base
@@ -241,6 +263,32 @@ async fn test_buffer_metrics_drop_newest() {
     assert_eq!(1, snapshot.dropped_event_count_intentional);
 }
 
+#[tokio::test]
+async fn test_buffer_metrics_drop_oldest() {
+    // Get a buffer that drops the oldest items when full.
+    let (mut tx, rx, handle) = build_buffer(2, WhenFull::DropOldest, None);
+
+    // Send three items through, and make sure the buffer usage stats reflect that.
+    assert_current_send_capacity(&mut tx, Some(2), None);
+    assert_send_ok_with_capacities(&mut tx, 7, Some(1), None).await;
+    assert_send_ok_with_capacities(&mut tx, 8, Some(0), None).await;
+    assert_send_ok_with_capacities(&mut tx, 2, Some(0), None).await;
+
+    let snapshot = handle.snapshot();
+    assert_eq!(3, snapshot.received_event_count);
+    assert_eq!(0, snapshot.sent_event_count);
+    assert_eq!(1, snapshot.dropped_event_count_intentional);
+
+    // Then, when we collect all of the messages from the receiver, the metrics should also reflect that.
+    let results: Vec<u64> = drain_receiver(tx, rx).await;
+    assert_eq!(results, vec![8, 2]);
+
+    let snapshot = handle.snapshot();
+    assert_eq!(3, snapshot.received_event_count);
+    assert_eq!(2, snapshot.sent_event_count);
+    assert_eq!(1, snapshot.dropped_event_count_intentional);
+}
+
 #[tokio::test]
 async fn test_buffer_metrics_overflow_block() {
     // Get an overflow buffer, where the overflow buffer is in blocking mode, and both the base
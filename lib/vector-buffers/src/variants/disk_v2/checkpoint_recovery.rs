@@ -456,7 +456,10 @@ where
             RecordStatus::Valid { id: last_record_id } => {
                 let record = try_as_record_archive(data_file_mmap.as_ref())
                     .expect("record was already validated");
-                let item = match decode_record_payload::<T>(record) {
+                let item = match decode_record_payload::<T>(
+                    record,
+                    self.ledger().config().encryption.as_ref(),
+                ) {
                     Ok(item) => item,
                     Err(error) => {
                         warn!(
@@ -500,7 +503,8 @@ where
             return Ok(scan);
         };
 
-        let mut record_reader = RecordReader::new(data_file);
+        let mut record_reader =
+            RecordReader::new(data_file).with_cipher(self.ledger().config().encryption.clone());
 
         let truncate_at = loop {
             let token = match self
@@ -6,7 +6,10 @@ use std::{
 use crc32fast::Hasher;
 use snafu::Snafu;
 
+use crate::config::DiskBufferCompression;
+
 use super::{
+    encryption::RecordCipher,
     io::{Filesystem, ProductionFilesystem},
     ledger::LEDGER_LEN,
     record::RECORD_HEADER_LEN,
@@ -179,6 +182,24 @@ pub struct DiskBufferConfig<FS> {
     /// amount of data written since the last flush would be lost.
     pub(crate) flush_interval: Duration,
 
+    /// Compression applied to record payloads.
+    ///
+    /// Records are compressed before they are encrypted, and the size of the compressed record is
+    /// what counts towards the data file and buffer size limits.
+    pub(crate) compression: DiskBufferCompression,
+
+    /// Cipher used to encrypt record payloads.
+    ///
+    /// When `None`, records are written in plaintext. Encrypted records can only be read when a
+    /// cipher holding the key they were encrypted with is configured.
+    pub(crate) encryption: Option<RecordCipher>,
+
+    /// Maximum age of records.
+    ///
+    /// When set, records are stamped with the time they were written, and records older than this
+    /// are discarded instead of being read.
+    pub(crate) max_age: Option<Duration>,
+
     /// Filesystem implementation for opening data files.
     ///
     /// We allow parameterizing the filesystem implementation for ease of testing.  The "filesystem"
@@ -199,6 +220,9 @@ where
     pub(crate) max_record_size: Option<usize>,
     pub(crate) write_buffer_size: Option<usize>,
     pub(crate) flush_interval: Option<Duration>,
+    pub(crate) compression: DiskBufferCompression,
+    pub(crate) encryption: Option<RecordCipher>,
+    pub(crate) max_age: Option<Duration>,
     pub(crate) filesystem: FS,
 }
 
@@ -214,6 +238,9 @@ impl DiskBufferConfigBuilder {
             max_record_size: None,
             write_buffer_size: None,
             flush_interval: None,
+            compression: DiskBufferCompression::None,
+            encryption: None,
+            max_age: None,
             filesystem: ProductionFilesystem,
         }
     }
@@ -293,6 +320,37 @@ where
         self
     }
 
+    /// Sets the compression applied to record payloads.
+    ///
+    /// Records are read back based on how they were written, so the compression can be changed
+    /// without making existing records unreadable.
+    ///
+    /// Defaults to no compression.
+    pub fn compression(mut self, compression: DiskBufferCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Sets the cipher used to encrypt record payloads.
+    ///
+    /// Records that were written in plaintext, before encryption was enabled, can still be read.
+    ///
+    /// Defaults to no encryption.
+    pub fn encryption(mut self, cipher: RecordCipher) -> Self {
+        self.encryption = Some(cipher);
+        self
+    }
+
+    /// Sets the maximum age of records.
+    ///
+    /// Only records written while a maximum age is configured can expire.
+    ///
+    /// Defaults to no maximum age.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
     /// Filesystem implementation for opening data files.
     ///
     /// We allow parameterizing the filesystem implementation for ease of testing.  The "filesystem"
@@ -312,6 +370,9 @@ where
             max_record_size: self.max_record_size,
             write_buffer_size: self.write_buffer_size,
             flush_interval: self.flush_interval,
+            compression: self.compression,
+            encryption: self.encryption,
+            max_age: self.max_age,
             filesystem,
         }
     }
@@ -326,6 +387,9 @@ where
         let max_record_size = self.max_record_size.unwrap_or(DEFAULT_MAX_RECORD_SIZE);
         let write_buffer_size = self.write_buffer_size.unwrap_or(DEFAULT_WRITE_BUFFER_SIZE);
         let flush_interval = self.flush_interval.unwrap_or(DEFAULT_FLUSH_INTERVAL);
+        let compression = self.compression;
+        let encryption = self.encryption;
+        let max_age = self.max_age;
         let filesystem = self.filesystem;
 
         // Validate the input parameters.
@@ -437,6 +501,9 @@ where
             max_record_size,
             write_buffer_size,
             flush_interval,
+            compression,
+            encryption,
+            max_age,
             filesystem,
         })
     }
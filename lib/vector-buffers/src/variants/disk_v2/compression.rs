@@ -0,0 +1,107 @@
+use snafu::Snafu;
+use vector_common::decompression::{CappedDecoder, max_decompressed_size_bytes};
+
+use crate::config::DiskBufferCompression;
+
+/// Bits of the record metadata that hold the compression algorithm of a record's payload.
+///
+/// These bits are reserved by the disk buffer, and are stripped before the metadata is handed back
+/// to `T`.
+pub(crate) const COMPRESSION_METADATA_MASK: u32 = 0b11 << 29;
+
+const ZSTD_METADATA_BITS: u32 = 0b01 << 29;
+const LZ4_METADATA_BITS: u32 = 0b10 << 29;
+
+const LZ4_LEN_PREFIX: usize = 4;
+
+/// Error that occurred while compressing or decompressing a record payload.
+#[derive(Debug, Snafu)]
+pub enum CompressionError {
+    /// The payload could not be compressed.
+    #[snafu(display("failed to compress record payload: {source}"))]
+    Compress { source: std::io::Error },
+
+    /// The payload could not be decompressed.
+    #[snafu(display("failed to decompress record payload: {reason}"))]
+    Decompress { reason: String },
+
+    /// The compression bits of the record metadata do not map to a known algorithm.
+    #[snafu(display("unknown record compression (metadata: {metadata:#034b})"))]
+    UnknownAlgorithm { metadata: u32 },
+}
+
+impl DiskBufferCompression {
+    /// Compresses `payload` into `dst`, returning the metadata bits to set on the record.
+    ///
+    /// Returns `None` if the payload should be stored as-is: either because compression is disabled,
+    /// the payload did not shrink, or the payload is larger than what a reader is willing to
+    /// decompress.
+    pub(super) fn compress(
+        self,
+        payload: &[u8],
+        dst: &mut Vec<u8>,
+    ) -> Result<Option<u32>, CompressionError> {
+        if payload.len() > max_decompressed_size_bytes() {
+            return Ok(None);
+        }
+
+        let metadata_bits = match self {
+            Self::None => return Ok(None),
+            Self::Zstd => {
+                dst.clear();
+                zstd::stream::copy_encode(payload, &mut *dst, zstd::DEFAULT_COMPRESSION_LEVEL)
+                    .map_err(|source| CompressionError::Compress { source })?;
+                ZSTD_METADATA_BITS
+            }
+            Self::Lz4 => {
+                let len = u32::try_from(payload.len()).expect("record payload must fit in u32");
+                dst.clear();
+                dst.extend_from_slice(&len.to_le_bytes());
+                dst.extend_from_slice(&lz4_flex::block::compress(payload));
+                LZ4_METADATA_BITS
+            }
+        };
+
+        Ok((dst.len() < payload.len()).then_some(metadata_bits))
+    }
+}
+
+/// Decompresses a record payload, based on the compression bits of the record metadata.
+///
+/// Returns `None` if the record was not compressed.
+pub(super) fn decompress(
+    metadata: u32,
+    payload: &[u8],
+) -> Result<Option<Vec<u8>>, CompressionError> {
+    let limit = max_decompressed_size_bytes();
+    match metadata & COMPRESSION_METADATA_MASK {
+        0 => Ok(None),
+        ZSTD_METADATA_BITS => CappedDecoder::zstd_with_limit(payload, limit)
+            .and_then(CappedDecoder::decompress)
+            .map(Some)
+            .map_err(|e| CompressionError::Decompress {
+                reason: e.to_string(),
+            }),
+        LZ4_METADATA_BITS => {
+            if payload.len() < LZ4_LEN_PREFIX {
+                return Err(CompressionError::Decompress {
+                    reason: "payload is truncated".to_string(),
+                });
+            }
+            let (len, compressed) = payload.split_at(LZ4_LEN_PREFIX);
+            let len = u32::from_le_bytes(len.try_into().expect("length prefix is 4 bytes"));
+            let len = usize::try_from(len).unwrap_or(usize::MAX);
+            if len > limit {
+                return Err(CompressionError::Decompress {
+                    reason: format!("decompressed size of {len} bytes exceeds limit of {limit}"),
+                });
+            }
+            lz4_flex::block::decompress(compressed, len)
+                .map(Some)
+                .map_err(|e| CompressionError::Decompress {
+                    reason: e.to_string(),
+                })
+        }
+        _ => Err(CompressionError::UnknownAlgorithm { metadata }),
+    }
+}
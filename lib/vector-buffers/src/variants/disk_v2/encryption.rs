@@ -0,0 +1,177 @@
+use std::{fmt, sync::Arc};
+
+use chacha20poly1305::{
+    Key, KeyInit, XChaCha20Poly1305, XNonce,
+    aead::{Aead, Payload},
+};
+use snafu::Snafu;
+
+/// Flag set in the metadata of a record whose payload is encrypted.
+///
+/// The highest bit of the record metadata is reserved by the disk buffer, and is stripped before
+/// the metadata is handed back to `T`.
+pub(crate) const ENCRYPTED_METADATA_FLAG: u32 = 1 << 31;
+
+/// Length, in bytes, of an encryption key.
+pub const ENCRYPTION_KEY_LEN: usize = 32;
+
+const ENVELOPE_VERSION: u8 = 1;
+const NONCE_LEN: usize = 24;
+const TAG_LEN: usize = 16;
+
+/// Number of bytes that encryption adds to a record payload.
+pub(crate) const ENVELOPE_OVERHEAD: usize = 1 + NONCE_LEN + TAG_LEN;
+
+/// Error that occurred while encrypting or decrypting a record payload.
+#[derive(Debug, Snafu)]
+pub enum EncryptionError {
+    /// The given key was not of the expected length.
+    #[snafu(display("encryption key must be {ENCRYPTION_KEY_LEN} bytes, got {len} bytes"))]
+    InvalidKeyLength { len: usize },
+
+    /// The payload could not be encrypted.
+    #[snafu(display("failed to encrypt record payload"))]
+    Encrypt,
+
+    /// The encrypted payload was written by an unknown version of the envelope format.
+    #[snafu(display("unsupported encrypted payload version: {version}"))]
+    UnsupportedVersion { version: u8 },
+
+    /// The encrypted payload was too short to hold the nonce and authentication tag.
+    #[snafu(display("encrypted payload is truncated"))]
+    Truncated,
+
+    /// None of the configured keys were able to decrypt and authenticate the payload.
+    #[snafu(display("record could not be decrypted with any of the configured keys"))]
+    NoMatchingKey,
+}
+
+/// Encrypts and decrypts record payloads.
+///
+/// Payloads are encrypted with XChaCha20-Poly1305, using a random nonce per record, and the record
+/// ID and metadata as associated data so that an encrypted payload cannot be moved to another
+/// record without failing authentication. The encrypted payload is laid out as follows:
+///
+/// ```text
+/// envelope:
+///   version:    uint8
+///   nonce:      uint8[24]
+///   ciphertext: uint8[payload_len + 16]
+/// ```
+///
+/// Records are always encrypted with the current key. When decrypting, the current key is tried
+/// first, followed by each of the previous keys, which keeps data files written before a key
+/// rotation readable.
+#[derive(Clone)]
+pub struct RecordCipher {
+    keys: Arc<[XChaCha20Poly1305]>,
+}
+
+impl RecordCipher {
+    /// Creates a new [`RecordCipher`] from the current key and any previously used keys.
+    ///
+    /// # Errors
+    ///
+    /// If any of the keys is not exactly [`ENCRYPTION_KEY_LEN`] bytes long, an error variant will
+    /// be returned.
+    pub fn new<K>(
+        current_key: K,
+        previous_keys: impl IntoIterator<Item = K>,
+    ) -> Result<Self, EncryptionError>
+    where
+        K: AsRef<[u8]>,
+    {
+        let keys = std::iter::once(current_key)
+            .chain(previous_keys)
+            .map(|key| {
+                let key = key.as_ref();
+                let key: [u8; ENCRYPTION_KEY_LEN] = key
+                    .try_into()
+                    .map_err(|_| EncryptionError::InvalidKeyLength { len: key.len() })?;
+                Ok(XChaCha20Poly1305::new(&Key::from(key)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { keys: keys.into() })
+    }
+
+    /// Encrypts `plaintext` with the current key, writing the envelope into `dst`.
+    pub(super) fn encrypt(
+        &self,
+        id: u64,
+        metadata: u32,
+        plaintext: &[u8],
+        dst: &mut Vec<u8>,
+    ) -> Result<(), EncryptionError> {
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::fill(&mut nonce);
+
+        let aad = associated_data(id, metadata);
+        let ciphertext = self.keys[0]
+            .encrypt(
+                &XNonce::from(nonce),
+                Payload {
+                    msg: plaintext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| EncryptionError::Encrypt)?;
+
+        dst.clear();
+        dst.reserve(1 + NONCE_LEN + ciphertext.len());
+        dst.push(ENVELOPE_VERSION);
+        dst.extend_from_slice(&nonce);
+        dst.extend_from_slice(&ciphertext);
+        Ok(())
+    }
+
+    /// Decrypts the given envelope, trying each of the configured keys in turn.
+    pub(super) fn decrypt(
+        &self,
+        id: u64,
+        metadata: u32,
+        envelope: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError> {
+        let (&version, rest) = envelope.split_first().ok_or(EncryptionError::Truncated)?;
+        if version != ENVELOPE_VERSION {
+            return Err(EncryptionError::UnsupportedVersion { version });
+        }
+        if rest.len() < NONCE_LEN + TAG_LEN {
+            return Err(EncryptionError::Truncated);
+        }
+
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        let nonce: [u8; NONCE_LEN] = nonce.try_into().expect("nonce length already checked");
+        let nonce = XNonce::from(nonce);
+        let aad = associated_data(id, metadata);
+
+        self.keys
+            .iter()
+            .find_map(|key| {
+                key.decrypt(
+                    &nonce,
+                    Payload {
+                        msg: ciphertext,
+                        aad: &aad,
+                    },
+                )
+                .ok()
+            })
+            .ok_or(EncryptionError::NoMatchingKey)
+    }
+}
+
+impl fmt::Debug for RecordCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordCipher")
+            .field("keys", &self.keys.len())
+            .finish()
+    }
+}
+
+fn associated_data(id: u64, metadata: u32) -> [u8; 12] {
+    let mut aad = [0u8; 12];
+    aad[..8].copy_from_slice(&id.to_be_bytes());
+    aad[8..].copy_from_slice(&metadata.to_be_bytes());
+    aad
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+use std::cell::Cell;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Bit of the record metadata that flags a record whose payload is prefixed with the time it was
+/// written.
+///
+/// This bit is reserved by the disk buffer, and is stripped before the metadata is handed back to
+/// `T`.
+pub(crate) const TIMESTAMPED_METADATA_FLAG: u32 = 1 << 28;
+
+/// Length, in bytes, of the write timestamp that prefixes the payload of timestamped records.
+pub(crate) const TIMESTAMP_LEN: usize = 8;
+
+#[cfg(test)]
+thread_local! {
+    /// How far tests have moved the clock ahead of the system time.
+    ///
+    /// Record timestamps are persisted, so they come from the system time rather than from the Tokio
+    /// clock, which can't be paused for them. Tests move this clock instead of waiting for records
+    /// to expire.
+    static CLOCK_OFFSET: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+}
+
+/// Moves the clock used to stamp and expire records ahead by `duration`, for the current thread.
+#[cfg(test)]
+pub(crate) fn advance_clock(duration: Duration) {
+    CLOCK_OFFSET.with(|offset| offset.set(offset.get() + duration));
+}
+
+/// Gets the current time.
+#[cfg(not(test))]
+fn now() -> SystemTime {
+    SystemTime::now()
+}
+
+/// Gets the current time, as moved by [`advance_clock`].
+#[cfg(test)]
+fn now() -> SystemTime {
+    SystemTime::now() + CLOCK_OFFSET.with(Cell::get)
+}
+
+/// Gets the current time, in milliseconds since the Unix epoch.
+fn now_millis() -> u64 {
+    now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| {
+        u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+    })
+}
+
+/// Prefixes `payload` with the current time, writing the timestamped payload into `dst`.
+pub(super) fn stamp(payload: &[u8], dst: &mut Vec<u8>) {
+    dst.clear();
+    dst.extend_from_slice(&now_millis().to_be_bytes());
+    dst.extend_from_slice(payload);
+}
+
+/// Splits the write timestamp off of a record payload, based on the record metadata.
+///
+/// Returns the record metadata without the timestamp flag, the time the record was written (in
+/// milliseconds since the Unix epoch) if it was timestamped, and the remainder of the payload.
+///
+/// Returns `None` if the record is flagged as timestamped but its payload is too short to hold a
+/// timestamp.
+pub(super) fn split_timestamp(metadata: u32, payload: &[u8]) -> Option<(u32, Option<u64>, &[u8])> {
+    if metadata & TIMESTAMPED_METADATA_FLAG == 0 {
+        return Some((metadata, None, payload));
+    }
+
+    let (timestamp, payload) = payload.split_first_chunk::<TIMESTAMP_LEN>()?;
+    Some((
+        metadata & !TIMESTAMPED_METADATA_FLAG,
+        Some(u64::from_be_bytes(*timestamp)),
+        payload,
+    ))
+}
+
+/// Whether or not a record written at `written_at`, in milliseconds since the Unix epoch, is older
+/// than `max_age`.
+pub(super) fn is_expired(written_at: u64, max_age: Duration) -> bool {
+    u128::from(now_millis().saturating_sub(written_at)) > max_age.as_millis()
+}
+
+/// Whether or not a data file last modified at `modified` only holds records older than `max_age`.
+pub(super) fn is_data_file_expired(modified: SystemTime, max_age: Duration) -> bool {
+    now()
+        .duration_since(modified)
+        .is_ok_and(|age| age > max_age)
+}
@@ -0,0 +1,163 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use fslock::LockFile;
+use tokio::fs::File;
+
+use super::{
+    common::{MAX_FILE_ID, data_file_name},
+    encryption::RecordCipher,
+    ledger::{LedgerLoadCreateError, LedgerSnapshot},
+    reader::{ReaderError, RecordReader},
+};
+use crate::Bufferable;
+
+/// A record read from a disk buffer by a [`BufferInspector`].
+#[derive(Debug)]
+pub struct InspectedRecord<T> {
+    /// The ID of the data file the record was read from.
+    pub file_id: u16,
+
+    /// The ID of the record.
+    pub record_id: u64,
+
+    /// The size of the record on disk, in bytes.
+    pub record_bytes: u64,
+
+    /// When the record was written, in milliseconds since the Unix epoch.
+    ///
+    /// Records are only stamped with the time they were written when the buffer has a maximum age
+    /// configured, so this is `None` otherwise.
+    pub written_at: Option<u64>,
+
+    /// The decoded record.
+    pub record: T,
+}
+
+/// Read-only access to the unread records of a disk buffer that is not in use.
+///
+/// Unlike [`BufferReader`][super::BufferReader], records are never acknowledged or deleted, and
+/// the ledger is left untouched, so inspecting a buffer does not change what a Vector process
+/// would read from it afterwards. The buffer lock is held for as long as the inspector exists, so
+/// the buffer cannot be opened by a running Vector process in the meantime.
+pub struct BufferInspector<T> {
+    data_dir: PathBuf,
+    _lock: LockFile,
+    ledger: LedgerSnapshot,
+    cipher: Option<RecordCipher>,
+    next_file_id: Option<u16>,
+    current: Option<(u16, RecordReader<File, T>)>,
+}
+
+impl<T> BufferInspector<T>
+where
+    T: Bufferable,
+{
+    /// Opens the disk buffer in `data_dir` for inspection.
+    ///
+    /// A cipher must be given in order to read records from an encrypted buffer.
+    ///
+    /// # Errors
+    ///
+    /// If the buffer is in use by another process, or its ledger cannot be loaded, an error
+    /// variant will be returned describing the error.
+    pub async fn open<P>(
+        data_dir: P,
+        cipher: Option<RecordCipher>,
+    ) -> Result<Self, LedgerLoadCreateError>
+    where
+        P: AsRef<Path>,
+    {
+        let data_dir = data_dir.as_ref().to_path_buf();
+        let (lock, ledger) = LedgerSnapshot::load(&data_dir).await?;
+
+        Ok(Self {
+            data_dir,
+            _lock: lock,
+            ledger,
+            cipher,
+            next_file_id: Some(ledger.reader_file_id),
+            current: None,
+        })
+    }
+
+    /// Gets the number of events that have been written to the buffer, but not yet acknowledged.
+    pub fn unread_event_count(&self) -> u64 {
+        self.ledger
+            .next_writer_record_id
+            .saturating_sub(self.ledger.last_reader_record_id + 1)
+    }
+
+    /// Reads the next unread record.
+    ///
+    /// Data files are read in order from the reader's current data file up to the writer's, and
+    /// data files which no longer exist are skipped. Records that were already acknowledged are
+    /// skipped as well.
+    ///
+    /// # Errors
+    ///
+    /// If a record cannot be read or decoded, an error variant will be returned describing the
+    /// error. The rest of the data file the record was in is skipped, so reading can carry on from
+    /// the next data file by calling this method again.
+    pub async fn next(&mut self) -> Result<Option<InspectedRecord<T>>, ReaderError<T>> {
+        loop {
+            let Some((file_id, reader)) = self.current.as_mut() else {
+                let Some(file_id) = self.next_file_id else {
+                    return Ok(None);
+                };
+                self.next_file_id =
+                    (file_id != self.ledger.writer_file_id).then_some((file_id + 1) % MAX_FILE_ID);
+
+                match File::open(self.data_dir.join(data_file_name(file_id))).await {
+                    Ok(file) => {
+                        let reader = RecordReader::new(file).with_cipher(self.cipher.clone());
+                        self.current = Some((file_id, reader));
+                    }
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                        debug!(file_id, "Data file does not exist. Skipping.");
+                    }
+                    Err(e) => return Err(ReaderError::Io { source: e }),
+                }
+                continue;
+            };
+            let file_id = *file_id;
+
+            let token = match reader.try_next_record(true).await {
+                Ok(Some(token)) => token,
+                Ok(None) => {
+                    self.current = None;
+                    continue;
+                }
+                Err(e) => {
+                    self.current = None;
+                    return Err(e);
+                }
+            };
+
+            let record_id = token.record_id();
+            if record_id <= self.ledger.last_reader_record_id {
+                continue;
+            }
+
+            let record_bytes = token.record_bytes() as u64;
+            let written_at = token.written_at();
+            match reader.read_record(token) {
+                Ok(record) => {
+                    return Ok(Some(InspectedRecord {
+                        file_id,
+                        record_id,
+                        record_bytes,
+                        written_at,
+                        record,
+                    }));
+                }
+                Err(e) => {
+                    self.current = None;
+                    return Err(e);
+                }
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@ use std::{
     future::Future,
     io,
     path::{Path, PathBuf},
+    time::SystemTime,
 };
 
 use tokio::{
@@ -15,6 +16,7 @@ const FILE_MODE_OWNER_RW_GROUP_RO: u32 = 0o640;
 /// File metadata.
 pub struct Metadata {
     pub(crate) len: u64,
+    pub(crate) modified: Option<SystemTime>,
 }
 
 impl Metadata {
@@ -22,6 +24,11 @@ impl Metadata {
     pub fn len(&self) -> u64 {
         self.len
     }
+
+    /// Gets the last modification time of the file, if available.
+    pub fn modified(&self) -> Option<SystemTime> {
+        self.modified
+    }
 }
 
 /// Generalized interface for opening and deleting files from a filesystem.
@@ -289,6 +296,7 @@ impl AsyncFile for tokio::fs::File {
         let metadata = self.metadata().await?;
         Ok(Metadata {
             len: metadata.len(),
+            modified: metadata.modified().ok(),
         })
     }
 
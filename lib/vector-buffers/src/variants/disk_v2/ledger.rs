@@ -1,11 +1,11 @@
 use std::{
-    fmt, io, mem,
-    path::PathBuf,
+    fmt, io, iter, mem,
+    path::{Path, PathBuf},
     sync::{
         Arc,
         atomic::{AtomicBool, AtomicU16, AtomicU64, Ordering},
     },
-    time::Instant,
+    time::{Duration, Instant},
 };
 
 use bytecheck::CheckBytes;
@@ -13,11 +13,11 @@ use bytes::BytesMut;
 use crossbeam_utils::atomic::AtomicCell;
 use fslock::LockFile;
 use futures::StreamExt;
-use rkyv::{Archive, Serialize, with::Atomic};
+use rkyv::{AlignedVec, Archive, Serialize, with::Atomic};
 use snafu::{ResultExt, Snafu};
 use tokio::{
     fs,
-    io::AsyncWriteExt,
+    io::{AsyncReadExt, AsyncWriteExt},
     sync::{Mutex, MutexGuard, Notify},
 };
 use vector_common::finalizer::OrderedFinalizer;
@@ -27,12 +27,14 @@ use super::{
     backed_archive::BackedArchive,
     common::{
         DEFAULT_DATA_FILE_CLEANUP_INTERVAL, DiskBufferConfig, MAX_FILE_ID, align16,
-        data_file_id_in_range, data_file_name, parse_data_file_id,
+        create_crc32c_hasher, data_file_id_in_range, data_file_name, parse_data_file_id,
     },
+    expiry,
     io::{AsyncFile, WritableMemoryMap},
+    record::{RecordStatus, validate_record_archive},
     ser::SerializeError,
 };
-use crate::buffer_usage_data::BufferUsageHandle;
+use crate::buffer_usage_data::{BufferUsageHandle, DropReason};
 
 pub const LEDGER_LEN: usize = align16(mem::size_of::<ArchivedLedgerState>());
 
@@ -227,6 +229,55 @@ impl ArchivedLedgerState {
     }
 }
 
+/// A point-in-time copy of the reader and writer positions stored in a ledger.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct LedgerSnapshot {
+    pub reader_file_id: u16,
+    pub writer_file_id: u16,
+    pub last_reader_record_id: u64,
+    pub next_writer_record_id: u64,
+}
+
+impl LedgerSnapshot {
+    /// Loads the ledger of the buffer in `data_dir`, without opening the buffer itself.
+    ///
+    /// The advisory lock for the buffer directory is taken, and handed back alongside the snapshot,
+    /// so that no Vector process can open the buffer while the snapshot is in use.
+    ///
+    /// # Errors
+    ///
+    /// If the lock is already held, or the ledger cannot be read or deserialized, an error variant
+    /// will be returned describing the error.
+    pub(super) async fn load(data_dir: &Path) -> Result<(LockFile, Self), LedgerLoadCreateError> {
+        let mut lock = LockFile::open(&data_dir.join("buffer.lock")).context(IoSnafu)?;
+        if !lock.try_lock().context(IoSnafu)? {
+            return Err(LedgerLoadCreateError::LedgerLockAlreadyHeld);
+        }
+
+        // The ledger has to be suitably aligned to be deserialized in place.
+        let ledger_bytes = fs::read(data_dir.join("buffer.db"))
+            .await
+            .context(IoSnafu)?;
+        let mut buf = AlignedVec::with_capacity(ledger_bytes.len());
+        buf.extend_from_slice(&ledger_bytes);
+
+        let ledger_state = BackedArchive::<_, LedgerState>::from_backing(buf).map_err(|e| {
+            LedgerLoadCreateError::FailedToDeserialize {
+                reason: e.into_inner(),
+            }
+        })?;
+        let state = ledger_state.get_archive_ref();
+        let snapshot = Self {
+            reader_file_id: state.get_current_reader_file_id(),
+            writer_file_id: state.get_current_writer_file_id(),
+            last_reader_record_id: state.get_last_reader_record_id(),
+            next_writer_record_id: state.get_next_writer_record_id(),
+        };
+
+        Ok((lock, snapshot))
+    }
+}
+
 /// Tracks the internal state of the buffer.
 pub(crate) struct Ledger<FS>
 where
@@ -251,6 +302,8 @@ where
     pending_acks: AtomicU64,
     // The file ID offset of the reader past the acknowledged reader file ID.
     unacked_reader_file_id_offset: AtomicU16,
+    // Number of events in discarded data files that the reader has yet to skip over.
+    discarded_events: AtomicU64,
     // Runtime-only coordination and durable-boundary state for stale data file cleanup.
     data_file_cleanup: DataFileCleanupState,
     // Last flush of all unflushed files: ledger, data file, etc.
@@ -413,6 +466,23 @@ where
         self.config.data_dir.join(data_file_name(file_id))
     }
 
+    /// Gets the IDs of the data files that the reader has not yet started reading, oldest first.
+    ///
+    /// The data file that the writer is currently writing to is not included.
+    pub fn get_unread_data_file_ids(&self) -> impl Iterator<Item = u16> {
+        let (reader_file_id, writer_file_id) = self.get_current_reader_writer_file_id();
+
+        // When the reader is waiting on the writer to open the next data file, there are no unread
+        // data files, but the range below would wrap all the way around.
+        let has_unread_data_files =
+            reader_file_id != writer_file_id && reader_file_id != self.get_next_writer_file_id();
+        iter::successors(has_unread_data_files.then_some(reader_file_id), |file_id| {
+            Some((file_id + 1) % MAX_FILE_ID)
+        })
+        .skip(1)
+        .take_while(move |file_id| *file_id != writer_file_id)
+    }
+
     pub(super) async fn lock_data_file_cleanup(&self) -> MutexGuard<'_, ()> {
         self.data_file_cleanup.lock().await
     }
@@ -545,7 +615,11 @@ where
         self.usage_handle
             .increment_received_event_count_and_byte_size(event_count, byte_size);
         self.usage_handle
-            .increment_dropped_event_count_and_byte_size(event_count, byte_size, false);
+            .increment_dropped_event_count_and_byte_size(
+                event_count,
+                byte_size,
+                DropReason::Unprocessable,
+            );
     }
 
     /// Tracks the statistics of multiple successful reads.
@@ -561,7 +635,11 @@ where
         // The corrupt payload cannot provide a trustworthy event count, but its framing still
         // provides the exact byte span that has left the buffer.
         self.usage_handle
-            .increment_dropped_event_count_and_byte_size(0, total_record_size, false);
+            .increment_dropped_event_count_and_byte_size(
+                0,
+                total_record_size,
+                DropReason::Unprocessable,
+            );
     }
 
     /// Marks the writer as finished.
@@ -603,6 +681,11 @@ where
         );
     }
 
+    /// Returns `true` if the reader has moved past a data file that is not yet fully acknowledged.
+    pub fn has_unacked_reader_file(&self) -> bool {
+        self.unacked_reader_file_id_offset.load(Ordering::Acquire) > 0
+    }
+
     /// Increments the acknowledged reader file ID.
     ///
     /// As records may be read and stored for a small period of time (batching in a sink, etc), we
@@ -701,7 +784,65 @@ where
         //
         // TODO: Can we do better here?
         self.usage_handle
-            .increment_dropped_event_count_and_byte_size(count, 0, false);
+            .increment_dropped_event_count_and_byte_size(count, 0, DropReason::Unprocessable);
+    }
+
+    /// Tracks a data file that was discarded before the reader started reading it.
+    ///
+    /// The discarded events are intentionally dropped. As the reader will see a gap in record IDs
+    /// when it skips over the discarded data file, we also remember how many events were discarded
+    /// so that the reader does not report them as dropped a second time.
+    pub fn track_discarded_data_file(&self, event_count: u64, byte_size: u64) {
+        self.decrement_total_buffer_size(byte_size);
+        self.discarded_events
+            .fetch_add(event_count, Ordering::AcqRel);
+        self.usage_handle
+            .increment_dropped_event_count_and_byte_size(
+                event_count,
+                byte_size,
+                DropReason::DropOldest,
+            );
+    }
+
+    /// Consumes up to `count` events from the number of events in discarded data files.
+    ///
+    /// Returns the number of events consumed, which were already tracked as dropped when their data
+    /// file was discarded.
+    pub fn consume_discarded_events(&self, count: u64) -> u64 {
+        let result = self
+            .discarded_events
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+                Some(n.saturating_sub(count))
+            });
+        let previous = match result {
+            Ok(n) | Err(n) => n,
+        };
+        previous.min(count)
+    }
+
+    /// Tracks records that were read but discarded because they expired.
+    ///
+    /// Expired records are acknowledged like any other record, so this is tracked in lieu of
+    /// [`track_reads`].
+    pub fn track_expired_reads(&self, event_count: u64, total_record_size: u64) {
+        self.decrement_total_buffer_size(total_record_size);
+        self.usage_handle
+            .increment_dropped_event_count_and_byte_size(
+                event_count,
+                total_record_size,
+                DropReason::Expired,
+            );
+    }
+
+    /// Records that a record was intentionally dropped because the buffer was full and no data file
+    /// could be evicted to make room for it.
+    ///
+    /// This only happens when dropping the oldest events, so the drop is attributed to that mode.
+    pub fn track_discarded_record(&self, count: u64, byte_size: u64) {
+        self.usage_handle
+            .increment_received_event_count_and_byte_size(count, byte_size);
+        self.usage_handle
+            .increment_dropped_event_count_and_byte_size(count, byte_size, DropReason::DropOldest);
     }
 
     /// Records that a record was dropped because it could never be written to the buffer (e.g. it
@@ -710,7 +851,11 @@ where
         self.usage_handle
             .increment_received_event_count_and_byte_size(count, byte_size);
         self.usage_handle
-            .increment_dropped_event_count_and_byte_size(count, byte_size, false);
+            .increment_dropped_event_count_and_byte_size(
+                count,
+                byte_size,
+                DropReason::Unprocessable,
+            );
     }
 }
 
@@ -821,6 +966,7 @@ where
             writer_done: AtomicBool::new(false),
             pending_acks: AtomicU64::new(0),
             unacked_reader_file_id_offset: AtomicU16::new(0),
+            discarded_events: AtomicU64::new(0),
             data_file_cleanup: DataFileCleanupState::new(cleanup_reader_file_id),
             last_flush: AtomicCell::new(Instant::now()),
             usage_handle,
@@ -839,6 +985,148 @@ where
         Ok(ledger)
     }
 
+    /// Gets the record ID of the first record in the given data file.
+    ///
+    /// Returns `None` if the first record of the data file could not be read.
+    async fn get_first_record_id(&self, file_id: u16) -> io::Result<Option<u64>> {
+        let data_file_path = self.get_data_file_path(file_id);
+        let mut data_file = self
+            .filesystem()
+            .open_file_readable(&data_file_path)
+            .await?;
+
+        let mut length_buf = [0; 8];
+        if let Err(e) = data_file.read_exact(&mut length_buf).await {
+            return match e.kind() {
+                io::ErrorKind::UnexpectedEof => Ok(None),
+                _ => Err(e),
+            };
+        }
+        let record_len = match usize::try_from(u64::from_be_bytes(length_buf)) {
+            Ok(len) if len > 0 && len <= self.config.max_record_size => len,
+            _ => return Ok(None),
+        };
+
+        let mut record_buf = AlignedVec::with_capacity(record_len);
+        record_buf.resize(record_len, 0);
+        if let Err(e) = data_file.read_exact(record_buf.as_mut_slice()).await {
+            return match e.kind() {
+                io::ErrorKind::UnexpectedEof => Ok(None),
+                _ => Err(e),
+            };
+        }
+
+        match validate_record_archive(record_buf.as_slice(), &create_crc32c_hasher()) {
+            RecordStatus::Valid { id } => Ok(Some(id)),
+            RecordStatus::Corrupted { .. } | RecordStatus::FailedDeserialization(_) => Ok(None),
+        }
+    }
+
+    /// Deletes a data file that the reader has not yet started reading, tracking its events as
+    /// intentionally dropped.
+    ///
+    /// The caller must hold the data file cleanup lock, and the data file must be one of
+    /// [`get_unread_data_file_ids`].
+    ///
+    /// Returns the number of events and bytes discarded, or `None` if the number of events in the
+    /// data file could not be determined, in which case it is left untouched.
+    ///
+    /// # Errors
+    ///
+    /// If an I/O error occurred while reading or deleting the data file, an error variant will be
+    /// returned describing the error. If the data file does not exist, the error kind is
+    /// `NotFound`.
+    pub(super) async fn discard_unread_data_file(
+        &self,
+        file_id: u16,
+    ) -> io::Result<Option<(u64, u64)>> {
+        let Some(first_record_id) = self.get_first_record_id(file_id).await? else {
+            return Ok(None);
+        };
+
+        // We need to know how many events are in the data file, which we can calculate from the
+        // ID of its first record and the ID of the first record in the data file after it. If the
+        // data file after it is the one being written to, but nothing has been flushed to it yet,
+        // its first record will have the next record ID the writer publishes.
+        let next_file_id = (file_id + 1) % MAX_FILE_ID;
+        let is_writer_file = next_file_id == self.get_current_writer_file_id();
+        let next_first_record_id = match self.get_first_record_id(next_file_id).await {
+            Ok(Some(record_id)) => record_id,
+            Ok(None) if is_writer_file => self.state().get_next_writer_record_id(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound && is_writer_file => {
+                self.state().get_next_writer_record_id()
+            }
+            Ok(None) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        let data_file_path = self.get_data_file_path(file_id);
+        let data_file_size = self
+            .filesystem()
+            .open_file_readable(&data_file_path)
+            .await?
+            .metadata()
+            .await?
+            .len();
+        self.filesystem().delete_file(&data_file_path).await?;
+
+        let event_count = next_first_record_id.saturating_sub(first_record_id);
+        self.track_discarded_data_file(event_count, data_file_size);
+
+        Ok(Some((event_count, data_file_size)))
+    }
+
+    /// Deletes the data files that the reader has not yet started reading, and that only hold
+    /// records older than `max_age`.
+    ///
+    /// A data file is only ever written to in order, so once it was last modified longer ago than
+    /// `max_age`, every record in it has expired.
+    ///
+    /// Returns the number of data files that were deleted.
+    ///
+    /// # Errors
+    ///
+    /// If an I/O error occurred while checking or deleting a data file, an error variant will be
+    /// returned describing the error.
+    pub(super) async fn cleanup_expired_data_files(&self, max_age: Duration) -> io::Result<usize> {
+        // Holding the cleanup lock keeps the reader from opening a data file while we're deleting
+        // it, and the writer from evicting it at the same time.
+        let _cleanup_guard = self.lock_data_file_cleanup().await;
+        let mut deleted_files = 0;
+
+        for file_id in self.get_unread_data_file_ids() {
+            let data_file_path = self.get_data_file_path(file_id);
+            let modified = match self.filesystem().open_file_readable(&data_file_path).await {
+                Ok(data_file) => data_file.metadata().await?.modified(),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e),
+            };
+
+            // Data files are written in order, so if this data file still holds records that
+            // haven't expired, neither have any of the data files after it.
+            if !modified.is_some_and(|modified| expiry::is_data_file_expired(modified, max_age)) {
+                break;
+            }
+
+            match self.discard_unread_data_file(file_id).await? {
+                Some((event_count, data_file_size)) => {
+                    deleted_files += 1;
+                    info!(
+                        data_file_path = data_file_path.to_string_lossy().as_ref(),
+                        event_count, data_file_size, "Deleted expired data file."
+                    );
+                }
+                None => break,
+            }
+        }
+
+        if deleted_files > 0 {
+            self.notify_reader_waiters();
+        }
+
+        Ok(deleted_files)
+    }
+
     #[must_use]
     pub(super) fn spawn_finalizer(self: Arc<Self>) -> OrderedFinalizer<u64> {
         let (finalizer, mut stream) = OrderedFinalizer::new(None);
@@ -871,6 +1159,15 @@ where
                     );
                 }
 
+                if let Some(max_age) = ledger.config.max_age
+                    && let Err(error) = ledger.cleanup_expired_data_files(max_age).await
+                {
+                    debug!(
+                        error = %error,
+                        "Failed to delete expired data files; will retry."
+                    );
+                }
+
                 drop(ledger);
             }
         });
@@ -894,6 +1191,10 @@ where
                 "unacked_reader_file_id_offset",
                 &self.unacked_reader_file_id_offset.load(Ordering::Acquire),
             )
+            .field(
+                "discarded_events",
+                &self.discarded_events.load(Ordering::Acquire),
+            )
             .field("writer_done", &self.writer_done.load(Ordering::Acquire))
             .field("last_flush", &self.last_flush.load())
             .finish_non_exhaustive()
@@ -14,6 +14,7 @@
 //! - no more than 65,536 data files can exist at any given time
 //! - buffer can grow to a maximum of ~8TB in total size (65k files * 128MB)
 //! - all records are checksummed (CRC32C)
+//! - record payloads can optionally be encrypted (XChaCha20-Poly1305)
 //! - all records are written sequentially/contiguously, and do not span over multiple data files
 //! - writers create and write to data files, while readers read from and delete data files
 //! - endianness of the files is based on the host system (we don't support loading the buffer files
@@ -52,6 +53,38 @@
 //! potentially changed based on the payload that a record contains. The only safe way to access the
 //! records in a disk buffer should be through the reader/writer interface in this module.
 //!
+//! #### Compression
+//!
+//! When the buffer is configured with compression, the encoded payload of each record is
+//! compressed before being archived, and the algorithm used is stored in two reserved bits of the
+//! record metadata, just below the encryption flag. Records whose payload does not shrink are
+//! stored uncompressed, with those bits cleared. As the algorithm is recorded per record, changing
+//! the compression setting never makes existing records unreadable. Since compression happens
+//! before archiving, the length delimiter of a record, and thus all size accounting done by the
+//! ledger, reflects the compressed size.
+//!
+//! #### Encryption
+//!
+//! When the buffer is configured with encryption keys, the payload of each record is replaced by an
+//! authenticated, encrypted envelope of the encoded payload, and the highest bit of the record
+//! metadata is set to flag it as such. The checksum covers the envelope, so corruption is still
+//! detected before any decryption is attempted. Records without the flag are read as-is, which
+//! allows enabling encryption on an existing buffer. See [`RecordCipher`] for the envelope format
+//! and how key rotation is handled.
+//!
+//! #### Expiry
+//!
+//! When the buffer is configured with a maximum age, the payload of each record is prefixed with
+//! the time it was written, in milliseconds since the Unix epoch, and the bit below the compression
+//! bits of the record metadata is set to flag it as such. The timestamp sits outside of the
+//! encrypted envelope, so that readers can tell whether a record has expired without decrypting
+//! it. Records without the flag never expire on their own.
+//!
+//! Expired records are skipped by the reader, and acknowledged as soon as they are read. Data files
+//! that the reader has not yet started reading, and that were last modified longer ago than the
+//! maximum age, only hold expired records, and so are deleted outright by the background data file
+//! cleanup task.
+//!
 //! ### Data files
 //!
 //! Data files contain the buffered records and nothing else. Records are written
@@ -116,6 +149,14 @@
 //! wrap around at 65,536 (2^16), the maximum data file size in total for a given buffer is ~8TB (6
 //! 5k files * 128MB).
 //!
+//! #### Evicting the oldest records
+//!
+//! Instead of waiting, the writer can evict the oldest records to make room for new ones. Eviction
+//! deletes a whole data file at a time: the oldest data file that the reader has not yet started
+//! reading, and that the writer is not writing to. The reader treats an evicted data file like an
+//! empty one, and skips over the resulting gap in record IDs. If no data file can be evicted, the
+//! record being written is dropped instead.
+//!
 //! ### Reading records
 //!
 //! Due to the on-disk layout, reading records is an incredibly straight-forward progress: we open a
@@ -175,6 +216,7 @@ use std::{
     num::NonZeroU64,
     path::{Path, PathBuf},
     sync::Arc,
+    time::Duration,
 };
 
 use async_trait::async_trait;
@@ -185,6 +227,10 @@ use vector_common::finalization::Finalizable;
 mod backed_archive;
 mod checkpoint_recovery;
 pub(crate) mod common;
+mod compression;
+mod encryption;
+mod expiry;
+mod inspect;
 mod io;
 mod ledger;
 mod reader;
@@ -198,6 +244,8 @@ mod tests;
 use self::ledger::Ledger;
 pub use self::{
     common::{DiskBufferConfig, DiskBufferConfigBuilder},
+    encryption::{ENCRYPTION_KEY_LEN, RecordCipher},
+    inspect::{BufferInspector, InspectedRecord},
     io::{Filesystem, ProductionFilesystem},
     ledger::LedgerLoadCreateError,
     reader::{BufferReader, ReaderError},
@@ -206,6 +254,7 @@ pub use self::{
 use crate::{
     Bufferable,
     buffer_usage_data::BufferUsageHandle,
+    config::DiskBufferCompression,
     topology::{
         builder::IntoBuffer,
         channel::{ReceiverAdapter, SenderAdapter},
@@ -341,6 +390,9 @@ pub struct DiskV2Buffer {
     id: String,
     data_dir: PathBuf,
     max_size: NonZeroU64,
+    compression: DiskBufferCompression,
+    encryption: Option<RecordCipher>,
+    max_age: Option<Duration>,
 }
 
 impl DiskV2Buffer {
@@ -349,8 +401,32 @@ impl DiskV2Buffer {
             id,
             data_dir,
             max_size,
+            compression: DiskBufferCompression::None,
+            encryption: None,
+            max_age: None,
         }
     }
+
+    /// Sets the compression applied to the records written to this buffer.
+    #[must_use]
+    pub fn with_compression(mut self, compression: DiskBufferCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Sets the cipher used to encrypt the records written to this buffer.
+    #[must_use]
+    pub fn with_encryption(mut self, encryption: Option<RecordCipher>) -> Self {
+        self.encryption = encryption;
+        self
+    }
+
+    /// Sets the maximum age of the records in this buffer.
+    #[must_use]
+    pub fn with_max_age(mut self, max_age: Option<Duration>) -> Self {
+        self.max_age = max_age;
+        self
+    }
 }
 
 #[async_trait]
@@ -371,6 +447,9 @@ where
             &self.data_dir,
             self.id.as_str(),
             self.max_size,
+            self.compression,
+            self.encryption,
+            self.max_age,
         )
         .await?;
 
@@ -383,6 +462,9 @@ async fn build_disk_v2_buffer<T>(
     data_dir: &Path,
     id: &str,
     max_size: NonZeroU64,
+    compression: DiskBufferCompression,
+    encryption: Option<RecordCipher>,
+    max_age: Option<Duration>,
 ) -> Result<
     (
         BufferWriter<T, ProductionFilesystem>,
@@ -396,7 +478,15 @@ where
     usage_handle.set_buffer_limits(Some(max_size.get()), None);
 
     let buffer_path = get_disk_v2_data_dir_path(data_dir, id);
-    let builder = DiskBufferConfigBuilder::from_path(buffer_path).max_buffer_size(max_size.get());
+    let mut builder = DiskBufferConfigBuilder::from_path(buffer_path)
+        .max_buffer_size(max_size.get())
+        .compression(compression);
+    if let Some(cipher) = encryption {
+        builder = builder.encryption(cipher);
+    }
+    if let Some(max_age) = max_age {
+        builder = builder.max_age(max_age);
+    }
     // Shrink the data-file size (and the matching record size) so files fill and
     // rotate constantly. That is what reaches the rare recovery paths the bug hides
     // in: reopening a file whose last write was cut short, and reusing a file number
@@ -15,6 +15,9 @@ use vector_common::{finalization::BatchNotifier, finalizer::OrderedFinalizer};
 use super::{
     Filesystem,
     common::create_crc32c_hasher,
+    compression::{self, COMPRESSION_METADATA_MASK},
+    encryption::{ENCRYPTED_METADATA_FLAG, RecordCipher},
+    expiry,
     ledger::Ledger,
     record::{ArchivedRecord, Record, RecordStatus, validate_record_archive},
 };
@@ -30,13 +33,15 @@ use crate::{
 pub(super) struct ReadToken {
     record_id: u64,
     record_bytes: usize,
+    written_at: Option<u64>,
 }
 
 impl ReadToken {
-    pub fn new(record_id: u64, record_bytes: usize) -> Self {
+    pub fn new(record_id: u64, record_bytes: usize, written_at: Option<u64>) -> Self {
         Self {
             record_id,
             record_bytes,
+            written_at,
         }
     }
 
@@ -48,11 +53,27 @@ impl ReadToken {
         self.record_bytes
     }
 
+    /// Gets the time the record was written, in milliseconds since the Unix epoch, if the record
+    /// was timestamped.
+    pub fn written_at(&self) -> Option<u64> {
+        self.written_at
+    }
+
     fn into_record_id(self) -> u64 {
         self.record_id
     }
 }
 
+/// Marker data for a record that was read, used once the record is acknowledged.
+#[derive(Clone, Copy, Debug)]
+struct RecordMarker {
+    /// Size of the record, in bytes.
+    bytes: u64,
+
+    /// Whether or not the record expired, and so was discarded instead of being returned.
+    expired: bool,
+}
+
 /// Error that occurred during calls to [`BufferReader`].
 #[derive(Debug, Snafu)]
 pub enum ReaderError<T>
@@ -107,6 +128,17 @@ where
     #[snafu(display("record version not compatible: {}", reason))]
     Incompatible { reason: String },
 
+    /// The record payload could not be decrypted.
+    ///
+    /// This can occur when the key that was used to encrypt the record is no longer configured, or
+    /// when an encrypted record is read by a buffer that has no encryption configured at all.
+    #[snafu(display("failed to decrypt record: {}", reason))]
+    Decryption { reason: String },
+
+    /// The record payload could not be decompressed.
+    #[snafu(display("failed to decompress record: {}", reason))]
+    Decompression { reason: String },
+
     /// The reader detected that a data file contains a partially-written record.
     ///
     /// Records should never be partially written to a data file (we don't split records across data
@@ -155,6 +187,8 @@ where
             ReaderError::Checksum { .. } => "checksum_mismatch",
             ReaderError::Decode { .. } => "decode_failed",
             ReaderError::Incompatible { .. } => "incompatible_record_version",
+            ReaderError::Decryption { .. } => "decryption_failed",
+            ReaderError::Decompression { .. } => "decompression_failed",
             ReaderError::PartialWrite => "partial_write",
             ReaderError::EmptyRecord => "empty_record",
         }
@@ -170,6 +204,8 @@ where
             | ReaderError::Checksum { .. }
             | ReaderError::Decode { .. }
             | ReaderError::Incompatible { .. }
+            | ReaderError::Decryption { .. }
+            | ReaderError::Decompression { .. }
             | ReaderError::PartialWrite => Some(BufferReadError { error_code, error }),
         }
     }
@@ -211,6 +247,11 @@ impl<T: Bufferable> PartialEq for ReaderError<T> {
             (Self::Incompatible { reason: l_reason }, Self::Incompatible { reason: r_reason }) => {
                 l_reason == r_reason
             }
+            (Self::Decryption { reason: l_reason }, Self::Decryption { reason: r_reason })
+            | (
+                Self::Decompression { reason: l_reason },
+                Self::Decompression { reason: r_reason },
+            ) => l_reason == r_reason,
             _ => core::mem::discriminant(self) == core::mem::discriminant(other),
         }
     }
@@ -221,6 +262,7 @@ pub(super) struct RecordReader<R, T> {
     reader: BufReader<R>,
     aligned_buf: AlignedVec,
     checksummer: Hasher,
+    cipher: Option<RecordCipher>,
     current_record_id: u64,
     _t: PhantomData<T>,
 }
@@ -239,11 +281,18 @@ where
             reader: BufReader::with_capacity(256 * 1024, reader),
             aligned_buf: AlignedVec::new(),
             checksummer: create_crc32c_hasher(),
+            cipher: None,
             current_record_id: 0,
             _t: PhantomData,
         }
     }
 
+    /// Sets the cipher used to decrypt encrypted records.
+    pub fn with_cipher(mut self, cipher: Option<RecordCipher>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
     #[cfg_attr(test, instrument(skip(self), level = "trace"))]
     async fn read_length_delimiter(
         &mut self,
@@ -371,8 +420,14 @@ where
                 record_bytes,
             }),
             RecordStatus::Valid { id, .. } => {
+                // SAFETY: `validate_record_archive` just checked that the buffer holds a valid
+                // record archive.
+                let record = unsafe { archived_root::<Record<'_>>(buf) };
+                let written_at = expiry::split_timestamp(record.metadata(), record.payload())
+                    .and_then(|(_, written_at, _)| written_at);
+
                 self.current_record_id = id;
-                Ok(Some(ReadToken::new(id, record_bytes)))
+                Ok(Some(ReadToken::new(id, record_bytes, written_at)))
             }
         }
     }
@@ -401,7 +456,7 @@ where
         // - `try_next_record` does all the archive checks, checksum validation, etc
         let record = unsafe { archived_root::<Record<'_>>(&self.aligned_buf) };
 
-        decode_record_payload(record)
+        decode_record_payload(record, self.cipher.as_ref())
     }
 }
 
@@ -434,7 +489,7 @@ where
     data_file_record_count: u64,
     data_file_marked_record_count: u64,
     ready_to_read: bool,
-    record_acks: OrderedAcknowledgements<u64, u64>,
+    record_acks: OrderedAcknowledgements<u64, RecordMarker>,
     data_file_acks: OrderedAcknowledgements<u64, ()>,
     finalizer: OrderedFinalizer<u64>,
     _t: PhantomData<T>,
@@ -482,7 +537,13 @@ where
         self.data_file_start_record_id = None;
     }
 
-    fn track_read(&mut self, record_id: u64, record_bytes: u64, event_count: NonZeroU64) {
+    fn track_read(
+        &mut self,
+        record_id: u64,
+        record_bytes: u64,
+        event_count: NonZeroU64,
+        expired: bool,
+    ) {
         // We explicitly reduce the event count by one here in order to correctly calculate the
         // "last" record ID, which you can visualize as follows...
         //
@@ -516,10 +577,14 @@ where
         // We've done a "real" record read, so we need to track it for acknowledgement.  Check our
         // acknowledge state first to see if this is the next record ID we expected.
         self.data_file_record_count += 1;
-        if let Err(me) =
-            self.record_acks
-                .add_marker(record_id, Some(event_count.get()), Some(record_bytes))
-        {
+        if let Err(me) = self.record_acks.add_marker(
+            record_id,
+            Some(event_count.get()),
+            Some(RecordMarker {
+                bytes: record_bytes,
+                expired,
+            }),
+        ) {
             match me {
                 MarkerError::MonotonicityViolation => {
                     // Reaching here means the reader saw a record id that did not
@@ -626,6 +691,8 @@ where
         let mut events_acknowledged: u64 = 0;
         let mut events_skipped: u64 = 0;
         let mut bytes_acknowledged: u64 = 0;
+        let mut events_expired: u64 = 0;
+        let mut bytes_expired: u64 = 0;
 
         let consumed_acks = self.ledger.consume_pending_acks();
         if consumed_acks > 0 {
@@ -650,16 +717,28 @@ where
                     }
                     // We got a valid marker representing a known number of events.
                     EligibleMarkerLength::Known(len) => {
-                        // We specifically pass the size of the record, in bytes, as the marker data.
-                        let record_bytes = data.expect("record bytes should always be known");
+                        // We specifically pass the size of the record, in bytes, as the marker data,
+                        // along with whether or not the record expired.
+                        let RecordMarker {
+                            bytes: record_bytes,
+                            expired,
+                        } = data.expect("record bytes should always be known");
 
                         records_acknowledged = records_acknowledged.checked_add(1).expect(
                             "acknowledging more than 2^64 records at a time is obviously a bug",
                         );
-                        events_acknowledged = events_acknowledged.checked_add(len).expect(
+
+                        // Expired records were discarded rather than sent, so we track them
+                        // separately.
+                        let (events, bytes) = if expired {
+                            (&mut events_expired, &mut bytes_expired)
+                        } else {
+                            (&mut events_acknowledged, &mut bytes_acknowledged)
+                        };
+                        *events = events.checked_add(len).expect(
                             "acknowledging more than 2^64 events at a time is obviously a bug",
                         );
-                        bytes_acknowledged = bytes_acknowledged.checked_add(record_bytes).expect(
+                        *bytes = bytes.checked_add(record_bytes).expect(
                             "acknowledging more than 2^64 bytes at a time is obviously a bug",
                         );
                     }
@@ -670,10 +749,14 @@ where
             if had_eligible_records {
                 self.ledger
                     .track_reads(events_acknowledged, bytes_acknowledged);
+                if events_expired > 0 {
+                    self.ledger
+                        .track_expired_reads(events_expired, bytes_expired);
+                }
 
-                // We need to account for skipped events, too, so that our "last reader record ID"
-                // value stays correct as we process these gap markers.
-                let last_increment_amount = events_acknowledged + events_skipped;
+                // We need to account for expired and skipped events, too, so that our "last reader
+                // record ID" value stays correct as we process these gap markers.
+                let last_increment_amount = events_acknowledged + events_expired + events_skipped;
                 self.ledger
                     .state()
                     .increment_last_reader_record_id(last_increment_amount);
@@ -682,7 +765,11 @@ where
                     .add_acknowledgements(records_acknowledged);
             }
 
-            // If any events were skipped, do our logging/metrics for that.
+            // If any events were skipped, do our logging/metrics for that. Events in data files
+            // that were discarded, whether evicted by the writer or expired, were already tracked
+            // as dropped when they were discarded.
+            let events_skipped =
+                events_skipped - self.ledger.consume_discarded_events(events_skipped);
             if events_skipped > 0 {
                 self.ledger.track_dropped_events(events_skipped);
             }
@@ -733,6 +820,7 @@ where
                     records_acknowledged,
                     events_acknowledged,
                     events_skipped,
+                    events_expired,
                     bytes_acknowledged,
                     data_files_completed,
                     "Finished handling acknowledgements."
@@ -800,14 +888,20 @@ where
         // we'll simply wait for the writer to signal to us that progress has been made, which
         // implies a data file existing.
         loop {
+            // Data files we haven't started reading yet may be discarded, either evicted by the
+            // writer or deleted once expired, so hold the cleanup lock while opening the data file
+            // to avoid racing with that.
+            let cleanup_guard = self.ledger.lock_data_file_cleanup().await;
             let (reader_file_id, writer_file_id) = self.ledger.get_current_reader_writer_file_id();
             let data_file_path = self.ledger.get_current_reader_data_file_path();
-            let data_file = match self
+            let maybe_data_file = self
                 .ledger
                 .filesystem()
                 .open_file_readable(&data_file_path)
-                .await
-            {
+                .await;
+            drop(cleanup_guard);
+
+            let data_file = match maybe_data_file {
                 Ok(data_file) => data_file,
                 Err(e) => match e.kind() {
                     ErrorKind::NotFound => {
@@ -820,8 +914,18 @@ where
                                 "Data file does not yet exist. Waiting for writer to create."
                             );
                             self.ledger.wait_for_writer().await;
-                        } else {
+                        } else if !self.ledger.has_unacked_reader_file() {
                             self.ledger.increment_acked_reader_file_id();
+                        } else {
+                            // We've already moved past a data file that is still pending
+                            // acknowledgement, so this data file was discarded. Treat it like an
+                            // empty data file so that the acknowledged reader file ID only advances
+                            // past it once the data files before it have been acknowledged.
+                            debug!(
+                                data_file_path = data_file_path.to_string_lossy().as_ref(),
+                                "Data file was discarded. Skipping."
+                            );
+                            self.roll_to_next_data_file();
                         }
                         continue;
                     }
@@ -835,7 +939,9 @@ where
                 "Opened data file for reading."
             );
 
-            self.reader = Some(RecordReader::new(data_file));
+            self.reader = Some(
+                RecordReader::new(data_file).with_cipher(self.ledger.config().encryption.clone()),
+            );
             return Ok(());
         }
     }
@@ -900,7 +1006,10 @@ where
                     let record = try_as_record_archive(data_file_mmap.as_ref())
                         .expect("record was already validated");
 
-                    let Ok(item) = decode_record_payload::<T>(record) else {
+                    let Ok(item) = decode_record_payload::<T>(
+                        record,
+                        self.ledger.config().encryption.as_ref(),
+                    ) else {
                         // If there's an error decoding the item, just fall back to the slow path,
                         // because this file might actually be where we left off, so we don't want
                         // to incorrectly skip ahead or anything.
@@ -999,15 +1108,30 @@ where
     /// If the writer is closed and there is no more data in the buffer, `None` is returned.
     /// Otherwise, reads the next record or waits until the next record is available.
     ///
+    /// Records older than the configured maximum age are discarded rather than returned.
+    ///
     /// # Errors
     ///
     /// If an error occurred while reading a record, an error variant will be returned describing
     /// the error.
     #[cfg_attr(test, instrument(skip(self), level = "trace"))]
+    pub async fn next(&mut self) -> Result<Option<T>, ReaderError<T>> {
+        loop {
+            match self.next_record().await? {
+                // Dropping an expired record acknowledges it, so that it's removed from the buffer
+                // in order with the records around it.
+                Some((record, true)) => drop(record),
+                Some((record, false)) => return Ok(Some(record)),
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// Reads a record, along with whether or not it expired.
     // The inline antithesis assertion blocks push this over the line limit. Their
     // source lines count even when the feature is off, so the allow is unconditional.
     #[allow(clippy::too_many_lines)]
-    pub async fn next(&mut self) -> Result<Option<T>, ReaderError<T>> {
+    async fn next_record(&mut self) -> Result<Option<(T, bool)>, ReaderError<T>> {
         let mut force_check_pending_data_files = false;
 
         let token = loop {
@@ -1178,6 +1302,14 @@ where
         let record_id = token.record_id();
         let record_bytes = token.record_bytes() as u64;
 
+        // Records are only checked for expiry once we're caught up, as during initialization we're
+        // only replaying records that were already read.
+        let expired = self.ready_to_read
+            && token
+                .written_at()
+                .zip(self.ledger.config().max_age)
+                .is_some_and(|(written_at, max_age)| expiry::is_expired(written_at, max_age));
+
         let read_result = self
             .reader
             .as_mut()
@@ -1217,7 +1349,7 @@ where
             self.track_dropped_read(record_bytes);
             return Err(ReaderError::EmptyRecord);
         };
-        self.track_read(record_id, record_bytes, record_events);
+        self.track_read(record_id, record_bytes, record_events, expired);
 
         let (batch, receiver) = BatchNotifier::new_with_receiver();
         record.add_batch_notifier(batch);
@@ -1228,33 +1360,67 @@ where
                 record_id,
                 record_events,
                 record_bytes,
+                expired,
                 data_file_id = self.ledger.get_current_reader_file_id(),
                 "Read record."
             );
         }
 
-        Ok(Some(record))
+        Ok(Some((record, expired)))
     }
 }
 
 pub(crate) fn decode_record_payload<T: Bufferable>(
     record: &ArchivedRecord<'_>,
+    cipher: Option<&RecordCipher>,
 ) -> Result<T, ReaderError<T>> {
+    // Strip the write timestamp first, if the record was timestamped, as it sits outside of any
+    // encryption.
+    let (record_metadata, _, payload) =
+        expiry::split_timestamp(record.metadata(), record.payload()).ok_or_else(|| {
+            ReaderError::Incompatible {
+                reason: "timestamped record is too short to hold a timestamp".to_string(),
+            }
+        })?;
+
+    // Decrypt the payload next, if the record was encrypted, as the metadata of `T` excludes the
+    // flag we use to mark encrypted records.
+    let decrypted;
+    let payload = if record_metadata & ENCRYPTED_METADATA_FLAG == 0 {
+        payload
+    } else {
+        let cipher = cipher.ok_or_else(|| ReaderError::Decryption {
+            reason: "record is encrypted but no encryption key is configured".to_string(),
+        })?;
+        decrypted = cipher
+            .decrypt(record.id(), record_metadata, payload)
+            .map_err(|e| ReaderError::Decryption {
+                reason: e.to_string(),
+            })?;
+        decrypted.as_slice()
+    };
+
+    // Compressed records are then decompressed, based on the algorithm stored in the metadata.
+    let decompressed = compression::decompress(record_metadata, payload).map_err(|e| {
+        ReaderError::Decompression {
+            reason: e.to_string(),
+        }
+    })?;
+    let payload = decompressed.as_deref().unwrap_or(payload);
+    let record_metadata = record_metadata & !(ENCRYPTED_METADATA_FLAG | COMPRESSION_METADATA_MASK);
+
     // Try and convert the raw record metadata into the true metadata type used by `T`, and then
     // also verify that `T` is able to decode records with the metadata used for this record in particular.
-    let metadata = T::Metadata::from_u32(record.metadata()).ok_or(ReaderError::Incompatible {
+    let metadata = T::Metadata::from_u32(record_metadata).ok_or(ReaderError::Incompatible {
         reason: format!("invalid metadata for {}", std::any::type_name::<T>()),
     })?;
 
     if !T::can_decode(metadata) {
         return Err(ReaderError::Incompatible {
-            reason: format!(
-                "record metadata not supported (metadata: {:#036b})",
-                record.metadata()
-            ),
+            reason: format!("record metadata not supported (metadata: {record_metadata:#036b})"),
         });
     }
 
     // Now we can finally try decoding.
-    T::decode(metadata, record.payload()).context(DecodeSnafu)
+    T::decode(metadata, payload).context(DecodeSnafu)
 }
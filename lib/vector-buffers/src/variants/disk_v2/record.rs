@@ -62,7 +62,9 @@ pub struct Record<'a> {
 
     /// The record metadata.
     ///
-    /// Based on `Encodable::Metadata`.
+    /// Based on `Encodable::Metadata`, with the highest bit reserved to flag an encrypted payload,
+    /// the two bits below it reserved for the compression algorithm of the payload, and the bit
+    /// below those reserved to flag a payload prefixed with the time the record was written.
     pub(super) metadata: u32,
 
     /// The record payload.
@@ -130,6 +132,11 @@ impl<'a> Record<'a> {
 }
 
 impl ArchivedRecord<'_> {
+    /// Gets the ID of this record.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
     /// Gets the metadata of this record.
     pub fn metadata(&self) -> u32 {
         self.metadata
@@ -0,0 +1,146 @@
+use std::{path::Path, sync::Arc};
+
+use vector_common::finalization::Finalizable;
+
+use super::{FilesystemUnderTest, create_default_buffer_v2, read_next, read_next_some};
+use crate::{
+    Bufferable, DiskBufferCompression, assert_buffer_is_empty, assert_buffer_records,
+    buffer_usage_data::BufferUsageHandle,
+    test::{SizedRecord, acknowledge, with_temp_dir},
+    variants::disk_v2::{
+        Buffer, BufferReader, BufferWriter, DiskBufferConfigBuilder, Ledger, RecordCipher,
+    },
+};
+
+/// Creates a disk v2 buffer with all default values, compressing records with the given algorithm.
+async fn create_compressed_buffer_v2<P, R>(
+    data_dir: P,
+    compression: DiskBufferCompression,
+    cipher: Option<RecordCipher>,
+) -> (
+    BufferWriter<R, FilesystemUnderTest>,
+    BufferReader<R, FilesystemUnderTest>,
+    Arc<Ledger<FilesystemUnderTest>>,
+)
+where
+    P: AsRef<Path>,
+    R: Bufferable,
+{
+    let mut builder = DiskBufferConfigBuilder::from_path(data_dir).compression(compression);
+    if let Some(cipher) = cipher {
+        builder = builder.encryption(cipher);
+    }
+    let config = builder.build().expect("creating buffer should not fail");
+    let usage_handle = BufferUsageHandle::noop();
+    Buffer::from_config_inner(config, usage_handle)
+        .await
+        .expect("should not fail to create buffer")
+}
+
+async fn write_record(
+    writer: &mut BufferWriter<SizedRecord, FilesystemUnderTest>,
+    record: SizedRecord,
+) {
+    writer
+        .write_record(record)
+        .await
+        .expect("write should not fail");
+    writer.flush().await.expect("flush should not fail");
+}
+
+async fn compressed_round_trip(compression: DiskBufferCompression, cipher: Option<RecordCipher>) {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Write the same record to an uncompressed buffer first, so we know how much space it
+            // takes up when stored as-is.
+            let uncompressed_size = {
+                let (mut writer, _reader, ledger) =
+                    create_default_buffer_v2(data_dir.join("uncompressed")).await;
+                write_record(&mut writer, SizedRecord::new(64 * 1024)).await;
+                ledger.get_total_buffer_size()
+            };
+
+            let (mut writer, mut reader, ledger) =
+                create_compressed_buffer_v2(data_dir.join("compressed"), compression, cipher).await;
+            write_record(&mut writer, SizedRecord::new(64 * 1024)).await;
+            writer.close();
+
+            // The ledger should only account for the compressed record.
+            assert_buffer_records!(ledger, 1);
+            let compressed_size = ledger.get_total_buffer_size();
+            assert!(
+                compressed_size * 10 < uncompressed_size,
+                "expected compressed size ({compressed_size}) to be much smaller than uncompressed \
+                 size ({uncompressed_size})"
+            );
+
+            let mut record = read_next_some(&mut reader).await;
+            acknowledge(record.take_finalizers()).await;
+            assert_eq!(record, SizedRecord::new(64 * 1024));
+            assert_eq!(read_next(&mut reader).await, None);
+            assert_buffer_is_empty!(ledger);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn zstd_records_round_trip() {
+    compressed_round_trip(DiskBufferCompression::Zstd, None).await;
+}
+
+#[tokio::test]
+async fn lz4_records_round_trip() {
+    compressed_round_trip(DiskBufferCompression::Lz4, None).await;
+}
+
+#[tokio::test]
+async fn compressed_and_encrypted_records_round_trip() {
+    let cipher = RecordCipher::new([0xA5; 32], []).expect("key should be valid");
+    compressed_round_trip(DiskBufferCompression::Zstd, Some(cipher)).await;
+}
+
+#[tokio::test]
+async fn records_readable_after_changing_compression() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let settings = [
+                (DiskBufferCompression::Zstd, 1024),
+                (DiskBufferCompression::Lz4, 2048),
+                (DiskBufferCompression::None, 4096),
+            ];
+            for (compression, record_size) in settings {
+                let (mut writer, reader, ledger) =
+                    create_compressed_buffer_v2(data_dir.clone(), compression, None).await;
+                write_record(&mut writer, SizedRecord::new(record_size)).await;
+                writer.close();
+                drop(writer);
+                drop(reader);
+                drop(ledger);
+            }
+
+            // Reopen the buffer with compression disabled: every record should still be readable,
+            // regardless of how it was written.
+            let (writer, mut reader, ledger) = create_compressed_buffer_v2::<_, SizedRecord>(
+                data_dir,
+                DiskBufferCompression::None,
+                None,
+            )
+            .await;
+            drop(writer);
+
+            for (_, record_size) in settings {
+                let mut record = read_next_some(&mut reader).await;
+                acknowledge(record.take_finalizers()).await;
+                assert_eq!(record, SizedRecord::new(record_size));
+            }
+            assert_eq!(read_next(&mut reader).await, None);
+            assert_buffer_is_empty!(ledger);
+        }
+    })
+    .await;
+}
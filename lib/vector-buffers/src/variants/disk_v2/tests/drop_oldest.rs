@@ -0,0 +1,124 @@
+use vector_common::finalization::Finalizable;
+
+use super::{
+    create_buffer_v2_with_data_file_count_limit_and_usage,
+    get_minimum_data_file_size_for_record_payload, read_next, read_next_some,
+};
+use crate::{
+    assert_buffer_is_empty, assert_buffer_records, assert_reader_writer_v2_file_positions,
+    test::{SizedRecord, acknowledge, install_tracing_helpers, with_temp_dir},
+    variants::disk_v2::TryWriteOutcome,
+};
+
+#[tokio::test]
+async fn writer_evicts_oldest_unread_data_file_when_full() {
+    let _a = install_tracing_helpers();
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Each record fills an entire data file, and the buffer can hold three of them.
+            let record_size = 96;
+            let max_data_file_size =
+                get_minimum_data_file_size_for_record_payload(&SizedRecord::new(record_size));
+            let (mut writer, mut reader, ledger, usage) =
+                create_buffer_v2_with_data_file_count_limit_and_usage(
+                    data_dir,
+                    max_data_file_size,
+                    4,
+                )
+                .await;
+
+            for _ in 0..3 {
+                let result = writer
+                    .write_record_dropping_oldest(SizedRecord::new(record_size))
+                    .await
+                    .expect("write should not fail");
+                assert_eq!(result, TryWriteOutcome::Written);
+                writer.flush().await.expect("flush should not fail");
+            }
+            assert_buffer_records!(ledger, 3);
+            assert_reader_writer_v2_file_positions!(ledger, 0, 2);
+
+            // The buffer is now full, so writing another record should evict the oldest data file
+            // that the reader hasn't started reading yet, which is the second one.
+            let result = writer
+                .write_record_dropping_oldest(SizedRecord::new(record_size))
+                .await
+                .expect("write should not fail");
+            assert_eq!(result, TryWriteOutcome::Written);
+            writer.flush().await.expect("flush should not fail");
+            writer.close();
+
+            let snapshot = usage.snapshot();
+            assert_eq!(4, snapshot.received_event_count);
+            assert_eq!(1, snapshot.dropped_event_count_intentional);
+            assert!(snapshot.dropped_event_byte_size_intentional > 0);
+
+            // The reader should skip right over the evicted data file.
+            for _ in 0..3 {
+                let mut record = read_next_some(&mut reader).await;
+                acknowledge(record.take_finalizers()).await;
+                assert_eq!(record, SizedRecord::new(record_size));
+            }
+            assert_eq!(read_next(&mut reader).await, None);
+            assert_buffer_is_empty!(ledger);
+
+            // The skipped events were already accounted for when their data file was evicted.
+            let snapshot = usage.snapshot();
+            assert_eq!(3, snapshot.sent_event_count);
+            assert_eq!(1, snapshot.dropped_event_count_intentional);
+            assert_eq!(0, snapshot.dropped_event_count);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn writer_drops_record_when_no_data_file_can_be_evicted() {
+    let _a = install_tracing_helpers();
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Each record fills an entire data file, and the buffer can only hold one of them, so
+            // the only data files are the ones being read from and written to.
+            let record_size = 96;
+            let max_data_file_size =
+                get_minimum_data_file_size_for_record_payload(&SizedRecord::new(record_size));
+            let (mut writer, mut reader, ledger, usage) =
+                create_buffer_v2_with_data_file_count_limit_and_usage(
+                    data_dir,
+                    max_data_file_size,
+                    2,
+                )
+                .await;
+
+            let result = writer
+                .write_record_dropping_oldest(SizedRecord::new(record_size))
+                .await
+                .expect("write should not fail");
+            assert_eq!(result, TryWriteOutcome::Written);
+            writer.flush().await.expect("flush should not fail");
+
+            let result = writer
+                .write_record_dropping_oldest(SizedRecord::new(record_size))
+                .await
+                .expect("write should not fail");
+            assert_eq!(result, TryWriteOutcome::Dropped);
+            writer.flush().await.expect("flush should not fail");
+            writer.close();
+
+            let snapshot = usage.snapshot();
+            assert_eq!(2, snapshot.received_event_count);
+            assert_eq!(1, snapshot.dropped_event_count_intentional);
+
+            let mut record = read_next_some(&mut reader).await;
+            acknowledge(record.take_finalizers()).await;
+            assert_eq!(record, SizedRecord::new(record_size));
+            assert_eq!(read_next(&mut reader).await, None);
+            assert_buffer_is_empty!(ledger);
+        }
+    })
+    .await;
+}
@@ -0,0 +1,202 @@
+use std::{path::Path, sync::Arc};
+
+use tokio::fs;
+use vector_common::finalization::Finalizable;
+
+use super::{FilesystemUnderTest, create_default_buffer_v2, read_next, read_next_some};
+use crate::{
+    Bufferable, assert_buffer_is_empty, assert_buffer_records,
+    buffer_usage_data::BufferUsageHandle,
+    test::{SizedRecord, acknowledge, with_temp_dir},
+    variants::disk_v2::{
+        Buffer, BufferReader, BufferWriter, DiskBufferConfigBuilder, Ledger, ReaderError,
+        RecordCipher,
+    },
+};
+
+const KEY_A: [u8; 32] = [0xA5; 32];
+const KEY_B: [u8; 32] = [0x5A; 32];
+
+/// Creates a disk v2 buffer with all default values, encrypting records with the given cipher.
+async fn create_encrypted_buffer_v2<P, R>(
+    data_dir: P,
+    cipher: RecordCipher,
+) -> (
+    BufferWriter<R, FilesystemUnderTest>,
+    BufferReader<R, FilesystemUnderTest>,
+    Arc<Ledger<FilesystemUnderTest>>,
+)
+where
+    P: AsRef<Path>,
+    R: Bufferable,
+{
+    let config = DiskBufferConfigBuilder::from_path(data_dir)
+        .encryption(cipher)
+        .build()
+        .expect("creating buffer should not fail");
+    let usage_handle = BufferUsageHandle::noop();
+    Buffer::from_config_inner(config, usage_handle)
+        .await
+        .expect("should not fail to create buffer")
+}
+
+fn cipher(current_key: [u8; 32], previous_keys: &[[u8; 32]]) -> RecordCipher {
+    RecordCipher::new(current_key, previous_keys.iter().copied()).expect("keys should be valid")
+}
+
+async fn write_records(
+    writer: &mut BufferWriter<SizedRecord, FilesystemUnderTest>,
+    records: &[SizedRecord],
+) {
+    for record in records {
+        writer
+            .write_record(record.clone())
+            .await
+            .expect("write should not fail");
+    }
+    writer.flush().await.expect("flush should not fail");
+}
+
+#[tokio::test]
+async fn encrypted_records_round_trip() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let (mut writer, mut reader, ledger) =
+                create_encrypted_buffer_v2(data_dir.clone(), cipher(KEY_A, &[])).await;
+            assert_buffer_is_empty!(ledger);
+
+            // `SizedRecord` payloads are a run of identical bytes, which is easy to spot in the data
+            // file if it was written in the clear.
+            let expected = SizedRecord::new(4096);
+            write_records(&mut writer, std::slice::from_ref(&expected)).await;
+            assert_buffer_records!(ledger, 1);
+
+            let mut data_file_contents = Vec::new();
+            let mut entries = fs::read_dir(&data_dir).await.expect("should read data dir");
+            while let Some(entry) = entries.next_entry().await.expect("should read entry") {
+                if entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with("buffer-data")
+                {
+                    data_file_contents = fs::read(entry.path()).await.expect("should read file");
+                }
+            }
+            let plaintext = vec![0x42; 4096];
+            assert!(!data_file_contents.is_empty());
+            assert!(
+                !data_file_contents
+                    .windows(plaintext.len())
+                    .any(|window| window == plaintext.as_slice()),
+                "data file should not contain the plaintext payload"
+            );
+
+            writer.close();
+            let mut record = read_next_some(&mut reader).await;
+            acknowledge(record.take_finalizers()).await;
+            assert_eq!(record, expected);
+            assert_eq!(read_next(&mut reader).await, None);
+            assert_buffer_is_empty!(ledger);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn rotated_key_keeps_older_records_readable() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Write a record with the original key.
+            let (mut writer, reader, ledger) =
+                create_encrypted_buffer_v2(data_dir.clone(), cipher(KEY_A, &[])).await;
+            write_records(&mut writer, &[SizedRecord::new(64)]).await;
+            writer.close();
+            drop(writer);
+            drop(reader);
+            drop(ledger);
+
+            // Rotate to a new key, keeping the original one around for reading, and write another
+            // record, which should be encrypted with the new key.
+            let (mut writer, mut reader, ledger) =
+                create_encrypted_buffer_v2(data_dir.clone(), cipher(KEY_B, &[KEY_A])).await;
+            write_records(&mut writer, &[SizedRecord::new(128)]).await;
+            writer.close();
+
+            for expected in [SizedRecord::new(64), SizedRecord::new(128)] {
+                let mut record = read_next_some(&mut reader).await;
+                acknowledge(record.take_finalizers()).await;
+                assert_eq!(record, expected);
+            }
+            assert_eq!(read_next(&mut reader).await, None);
+            assert_buffer_is_empty!(ledger);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn plaintext_records_readable_after_enabling_encryption() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let (mut writer, reader, ledger) = create_default_buffer_v2(data_dir.clone()).await;
+            write_records(&mut writer, &[SizedRecord::new(64)]).await;
+            writer.close();
+            drop(writer);
+            drop(reader);
+            drop(ledger);
+
+            let (mut writer, mut reader, ledger) =
+                create_encrypted_buffer_v2(data_dir.clone(), cipher(KEY_A, &[])).await;
+            write_records(&mut writer, &[SizedRecord::new(128)]).await;
+            writer.close();
+
+            for expected in [SizedRecord::new(64), SizedRecord::new(128)] {
+                let mut record = read_next_some(&mut reader).await;
+                acknowledge(record.take_finalizers()).await;
+                assert_eq!(record, expected);
+            }
+            assert_eq!(read_next(&mut reader).await, None);
+            assert_buffer_is_empty!(ledger);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn encrypted_records_unreadable_with_unknown_key() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let (mut writer, reader, ledger) =
+                create_encrypted_buffer_v2(data_dir.clone(), cipher(KEY_A, &[])).await;
+            write_records(&mut writer, &[SizedRecord::new(64)]).await;
+            writer.close();
+            drop(writer);
+            drop(reader);
+            drop(ledger);
+
+            // The record was encrypted with a key that is no longer configured, so it cannot be
+            // decrypted and is dropped instead.
+            let (writer, mut reader, ledger) =
+                create_encrypted_buffer_v2::<_, SizedRecord>(data_dir.clone(), cipher(KEY_B, &[]))
+                    .await;
+            drop(writer);
+
+            let result = reader.next().await;
+            assert!(
+                matches!(result, Err(ReaderError::Decryption { .. })),
+                "expected decryption error, got {result:?}"
+            );
+            assert_eq!(read_next(&mut reader).await, None);
+            assert_buffer_is_empty!(ledger);
+        }
+    })
+    .await;
+}
@@ -0,0 +1,153 @@
+use std::{path::Path, sync::Arc, time::Duration};
+
+use vector_common::finalization::Finalizable;
+
+use super::{
+    FilesystemUnderTest, get_minimum_data_file_size_for_record_payload, read_next, read_next_some,
+};
+use crate::{
+    Bufferable, assert_buffer_is_empty,
+    buffer_usage_data::BufferUsageHandle,
+    test::{SizedRecord, acknowledge, install_tracing_helpers, with_temp_dir},
+    variants::disk_v2::{
+        Buffer, BufferReader, BufferWriter, DiskBufferConfigBuilder, Ledger,
+        expiry::{TIMESTAMP_LEN, advance_clock},
+    },
+};
+
+/// Records are expired by moving the clock ahead, so this is long enough that no record expires
+/// while a test runs.
+const MAX_AGE: Duration = Duration::from_secs(3600);
+
+/// Creates a disk v2 buffer whose records expire after `MAX_AGE`, with data files sized to hold a
+/// single record of the given payload size.
+async fn create_expiring_buffer_v2<P, R>(
+    data_dir: P,
+    record_size: u32,
+) -> (
+    BufferWriter<R, FilesystemUnderTest>,
+    BufferReader<R, FilesystemUnderTest>,
+    Arc<Ledger<FilesystemUnderTest>>,
+    BufferUsageHandle,
+)
+where
+    P: AsRef<Path>,
+    R: Bufferable,
+{
+    // Timestamped records carry a few extra bytes, so size the data files for a payload that's
+    // just as much bigger.
+    let timestamp_len = u32::try_from(TIMESTAMP_LEN).unwrap();
+    let max_data_file_size = get_minimum_data_file_size_for_record_payload(&SizedRecord::new(
+        record_size + timestamp_len,
+    ));
+
+    let config = DiskBufferConfigBuilder::from_path(data_dir)
+        .max_record_size(usize::try_from(max_data_file_size).unwrap())
+        .max_data_file_size(max_data_file_size)
+        .max_age(MAX_AGE)
+        .build()
+        .expect("creating buffer should not fail");
+    let usage_handle = BufferUsageHandle::noop();
+    let (writer, reader, ledger) = Buffer::from_config_inner(config, usage_handle.clone())
+        .await
+        .expect("should not fail to create buffer");
+    (writer, reader, ledger, usage_handle)
+}
+
+#[tokio::test]
+async fn reader_skips_expired_records() {
+    let _a = install_tracing_helpers();
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let (mut writer, mut reader, ledger, usage) =
+                create_expiring_buffer_v2(data_dir, 32).await;
+
+            for record_size in [8, 16] {
+                writer
+                    .write_record(SizedRecord::new(record_size))
+                    .await
+                    .expect("write should not fail");
+            }
+            writer.flush().await.expect("flush should not fail");
+
+            advance_clock(MAX_AGE * 2);
+
+            writer
+                .write_record(SizedRecord::new(32))
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            writer.close();
+
+            // Only the record written after the others expired should be returned.
+            let mut record = read_next_some(&mut reader).await;
+            acknowledge(record.take_finalizers()).await;
+            assert_eq!(record, SizedRecord::new(32));
+            assert_eq!(read_next(&mut reader).await, None);
+            assert_buffer_is_empty!(ledger);
+
+            let snapshot = usage.snapshot();
+            assert_eq!(3, snapshot.received_event_count);
+            assert_eq!(1, snapshot.sent_event_count);
+            assert_eq!(2, snapshot.dropped_event_count_intentional);
+            assert_eq!(0, snapshot.dropped_event_count);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn expired_data_files_are_deleted_before_being_read() {
+    let _a = install_tracing_helpers();
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Each record fills an entire data file.
+            let record_size = 96;
+            let (mut writer, mut reader, ledger, usage) =
+                create_expiring_buffer_v2(data_dir, record_size).await;
+
+            for _ in 0..3 {
+                writer
+                    .write_record(SizedRecord::new(record_size))
+                    .await
+                    .expect("write should not fail");
+                writer.flush().await.expect("flush should not fail");
+            }
+
+            advance_clock(MAX_AGE * 2);
+
+            writer
+                .write_record(SizedRecord::new(record_size))
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            writer.close();
+
+            // The data files between the reader and the writer have expired, so they can be deleted
+            // without being read. The background cleanup task may have already beaten us to it.
+            ledger
+                .cleanup_expired_data_files(MAX_AGE)
+                .await
+                .expect("cleanup should not fail");
+
+            // The record in the reader's current data file is skipped when read, and the deleted
+            // data files are skipped entirely.
+            let mut record = read_next_some(&mut reader).await;
+            acknowledge(record.take_finalizers()).await;
+            assert_eq!(record, SizedRecord::new(record_size));
+            assert_eq!(read_next(&mut reader).await, None);
+            assert_buffer_is_empty!(ledger);
+
+            let snapshot = usage.snapshot();
+            assert_eq!(4, snapshot.received_event_count);
+            assert_eq!(1, snapshot.sent_event_count);
+            assert_eq!(3, snapshot.dropped_event_count_intentional);
+            assert_eq!(0, snapshot.dropped_event_count);
+        }
+    })
+    .await;
+}
@@ -0,0 +1,66 @@
+use crate::{
+    test::{SizedRecord, acknowledge, install_tracing_helpers, with_temp_dir},
+    variants::disk_v2::{
+        BufferInspector, LedgerLoadCreateError,
+        tests::{create_default_buffer_v2, read_next_some},
+    },
+};
+
+#[tokio::test]
+async fn inspector_reads_unacknowledged_records() {
+    let _a = install_tracing_helpers();
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let (mut writer, mut reader, ledger) = create_default_buffer_v2(data_dir.clone()).await;
+
+            for record_size in [16, 32, 64] {
+                writer
+                    .write_record(SizedRecord::new(record_size))
+                    .await
+                    .expect("write should not fail");
+            }
+            writer.flush().await.expect("flush should not fail");
+            writer.close();
+
+            // Acknowledge the first record, which should then be skipped by the inspector.
+            let first_read = read_next_some(&mut reader).await;
+            acknowledge(first_read).await;
+            let second_read = read_next_some(&mut reader).await;
+            assert_eq!(SizedRecord::new(32), second_read);
+            ledger.flush().expect("should not fail to flush ledger");
+
+            // The buffer can't be inspected while it's open.
+            let result = BufferInspector::<SizedRecord>::open(&data_dir, None).await;
+            assert!(matches!(
+                result,
+                Err(LedgerLoadCreateError::LedgerLockAlreadyHeld)
+            ));
+
+            drop(reader);
+            drop(writer);
+            drop(ledger);
+
+            let mut inspector = BufferInspector::<SizedRecord>::open(&data_dir, None)
+                .await
+                .expect("should not fail to open buffer for inspection");
+            assert_eq!(2, inspector.unread_event_count());
+
+            let mut records = Vec::new();
+            while let Some(inspected) = inspector.next().await.expect("read should not fail") {
+                assert!(inspected.record_bytes > 0);
+                assert_eq!(None, inspected.written_at);
+                records.push(inspected.record);
+            }
+            assert_eq!(vec![SizedRecord::new(32), SizedRecord::new(64)], records);
+
+            // Inspecting the buffer doesn't change what's left to read from it.
+            drop(inspector);
+            let (_writer, mut reader, _ledger) =
+                create_default_buffer_v2::<_, SizedRecord>(data_dir).await;
+            assert_eq!(SizedRecord::new(32), read_next_some(&mut reader).await);
+        }
+    })
+    .await;
+}
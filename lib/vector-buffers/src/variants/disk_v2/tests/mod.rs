@@ -24,8 +24,13 @@ type FilesystemUnderTest = ProductionFilesystem;
 
 mod acknowledgements;
 mod basic;
+mod compression;
+mod drop_oldest;
+mod encryption;
+mod expiry;
 mod filter_metrics;
 mod initialization;
+mod inspect;
 mod invariants;
 mod known_errors;
 mod model;
@@ -34,7 +39,10 @@ mod size_limits;
 
 impl AsyncFile for DuplexStream {
     async fn metadata(&self) -> io::Result<Metadata> {
-        Ok(Metadata { len: 0 })
+        Ok(Metadata {
+            len: 0,
+            modified: None,
+        })
     }
 
     async fn truncate(&self, size: u64) -> io::Result<()> {
@@ -57,6 +65,7 @@ impl AsyncFile for Cursor<Vec<u8>> {
     async fn metadata(&self) -> io::Result<Metadata> {
         Ok(Metadata {
             len: u64::try_from(self.get_ref().len()).expect("cursor length should fit in u64"),
+            modified: None,
         })
     }
 
@@ -273,6 +282,31 @@ pub(crate) async fn create_buffer_v2_with_data_file_count_limit<P, R>(
     BufferReader<R, FilesystemUnderTest>,
     Arc<Ledger<FilesystemUnderTest>>,
 )
+where
+    P: AsRef<Path>,
+    R: Bufferable,
+{
+    let (writer, reader, ledger, _) = create_buffer_v2_with_data_file_count_limit_and_usage(
+        data_dir,
+        max_data_file_size,
+        data_file_count_limit,
+    )
+    .await;
+    (writer, reader, ledger)
+}
+
+/// Creates a disk v2 buffer that is sized such that only a fixed number of data files are allowed,
+/// but returns a handle to the buffer usage tracker.
+pub(crate) async fn create_buffer_v2_with_data_file_count_limit_and_usage<P, R>(
+    data_dir: P,
+    max_data_file_size: u64,
+    data_file_count_limit: u64,
+) -> (
+    BufferWriter<R, FilesystemUnderTest>,
+    BufferReader<R, FilesystemUnderTest>,
+    Arc<Ledger<FilesystemUnderTest>>,
+    BufferUsageHandle,
+)
 where
     P: AsRef<Path>,
     R: Bufferable,
@@ -302,10 +336,10 @@ where
         .build()
         .expect("creating buffer should not fail");
     let usage_handle = BufferUsageHandle::noop();
-
-    Buffer::from_config_inner(config, usage_handle)
+    let (writer, reader, ledger) = Buffer::from_config_inner(config, usage_handle.clone())
         .await
-        .expect("should not fail to create buffer")
+        .expect("should not fail to create buffer");
+    (writer, reader, ledger, usage_handle)
 }
 
 /// Creates a disk v2 buffer with the specified maximum record size, but returns a handle to the
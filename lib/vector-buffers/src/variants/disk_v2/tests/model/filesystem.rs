@@ -215,7 +215,10 @@ impl AsyncFile for TestFile {
             inner.buf.as_ref().expect("file buf consumed").len()
         };
 
-        Ok(Metadata { len: len as u64 })
+        Ok(Metadata {
+            len: len as u64,
+            modified: None,
+        })
     }
 
     async fn truncate(&self, size: u64) -> io::Result<()> {
@@ -26,6 +26,8 @@ use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 use super::{
     common::{DiskBufferConfig, create_crc32c_hasher},
+    encryption::{ENCRYPTED_METADATA_FLAG, ENVELOPE_OVERHEAD, RecordCipher},
+    expiry::{self, TIMESTAMP_LEN, TIMESTAMPED_METADATA_FLAG},
     io::Filesystem,
     ledger::Ledger,
     record::{Record, RecordStatus, validate_record_archive},
@@ -450,9 +452,15 @@ impl<W: fmt::Debug> fmt::Debug for TrackingBufWriter<W> {
 pub(super) struct RecordWriter<W, T> {
     writer: TrackingBufWriter<W>,
     encode_buf: Vec<u8>,
+    compress_buf: Vec<u8>,
+    encrypt_buf: Vec<u8>,
+    timestamp_buf: Vec<u8>,
     ser_buf: AlignedVec,
     ser_scratch: AlignedVec,
     checksummer: Hasher,
+    compression: DiskBufferCompression,
+    cipher: Option<RecordCipher>,
+    timestamps: bool,
     max_record_size: usize,
     current_data_file_size: u64,
     max_data_file_size: u64,
@@ -500,9 +508,15 @@ where
         Self {
             writer: TrackingBufWriter::with_capacity(write_buffer_size, writer),
             encode_buf: Vec::with_capacity(16_384),
+            compress_buf: Vec::new(),
+            encrypt_buf: Vec::new(),
+            timestamp_buf: Vec::new(),
             ser_buf: AlignedVec::with_capacity(16_384),
             ser_scratch: AlignedVec::with_capacity(16_384),
             checksummer: create_crc32c_hasher(),
+            compression: DiskBufferCompression::None,
+            cipher: None,
+            timestamps: false,
             max_record_size,
             current_data_file_size,
             max_data_file_size,
@@ -510,6 +524,36 @@ where
         }
     }
 
+    /// Sets the cipher used to encrypt records.
+    ///
+    /// Encrypting a record grows its payload by a fixed amount, so the limit on the encoded size of
+    /// a record is lowered accordingly to keep the archived record within `max_record_size`.
+    pub fn with_cipher(mut self, cipher: Option<RecordCipher>) -> Self {
+        if cipher.is_some() && self.cipher.is_none() {
+            self.max_record_size = self.max_record_size.saturating_sub(ENVELOPE_OVERHEAD);
+        }
+        self.cipher = cipher;
+        self
+    }
+
+    /// Sets the compression applied to records.
+    pub fn with_compression(mut self, compression: DiskBufferCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Sets whether or not records are stamped with the time they were written.
+    ///
+    /// Like encryption, stamping a record grows its payload by a fixed amount, so the limit on the
+    /// encoded size of a record is lowered accordingly.
+    pub fn with_timestamps(mut self, timestamps: bool) -> Self {
+        if timestamps && !self.timestamps {
+            self.max_record_size = self.max_record_size.saturating_sub(TIMESTAMP_LEN);
+        }
+        self.timestamps = timestamps;
+        self
+    }
+
     /// Gets a reference to the underlying writer.
     #[cfg(test)]
     pub fn get_ref(&self) -> &W {
@@ -571,9 +615,48 @@ where
             });
         }
 
+        // When compression is enabled, the encoded record is compressed, and the algorithm used is
+        // stored in the record metadata so that readers can decompress it regardless of how the
+        // buffer is currently configured.  Records that do not shrink are stored uncompressed.
         let metadata = T::get_metadata().into_u32();
-        let wrapped_record =
-            Record::with_checksum(id, metadata, &self.encode_buf, &self.checksummer);
+        let compressed = self
+            .compression
+            .compress(&self.encode_buf, &mut self.compress_buf)
+            .map_err(|e| WriterError::FailedToSerialize {
+                reason: e.to_string(),
+            })?;
+        let (metadata, payload) = match compressed {
+            None => (metadata, self.encode_buf.as_slice()),
+            Some(compression_bits) => (metadata | compression_bits, self.compress_buf.as_slice()),
+        };
+
+        // When encryption is enabled, the payload is sealed into its encrypted envelope, and the
+        // record is flagged as such so that readers know to decrypt it first.
+        let (metadata, payload) = match &self.cipher {
+            None => (metadata, payload),
+            Some(cipher) => {
+                let metadata = metadata | ENCRYPTED_METADATA_FLAG;
+                cipher
+                    .encrypt(id, metadata, payload, &mut self.encrypt_buf)
+                    .map_err(|e| WriterError::FailedToSerialize {
+                        reason: e.to_string(),
+                    })?;
+                (metadata, self.encrypt_buf.as_slice())
+            }
+        };
+
+        // When records can expire, the time the record was written is prefixed to the payload,
+        // outside of any encryption, so that readers can check it before doing any other work.
+        let (metadata, payload) = if self.timestamps {
+            expiry::stamp(payload, &mut self.timestamp_buf);
+            (
+                metadata | TIMESTAMPED_METADATA_FLAG,
+                self.timestamp_buf.as_slice(),
+            )
+        } else {
+            (metadata, payload)
+        };
+        let wrapped_record = Record::with_checksum(id, metadata, payload, &self.checksummer);
 
         // Push 8 dummy bytes where our length delimiter will sit.  We'll fix this up after
         // serialization.  Notably, `AlignedSerializer` will report the serializer position as
@@ -776,16 +859,9 @@ where
         })?;
 
         // Now we can actually decode it as `T`.
-        let record_metadata = T::Metadata::from_u32(wrapped_record.metadata()).ok_or(
-            WriterError::InconsistentState {
-                reason: "failed to decode record metadata immediately after encoding it"
-                    .to_string(),
-            },
-        )?;
-
-        T::decode(record_metadata, wrapped_record.payload()).map_err(|_| {
+        decode_record_payload::<T>(wrapped_record, self.cipher.as_ref()).map_err(|e| {
             WriterError::InconsistentState {
-                reason: "failed to decode record immediately after encoding it".to_string(),
+                reason: format!("failed to decode record immediately after encoding it: {e}"),
             }
         })
     }
@@ -993,7 +1069,8 @@ where
             Err(error) if error.kind() == ErrorKind::NotFound => return Ok(false),
             Err(source) => return Err(WriterError::Io { source }),
         };
-        let mut reader: RecordReader<FS::File, T> = RecordReader::new(successor_data_file);
+        let mut reader: RecordReader<FS::File, T> =
+            RecordReader::new(successor_data_file).with_cipher(self.config.encryption.clone());
         let token = match reader.try_next_record(true).await {
             Ok(Some(token)) => token,
             Ok(None) => return Ok(false),
@@ -1200,7 +1277,7 @@ where
             .open_file_readable(&data_file_path)
             .await
             .context(IoSnafu)?;
-        let mut reader = RecordReader::new(data_file);
+        let mut reader = RecordReader::new(data_file).with_cipher(self.config.encryption.clone());
         let mut scan = WriterCheckpointScan::new(checkpoint_next_record_id);
 
         loop {
@@ -1317,11 +1394,10 @@ where
                 // next writer record ID should be.
                 let record = try_as_record_archive(data_file_mmap.as_ref())
                     .expect("record was already validated");
-                let item = decode_record_payload::<T>(record).map_err(|e| {
-                    WriterError::FailedToValidate {
+                let item = decode_record_payload::<T>(record, self.config.encryption.as_ref())
+                    .map_err(|e| WriterError::FailedToValidate {
                         reason: e.to_string(),
-                    }
-                })?;
+                    })?;
 
                 // Since we have a valid record, checksum and all, see if the writer record ID
                 // in the ledger lines up with the record ID we have here.  Specifically, the record
@@ -1621,13 +1697,18 @@ where
                 // Make sure the file is flushed to disk, especially if we just created it.
                 data_file.sync_all().await?;
 
-                self.writer = Some(RecordWriter::new(
-                    data_file,
-                    data_file_size,
-                    self.config.write_buffer_size,
-                    self.config.max_data_file_size,
-                    self.config.max_record_size,
-                ));
+                self.writer = Some(
+                    RecordWriter::new(
+                        data_file,
+                        data_file_size,
+                        self.config.write_buffer_size,
+                        self.config.max_data_file_size,
+                        self.config.max_record_size,
+                    )
+                    .with_cipher(self.config.encryption.clone())
+                    .with_compression(self.config.compression)
+                    .with_timestamps(self.config.max_age.is_some()),
+                );
                 self.data_file_size = data_file_size;
 
                 // If we opened the "next" data file, we need to increment the current writer
@@ -1910,6 +1991,78 @@ where
         }
     }
 
+    /// Writes a record, evicting the oldest data files in the buffer if the buffer is full.
+    ///
+    /// Only data files that the reader has not yet started reading, and that the writer is not
+    /// currently writing to, can be evicted. If the buffer is full and no such data file exists,
+    /// the record itself is dropped instead, so this never waits for reader progress.
+    ///
+    /// Evicted and dropped events are tracked as intentionally dropped.
+    ///
+    /// # Errors
+    ///
+    /// If an error occurred while writing the record, or while evicting a data file, an error
+    /// variant will be returned describing the error.
+    #[instrument(skip_all, level = "debug")]
+    pub async fn write_record_dropping_oldest(
+        &mut self,
+        mut record: T,
+    ) -> Result<TryWriteOutcome<T>, WriterError<T>> {
+        loop {
+            match self.try_write_record_inner(record).await? {
+                Ok(0) => return Ok(TryWriteOutcome::Dropped),
+                Ok(_) => return Ok(TryWriteOutcome::Written),
+                Err(old_record) => {
+                    if !self.evict_oldest_data_file().await.context(IoSnafu)? {
+                        let event_count = old_record.event_count() as u64;
+                        let byte_size = old_record.size_of() as u64;
+                        debug!(
+                            event_count,
+                            "Buffer is full and no data file can be evicted. Dropping record."
+                        );
+                        self.ledger.track_discarded_record(event_count, byte_size);
+                        return Ok(TryWriteOutcome::Dropped);
+                    }
+                    record = old_record;
+                }
+            }
+        }
+    }
+
+    /// Evicts the oldest data file that the reader has not yet started reading.
+    ///
+    /// Returns `true` if a data file was evicted, or `false` if there was no data file that could be
+    /// evicted.
+    async fn evict_oldest_data_file(&mut self) -> io::Result<bool> {
+        // Holding the cleanup lock keeps the reader from opening the data file while we're
+        // evicting it: it either opens the data file before we get here, and so has moved on to
+        // it, or it finds the data file missing and skips over it.
+        let _cleanup_guard = self.ledger.lock_data_file_cleanup().await;
+
+        // Find the oldest data file that the reader has not yet started reading, skipping over any
+        // data files we've already evicted.
+        for file_id in self.ledger.get_unread_data_file_ids() {
+            match self.ledger.discard_unread_data_file(file_id).await {
+                Ok(Some((event_count, data_file_size))) => {
+                    warn!(
+                        data_file_path = self
+                            .ledger
+                            .get_data_file_path(file_id)
+                            .to_string_lossy()
+                            .as_ref(),
+                        event_count, data_file_size, "Buffer is full. Evicted oldest data file."
+                    );
+                    return Ok(true);
+                }
+                Ok(None) => return Ok(false),
+                Err(e) if e.kind() == ErrorKind::NotFound => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(false)
+    }
+
     #[instrument(skip(self), level = "debug")]
     async fn flush_inner(&mut self, force_full_flush: bool) -> io::Result<()> {
         // We always flush the `BufWriter` when this is called, but we don't always flush to disk or
@@ -1,4 +1,4 @@
-use std::error::Error;
+use std::{error::Error, time::Duration};
 
 use async_trait::async_trait;
 
@@ -14,18 +14,27 @@ use crate::{
 
 pub struct MemoryBuffer {
     capacity: MemoryBufferSize,
+    max_age: Option<Duration>,
 }
 
 impl MemoryBuffer {
     pub fn new(capacity: MemoryBufferSize) -> Self {
-        MemoryBuffer { capacity }
+        MemoryBuffer {
+            capacity,
+            max_age: None,
+        }
     }
 
     #[cfg(test)]
     pub fn with_max_events(n: std::num::NonZeroUsize) -> Self {
-        Self {
-            capacity: MemoryBufferSize::MaxEvents(n),
-        }
+        Self::new(MemoryBufferSize::MaxEvents(n))
+    }
+
+    /// Sets the maximum age of the items in this buffer.
+    #[must_use]
+    pub fn with_max_age(mut self, max_age: Option<Duration>) -> Self {
+        self.max_age = max_age;
+        self
     }
 }
 
@@ -45,7 +54,10 @@ where
 
         usage_handle.set_buffer_limits(max_bytes, max_size);
 
-        let (tx, rx) = limited(self.capacity, None, None);
+        let (tx, mut rx) = limited(self.capacity, None, None);
+        if let Some(max_age) = self.max_age {
+            rx = rx.with_max_age(max_age, usage_handle);
+        }
         Ok((tx.into(), rx.into()))
     }
 }
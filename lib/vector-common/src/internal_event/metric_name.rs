@@ -25,6 +25,7 @@ pub enum CounterName {
     AggregateEventsRecordedTotal,
     AggregateFailedUpdates,
     AggregateFlushesTotal,
+    AggregateLateEventsTotal,
     ApiStartedTotal,
     CheckpointsTotal,
     ChecksumErrorsTotal,
@@ -282,6 +283,7 @@ impl CounterName {
             Self::AggregateEventsRecordedTotal => "aggregate_events_recorded_total",
             Self::AggregateFailedUpdates => "aggregate_failed_updates",
             Self::AggregateFlushesTotal => "aggregate_flushes_total",
+            Self::AggregateLateEventsTotal => "aggregate_late_events_total",
             Self::ApiStartedTotal => "api_started_total",
             Self::CheckpointsTotal => "checkpoints_total",
             Self::ChecksumErrorsTotal => "checksum_errors_total",
@@ -87,6 +87,11 @@ pub(super) struct Inner {
     /// An internal vector id that can be used to identify this event across all components.
     #[derivative(PartialEq = "ignore")]
     pub(crate) source_event_id: Option<Uuid>,
+
+    /// Whether this event holds a payload that the source failed to decode. Such events are routed
+    /// to the source's decode errors output rather than its default output.
+    #[serde(default, skip)]
+    pub(crate) decode_error: bool,
 }
 
 /// Metric Origin metadata for submission to Datadog.
@@ -252,6 +257,17 @@ impl EventMetadata {
         self.inner.source_event_id
     }
 
+    /// Returns `true` if this event holds a payload that the source failed to decode.
+    #[must_use]
+    pub fn is_decode_error(&self) -> bool {
+        self.inner.decode_error
+    }
+
+    /// Marks this event as holding a payload that the source failed to decode.
+    pub fn set_decode_error(&mut self) {
+        self.get_mut().decode_error = true;
+    }
+
     /// Returns the timestamp of the last transform buffer enqueue operation, if it exists.
     #[must_use]
     pub fn last_transform_timestamp(&self) -> Option<Instant> {
@@ -277,6 +293,7 @@ impl Default for Inner {
             dropped_fields: ObjectMap::new(),
             datadog_origin_metadata: None,
             source_event_id: Some(Uuid::new_v4()),
+            decode_error: false,
         }
     }
 }
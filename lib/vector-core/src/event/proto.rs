@@ -695,6 +695,7 @@ impl From<Metadata> for EventMetadata {
                 dropped_fields: ObjectMap::new(),
                 datadog_origin_metadata,
                 source_event_id,
+                decode_error: false,
             }),
             last_transform_timestamp: None,
         }
@@ -19,6 +19,13 @@ pub use sender::{SourceSender, SourceSenderItem};
 
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+/// The name of the output that events holding payloads a source failed to decode are routed to.
+///
+/// Sources add this output when their decoder is configured to keep such payloads. Events marked
+/// with [`crate::event::EventMetadata::set_decode_error`] that are sent to the default output are
+/// diverted to it instead.
+pub const DECODE_ERRORS_OUTPUT: &str = "decode_errors";
+
 /// Default number of events batched per source send, and the base used for source output buffer
 /// sizing. Used when the chunk size has not been configured at startup.
 pub const DEFAULT_CHUNK_SIZE_EVENTS: usize = 1000;
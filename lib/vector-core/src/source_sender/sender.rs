@@ -2,9 +2,7 @@
 use std::time::Duration;
 use std::{collections::HashMap, time::Instant};
 
-use futures::Stream;
-#[cfg(any(test, feature = "test"))]
-use futures::StreamExt as _;
+use futures::{Stream, StreamExt as _};
 use vector_buffers::EventCount;
 #[cfg(any(test, feature = "test"))]
 use vector_buffers::topology::channel::LimitedReceiver;
@@ -24,7 +22,7 @@ use vector_common::{
 
 use std::sync::Arc;
 
-use super::{Builder, Output, PostProcessor, SendError};
+use super::{Builder, DECODE_ERRORS_OUTPUT, Output, PostProcessor, SendError, chunk_size_events};
 #[cfg(any(test, feature = "test"))]
 use super::{
     LAG_TIME_NAME, OutputMetrics, SEND_BATCH_LATENCY_NAME, SEND_LATENCY_NAME, TEST_BUFFER_SIZE,
@@ -249,24 +247,55 @@ impl SourceSender {
 
     /// Send an event to the default output.
     ///
+    /// Events that failed to decode are sent to the [`DECODE_ERRORS_OUTPUT`] output instead, if
+    /// this sender has one.
+    ///
     /// This internally handles emitting [EventsSent] and [ComponentEventsDropped] events.
     pub async fn send_event(&mut self, event: impl Into<EventArray>) -> Result<(), SendError> {
+        let mut event = event.into();
+        if let EventArray::Logs(logs) = &mut event
+            && let Some(output) = self.named_outputs.get_mut(DECODE_ERRORS_OUTPUT)
+            && logs.iter().any(|log| log.metadata().is_decode_error())
+        {
+            let (errors, rest) = std::mem::take(logs)
+                .into_iter()
+                .partition::<Vec<_>, _>(|log| log.metadata().is_decode_error());
+            output.send_event(errors).await?;
+            if rest.is_empty() {
+                return Ok(());
+            }
+            *logs = rest;
+        }
         self.default_output_mut().send_event(event).await
     }
 
     /// Send a stream of events to the default output.
     ///
+    /// Events that failed to decode are sent to the [`DECODE_ERRORS_OUTPUT`] output instead, if
+    /// this sender has one.
+    ///
     /// This internally handles emitting [EventsSent] and [ComponentEventsDropped] events.
     pub async fn send_event_stream<S, E>(&mut self, events: S) -> Result<(), SendError>
     where
         S: Stream<Item = E> + Unpin,
         E: Into<Event> + ByteSizeOf,
     {
-        self.default_output_mut().send_event_stream(events).await
+        if !self.named_outputs.contains_key(DECODE_ERRORS_OUTPUT) {
+            return self.default_output_mut().send_event_stream(events).await;
+        }
+
+        let mut stream = events.ready_chunks(chunk_size_events());
+        while let Some(events) = stream.next().await {
+            self.send_batch(events).await?;
+        }
+        Ok(())
     }
 
     /// Send a batch of events to the default output.
     ///
+    /// Events that failed to decode are sent to the [`DECODE_ERRORS_OUTPUT`] output instead, if
+    /// this sender has one.
+    ///
     /// This internally handles emitting [EventsSent] and [ComponentEventsDropped] events.
     pub async fn send_batch<I, E>(&mut self, events: I) -> Result<(), SendError>
     where
@@ -274,11 +303,28 @@ impl SourceSender {
         I: IntoIterator<Item = E>,
         <I as IntoIterator>::IntoIter: ExactSizeIterator,
     {
+        let Some(output) = self.named_outputs.get_mut(DECODE_ERRORS_OUTPUT) else {
+            return self.default_output_mut().send_batch(events).await;
+        };
+
+        let (errors, events) = events
+            .into_iter()
+            .map(Into::into)
+            .partition::<Vec<Event>, _>(|event| event.metadata().is_decode_error());
+        if !errors.is_empty() {
+            output.send_batch(errors).await?;
+        }
+        if events.is_empty() {
+            return Ok(());
+        }
         self.default_output_mut().send_batch(events).await
     }
 
     /// Send a batch of events event to a named output.
     ///
+    /// Events that failed to decode are sent to the [`DECODE_ERRORS_OUTPUT`] output instead, if
+    /// this sender has one.
+    ///
     /// This internally handles emitting [EventsSent] and [ComponentEventsDropped] events.
     pub async fn send_batch_named<I, E>(&mut self, name: &str, events: I) -> Result<(), SendError>
     where
@@ -286,6 +332,29 @@ impl SourceSender {
         I: IntoIterator<Item = E>,
         <I as IntoIterator>::IntoIter: ExactSizeIterator,
     {
+        let Some(output) = self
+            .named_outputs
+            .get_mut(DECODE_ERRORS_OUTPUT)
+            .filter(|_| name != DECODE_ERRORS_OUTPUT)
+        else {
+            return self
+                .named_outputs
+                .get_mut(name)
+                .expect("unknown output")
+                .send_batch(events)
+                .await;
+        };
+
+        let (errors, events) = events
+            .into_iter()
+            .map(Into::into)
+            .partition::<Vec<Event>, _>(|event| event.metadata().is_decode_error());
+        if !errors.is_empty() {
+            output.send_batch(errors).await?;
+        }
+        if events.is_empty() {
+            return Ok(());
+        }
         self.named_outputs
             .get_mut(name)
             .expect("unknown output")
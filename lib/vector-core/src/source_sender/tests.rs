@@ -11,7 +11,10 @@ use vrl::event_path;
 
 use super::*;
 use crate::{
-    event::{Event, LogEvent, Metric, MetricKind, MetricValue, TraceEvent, into_event_stream},
+    event::{
+        Event, EventStatus, LogEvent, Metric, MetricKind, MetricValue, TraceEvent,
+        into_event_stream,
+    },
     metrics::{self, Controller},
 };
 
@@ -524,3 +527,59 @@ async fn post_processor_whole_event_replacement_preserves_finalizers() {
         "datadog_api_key is not preserved when the processor replaces the entire inner value"
     );
 }
+
+#[tokio::test]
+async fn routes_decode_errors_to_named_output() {
+    let (mut sender, recv) = SourceSender::new_test();
+    let errors = sender.add_outputs(EventStatus::Delivered, DECODE_ERRORS_OUTPUT.to_owned());
+    let logs = sender.add_outputs(EventStatus::Delivered, "logs".to_owned());
+
+    let decode_error = || {
+        let mut log = LogEvent::from("invalid");
+        log.metadata_mut().set_decode_error();
+        Event::Log(log)
+    };
+
+    sender
+        .send_batch(vec![Event::Log(LogEvent::from("valid")), decode_error()])
+        .await
+        .expect("Send should not fail");
+    sender
+        .send_event(decode_error())
+        .await
+        .expect("Send should not fail");
+    sender
+        .send_event_stream(futures::stream::iter([
+            decode_error(),
+            Event::Log(LogEvent::from("valid")),
+        ]))
+        .await
+        .expect("Send should not fail");
+    sender
+        .send_batch_named(
+            "logs",
+            vec![decode_error(), Event::Log(LogEvent::from("named"))],
+        )
+        .await
+        .expect("Send should not fail");
+    drop(sender);
+
+    let messages = |events: Vec<Event>| {
+        events
+            .into_iter()
+            .map(|event| event.into_log()["message"].to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        messages(recv.collect().await),
+        vec!["valid".to_owned(), "valid".to_owned()]
+    );
+    assert_eq!(
+        messages(logs.flat_map(into_event_stream).collect().await),
+        vec!["named".to_owned()]
+    );
+    assert_eq!(
+        messages(errors.flat_map(into_event_stream).collect().await),
+        vec!["invalid".to_owned(); 4]
+    );
+}
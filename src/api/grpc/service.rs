@@ -10,23 +10,28 @@ use std::time::Duration;
 use futures::{StreamExt as FuturesStreamExt, stream};
 use rand::{RngExt, SeedableRng as _, rngs::SmallRng};
 use tokio::select;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, oneshot};
 use tokio::time::{self, interval};
 use tokio_stream::{
     Stream,
     wrappers::{IntervalStream, ReceiverStream},
 };
 use tonic::{Request, Response, Status};
-use vector_lib::tap::{
-    controller::{TapController, TapPatterns, TapPayload},
-    topology::WatchRx,
+use vector_lib::{
+    sensitive_string::SensitiveString,
+    tap::{
+        controller::{TapController, TapPatterns, TapPayload},
+        topology::WatchRx,
+    },
 };
 
+use crate::config::ComponentKey;
 use crate::event::{Metric, MetricValue};
 use crate::metrics::Controller;
 use crate::proto::observability::{
     self, Component as ProtoComponent, ComponentType, EventNotification, TappedEvent, *,
 };
+use crate::topology::{self, ControlCommand, ControlRequest, ControlSender};
 
 type BoxStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send>>;
 
@@ -393,14 +398,110 @@ fn ports_to_proto_outputs(
         .collect()
 }
 
+/// Validates the `component_id` of a control request.
+fn component_key(component_id: String) -> Result<ComponentKey, Status> {
+    if component_id.is_empty() {
+        return Err(Status::invalid_argument("component_id must be specified"));
+    }
+    Ok(component_id.into())
+}
+
+fn component_ids(keys: Vec<ComponentKey>) -> Vec<String> {
+    keys.into_iter().map(ComponentKey::into_id).collect()
+}
+
+/// Converts the result of a control command into its proto representation.
+fn control_response(result: topology::ControlResult) -> ControlResponse {
+    let outcome = match result {
+        Ok(changes) => control_response::Outcome::Applied(ComponentChanges {
+            added: component_ids(changes.added),
+            changed: component_ids(changes.changed),
+            removed: component_ids(changes.removed),
+        }),
+        Err(error) => {
+            let message = error.to_string();
+            let (kind, messages) = match error {
+                topology::ControlError::InvalidConfig { errors } => {
+                    (ControlErrorKind::InvalidConfig, errors)
+                }
+                topology::ControlError::RolledBack => (ControlErrorKind::RolledBack, Vec::new()),
+                topology::ControlError::Fatal { .. } => (ControlErrorKind::Fatal, Vec::new()),
+                topology::ControlError::SourceNotFound { .. }
+                | topology::ControlError::SinkNotFound { .. } => {
+                    (ControlErrorKind::ComponentNotFound, Vec::new())
+                }
+                topology::ControlError::SourceAlreadyPaused { .. }
+                | topology::ControlError::SourceNotPaused { .. } => {
+                    (ControlErrorKind::InvalidState, Vec::new())
+                }
+            };
+            control_response::Outcome::Error(ControlError {
+                kind: kind as i32,
+                message,
+                messages,
+            })
+        }
+    };
+    ControlResponse {
+        outcome: Some(outcome),
+    }
+}
+
 /// gRPC observability service implementation.
 pub struct ObservabilityService {
     watch_rx: WatchRx,
+    control_tx: ControlSender,
+    control_token: Option<SensitiveString>,
 }
 
 impl ObservabilityService {
-    pub const fn new(watch_rx: WatchRx) -> Self {
-        Self { watch_rx }
+    pub const fn new(
+        watch_rx: WatchRx,
+        control_tx: ControlSender,
+        control_token: Option<SensitiveString>,
+    ) -> Self {
+        Self {
+            watch_rx,
+            control_tx,
+            control_token,
+        }
+    }
+
+    /// Checks that a control request carries the configured control token.
+    fn authorize<T>(&self, request: &Request<T>) -> Result<(), Status> {
+        let Some(token) = &self.control_token else {
+            return Err(Status::permission_denied(
+                "Control RPCs are disabled. Set `api.control_token` to enable them.",
+            ));
+        };
+        let provided = request
+            .metadata()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        // The comparison takes the same time wherever the tokens differ, so that the token can't be
+        // guessed from response times.
+        if provided.is_some_and(|provided| {
+            provided.len() == token.inner().len()
+                && openssl::memcmp::eq(provided.as_bytes(), token.inner().as_bytes())
+        }) {
+            Ok(())
+        } else {
+            Err(Status::unauthenticated("Missing or invalid control token."))
+        }
+    }
+
+    /// Submits a control command to the application and waits for its result.
+    async fn control(&self, command: ControlCommand) -> Result<Response<ControlResponse>, Status> {
+        let (reply, result) = oneshot::channel();
+        self.control_tx
+            .send(ControlRequest { command, reply })
+            .await
+            .map_err(|_| Status::unavailable("Vector is shutting down."))?;
+        let result = result
+            .await
+            .map_err(|_| Status::unavailable("Vector is shutting down."))?;
+        Ok(Response::new(control_response(result)))
     }
 }
 
@@ -704,6 +805,52 @@ impl observability::Service for ObservabilityService {
 
         Ok(Response::new(Box::pin(stream)))
     }
+
+    // ========== Topology Control ==========
+
+    async fn reload_config(
+        &self,
+        request: Request<ReloadConfigRequest>,
+    ) -> Result<Response<ControlResponse>, Status> {
+        self.authorize(&request)?;
+        self.control(ControlCommand::Reload).await
+    }
+
+    async fn pause_source(
+        &self,
+        request: Request<PauseSourceRequest>,
+    ) -> Result<Response<ControlResponse>, Status> {
+        self.authorize(&request)?;
+        let key = component_key(request.into_inner().component_id)?;
+        self.control(ControlCommand::PauseSource(key)).await
+    }
+
+    async fn resume_source(
+        &self,
+        request: Request<ResumeSourceRequest>,
+    ) -> Result<Response<ControlResponse>, Status> {
+        self.authorize(&request)?;
+        let key = component_key(request.into_inner().component_id)?;
+        self.control(ControlCommand::ResumeSource(key)).await
+    }
+
+    async fn drain_sink(
+        &self,
+        request: Request<DrainSinkRequest>,
+    ) -> Result<Response<ControlResponse>, Status> {
+        self.authorize(&request)?;
+        let key = component_key(request.into_inner().component_id)?;
+        self.control(ControlCommand::DrainSink(key)).await
+    }
+
+    async fn shutdown_sink(
+        &self,
+        request: Request<ShutdownSinkRequest>,
+    ) -> Result<Response<ControlResponse>, Status> {
+        self.authorize(&request)?;
+        let key = component_key(request.into_inner().component_id)?;
+        self.control(ControlCommand::ShutdownSink(key)).await
+    }
 }
 
 /// Reservoir sampler for tap events, batched and flushed on an interval.
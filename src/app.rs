@@ -15,7 +15,7 @@ use exitcode::ExitCode;
 use futures::StreamExt;
 use tokio::{
     runtime::{self, Handle, Runtime},
-    sync::{MutexGuard, broadcast::error::RecvError},
+    sync::{MutexGuard, broadcast::error::RecvError, mpsc},
 };
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
@@ -33,8 +33,9 @@ use crate::{
     },
     signal::{SignalHandler, SignalPair, SignalRx, SignalTo},
     topology::{
-        ReloadOutcome, RunningTopology, SharedTopologyController, ShutdownErrorReceiver,
-        TopologyController,
+        CONTROL_CHANNEL_CAPACITY, ComponentChanges, ControlCommand, ControlError, ControlReceiver,
+        ControlRequest, ReloadOutcome, RunningTopology, SharedTopologyController,
+        ShutdownErrorReceiver, TopologyController,
     },
     trace,
 };
@@ -100,7 +101,7 @@ impl ApplicationConfig {
         extra_context: ExtraContext,
     ) -> Result<Self, ExitCode> {
         #[cfg(feature = "api")]
-        let api = config.api;
+        let api = config.api.clone();
 
         let (topology, graceful_crash_receiver) =
             RunningTopology::start_init_validated(config, extra_context.clone())
@@ -134,12 +135,17 @@ impl ApplicationConfig {
 
     /// Configure the gRPC API server, if applicable
     #[cfg(feature = "api")]
-    pub fn setup_api(&self, handle: &Handle) -> Option<api::GrpcServer> {
+    pub fn setup_api(
+        &self,
+        handle: &Handle,
+        control_tx: crate::topology::ControlSender,
+    ) -> Option<api::GrpcServer> {
         if self.api.enabled {
             // Start gRPC server
             let api_server = handle.block_on(api::GrpcServer::start(
                 self.topology.config(),
                 self.topology.watch(),
+                control_tx,
             ));
             match api_server {
                 Ok(server) => {
@@ -283,8 +289,10 @@ impl Application {
             signals,
         } = self;
 
+        let (control_tx, control_rx) = mpsc::channel(CONTROL_CHANNEL_CAPACITY);
+
         #[cfg(feature = "api")]
-        let api_server = config.setup_api(handle);
+        let api_server = config.setup_api(handle, control_tx.clone());
 
         let topology_controller = SharedTopologyController::new(TopologyController {
             #[cfg(feature = "api")]
@@ -292,6 +300,7 @@ impl Application {
             topology: config.topology,
             config_paths: config.config_paths.clone(),
             require_healthy: root_opts.require_healthy,
+            control_tx,
             extra_context: config.extra_context,
         });
 
@@ -301,6 +310,7 @@ impl Application {
             graceful_crash_receiver: config.graceful_crash_receiver,
             signals,
             topology_controller,
+            control_rx,
             allow_empty_config: root_opts.allow_empty_config,
         })
     }
@@ -312,6 +322,7 @@ pub struct StartedApplication {
     pub graceful_crash_receiver: ShutdownErrorReceiver,
     pub signals: SignalPair,
     pub topology_controller: SharedTopologyController,
+    pub control_rx: ControlReceiver,
     pub allow_empty_config: bool,
 }
 
@@ -326,6 +337,7 @@ impl StartedApplication {
             graceful_crash_receiver,
             signals,
             topology_controller,
+            mut control_rx,
             internal_topologies,
             allow_empty_config,
         } = self;
@@ -347,6 +359,15 @@ impl StartedApplication {
                 ).await {
                     break signal;
                 },
+                Some(request) = control_rx.recv() => if let Some(signal) = handle_control_request(
+                    request,
+                    &topology_controller,
+                    &config_paths,
+                    &mut signal_handler,
+                    allow_empty_config,
+                ).await {
+                    break signal;
+                },
                 // Trigger graceful shutdown if a component crashed, or all sources have ended.
                 error = graceful_crash.next() => break SignalTo::Shutdown(error),
                 _ = TopologyController::sources_finished(topology_controller.clone()), if has_sources => {
@@ -401,36 +422,14 @@ async fn handle_signal(
         }
         Ok(SignalTo::ReloadFromDisk) => {
             let mut topology_controller = topology_controller.lock().await;
-
-            // Reload paths
-            if let Some(paths) = config::process_paths(config_paths) {
-                topology_controller.config_paths = paths;
-            }
-
-            // Reload config
-            let new_config = config::load_from_paths_with_provider_and_secrets(
-                &topology_controller.config_paths,
+            let new_config = load_config_from_disk(
+                &mut topology_controller,
+                config_paths,
                 signal_handler,
                 allow_empty_config,
             )
             .await;
 
-            if let Ok(ref config) = new_config {
-                // Find all transforms that have external files to watch
-                let transform_keys_to_reload = config.transform_keys_with_external_files();
-
-                // Add these transforms to reload set
-                if !transform_keys_to_reload.is_empty() {
-                    info!(
-                        message = "Reloading transforms with external files.",
-                        count = transform_keys_to_reload.len()
-                    );
-                    topology_controller
-                        .topology
-                        .extend_reload_set(transform_keys_to_reload);
-                }
-            }
-
             reload_config_from_result(topology_controller, new_config).await
         }
         Ok(SignalTo::ReloadEnrichmentTables) => {
@@ -451,6 +450,96 @@ async fn handle_signal(
     }
 }
 
+/// Loads the configuration from disk for a reload, adding any transforms with external files to
+/// the reload set.
+async fn load_config_from_disk(
+    topology_controller: &mut TopologyController,
+    config_paths: &[ConfigPath],
+    signal_handler: &mut SignalHandler,
+    allow_empty_config: bool,
+) -> Result<Config, Vec<String>> {
+    // Reload paths
+    if let Some(paths) = config::process_paths(config_paths) {
+        topology_controller.config_paths = paths;
+    }
+
+    // Reload config
+    let new_config = config::load_from_paths_with_provider_and_secrets(
+        &topology_controller.config_paths,
+        signal_handler,
+        allow_empty_config,
+    )
+    .await;
+
+    if let Ok(ref config) = new_config {
+        // Find all transforms that have external files to watch
+        let transform_keys_to_reload = config.transform_keys_with_external_files();
+
+        // Add these transforms to reload set
+        if !transform_keys_to_reload.is_empty() {
+            info!(
+                message = "Reloading transforms with external files.",
+                count = transform_keys_to_reload.len()
+            );
+            topology_controller
+                .topology
+                .extend_reload_set(transform_keys_to_reload);
+        }
+    }
+
+    new_config
+}
+
+async fn handle_control_request(
+    request: ControlRequest,
+    topology_controller: &SharedTopologyController,
+    config_paths: &[ConfigPath],
+    signal_handler: &mut SignalHandler,
+    allow_empty_config: bool,
+) -> Option<SignalTo> {
+    let ControlRequest { command, reply } = request;
+    info!(message = "Handling topology control request.", ?command);
+
+    let mut topology_controller = topology_controller.lock().await;
+    let result = match command {
+        ControlCommand::Reload => {
+            match load_config_from_disk(
+                &mut topology_controller,
+                config_paths,
+                signal_handler,
+                allow_empty_config,
+            )
+            .await
+            {
+                Ok(new_config) => topology_controller.reload_with_changes(new_config).await,
+                Err(errors) => {
+                    handle_config_errors(errors.clone());
+                    emit!(VectorConfigLoadError);
+                    Err(ControlError::InvalidConfig { errors })
+                }
+            }
+        }
+        ControlCommand::PauseSource(key) => topology_controller
+            .topology
+            .pause_source(&key)
+            .map(|()| ComponentChanges::default()),
+        ControlCommand::ResumeSource(key) => topology_controller
+            .topology
+            .resume_source(&key)
+            .map(|()| ComponentChanges::default()),
+        ControlCommand::DrainSink(key) => topology_controller.remove_sink(&key, true).await,
+        ControlCommand::ShutdownSink(key) => topology_controller.remove_sink(&key, false).await,
+    };
+
+    let signal = match &result {
+        Err(ControlError::Fatal { error }) => Some(SignalTo::Shutdown(Some(error.clone()))),
+        _ => None,
+    };
+    // The client may have gone away in the meantime, in which case there is nobody to tell.
+    _ = reply.send(result);
+    signal
+}
+
 async fn reload_config_from_result(
     mut topology_controller: MutexGuard<'_, TopologyController>,
     config: Result<Config, Vec<String>>,
@@ -0,0 +1,556 @@
+#![allow(missing_docs)]
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use clap::Parser;
+use futures::stream;
+use vector_lib::{
+    buffers::{BufferInspector, config::DiskBufferEncryption},
+    event::{BatchNotifier, BatchStatus, EventArray, EventContainer, EventRef},
+    finalization::AddBatchNotifier,
+};
+
+use crate::{
+    config::{self, ComponentKey, ProxyConfig, SinkContext},
+    signal,
+};
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+pub struct Opts {
+    #[command(subcommand)]
+    sub_command: SubCommand,
+}
+
+impl Opts {
+    pub const fn dangerously_allow_env_var_interpolation(&self) -> bool {
+        match &self.sub_command {
+            SubCommand::Replay(opts) => opts.dangerously_allow_env_var_interpolation,
+            SubCommand::Inspect(_) | SubCommand::Dump(_) => false,
+        }
+    }
+}
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+enum SubCommand {
+    /// Report the number of unread records and events in a disk buffer, their total size, and
+    /// the timestamps of the oldest and newest events.
+    Inspect(BufferOpts),
+
+    /// Write the unread events in a disk buffer as native JSON, one event per line.
+    Dump(DumpOpts),
+
+    /// Send the unread events in a disk buffer to a sink.
+    ///
+    /// The replayed events are left in the buffer, so a Vector process started with the data
+    /// directory of the buffer sends them again. Move the buffer directory out of the data
+    /// directory once the replay has succeeded to avoid that.
+    Replay(ReplayOpts),
+}
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+struct BufferOpts {
+    /// The disk buffer directory, found at `<data_dir>/buffer/v2/<sink id>`.
+    ///
+    /// The buffer must not be in use by a running Vector process. It is only read from, so its
+    /// contents are left as they were.
+    path: PathBuf,
+
+    /// The key the buffer is encrypted with, encoded as base64.
+    #[arg(long, env = "VECTOR_BUFFER_ENCRYPTION_KEY")]
+    encryption_key: Option<String>,
+
+    /// Keys that were previously used to encrypt the buffer, encoded as base64.
+    #[arg(
+        long,
+        env = "VECTOR_BUFFER_PREVIOUS_ENCRYPTION_KEYS",
+        value_delimiter(',')
+    )]
+    previous_encryption_keys: Vec<String>,
+}
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+struct DumpOpts {
+    #[command(flatten)]
+    buffer: BufferOpts,
+
+    /// Write the events to this file instead of stdout.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug)]
+#[command(rename_all = "kebab-case")]
+struct ReplayOpts {
+    #[command(flatten)]
+    buffer: BufferOpts,
+
+    /// Read configuration from one or more files. Wildcard paths are supported.
+    /// File format is detected from the file name.
+    #[arg(id = "config", short, long, required = true, value_delimiter(','))]
+    paths: Vec<PathBuf>,
+
+    /// The ID of the sink to replay the events into.
+    ///
+    /// Defaults to the name of the buffer directory, which is the ID of the sink the buffer
+    /// belonged to.
+    #[arg(long)]
+    sink: Option<String>,
+
+    /// Allow interpolation of environment variables in configuration files. Enabling this may
+    /// expose environment secrets into your Vector configuration.
+    #[arg(
+        long,
+        env = "VECTOR_DANGEROUSLY_ALLOW_ENV_VAR_INTERPOLATION",
+        default_value = "false"
+    )]
+    dangerously_allow_env_var_interpolation: bool,
+}
+
+impl BufferOpts {
+    async fn open(&self) -> Result<BufferInspector<EventArray>, String> {
+        let cipher = self
+            .encryption_key
+            .as_ref()
+            .map(|key| {
+                DiskBufferEncryption {
+                    key: key.clone().into(),
+                    previous_keys: self
+                        .previous_encryption_keys
+                        .iter()
+                        .cloned()
+                        .map(Into::into)
+                        .collect(),
+                }
+                .build_cipher()
+            })
+            .transpose()
+            .map_err(|error| error.to_string())?;
+
+        BufferInspector::open(&self.path, cipher)
+            .await
+            .map_err(|error| format!("Failed to open buffer {:?}: {error}", self.path))
+    }
+}
+
+pub async fn cmd(opts: &Opts, signal_handler: &mut signal::SignalHandler) -> exitcode::ExitCode {
+    let result = match &opts.sub_command {
+        SubCommand::Inspect(opts) => inspect(opts).await,
+        SubCommand::Dump(opts) => dump(opts).await,
+        SubCommand::Replay(opts) => replay(opts, signal_handler).await,
+    };
+
+    match result {
+        Ok(()) => exitcode::OK,
+        Err((code, error)) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("{error}");
+            }
+            code
+        }
+    }
+}
+
+/// Reads every unread event array from the buffer, reporting and skipping any that can't be read.
+async fn for_each_record(
+    inspector: &mut BufferInspector<EventArray>,
+    mut f: impl FnMut(EventArray, u64, Option<DateTime<Utc>>) -> io::Result<()>,
+) -> io::Result<()> {
+    loop {
+        match inspector.next().await {
+            Ok(Some(inspected)) => {
+                let written_at = inspected
+                    .written_at
+                    .and_then(|millis| i64::try_from(millis).ok())
+                    .and_then(DateTime::from_timestamp_millis);
+                f(inspected.record, inspected.record_bytes, written_at)?;
+            }
+            Ok(None) => return Ok(()),
+            Err(error) => {
+                error!(message = "Failed to read record; skipping the rest of the data file.", %error);
+            }
+        }
+    }
+}
+
+fn event_timestamp(event: EventRef<'_>) -> Option<DateTime<Utc>> {
+    match event {
+        EventRef::Log(log) => log
+            .get_timestamp()
+            .and_then(|value| value.as_timestamp())
+            .copied(),
+        EventRef::Metric(metric) => metric.timestamp(),
+        EventRef::Trace(trace) => trace
+            .as_ref()
+            .get_timestamp()
+            .and_then(|value| value.as_timestamp())
+            .copied(),
+    }
+}
+
+async fn inspect(opts: &BufferOpts) -> Result<(), (exitcode::ExitCode, String)> {
+    let mut inspector = opts
+        .open()
+        .await
+        .map_err(|error| (exitcode::IOERR, error))?;
+    let unread_events = inspector.unread_event_count();
+
+    let mut records = 0_u64;
+    let mut events = 0_u64;
+    let mut bytes = 0_u64;
+    let mut oldest: Option<DateTime<Utc>> = None;
+    let mut newest: Option<DateTime<Utc>> = None;
+    for_each_record(&mut inspector, |array, record_bytes, written_at| {
+        records += 1;
+        bytes += record_bytes;
+        for event in array.iter_events() {
+            events += 1;
+            if let Some(timestamp) = event_timestamp(event).or(written_at) {
+                oldest = Some(oldest.map_or(timestamp, |oldest| oldest.min(timestamp)));
+                newest = Some(newest.map_or(timestamp, |newest| newest.max(timestamp)));
+            }
+        }
+        Ok(())
+    })
+    .await
+    .map_err(|error| (exitcode::IOERR, error.to_string()))?;
+
+    let format_timestamp = |timestamp: Option<DateTime<Utc>>| {
+        timestamp.map_or_else(|| "-".to_string(), |timestamp| timestamp.to_rfc3339())
+    };
+    #[allow(clippy::print_stdout)]
+    {
+        println!("Buffer: {}", opts.path.display());
+        println!("Unacknowledged events: {unread_events}");
+        println!("Records: {records}");
+        println!("Events: {events}");
+        println!("Bytes: {bytes}");
+        println!("Oldest event: {}", format_timestamp(oldest));
+        println!("Newest event: {}", format_timestamp(newest));
+    }
+
+    Ok(())
+}
+
+async fn dump(opts: &DumpOpts) -> Result<(), (exitcode::ExitCode, String)> {
+    let mut inspector = opts
+        .buffer
+        .open()
+        .await
+        .map_err(|error| (exitcode::IOERR, error))?;
+
+    let output: Box<dyn Write> = match &opts.output {
+        Some(path) => Box::new(File::create(path).map_err(|error| {
+            (
+                exitcode::CANTCREAT,
+                format!("Failed to create {path:?}: {error}"),
+            )
+        })?),
+        None => Box::new(io::stdout().lock()),
+    };
+    let mut output = BufWriter::new(output);
+
+    let result = for_each_record(&mut inspector, |array, _, _| {
+        for event in array.into_events() {
+            serde_json::to_writer(&mut output, &event)?;
+            output.write_all(b"\n")?;
+        }
+        Ok(())
+    })
+    .await;
+
+    result
+        .and_then(|()| output.flush())
+        .map_err(|error| (exitcode::IOERR, error.to_string()))
+}
+
+/// Gets the ID of the sink a buffer directory belonged to.
+fn buffer_sink_id(path: &Path) -> Option<String> {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map(ToString::to_string)
+}
+
+async fn replay(
+    opts: &ReplayOpts,
+    signal_handler: &mut signal::SignalHandler,
+) -> Result<(), (exitcode::ExitCode, String)> {
+    let paths = opts
+        .paths
+        .iter()
+        .map(|path| config::ConfigPath::File(path.clone(), None))
+        .collect::<Vec<_>>();
+    let paths = config::process_paths(&paths).ok_or_else(|| {
+        (
+            exitcode::CONFIG,
+            "Failed to read configuration.".to_string(),
+        )
+    })?;
+    let config = config::load_from_paths_with_provider_and_secrets(&paths, signal_handler, false)
+        .await
+        .map_err(|errors| (exitcode::CONFIG, errors.join("\n")))?;
+
+    let sink_id = opts
+        .sink
+        .clone()
+        .or_else(|| buffer_sink_id(&opts.buffer.path))
+        .ok_or_else(|| {
+            (
+                exitcode::USAGE,
+                "A sink ID must be given with --sink.".to_string(),
+            )
+        })?;
+    let key = ComponentKey::from(sink_id);
+    let sink = config.sink(&key).ok_or_else(|| {
+        (
+            exitcode::CONFIG,
+            format!("Sink \"{key}\" is not configured."),
+        )
+    })?;
+
+    let cx = SinkContext {
+        healthcheck: sink.healthcheck(),
+        globals: config.global.clone(),
+        proxy: ProxyConfig::merge_with_env(&config.global.proxy, sink.proxy()),
+        schema: config.schema,
+        ..SinkContext::default()
+    };
+    let (sink, _healthcheck) = sink
+        .build(cx)
+        .await
+        .map_err(|error| (exitcode::CONFIG, format!("Sink \"{key}\": {error}")))?;
+
+    let mut inspector = opts
+        .buffer
+        .open()
+        .await
+        .map_err(|error| (exitcode::IOERR, error))?;
+
+    // A single batch notifier is shared by every event, so that we only hear back once all of them
+    // have been processed by the sink.
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let mut events = 0_u64;
+    let input = stream::unfold(
+        (&mut inspector, batch, &mut events),
+        |(inspector, batch, events)| async move {
+            loop {
+                match inspector.next().await {
+                    Ok(Some(inspected)) => {
+                        let mut array = inspected.record;
+                        array.add_batch_notifier(batch.clone());
+                        *events += array.len() as u64;
+                        return Some((array, (inspector, batch, events)));
+                    }
+                    Ok(None) => return None,
+                    Err(error) => {
+                        error!(message = "Failed to read record; skipping the rest of the data file.", %error);
+                    }
+                }
+            }
+        },
+    );
+
+    sink.run(input).await.map_err(|()| {
+        (
+            exitcode::SOFTWARE,
+            "Sink failed while replaying events.".to_string(),
+        )
+    })?;
+
+    match receiver.await {
+        BatchStatus::Delivered => {
+            #[allow(clippy::print_stdout)]
+            {
+                println!("Replayed {events} events into sink \"{key}\".");
+                println!(
+                    "The events are still in the buffer, move it out of the data directory to keep \
+                    Vector from sending them again."
+                );
+            }
+            Ok(())
+        }
+        BatchStatus::Errored | BatchStatus::Rejected => Err((
+            exitcode::UNAVAILABLE,
+            format!("Sink \"{key}\" failed to deliver some of the {events} replayed events."),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU64, time::Duration};
+
+    use vector_lib::{
+        buffers::{BufferConfig, BufferType, DiskBufferCompression, WhenFull},
+        event::{Event, LogEvent},
+    };
+
+    use super::*;
+    use crate::test_util::temp_dir;
+
+    /// Writes a log event for each message to the disk buffer of the sink `out` in `data_dir`,
+    /// returning the options to open the buffer with.
+    async fn write_buffer(data_dir: &Path, messages: &[&str]) -> BufferOpts {
+        let buffer = BufferConfig::Single(BufferType::DiskV2 {
+            max_size: NonZeroU64::new(268435488).unwrap(),
+            when_full: WhenFull::Block,
+            compression: DiskBufferCompression::None,
+            encryption: None,
+            max_age: None,
+        });
+        let (mut sender, receiver) = buffer
+            .build::<EventArray>(
+                Some(data_dir.to_path_buf()),
+                "out".to_string(),
+                tracing::Span::none(),
+            )
+            .await
+            .unwrap();
+        for message in messages {
+            let event = Event::Log(LogEvent::from(*message));
+            sender.send(event.into(), None).await.unwrap();
+        }
+        sender.flush().await.unwrap();
+        drop((sender, receiver));
+
+        let opts = BufferOpts {
+            path: data_dir.join("buffer").join("v2").join("out"),
+            encryption_key: None,
+            previous_encryption_keys: Vec::new(),
+        };
+
+        // The buffer is released once its background tasks notice that it was dropped.
+        for _ in 0..100 {
+            if opts.open().await.is_ok() {
+                return opts;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("The buffer was not released.");
+    }
+
+    /// Reads the messages of the unread events in a buffer.
+    async fn read_messages(opts: &BufferOpts) -> Vec<String> {
+        let mut inspector = opts.open().await.unwrap();
+        let mut messages = Vec::new();
+        for_each_record(&mut inspector, |array, _, _| {
+            messages.extend(array.into_events().map(|event| {
+                event
+                    .into_log()
+                    .get_message()
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned()
+            }));
+            Ok(())
+        })
+        .await
+        .unwrap();
+        messages
+    }
+
+    #[test]
+    fn buffer_sink_id_is_the_directory_name() {
+        assert_eq!(
+            Some("out".to_string()),
+            buffer_sink_id(Path::new("/var/lib/vector/buffer/v2/out"))
+        );
+    }
+
+    #[test]
+    fn parses_previous_encryption_keys() {
+        let opts = Opts::try_parse_from([
+            "buffer",
+            "dump",
+            "/var/lib/vector/buffer/v2/out",
+            "--previous-encryption-keys",
+            "a2V5MQ==,a2V5Mg==",
+        ])
+        .unwrap();
+        let SubCommand::Dump(opts) = opts.sub_command else {
+            panic!("Expected the dump subcommand");
+        };
+        assert_eq!(
+            vec!["a2V5MQ==".to_string(), "a2V5Mg==".to_string()],
+            opts.buffer.previous_encryption_keys
+        );
+    }
+
+    #[tokio::test]
+    async fn dump_writes_unread_events() {
+        let data_dir = temp_dir();
+        let buffer = write_buffer(&data_dir, &["foo", "bar"]).await;
+        let output = data_dir.join("dump.json");
+
+        dump(&DumpOpts {
+            buffer,
+            output: Some(output.clone()),
+        })
+        .await
+        .unwrap();
+
+        let messages = std::fs::read_to_string(output)
+            .unwrap()
+            .lines()
+            .map(|line| {
+                let event: Event = serde_json::from_str(line).unwrap();
+                event
+                    .into_log()
+                    .get_message()
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["foo", "bar"], messages);
+    }
+
+    #[cfg(all(feature = "sources-stdin", feature = "sinks-file"))]
+    #[tokio::test]
+    async fn replay_leaves_events_in_the_buffer() {
+        let data_dir = temp_dir();
+        let buffer = write_buffer(&data_dir, &["foo", "bar"]).await;
+
+        let output = data_dir.join("out.log");
+        let config = data_dir.join("vector.yaml");
+        std::fs::write(
+            &config,
+            format!(
+                indoc::indoc! {r#"
+                    sources:
+                      in:
+                        type: stdin
+                    sinks:
+                      out:
+                        type: file
+                        inputs: [in]
+                        path: {}
+                        encoding:
+                          codec: text
+                "#},
+                output.display()
+            ),
+        )
+        .unwrap();
+
+        let opts = ReplayOpts {
+            buffer,
+            paths: vec![config],
+            sink: None,
+            dangerously_allow_env_var_interpolation: false,
+        };
+        let (mut signal_handler, _signal_rx) = signal::SignalHandler::new();
+        replay(&opts, &mut signal_handler).await.unwrap();
+
+        assert_eq!("foo\nbar\n", std::fs::read_to_string(output).unwrap());
+        assert_eq!(vec!["foo", "bar"], read_messages(&opts.buffer).await);
+    }
+}
@@ -15,8 +15,8 @@ use crate::tap;
 use crate::top;
 
 use crate::{
-    completion, config, convert_config, generate, generate_schema, get_version, graph, list,
-    signal, unit_test, validate,
+    buffer, completion, config, convert_config, generate, generate_schema, get_version, graph,
+    list, signal, unit_test, validate,
 };
 
 #[derive(Parser, Debug)]
@@ -43,7 +43,8 @@ impl Opts {
             | Some(SubCommand::Generate(_))
             | Some(SubCommand::ConvertConfig(_))
             | Some(SubCommand::List(_))
-            | Some(SubCommand::Test(_)) => {
+            | Some(SubCommand::Test(_))
+            | Some(SubCommand::Buffer(_)) => {
                 if self.root.verbose == 0 {
                     (self.root.quiet + 1, self.root.verbose)
                 } else {
@@ -473,6 +474,9 @@ pub enum SubCommand {
 
     /// Vector Remap Language CLI
     Vrl(vrl::cli::Opts),
+
+    /// Inspect, dump, or replay the contents of a disk buffer that is not in use.
+    Buffer(buffer::Opts),
 }
 
 impl SubCommand {
@@ -485,6 +489,7 @@ impl SubCommand {
             Self::Graph(g) => g.dangerously_allow_env_var_interpolation,
             Self::Test(t) => t.dangerously_allow_env_var_interpolation,
             Self::Validate(v) => v.dangerously_allow_env_var_interpolation,
+            Self::Buffer(b) => b.dangerously_allow_env_var_interpolation(),
             #[cfg(windows)]
             Self::Service(s) => s.dangerously_allow_env_var_interpolation(),
             _ => false,
@@ -497,6 +502,7 @@ impl SubCommand {
         color: bool,
     ) -> exitcode::ExitCode {
         match self {
+            Self::Buffer(b) => buffer::cmd(b, &mut signals.handler).await,
             Self::Completion(s) => completion::cmd(s),
             Self::ConvertConfig(opts) => convert_config::cmd(opts),
             Self::Generate(g) => generate::cmd(g),
@@ -1,7 +1,7 @@
 mod event;
 mod http;
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use tokio::sync::{Mutex, mpsc};
 use vector_lib::{
@@ -9,12 +9,14 @@ use vector_lib::{
         BytesEncoder, Decoder, DecodingConfig, Encoder, EncodingConfig, EncodingConfigWithFraming,
         decoding::{self, DeserializerConfig},
         encoding::{
-            self, Framer, FramingConfig, JsonSerializerConfig, SerializerConfig,
+            self, CefSerializerConfig, CefSerializerOptions, CsvSerializerConfig,
+            CsvSerializerOptions, Framer, FramingConfig, JsonSerializerConfig, SerializerConfig,
             TextSerializerConfig,
         },
     },
     config::{DataType, LogNamespace},
     event::Event,
+    lookup::{OwnedTargetPath, owned_value_path},
 };
 
 use self::http::HttpResourceOutputContext;
@@ -157,6 +159,7 @@ fn deserializer_config_to_serializer(config: &DeserializerConfig) -> encoding::S
                     desc_file: config.protobuf.desc_file.clone(),
                     message_type: config.protobuf.message_type.clone(),
                     use_json_names: config.protobuf.use_json_names,
+                    schema_registry: None,
                 },
             })
         }
@@ -171,6 +174,40 @@ fn deserializer_config_to_serializer(config: &DeserializerConfig) -> encoding::S
         DeserializerConfig::Avro { avro } => SerializerConfig::Avro { avro: avro.into() },
         // TODO: Influxdb has no serializer yet
         DeserializerConfig::Influxdb { .. } => todo!(),
+        // The serializer can't write the header row that the deserializer would read the column
+        // names from, so only deserializers that are given the columns are supported.
+        DeserializerConfig::Csv(config) if config.has_header_row() => {
+            unimplemented!("CSV decoding is only supported with `columns` set")
+        }
+        DeserializerConfig::Csv(config) => {
+            SerializerConfig::Csv(CsvSerializerConfig::new(CsvSerializerOptions {
+                delimiter: config.csv.delimiter,
+                quote: config.csv.quote,
+                double_quote: config.csv.double_quote,
+                escape: config.csv.escape,
+                fields: config
+                    .csv
+                    .columns
+                    .iter()
+                    .map(|column| OwnedTargetPath::event(owned_value_path!(column.as_str())).into())
+                    .collect(),
+                ..Default::default()
+            }))
+        }
+        DeserializerConfig::Logfmt { .. } => SerializerConfig::Logfmt,
+        DeserializerConfig::Msgpack => SerializerConfig::Msgpack,
+        // The deserializer decodes the header fields into the `cef` object, which is where the
+        // serializer reads them from by default, but the extensions have to be listed, so only
+        // the `message` field is encoded as one.
+        DeserializerConfig::Cef { .. } => {
+            SerializerConfig::Cef(CefSerializerConfig::new(CefSerializerOptions {
+                extensions: HashMap::from([(
+                    "message".to_string(),
+                    OwnedTargetPath::event(owned_value_path!("message")).into(),
+                )]),
+                ..Default::default()
+            }))
+        }
         DeserializerConfig::Vrl { .. } => unimplemented!(),
         #[cfg(feature = "codecs-opentelemetry")]
         DeserializerConfig::Otlp { .. } => SerializerConfig::Otlp,
@@ -219,11 +256,12 @@ fn serializer_config_to_deserializer(
 ) -> vector_lib::Result<decoding::Deserializer> {
     let deserializer_config = match config {
         SerializerConfig::Avro { .. } => todo!(),
-        SerializerConfig::Cef { .. } => todo!(),
+        SerializerConfig::Cef { .. } => DeserializerConfig::Cef(Default::default()),
         SerializerConfig::Csv { .. } => todo!(),
         SerializerConfig::Gelf { .. } => DeserializerConfig::Gelf(Default::default()),
         SerializerConfig::Json(_) => DeserializerConfig::Json(Default::default()),
-        SerializerConfig::Logfmt => todo!(),
+        SerializerConfig::Logfmt => DeserializerConfig::Logfmt(Default::default()),
+        SerializerConfig::Msgpack => DeserializerConfig::Msgpack,
         SerializerConfig::Native => DeserializerConfig::Native,
         SerializerConfig::NativeJson => DeserializerConfig::NativeJson(Default::default()),
         SerializerConfig::Protobuf(config) => {
@@ -232,6 +270,7 @@ fn serializer_config_to_deserializer(
                     desc_file: config.protobuf.desc_file.clone(),
                     message_type: config.protobuf.message_type.clone(),
                     use_json_names: config.protobuf.use_json_names,
+                    schema_registry: None,
                 },
             })
         }
@@ -1,14 +1,14 @@
 use std::net::{Ipv4Addr, SocketAddr};
 
 use url::Url;
-use vector_lib::configurable::configurable_component;
+use vector_lib::{configurable::configurable_component, sensitive_string::SensitiveString};
 
 /// API options.
 #[configurable_component]
 #[configurable(metadata(
-    docs::warnings = "The API currently does not support authentication. Only enable it in isolated environments or for debugging. It must not be exposed to untrusted clients."
+    docs::warnings = "The read-only parts of the API do not support authentication. Only enable it in isolated environments or for debugging. It must not be exposed to untrusted clients."
 ))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[serde(default, deny_unknown_fields)]
 pub struct Options {
     /// Whether the API is enabled for this Vector instance.
@@ -24,6 +24,16 @@ pub struct Options {
     #[configurable(metadata(docs::examples = "127.0.0.1:1234"))]
     #[configurable(metadata(docs::required = false))]
     pub address: Option<SocketAddr>,
+
+    /// The token required to use the API's topology control RPCs, such as reloading the
+    /// configuration or pausing a source.
+    ///
+    /// Clients must send it in the `authorization` metadata as `Bearer <token>`. If left
+    /// unspecified, the control RPCs are disabled. Changes to this option take effect when the
+    /// API is restarted.
+    #[configurable(metadata(docs::examples = "${VECTOR_API_CONTROL_TOKEN}"))]
+    #[configurable(metadata(docs::required = false))]
+    pub control_token: Option<SensitiveString>,
 }
 
 impl_generate_config_from_default!(Options);
@@ -33,6 +43,7 @@ impl Default for Options {
         Self {
             enabled: default_enabled(),
             address: default_address(),
+            control_token: None,
         }
     }
 }
@@ -74,9 +85,17 @@ impl Options {
             }
         };
 
+        let control_token = match (&self.control_token, other.control_token) {
+            (Some(a), Some(b)) if *a != b => {
+                return Err("Conflicting `api` control token.".to_string());
+            }
+            (a, b) => a.clone().or(b),
+        };
+
         let options = Options {
             address,
             enabled: self.enabled | other.enabled,
+            control_token,
         };
 
         *self = options;
@@ -89,6 +108,7 @@ fn bool_merge() {
     let mut a = Options {
         enabled: true,
         address: None,
+        control_token: None,
     };
 
     a.merge(Options::default()).unwrap();
@@ -98,6 +118,7 @@ fn bool_merge() {
         Options {
             enabled: true,
             address: default_address(),
+            control_token: None,
         }
     );
 }
@@ -108,6 +129,7 @@ fn bind_merge() {
     let mut a = Options {
         enabled: true,
         address: Some(address),
+        control_token: None,
     };
 
     a.merge(Options::default()).unwrap();
@@ -117,6 +139,7 @@ fn bind_merge() {
         Options {
             enabled: true,
             address: Some(address),
+            control_token: None,
         }
     );
 }
@@ -135,3 +158,20 @@ fn bind_conflict() {
 
     assert!(a.merge(b).is_err());
 }
+
+#[test]
+fn control_token_merge() {
+    let mut a = Options {
+        control_token: Some("secret".to_string().into()),
+        ..Options::default()
+    };
+
+    a.merge(Options::default()).unwrap();
+    assert_eq!(a.control_token, Some("secret".to_string().into()));
+
+    let b = Options {
+        control_token: Some("other".to_string().into()),
+        ..Options::default()
+    };
+    assert!(a.merge(b).is_err());
+}
@@ -61,7 +61,7 @@ pub use provider::ProviderConfig;
 pub use secret::SecretBackend;
 pub use sink::{BoxedSink, SinkConfig, SinkContext, SinkHealthcheckOptions, SinkOuter};
 pub use sink_validated::{DynValidatedSink, ValidatedSink};
-pub use source::{BoxedSource, SourceConfig, SourceContext, SourceOuter};
+pub use source::{BoxedSource, SourceConfig, SourceContext, SourceOuter, TestInputDecoder};
 pub use transform::{
     BoxedTransform, TransformConfig, TransformContext, TransformOuter, get_transform_output_ids,
 };
@@ -144,7 +144,7 @@ impl ConfigPath {
     }
 }
 
-#[derive(Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct Config {
     #[cfg(feature = "api")]
     pub api: api::Options,
@@ -193,6 +193,13 @@ impl Config {
         self.sinks.get(id)
     }
 
+    /// Returns a copy of this configuration without the given sink, or `None` if there is no
+    /// such sink.
+    pub fn without_sink(&self, id: &ComponentKey) -> Option<Self> {
+        let mut config = self.clone();
+        config.sinks.shift_remove(id).map(|_| config)
+    }
+
     pub fn enrichment_tables(
         &self,
     ) -> impl Iterator<Item = (&ComponentKey, &EnrichmentTableOuter<OutputId>)> {
@@ -552,11 +559,18 @@ impl TestDefinition<OutputId> {
 #[serde(deny_unknown_fields)]
 pub struct TestInput {
     /// The name of the transform to insert the input event to.
+    ///
+    /// This can also be the name of a source that decodes the bytes it receives, such as `socket`,
+    /// `syslog`, or `http_server`. The `raw` value of the input is then run through the framing
+    /// and decoding of the source, and the decoded events are sent to the components that consume
+    /// the source. Inputs inserted at an `opentelemetry` source are read as OTLP/JSON export
+    /// requests, and the decoded events are sent to the output of the source for their signal.
     pub insert_at: ComponentKey,
 
     /// The type of the input event.
     ///
-    /// Can be either `raw`, `vrl`, `log`, or `metric.
+    /// Can be either `raw`, `vrl`, `log`, `metric`, or `trace`. Inputs inserted at a source must
+    /// be `raw`.
     #[serde(default = "default_test_input_type", rename = "type")]
     pub type_str: String,
 
@@ -580,6 +594,21 @@ pub struct TestInput {
     ///
     /// Only relevant when `type` is `metric`.
     pub metric: Option<Metric>,
+
+    /// The set of fields to use when creating a trace input event.
+    ///
+    /// Only relevant when `type` is `trace`.
+    pub trace_fields: Option<IndexMap<String, Value>>,
+
+    /// The time at which to send the input event, in milliseconds since the start of the test.
+    ///
+    /// When any input of a test sets this, the test runs with a virtual clock that only advances
+    /// when every component is idle, and that time-dependent transforms, such as `reduce`,
+    /// `dedupe`, `throttle`, `window`, and `aggregate`, observe instead of the system clock. Inputs
+    /// without a time are sent at the time of the input before them, and times must not decrease
+    /// from one input to the next.
+    #[configurable(metadata(docs::examples = 30000))]
+    pub at_ms: Option<u64>,
 }
 
 fn default_test_input_type() -> String {
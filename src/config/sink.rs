@@ -343,6 +343,10 @@ pub struct SinkContext {
     pub app_name: String,
     pub app_name_slug: String,
 
+    /// Whether the sink is new to the topology, rather than rebuilt because its configuration
+    /// changed on reload.
+    pub is_new: bool,
+
     /// Extra context data provided by the running app and shared across all components. This can be
     /// used to pass shared settings or other data from outside the components.
     pub extra_context: ExtraContext,
@@ -359,6 +363,7 @@ impl Default for SinkContext {
             schema: Default::default(),
             app_name: crate::get_app_name().to_string(),
             app_name_slug: crate::get_slugified_app_name(),
+            is_new: true,
             extra_context: Default::default(),
         }
     }
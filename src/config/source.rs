@@ -1,7 +1,9 @@
 use std::{cell::RefCell, collections::HashMap, time::Duration};
 
 use async_trait::async_trait;
+use bytes::{Bytes, BytesMut};
 use dyn_clone::DynClone;
+use tokio_util::codec::Decoder as _;
 use vector_config::{Configurable, GenerateError, Metadata, NamedComponent};
 use vector_config_common::{
     attributes::CustomAttribute,
@@ -13,12 +15,13 @@ use vector_lib::{
         AcknowledgementsConfig, GlobalOptions, LogNamespace, SourceAcknowledgementsConfig,
         SourceOutput,
     },
+    event::Event,
     source::Source,
 };
 use vector_vrl_metrics::MetricsStorage;
 
 use super::{ComponentKey, ProxyConfig, Resource, dot_graph::GraphConfig, schema};
-use crate::{SourceSender, extra_context::ExtraContext, shutdown::ShutdownSignal};
+use crate::{SourceSender, codecs::Decoder, extra_context::ExtraContext, shutdown::ShutdownSignal};
 
 pub type BoxedSource = Box<dyn SourceConfig>;
 
@@ -127,10 +130,40 @@ pub trait SourceConfig: DynClone + NamedComponent + core::fmt::Debug + Send + Sy
     fn send_timeout(&self) -> Option<Duration> {
         None
     }
+
+    /// Builds the decoder this source runs the raw bytes it receives through, if any.
+    ///
+    /// This lets unit tests insert raw input at the source, running its framing and decoding before
+    /// the events reach the transforms.
+    fn build_decoder(
+        &self,
+        _global_log_namespace: LogNamespace,
+    ) -> crate::Result<Option<Box<dyn TestInputDecoder>>> {
+        Ok(None)
+    }
 }
 
 dyn_clone::clone_trait_object!(SourceConfig);
 
+/// Decodes the raw input that a unit test inserts at a source into the events the source sends.
+pub trait TestInputDecoder: Send + Sync {
+    /// Decodes `input`, returning each event along with the named output of the source that it's
+    /// sent to, or `None` for the default output.
+    fn decode(&self, input: Bytes) -> crate::Result<Vec<(Option<String>, Event)>>;
+}
+
+impl TestInputDecoder for Decoder {
+    fn decode(&self, input: Bytes) -> crate::Result<Vec<(Option<String>, Event)>> {
+        let mut decoder = self.clone();
+        let mut bytes = BytesMut::from(input.as_ref());
+        let mut events = Vec::new();
+        while let Some((decoded, _)) = decoder.decode_eof(&mut bytes)? {
+            events.extend(decoded.into_iter().map(|event| (None, event)));
+        }
+        Ok(events)
+    }
+}
+
 pub struct SourceContext {
     pub key: ComponentKey,
     pub globals: GlobalOptions,
@@ -2,6 +2,7 @@
 #[cfg(all(
     test,
     feature = "sources-demo_logs",
+    feature = "sources-socket",
     feature = "transforms-remap",
     feature = "transforms-route",
     feature = "transforms-filter",
@@ -14,8 +15,10 @@ mod unit_test_components;
 use std::{
     collections::{BTreeMap, HashMap, HashSet},
     sync::Arc,
+    time::Duration,
 };
 
+use bytes::Bytes;
 use futures_util::{StreamExt, stream::FuturesUnordered};
 use indexmap::IndexMap;
 use tokio::sync::{
@@ -37,10 +40,11 @@ use super::{OutputId, compiler::expand_globs, graph::Graph, transform::get_trans
 use crate::{
     conditions::Condition,
     config::{
-        self, ComponentKey, Config, ConfigBuilder, ConfigPath, SinkOuter, SourceOuter,
-        TestDefinition, TestInput, TestOutput, loading, loading::ConfigBuilderLoader,
+        self, BoxedSource, ComponentKey, Config, ConfigBuilder, ConfigPath, LogNamespace,
+        SinkOuter, SourceOuter, TestDefinition, TestInput, TestInputDecoder, TestOutput, loading,
+        loading::ConfigBuilderLoader,
     },
-    event::{Event, EventMetadata, LogEvent},
+    event::{Event, EventMetadata, LogEvent, TraceEvent},
     signal,
     topology::{
         RunningTopology,
@@ -51,7 +55,9 @@ use crate::{
 pub struct UnitTest {
     pub name: String,
     config: Config,
-    pieces: TopologyPieces,
+    /// The pieces of the topology, or `None` when the test runs with a virtual clock, in which case
+    /// they are built on the runtime of that clock so that the timers of the components follow it.
+    pieces: Option<TopologyPieces>,
     test_result_rxs: Vec<Receiver<UnitTestSinkResult>>,
 }
 
@@ -61,28 +67,72 @@ pub struct UnitTestResult {
 
 impl UnitTest {
     pub async fn run(self) -> UnitTestResult {
-        let diff = config::ConfigDiff::initial(&self.config);
-        let (topology, _) = RunningTopology::start_validated(self.config, diff, self.pieces)
-            .await
-            .unwrap();
-        topology.sources_finished().await;
-        let _stop_complete = topology.stop();
-
-        let mut in_flight = self
-            .test_result_rxs
-            .into_iter()
-            .collect::<FuturesUnordered<_>>();
-
-        let mut errors = Vec::new();
-        while let Some(partial_result) = in_flight.next().await {
-            let partial_result = partial_result.expect(
-                "An unexpected error occurred while executing unit tests. Please try again.",
-            );
-            errors.extend(partial_result.test_errors);
+        match self.pieces {
+            Some(pieces) => run_topology(self.config, pieces, self.test_result_rxs).await,
+            #[cfg(any(test, feature = "unit-test-virtual-clock"))]
+            None => run_with_virtual_clock(self.config, self.test_result_rxs).await,
+            #[cfg(not(any(test, feature = "unit-test-virtual-clock")))]
+            None => UnitTestResult {
+                errors: vec![
+                    "`at_ms` requires Vector to be built with the `unit-test-virtual-clock` feature"
+                        .to_string(),
+                ],
+            },
         }
+    }
+}
 
-        UnitTestResult { errors }
+/// Runs a test on a runtime of its own whose clock is paused, so that time only advances when
+/// every component is waiting on a timer, and jumps straight to the next timer when it does.
+#[cfg(any(test, feature = "unit-test-virtual-clock"))]
+async fn run_with_virtual_clock(
+    config: Config,
+    test_result_rxs: Vec<Receiver<UnitTestSinkResult>>,
+) -> UnitTestResult {
+    let (result_tx, result_rx) = oneshot::channel();
+    std::thread::spawn(move || {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .start_paused(true)
+            .build()
+            .expect("Unable to create the runtime of the virtual clock.");
+        let result = runtime.block_on(async move {
+            let diff = config::ConfigDiff::initial(&config);
+            match TopologyPiecesBuilder::new(&config, &diff).build().await {
+                Ok(pieces) => run_topology(config, pieces, test_result_rxs).await,
+                Err(errors) => UnitTestResult { errors },
+            }
+        });
+        _ = result_tx.send(result);
+    });
+
+    result_rx
+        .await
+        .expect("An unexpected error occurred while executing unit tests. Please try again.")
+}
+
+async fn run_topology(
+    config: Config,
+    pieces: TopologyPieces,
+    test_result_rxs: Vec<Receiver<UnitTestSinkResult>>,
+) -> UnitTestResult {
+    let diff = config::ConfigDiff::initial(&config);
+    let (topology, _) = RunningTopology::start_validated(config, diff, pieces)
+        .await
+        .unwrap();
+    topology.sources_finished().await;
+    let _stop_complete = topology.stop();
+
+    let mut in_flight = test_result_rxs.into_iter().collect::<FuturesUnordered<_>>();
+
+    let mut errors = Vec::new();
+    while let Some(partial_result) = in_flight.next().await {
+        let partial_result = partial_result
+            .expect("An unexpected error occurred while executing unit tests. Please try again.");
+        errors.extend(partial_result.test_errors);
     }
+
+    UnitTestResult { errors }
 }
 
 /// Loads Log Schema from configurations and sets global schema.
@@ -120,8 +170,8 @@ pub async fn build_unit_tests_main(
 pub async fn build_unit_tests(
     mut config_builder: ConfigBuilder,
 ) -> Result<Vec<UnitTest>, Vec<String>> {
-    // Sanitize config by removing existing sources and sinks
-    config_builder.sources = Default::default();
+    // Sanitize config by removing existing sinks, the sources are removed when initializing the
+    // build metadata
     config_builder.sinks = Default::default();
 
     let test_definitions = std::mem::take(&mut config_builder.tests);
@@ -158,8 +208,12 @@ pub async fn build_unit_tests(
 pub struct UnitTestBuildMetadata {
     // A set of all valid insert_at targets, used to validate test inputs.
     available_insert_targets: HashSet<ComponentKey>,
-    // A mapping from transform name to unit test source name.
+    // A mapping from transform or source name to unit test source name.
     source_ids: HashMap<ComponentKey, String>,
+    // The sources that raw input can be inserted at. Their decoders are only built by the tests
+    // that insert input at them, so that a source that can't be decoded only fails those tests.
+    sources: HashMap<ComponentKey, BoxedSource>,
+    log_namespace: LogNamespace,
     // A base setup of all necessary unit test sources that can be "hydrated"
     // with test input events to produces sources used in a particular test.
     template_sources: IndexMap<ComponentKey, UnitTestSourceConfig>,
@@ -172,14 +226,24 @@ impl UnitTestBuildMetadata {
         // A unique id used to name test sources and sinks to avoid name clashes
         let random_id = Uuid::new_v4().to_string();
 
+        // Sources are replaced by unit test sources of the same name, which send the decoded input
+        // inserted at them.
+        let log_namespace = config_builder.schema.log_namespace();
+        let sources = std::mem::take(&mut config_builder.sources)
+            .into_iter()
+            .map(|(key, source)| (key, source.inner))
+            .collect::<HashMap<_, _>>();
+
         let available_insert_targets = config_builder
             .transforms
             .keys()
+            .chain(sources.keys())
             .cloned()
             .collect::<HashSet<_>>();
 
-        let source_ids = available_insert_targets
-            .iter()
+        let mut source_ids = config_builder
+            .transforms
+            .keys()
             .map(|key| (key.clone(), format!("{}-{}-{}", key, "source", random_id)))
             .collect::<HashMap<_, _>>();
 
@@ -194,6 +258,16 @@ impl UnitTestBuildMetadata {
 
             template_sources.insert(key.clone(), UnitTestSourceConfig::default());
         }
+        for (key, source) in &sources {
+            source_ids.insert(key.clone(), key.to_string());
+            template_sources.insert(
+                key.clone(),
+                UnitTestSourceConfig {
+                    outputs: Some(source.outputs(log_namespace)),
+                    ..Default::default()
+                },
+            );
+        }
 
         let builder = config_builder.clone();
         let available_extract_targets = builder
@@ -226,6 +300,8 @@ impl UnitTestBuildMetadata {
         Ok(Self {
             available_insert_targets,
             source_ids,
+            sources,
+            log_namespace,
             template_sources,
             sink_ids,
         })
@@ -236,7 +312,10 @@ impl UnitTestBuildMetadata {
         &self,
         inputs: &[TestInput],
     ) -> Result<IndexMap<ComponentKey, SourceOuter>, Vec<String>> {
-        let inputs = build_and_validate_inputs(inputs, &self.available_insert_targets)?;
+        let scheduled = uses_virtual_clock(inputs);
+        let source_decoders = self.build_source_decoders(inputs)?;
+        let inputs =
+            build_and_validate_inputs(inputs, &self.available_insert_targets, &source_decoders)?;
         let mut template_sources = self.template_sources.clone();
         Ok(inputs
             .into_iter()
@@ -253,7 +332,17 @@ impl UnitTestBuildMetadata {
                                 insert_at.to_string()
                             )
                         });
-                source_config.events.extend(events);
+                if scheduled {
+                    source_config
+                        .send_at
+                        .extend(events.iter().map(|(at, _, _)| *at));
+                }
+                source_config
+                    .send_to
+                    .extend(events.iter().map(|(_, output, _)| output.clone()));
+                source_config
+                    .events
+                    .extend(events.into_iter().map(|(_, _, event)| event));
                 let id: &str = self
                     .source_ids
                     .get(&insert_at)
@@ -264,6 +353,42 @@ impl UnitTestBuildMetadata {
             .collect::<IndexMap<_, _>>())
     }
 
+    /// Builds the decoders of the sources that the given inputs are inserted at.
+    fn build_source_decoders(
+        &self,
+        inputs: &[TestInput],
+    ) -> Result<HashMap<ComponentKey, Box<dyn TestInputDecoder>>, Vec<String>> {
+        let mut source_decoders = HashMap::new();
+        let mut errors = Vec::new();
+        for input in inputs {
+            let Some(source) = self.sources.get(&input.insert_at) else {
+                continue;
+            };
+            if source_decoders.contains_key(&input.insert_at) {
+                continue;
+            }
+            match source.build_decoder(self.log_namespace) {
+                Ok(Some(decoder)) => {
+                    source_decoders.insert(input.insert_at.clone(), decoder);
+                }
+                Ok(None) => errors.push(format!(
+                    "source '{}' doesn't support unit test inputs",
+                    input.insert_at
+                )),
+                Err(error) => errors.push(format!(
+                    "failed to build the decoder of source '{}': {error}",
+                    input.insert_at
+                )),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(source_decoders)
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Convert test outputs into sinks for use in a unit testing topology
     pub fn hydrate_into_sinks(
         &self,
@@ -361,10 +486,9 @@ fn get_relevant_test_components(
             errors.push(format!(
                 "Unable to complete topology between input target '{}' and output target(s)",
                 source
-                    .to_string()
+                    .id()
                     .rsplit_once("-source-")
-                    .unwrap_or(("", ""))
-                    .0
+                    .map_or(source.id(), |(name, _)| name)
             ));
         } else {
             for path in paths {
@@ -470,7 +594,13 @@ async fn build_unit_test(
     }
     let config = config_builder.build()?;
     let diff = config::ConfigDiff::initial(&config);
-    let pieces = TopologyPiecesBuilder::new(&config, &diff).build().await?;
+    // The pieces of a test with a virtual clock are built on the runtime of that clock, as building
+    // them hands the result channels over to the test sinks.
+    let pieces = if uses_virtual_clock(&test.inputs) {
+        None
+    } else {
+        Some(TopologyPiecesBuilder::new(&config, &diff).build().await?)
+    };
 
     Ok(UnitTest {
         name: test.name,
@@ -526,10 +656,18 @@ fn get_loose_end_outputs_sink(config: &ConfigBuilder) -> Option<SinkOuter<String
     }
 }
 
+/// Whether a test runs with a virtual clock, which is the case when any of its inputs is scheduled.
+fn uses_virtual_clock(inputs: &[TestInput]) -> bool {
+    inputs.iter().any(|input| input.at_ms.is_some())
+}
+
+/// Builds the input events of a test by insertion target, along with the time to send them at and
+/// the output of the source that they're sent to.
 fn build_and_validate_inputs(
     test_inputs: &[TestInput],
     available_insert_targets: &HashSet<ComponentKey>,
-) -> Result<HashMap<ComponentKey, Vec<Event>>, Vec<String>> {
+    source_decoders: &HashMap<ComponentKey, Box<dyn TestInputDecoder>>,
+) -> Result<HashMap<ComponentKey, Vec<(Duration, Option<String>, Event)>>, Vec<String>> {
     let mut inputs = HashMap::new();
     let mut errors = Vec::new();
     if test_inputs.is_empty() {
@@ -537,16 +675,45 @@ fn build_and_validate_inputs(
         return Err(errors);
     }
 
+    let mut at = Duration::ZERO;
     for (index, input) in test_inputs.iter().enumerate() {
-        if available_insert_targets.contains(&input.insert_at) {
+        if let Some(at_ms) = input.at_ms {
+            if !cfg!(any(test, feature = "unit-test-virtual-clock")) {
+                errors.push(format!(
+                    "inputs[{index}]: `at_ms` requires Vector to be built with the \
+                    `unit-test-virtual-clock` feature"
+                ));
+            }
+            let input_at = Duration::from_millis(at_ms);
+            if input_at < at {
+                errors.push(format!(
+                    "inputs[{index}]: `at_ms` must not be lower than the time of the inputs before it"
+                ));
+            }
+            at = input_at;
+        }
+
+        if let Some(decoder) = source_decoders.get(&input.insert_at) {
+            match decode_input(input, decoder.as_ref()) {
+                Ok(input_events) => {
+                    inputs
+                        .entry(input.insert_at.clone())
+                        .or_insert_with(Vec::new)
+                        .extend(
+                            input_events
+                                .into_iter()
+                                .map(|(output, event)| (at, output, event)),
+                        );
+                }
+                Err(error) => errors.push(error),
+            }
+        } else if available_insert_targets.contains(&input.insert_at) {
             match build_input_event(input) {
                 Ok(input_event) => {
                     inputs
                         .entry(input.insert_at.clone())
-                        .and_modify(|events: &mut Vec<Event>| {
-                            events.push(input_event.clone());
-                        })
-                        .or_insert_with(|| vec![input_event]);
+                        .or_insert_with(Vec::new)
+                        .push((at, None, input_event));
                 }
                 Err(error) => errors.push(error),
             }
@@ -699,9 +866,48 @@ fn build_input_event(input: &TestInput) -> Result<Event, String> {
                 Err("input type 'metric' requires the field 'metric'".to_string())
             }
         }
+        "trace" => {
+            if let Some(trace_fields) = &input.trace_fields {
+                let mut event = LogEvent::default();
+                for (path, value) in trace_fields {
+                    event
+                        .parse_path_and_insert(path, value.clone())
+                        .map_err(|e| e.to_string())?;
+                }
+                Ok(Event::Trace(TraceEvent::from(event)))
+            } else {
+                Err("input type 'trace' requires the field 'trace_fields'".to_string())
+            }
+        }
         _ => Err(format!(
-            "unrecognized input type '{}', expected one of: 'raw', 'log' or 'metric'",
+            "unrecognized input type '{}', expected one of: 'raw', 'vrl', 'log', 'metric' or 'trace'",
             input.type_str
         )),
     }
 }
+
+/// Runs the raw value of an input through the framing and decoding of the source it is inserted
+/// at.
+fn decode_input(
+    input: &TestInput,
+    decoder: &dyn TestInputDecoder,
+) -> Result<Vec<(Option<String>, Event)>, String> {
+    if input.type_str != "raw" {
+        return Err(format!(
+            "input type must be 'raw' when inserting at source '{}', found '{}'",
+            input.insert_at, input.type_str
+        ));
+    }
+    let Some(value) = input.value.as_ref() else {
+        return Err("input type 'raw' requires the field 'value'".to_string());
+    };
+
+    decoder
+        .decode(Bytes::copy_from_slice(value.as_bytes()))
+        .map_err(|error| {
+            format!(
+                "failed to decode input at source '{}': {error}",
+                input.insert_at
+            )
+        })
+}
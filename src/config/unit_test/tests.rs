@@ -318,7 +318,7 @@ async fn parse_bad_input_event() {
         vec![
             indoc! {r"
             Failed to build test 'broken test':
-              unrecognized input type 'nah', expected one of: 'raw', 'log' or 'metric'"}
+              unrecognized input type 'nah', expected one of: 'raw', 'vrl', 'log', 'metric' or 'trace'"}
             .to_owned(),
         ]
     );
@@ -1367,3 +1367,273 @@ async fn expected_event_count_zero_split_with_conditions_rejected() {
         "expected config error about zero count with conditions after merge, got: {errs:?}"
     );
 }
+
+#[tokio::test]
+async fn test_virtual_clock() {
+    crate::test_util::trace_init();
+
+    let config: ConfigBuilder = crate::config::format::deserialize(
+        indoc! {r#"
+            transforms:
+              foo:
+                inputs:
+                  - ignored
+                type: reduce
+                group_by:
+                  - id
+                expire_after_ms: 30000
+            tests:
+              - name: expired between inputs
+                inputs:
+                  - insert_at: foo
+                    type: log
+                    log_fields:
+                      id: "1"
+                      message: first
+                  - insert_at: foo
+                    type: log
+                    at_ms: 45000
+                    log_fields:
+                      id: "1"
+                      message: second
+                outputs:
+                  - extract_from: foo
+                    expected_event_count: 2
+              - name: not expired between inputs
+                inputs:
+                  - insert_at: foo
+                    type: log
+                    log_fields:
+                      id: "1"
+                      message: first
+                  - insert_at: foo
+                    type: log
+                    at_ms: 15000
+                    log_fields:
+                      id: "1"
+                      message: second
+                outputs:
+                  - extract_from: foo
+                    expected_event_count: 1
+                    conditions:
+                      - type: vrl
+                        source: |
+                          assert_eq!(.message, "first")
+        "#},
+        crate::config::Format::Yaml,
+    )
+    .unwrap();
+
+    let mut tests = build_unit_tests(config).await.unwrap();
+    assert_eq!(tests.remove(0).run().await.errors, Vec::<String>::new());
+    assert_eq!(tests.remove(0).run().await.errors, Vec::<String>::new());
+}
+
+#[tokio::test]
+async fn test_virtual_clock_decreasing_time() {
+    crate::test_util::trace_init();
+
+    let config: ConfigBuilder = crate::config::format::deserialize(
+        indoc! {r#"
+            transforms:
+              foo:
+                inputs:
+                  - ignored
+                type: remap
+                source: .new_field = "value"
+            tests:
+              - name: decreasing time
+                inputs:
+                  - insert_at: foo
+                    value: first
+                    at_ms: 1000
+                  - insert_at: foo
+                    value: second
+                    at_ms: 500
+                outputs:
+                  - extract_from: foo
+                    expected_event_count: 2
+        "#},
+        crate::config::Format::Yaml,
+    )
+    .unwrap();
+
+    let errs = build_unit_tests(config).await.err().unwrap();
+    assert_eq!(
+        errs,
+        vec![
+            indoc! {r"
+            Failed to build test 'decreasing time':
+              inputs[1]: `at_ms` must not be lower than the time of the inputs before it"}
+            .to_owned()
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_trace_input() {
+    crate::test_util::trace_init();
+
+    let config: ConfigBuilder = crate::config::format::deserialize(
+        indoc! {r#"
+            transforms:
+              foo:
+                inputs:
+                  - ignored
+                type: remap
+                source: .tagged = true
+            tests:
+              - name: trace input
+                inputs:
+                  - insert_at: foo
+                    type: trace
+                    trace_fields:
+                      trace_id: abc
+                      spans[0].name: GET /
+                outputs:
+                  - extract_from: foo
+                    conditions:
+                      - type: vrl
+                        source: |
+                          assert_eq!(.trace_id, "abc")
+                          assert_eq!(.spans[0].name, "GET /")
+                          assert_eq!(.tagged, true)
+        "#},
+        crate::config::Format::Yaml,
+    )
+    .unwrap();
+
+    let mut tests = build_unit_tests(config).await.unwrap();
+    assert_eq!(tests.remove(0).run().await.errors, Vec::<String>::new());
+}
+
+#[tokio::test]
+async fn test_source_input() {
+    crate::test_util::trace_init();
+
+    let config: ConfigBuilder = crate::config::format::deserialize(
+        indoc! {r#"
+            sources:
+              in:
+                type: socket
+                mode: tcp
+                address: 0.0.0.0:9000
+                decoding:
+                  codec: json
+            transforms:
+              foo:
+                inputs:
+                  - in
+                type: remap
+                source: .doubled = to_int!(.value) * 2
+            tests:
+              - name: decoded at the source
+                inputs:
+                  - insert_at: in
+                    value: |
+                      {"value": 1}
+                      {"value": 2}
+                outputs:
+                  - extract_from: foo
+                    expected_event_count: 2
+                    conditions:
+                      - type: vrl
+                        source: assert_eq!(.doubled, 2)
+                      - type: vrl
+                        source: assert_eq!(.doubled, 4)
+              - name: not raw at the source
+                inputs:
+                  - insert_at: in
+                    type: log
+                    log_fields:
+                      value: 1
+                outputs:
+                  - extract_from: foo
+                    expected_event_count: 1
+        "#},
+        crate::config::Format::Yaml,
+    )
+    .unwrap();
+
+    let errs = build_unit_tests(config.clone()).await.err().unwrap();
+    assert_eq!(
+        errs,
+        vec![
+            indoc! {r"
+            Failed to build test 'not raw at the source':
+              input type must be 'raw' when inserting at source 'in', found 'log'"}
+            .to_owned(),
+        ]
+    );
+
+    let mut config = config;
+    config.tests.truncate(1);
+    let mut tests = build_unit_tests(config).await.unwrap();
+    assert_eq!(tests.remove(0).run().await.errors, Vec::<String>::new());
+}
+
+#[tokio::test]
+async fn test_source_decoder_error_only_fails_its_tests() {
+    crate::test_util::trace_init();
+
+    let config: ConfigBuilder = crate::config::format::deserialize(
+        indoc! {r#"
+            sources:
+              in:
+                type: socket
+                mode: tcp
+                address: 0.0.0.0:9000
+                decoding:
+                  codec: json
+              broken:
+                type: socket
+                mode: tcp
+                address: 0.0.0.0:9001
+                decoding:
+                  codec: protobuf
+                  protobuf:
+                    desc_file: /nonexistent/test.desc
+                    message_type: test.Message
+            transforms:
+              foo:
+                inputs:
+                  - in
+                  - broken
+                type: remap
+                source: .doubled = to_int!(.value) * 2
+            tests:
+              - name: decoded at the source
+                inputs:
+                  - insert_at: in
+                    value: '{"value": 1}'
+                outputs:
+                  - extract_from: foo
+                    conditions:
+                      - type: vrl
+                        source: assert_eq!(.doubled, 2)
+              - name: decoded at the broken source
+                inputs:
+                  - insert_at: broken
+                    value: ""
+                outputs:
+                  - extract_from: foo
+                    expected_event_count: 1
+        "#},
+        crate::config::Format::Yaml,
+    )
+    .unwrap();
+
+    let errs = build_unit_tests(config.clone()).await.err().unwrap();
+    assert_eq!(errs.len(), 1);
+    assert!(
+        errs[0].starts_with(indoc! {r"
+            Failed to build test 'decoded at the broken source':
+              failed to build the decoder of source 'broken':"}),
+        "{errs:?}"
+    );
+
+    let mut config = config;
+    config.tests.truncate(1);
+    let mut tests = build_unit_tests(config).await.unwrap();
+    assert_eq!(tests.remove(0).run().await.errors, Vec::<String>::new());
+}
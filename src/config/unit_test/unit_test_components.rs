@@ -1,7 +1,7 @@
 // Derivative's Debug impl generates `let _ = field.fmt(f)` which triggers this lint.
 #![allow(clippy::let_underscore_must_use)]
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use futures::{Sink, Stream, stream};
 use futures_util::{FutureExt, StreamExt, future, stream::BoxStream};
@@ -30,6 +30,24 @@ pub struct UnitTestSourceConfig {
     /// List of events sent from this source as part of the test.
     #[serde(skip)]
     pub events: Vec<Event>,
+
+    /// The time at which each event is sent, relative to the start of the source.
+    ///
+    /// When empty, all of the events are sent at once.
+    #[serde(skip)]
+    pub send_at: Vec<Duration>,
+
+    /// The named output that each event is sent to, where `None` is the default output.
+    ///
+    /// When empty, all of the events are sent to the default output.
+    #[serde(skip)]
+    pub send_to: Vec<Option<String>>,
+
+    /// The outputs of the source that this source replaces, if any.
+    ///
+    /// When not set, the source has a single default output of any type.
+    #[serde(skip)]
+    pub outputs: Option<Vec<SourceOutput>>,
 }
 
 impl_generate_config_from_default!(UnitTestSourceConfig);
@@ -38,21 +56,51 @@ impl_generate_config_from_default!(UnitTestSourceConfig);
 #[typetag::serde(name = "unit_test")]
 impl SourceConfig for UnitTestSourceConfig {
     async fn build(&self, cx: SourceContext) -> crate::Result<sources::Source> {
-        let events = self.events.clone().into_iter();
+        let events = self.events.clone();
+        let send_at = self.send_at.clone();
+        let send_to = self.send_to.clone();
 
         Ok(Box::pin(async move {
             let mut out = cx.out;
             let _shutdown = cx.shutdown;
-            out.send_batch(events).await.map_err(|_| ())?;
+
+            let start = tokio::time::Instant::now();
+            let mut scheduled = events
+                .into_iter()
+                .enumerate()
+                .map(|(index, event)| {
+                    let at = send_at.get(index).copied().unwrap_or_default();
+                    let output = send_to.get(index).cloned().flatten();
+                    (at, output, event)
+                })
+                .peekable();
+            while let Some((at, output, event)) = scheduled.next() {
+                if !send_at.is_empty() {
+                    tokio::time::sleep_until(start + at).await;
+                }
+                let mut batch = vec![event];
+                while let Some((_, _, event)) = scheduled
+                    .next_if(|(next_at, next_output, _)| *next_at == at && *next_output == output)
+                {
+                    batch.push(event);
+                }
+                match output {
+                    Some(output) => out.send_batch_named(&output, batch).await,
+                    None => out.send_batch(batch).await,
+                }
+                .map_err(|_| ())?;
+            }
             Ok(())
         }))
     }
 
     fn outputs(&self, _global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
-        vec![SourceOutput::new_maybe_logs(
-            DataType::all_bits(),
-            schema::Definition::default_legacy_namespace(),
-        )]
+        self.outputs.clone().unwrap_or_else(|| {
+            vec![SourceOutput::new_maybe_logs(
+                DataType::all_bits(),
+                schema::Definition::default_legacy_namespace(),
+            )]
+        })
     }
 
     fn can_acknowledge(&self) -> bool {
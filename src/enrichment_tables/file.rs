@@ -13,6 +13,9 @@ use vrl::value::{ObjectMap, Value};
 
 use crate::config::EnrichmentTableConfig;
 
+#[cfg(feature = "codecs-parquet")]
+mod parquet;
+
 /// File encoding configuration.
 #[configurable_component]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -25,7 +28,7 @@ pub enum Encoding {
     Csv {
         /// Whether or not the file contains column headers.
         ///
-        /// When set to `true`, the first row of the CSV file will be read as the header row, and
+        /// When set to `true`, the first row of the file will be read as the header row, and
         /// the values will be used for the names of each column. This is the default behavior.
         ///
         /// When set to `false`, columns are referred to by their numerical index.
@@ -36,6 +39,41 @@ pub enum Encoding {
         #[serde(default = "default_delimiter")]
         delimiter: char,
     },
+
+    /// Decodes the file as a [TSV][tsv] (tab-separated values) file.
+    ///
+    /// Fields are separated by tabs and are never quoted, so quote characters are read as part of
+    /// the value. Columns are coerced into their types by `schema`, as for CSV files.
+    ///
+    /// [tsv]: https://www.iana.org/assignments/media-types/text/tab-separated-values
+    Tsv {
+        /// Whether or not the file contains column headers.
+        ///
+        /// When set to `true`, the first row of the file will be read as the header row, and
+        /// the values will be used for the names of each column. This is the default behavior.
+        ///
+        /// When set to `false`, columns are referred to by their numerical index.
+        #[serde(default = "crate::serde::default_true")]
+        include_headers: bool,
+    },
+
+    /// Decodes the file as [JSON Lines][jsonl], with one JSON object per line.
+    ///
+    /// The columns of the table are the union of the keys of all objects. Rows that do not
+    /// contain a given key have a `null` value for that column. Values keep their JSON types, including nested objects and arrays.
+    ///
+    /// [jsonl]: https://jsonlines.org/
+    JsonLines,
+
+    /// Decodes the file as an [Apache Parquet][apache_parquet] file.
+    ///
+    /// The columns of the table are the columns of the Parquet schema. Values keep their Parquet
+    /// types: integers, floats, booleans, strings, binary, timestamps and dates, as well as
+    /// nested lists, maps, and structs.
+    ///
+    /// [apache_parquet]: https://parquet.apache.org/
+    #[cfg(feature = "codecs-parquet")]
+    Parquet,
 }
 
 impl Default for Encoding {
@@ -53,9 +91,7 @@ impl Default for Encoding {
 pub struct FileSettings {
     /// The path of the enrichment table file.
     ///
-    /// Currently, only [CSV][csv] files are supported.
-    ///
-    /// [csv]: https://en.wikipedia.org/wiki/Comma-separated_values
+    /// The format of the file is set by `encoding`.
     pub path: PathBuf,
 
     /// File encoding configuration.
@@ -75,6 +111,9 @@ pub struct FileConfig {
     ///
     /// This is used to coerce log fields from strings into their proper types. The available types are listed in the `Types` list below.
     ///
+    /// For the `json_lines` and `parquet` encodings, only string values are coerced. Values that already have a
+    /// native type are left unchanged.
+    ///
     /// Timestamp coercions need to be prefaced with `timestamp|`, for example `"timestamp|%F"`. Timestamp specifiers can use either of the following:
     ///
     /// 1. One of the built-in-formats listed in the `Timestamp Formats` table below.
@@ -179,16 +218,79 @@ impl FileConfig {
         })
     }
 
+    /// Coerces a value read from a typed file format according to the schema.
+    ///
+    /// Only strings are coerced, values that already have a native type are returned as is.
+    fn coerce_value(
+        &self,
+        timezone: TimeZone,
+        column: &str,
+        row: usize,
+        value: Value,
+    ) -> Result<Value, String> {
+        match value {
+            Value::Bytes(bytes) if self.schema.contains_key(column) => {
+                let value = std::str::from_utf8(&bytes)
+                    .map_err(|_| format!("invalid utf-8 in column {column} found in row {row}"))?;
+                self.parse_column(timezone, column, row, value)
+            }
+            value => Ok(value),
+        }
+    }
+
     /// Load the configured file into memory. Required to create a new file enrichment table.
     pub fn load_file(&self, timezone: TimeZone) -> crate::Result<FileData> {
-        let Encoding::Csv {
-            include_headers,
-            delimiter,
-        } = self.file.encoding;
+        let (headers, data) = match self.file.encoding {
+            Encoding::Csv {
+                include_headers,
+                delimiter,
+            } => self.load_csv(timezone, include_headers, delimiter, true)?,
+            Encoding::Tsv { include_headers } => {
+                self.load_csv(timezone, include_headers, '\t', false)?
+            }
+            Encoding::JsonLines => self.load_json_lines(timezone)?,
+            #[cfg(feature = "codecs-parquet")]
+            Encoding::Parquet => {
+                let (headers, data) = parquet::load(&self.file.path)?;
+                let data = data
+                    .into_iter()
+                    .enumerate()
+                    .map(|(row, values)| {
+                        headers
+                            .iter()
+                            .zip(values)
+                            .map(|(column, value)| self.coerce_value(timezone, column, row, value))
+                            .collect::<Result<Vec<_>, String>>()
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                (headers, data)
+            }
+        };
+
+        trace!(
+            "Loaded enrichment file {} with headers {:?}.",
+            self.file.path.to_str().unwrap_or("path with invalid utf"),
+            headers
+        );
+
+        Ok(FileData {
+            headers,
+            data,
+            modified: fs::metadata(&self.file.path)?.modified()?,
+        })
+    }
 
+    fn load_csv(
+        &self,
+        timezone: TimeZone,
+        include_headers: bool,
+        delimiter: char,
+        quoting: bool,
+    ) -> crate::Result<(Vec<String>, Vec<Vec<Value>>)> {
         let mut reader = csv::ReaderBuilder::new()
             .has_headers(include_headers)
             .delimiter(delimiter as u8)
+            .quoting(quoting)
             .from_path(&self.file.path)?;
 
         let first_row = reader.records().next();
@@ -219,19 +321,51 @@ impl FileConfig {
             })
             .collect::<crate::Result<Vec<_>>>()?;
 
-        trace!(
-            "Loaded enrichment file {} with headers {:?}.",
-            self.file.path.to_str().unwrap_or("path with invalid utf"),
-            headers
-        );
+        Ok((headers, data))
+    }
 
-        let file = reader.into_inner();
+    fn load_json_lines(&self, timezone: TimeZone) -> crate::Result<(Vec<String>, Vec<Vec<Value>>)> {
+        let contents = fs::read_to_string(&self.file.path)?;
 
-        Ok(FileData {
-            headers,
-            data,
-            modified: file.metadata()?.modified()?,
-        })
+        let mut headers = Vec::<String>::new();
+        let mut data = Vec::new();
+
+        for (line_number, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let object = match serde_json::from_str::<Value>(line) {
+                Ok(Value::Object(object)) => object,
+                Ok(_) => {
+                    return Err(format!("line {} is not a JSON object", line_number + 1).into());
+                }
+                Err(error) => {
+                    return Err(format!("unable to parse line {}: {error}", line_number + 1).into());
+                }
+            };
+
+            let row = data.len();
+            let mut values = vec![Value::Null; headers.len()];
+            for (key, value) in object {
+                let value = self.coerce_value(timezone, &key, row, value)?;
+                match headers.iter().position(|header| header == key.as_str()) {
+                    Some(idx) => values[idx] = value,
+                    None => {
+                        headers.push(key.into());
+                        values.push(value);
+                    }
+                }
+            }
+            data.push(values);
+        }
+
+        // Rows read before a column was first encountered don't have a value for it.
+        for values in &mut data {
+            values.resize(headers.len(), Value::Null);
+        }
+
+        Ok((headers, data))
     }
 }
 
@@ -260,7 +394,7 @@ pub struct FileData {
     pub modified: SystemTime,
 }
 
-/// A struct that implements [vector_lib::enrichment::Table] to handle loading enrichment data from a CSV file.
+/// A struct that implements [vector_lib::enrichment::Table] to handle loading enrichment data from a file.
 #[derive(Clone)]
 pub struct File {
     config: FileConfig,
@@ -752,6 +886,280 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_tsv_file() {
+        let dir = tempfile::tempdir().expect("Unable to create tempdir for enrichment table");
+        let path = dir.path().join("table.tsv");
+        fs::write(path.clone(), "foo\tbar\n\"a\", b\t1\nc\t2\n")
+            .expect("Failed to write enrichment table");
+
+        let config = FileConfig {
+            file: FileSettings {
+                path,
+                encoding: Encoding::Tsv {
+                    include_headers: true,
+                },
+            },
+            schema: HashMap::from([("bar".to_string(), "integer".to_string())]),
+        };
+        let data = config
+            .load_file(Default::default())
+            .expect("Failed to parse tsv");
+        assert_eq!(vec!["foo", "bar"], data.headers);
+        assert_eq!(
+            vec![
+                vec![Value::from("\"a\", b"), Value::from(1)],
+                vec![Value::from("c"), Value::from(2)],
+            ],
+            data.data
+        );
+    }
+
+    #[test]
+    fn parse_json_lines_file() {
+        let dir = tempfile::tempdir().expect("Unable to create tempdir for enrichment table");
+        let path = dir.path().join("table.jsonl");
+        fs::write(
+            path.clone(),
+            indoc::indoc! {r#"
+                {"id": 1, "name": "a", "location": {"city": "Paris"}, "tags": ["x", "y"]}
+
+                {"id": 2, "score": 1.5, "active": true, "code": "42"}
+            "#},
+        )
+        .expect("Failed to write enrichment table");
+
+        let config = FileConfig {
+            file: FileSettings {
+                path,
+                encoding: Encoding::JsonLines,
+            },
+            schema: HashMap::from([("code".to_string(), "integer".to_string())]),
+        };
+        let data = config
+            .load_file(Default::default())
+            .expect("Failed to parse json lines");
+        assert_eq!(
+            vec!["id", "location", "name", "tags", "active", "code", "score"],
+            data.headers
+        );
+        assert_eq!(
+            vec![
+                vec![
+                    Value::from(1),
+                    Value::from(ObjectMap::from([("city".into(), Value::from("Paris"))])),
+                    Value::from("a"),
+                    Value::from(vec![Value::from("x"), Value::from("y")]),
+                    Value::Null,
+                    Value::Null,
+                    Value::Null,
+                ],
+                vec![
+                    Value::from(2),
+                    Value::Null,
+                    Value::Null,
+                    Value::Null,
+                    Value::from(true),
+                    Value::from(42),
+                    Value::from_f64_or_zero(1.5),
+                ],
+            ],
+            data.data
+        );
+
+        let file = File::new(config, data);
+        let condition = Condition::Equals {
+            field: "id",
+            value: Value::from(1),
+        };
+        let select = ["location".to_string()];
+        assert_eq!(
+            Ok(ObjectMap::from([(
+                "location".into(),
+                Value::from(ObjectMap::from([("city".into(), Value::from("Paris"))]))
+            )])),
+            file.find_table_row(Case::Sensitive, &[condition], Some(&select), None, None)
+        );
+    }
+
+    #[test]
+    fn json_lines_file_requires_objects() {
+        let dir = tempfile::tempdir().expect("Unable to create tempdir for enrichment table");
+        let path = dir.path().join("table.jsonl");
+        fs::write(path.clone(), "{\"id\": 1}\n[1, 2]\n").expect("Failed to write enrichment table");
+
+        let config = FileConfig {
+            file: FileSettings {
+                path,
+                encoding: Encoding::JsonLines,
+            },
+            schema: HashMap::new(),
+        };
+        let error = config.load_file(Default::default()).unwrap_err();
+        assert_eq!("line 2 is not a JSON object", error.to_string());
+    }
+
+    #[cfg(feature = "codecs-parquet")]
+    #[test]
+    fn parse_parquet_file() {
+        use std::sync::Arc;
+
+        use arrow::{
+            array::{
+                ArrayRef, BooleanArray, Int64Array, ListArray, StringArray, StructArray,
+                TimestampMillisecondArray,
+            },
+            datatypes::{DataType, Field, Int64Type},
+            record_batch::RecordBatch,
+        };
+
+        let dir = tempfile::tempdir().expect("Unable to create tempdir for enrichment table");
+        let path = dir.path().join("table.parquet");
+
+        let location = StructArray::from(vec![
+            (
+                Arc::new(Field::new("city", DataType::Utf8, false)),
+                Arc::new(StringArray::from(vec!["Paris", "Lyon"])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("zone", DataType::Int64, false)),
+                Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef,
+            ),
+        ]);
+        let batch = RecordBatch::try_from_iter(vec![
+            ("id", Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef),
+            (
+                "name",
+                Arc::new(StringArray::from(vec![Some("a"), None])) as ArrayRef,
+            ),
+            (
+                "active",
+                Arc::new(BooleanArray::from(vec![true, false])) as ArrayRef,
+            ),
+            (
+                "tags",
+                Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                    Some(vec![Some(1), Some(2)]),
+                    Some(vec![]),
+                ])) as ArrayRef,
+            ),
+            ("location", Arc::new(location) as ArrayRef),
+            (
+                "seen",
+                Arc::new(TimestampMillisecondArray::from(vec![0, 1_000])) as ArrayRef,
+            ),
+            (
+                "code",
+                Arc::new(StringArray::from(vec!["10", "20"])) as ArrayRef,
+            ),
+        ])
+        .expect("Failed to build record batch");
+
+        let mut writer = ::parquet::arrow::ArrowWriter::try_new(
+            fs::File::create(&path).expect("Failed to create enrichment table"),
+            batch.schema(),
+            None,
+        )
+        .expect("Failed to create parquet writer");
+        writer.write(&batch).expect("Failed to write record batch");
+        writer.close().expect("Failed to close parquet writer");
+
+        let config = FileConfig {
+            file: FileSettings {
+                path,
+                encoding: Encoding::Parquet,
+            },
+            schema: HashMap::from([("code".to_string(), "integer".to_string())]),
+        };
+        let data = config
+            .load_file(Default::default())
+            .expect("Failed to parse parquet");
+        assert_eq!(
+            vec!["id", "name", "active", "tags", "location", "seen", "code"],
+            data.headers
+        );
+        assert_eq!(
+            vec![
+                vec![
+                    Value::from(1),
+                    Value::from("a"),
+                    Value::from(true),
+                    Value::from(vec![Value::from(1), Value::from(2)]),
+                    Value::from(ObjectMap::from([
+                        ("city".into(), Value::from("Paris")),
+                        ("zone".into(), Value::from(1)),
+                    ])),
+                    Value::from(chrono::Utc.timestamp_opt(0, 0).unwrap()),
+                    Value::from(10),
+                ],
+                vec![
+                    Value::from(2),
+                    Value::Null,
+                    Value::from(false),
+                    Value::Array(Vec::new()),
+                    Value::from(ObjectMap::from([
+                        ("city".into(), Value::from("Lyon")),
+                        ("zone".into(), Value::from(2)),
+                    ])),
+                    Value::from(chrono::Utc.timestamp_opt(1, 0).unwrap()),
+                    Value::from(20),
+                ],
+            ],
+            data.data
+        );
+    }
+
+    #[cfg(feature = "codecs-parquet")]
+    #[test]
+    fn parse_parquet_file_with_nested_dictionaries() {
+        use std::sync::Arc;
+
+        use arrow::{
+            array::{ArrayRef, ListBuilder, StringDictionaryBuilder},
+            datatypes::Int32Type,
+            record_batch::RecordBatch,
+        };
+
+        let dir = tempfile::tempdir().expect("Unable to create tempdir for enrichment table");
+        let path = dir.path().join("table.parquet");
+
+        let mut tags = ListBuilder::new(StringDictionaryBuilder::<Int32Type>::new());
+        tags.values().append_value("x");
+        tags.values().append_value("y");
+        tags.append(true);
+        tags.values().append_value("x");
+        tags.append(true);
+        let batch = RecordBatch::try_from_iter(vec![("tags", Arc::new(tags.finish()) as ArrayRef)])
+            .expect("Failed to build record batch");
+
+        let mut writer = ::parquet::arrow::ArrowWriter::try_new(
+            fs::File::create(&path).expect("Failed to create enrichment table"),
+            batch.schema(),
+            None,
+        )
+        .expect("Failed to create parquet writer");
+        writer.write(&batch).expect("Failed to write record batch");
+        writer.close().expect("Failed to close parquet writer");
+
+        let config = FileConfig {
+            file: FileSettings {
+                path,
+                encoding: Encoding::Parquet,
+            },
+            schema: HashMap::new(),
+        };
+        let data = config
+            .load_file(Default::default())
+            .expect("Failed to parse parquet");
+        assert_eq!(
+            vec![
+                vec![Value::from(vec![Value::from("x"), Value::from("y")])],
+                vec![Value::from(vec![Value::from("x")])],
+            ],
+            data.data
+        );
+    }
+
     #[test]
     fn parse_column() {
         let mut schema = HashMap::new();
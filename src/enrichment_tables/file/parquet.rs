@@ -0,0 +1,184 @@
+//! Loading of Parquet files for the `file` enrichment table.
+use std::{fs, path::Path, sync::Arc};
+
+use arrow::{
+    array::{Array, ArrayRef, AsArray},
+    compute::cast,
+    datatypes::{
+        DataType, Date32Type, Date64Type, Decimal128Type, Field, FieldRef, Float32Type,
+        Float64Type, Int8Type, Int16Type, Int32Type, Int64Type, TimeUnit, TimestampMicrosecondType,
+        TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt8Type,
+        UInt16Type, UInt32Type, UInt64Type,
+    },
+};
+use bytes::Bytes;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use vrl::value::{KeyString, ObjectMap, Value};
+
+/// Reads the Parquet file at `path`, returning the column names and the rows of the file.
+pub(super) fn load(path: &Path) -> crate::Result<(Vec<String>, Vec<Vec<Value>>)> {
+    let reader = ParquetRecordBatchReaderBuilder::try_new(fs::File::open(path)?)?.build()?;
+
+    let headers = reader
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| field.name().clone())
+        .collect::<Vec<_>>();
+
+    let mut data = Vec::new();
+    for batch in reader {
+        let batch = batch?;
+        // Dictionary encoded columns, including nested ones, are unpacked once per batch rather
+        // than once per row.
+        let columns = batch
+            .columns()
+            .iter()
+            .map(|column| {
+                let data_type = without_dictionaries(column.data_type());
+                if &data_type == column.data_type() {
+                    Ok(ArrayRef::clone(column))
+                } else {
+                    cast(column.as_ref(), &data_type)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for row in 0..batch.num_rows() {
+            data.push(
+                columns
+                    .iter()
+                    .map(|column| array_value(column.as_ref(), row))
+                    .collect::<Result<Vec<_>, String>>()?,
+            );
+        }
+    }
+
+    Ok((headers, data))
+}
+
+/// Returns the given type with every dictionary in it replaced by the type of its values.
+fn without_dictionaries(data_type: &DataType) -> DataType {
+    let field = |field: &FieldRef| {
+        Arc::new(Field::clone(field).with_data_type(without_dictionaries(field.data_type())))
+    };
+
+    match data_type {
+        DataType::Dictionary(_, value_type) => without_dictionaries(value_type),
+        DataType::List(item) => DataType::List(field(item)),
+        DataType::LargeList(item) => DataType::LargeList(field(item)),
+        DataType::FixedSizeList(item, size) => DataType::FixedSizeList(field(item), *size),
+        DataType::Struct(fields) => DataType::Struct(fields.iter().map(field).collect()),
+        DataType::Map(entries, sorted) => DataType::Map(field(entries), *sorted),
+        data_type => data_type.clone(),
+    }
+}
+
+/// Converts the value at `row` of the given Arrow array into a [`Value`].
+fn array_value(array: &dyn Array, row: usize) -> Result<Value, String> {
+    if array.is_null(row) {
+        return Ok(Value::Null);
+    }
+
+    Ok(match array.data_type() {
+        DataType::Null => Value::Null,
+        DataType::Boolean => array.as_boolean().value(row).into(),
+        DataType::Int8 => i64::from(array.as_primitive::<Int8Type>().value(row)).into(),
+        DataType::Int16 => i64::from(array.as_primitive::<Int16Type>().value(row)).into(),
+        DataType::Int32 => i64::from(array.as_primitive::<Int32Type>().value(row)).into(),
+        DataType::Int64 => array.as_primitive::<Int64Type>().value(row).into(),
+        DataType::UInt8 => i64::from(array.as_primitive::<UInt8Type>().value(row)).into(),
+        DataType::UInt16 => i64::from(array.as_primitive::<UInt16Type>().value(row)).into(),
+        DataType::UInt32 => i64::from(array.as_primitive::<UInt32Type>().value(row)).into(),
+        DataType::UInt64 => {
+            let value = array.as_primitive::<UInt64Type>().value(row);
+            i64::try_from(value)
+                .map_err(|_| format!("integer {value} found in row {row} is out of range"))?
+                .into()
+        }
+        DataType::Float32 => {
+            Value::from_f64_or_zero(array.as_primitive::<Float32Type>().value(row).into())
+        }
+        DataType::Float64 => {
+            Value::from_f64_or_zero(array.as_primitive::<Float64Type>().value(row))
+        }
+        DataType::Decimal128(_, _) => {
+            let value = array.as_primitive::<Decimal128Type>().value_as_string(row);
+            Value::from_f64_or_zero(
+                value
+                    .parse()
+                    .map_err(|_| format!("unable to parse decimal {value} found in row {row}"))?,
+            )
+        }
+        DataType::Utf8 => array.as_string::<i32>().value(row).into(),
+        DataType::LargeUtf8 => array.as_string::<i64>().value(row).into(),
+        DataType::Utf8View => array.as_string_view().value(row).into(),
+        DataType::Binary => Bytes::copy_from_slice(array.as_binary::<i32>().value(row)).into(),
+        DataType::LargeBinary => Bytes::copy_from_slice(array.as_binary::<i64>().value(row)).into(),
+        DataType::BinaryView => Bytes::copy_from_slice(array.as_binary_view().value(row)).into(),
+        DataType::FixedSizeBinary(_) => {
+            Bytes::copy_from_slice(array.as_fixed_size_binary().value(row)).into()
+        }
+        DataType::Date32 => timestamp(array.as_primitive::<Date32Type>().value_as_datetime(row))?,
+        DataType::Date64 => timestamp(array.as_primitive::<Date64Type>().value_as_datetime(row))?,
+        DataType::Timestamp(unit, _) => timestamp(match unit {
+            TimeUnit::Second => array
+                .as_primitive::<TimestampSecondType>()
+                .value_as_datetime(row),
+            TimeUnit::Millisecond => array
+                .as_primitive::<TimestampMillisecondType>()
+                .value_as_datetime(row),
+            TimeUnit::Microsecond => array
+                .as_primitive::<TimestampMicrosecondType>()
+                .value_as_datetime(row),
+            TimeUnit::Nanosecond => array
+                .as_primitive::<TimestampNanosecondType>()
+                .value_as_datetime(row),
+        })?,
+        DataType::List(_) => list_value(array.as_list::<i32>().value(row).as_ref())?,
+        DataType::LargeList(_) => list_value(array.as_list::<i64>().value(row).as_ref())?,
+        DataType::FixedSizeList(_, _) => {
+            list_value(array.as_fixed_size_list().value(row).as_ref())?
+        }
+        DataType::Struct(fields) => {
+            let array = array.as_struct();
+            fields
+                .iter()
+                .zip(array.columns())
+                .map(|(field, column)| {
+                    array_value(column.as_ref(), row)
+                        .map(|value| (KeyString::from(field.name().as_str()), value))
+                })
+                .collect::<Result<ObjectMap, String>>()?
+                .into()
+        }
+        DataType::Map(_, _) => {
+            let entries = array.as_map().value(row);
+            let (keys, values) = (entries.column(0), entries.column(1));
+            (0..entries.len())
+                .map(|idx| {
+                    let key = match array_value(keys.as_ref(), idx)? {
+                        Value::Bytes(key) => String::from_utf8_lossy(&key).into_owned(),
+                        key => key.to_string(),
+                    };
+                    Ok((KeyString::from(key), array_value(values.as_ref(), idx)?))
+                })
+                .collect::<Result<ObjectMap, String>>()?
+                .into()
+        }
+        data_type => return Err(format!("unsupported column type {data_type} in row {row}")),
+    })
+}
+
+fn list_value(values: &dyn Array) -> Result<Value, String> {
+    (0..values.len())
+        .map(|idx| array_value(values, idx))
+        .collect::<Result<Vec<_>, String>>()
+        .map(Value::Array)
+}
+
+fn timestamp(datetime: Option<chrono::NaiveDateTime>) -> Result<Value, String> {
+    datetime
+        .map(|datetime| Value::Timestamp(datetime.and_utc()))
+        .ok_or_else(|| "timestamp out of range".to_string())
+}
@@ -1,4 +1,4 @@
-use std::{num::NonZeroU64, sync::Arc};
+use std::{num::NonZeroU64, path::PathBuf, sync::Arc};
 
 use async_trait::async_trait;
 use futures::{FutureExt, future};
@@ -14,7 +14,10 @@ use vector_lib::{
 };
 use vrl::{path::OwnedTargetPath, value::Kind};
 
-use super::{Memory, internal_events::InternalMetricsConfig, source::EXPIRED_ROUTE};
+use super::{
+    Memory, internal_events::InternalMetricsConfig, snapshot::SNAPSHOT_FILENAME,
+    source::EXPIRED_ROUTE,
+};
 use crate::{
     config::{
         EnrichmentTableConfig, SinkConfig, SinkContext, SourceConfig, SourceContext, SourceOutput,
@@ -72,6 +75,10 @@ pub struct MemoryConfig {
     #[configurable(derived)]
     #[serde(skip_serializing_if = "vector_lib::serde::is_default")]
     pub source_config: Option<MemorySourceConfig>,
+    /// Configuration for persisting the table contents to disk.
+    #[configurable(derived)]
+    #[serde(skip_serializing_if = "vector_lib::serde::is_default")]
+    pub snapshot: Option<MemorySnapshotConfig>,
     /// Field in the incoming value used as the TTL override.
     #[configurable(derived)]
     #[serde(default)]
@@ -93,6 +100,8 @@ pub struct MemoryConfig {
     cuckoo: Arc<Mutex<Option<Box<CuckooMemoryTable>>>>,
     #[serde(skip)]
     bloom: Arc<Mutex<Option<Box<BloomMemoryTable>>>>,
+    #[serde(skip)]
+    component_key: Option<ComponentKey>,
 }
 
 /// Behavior for memory enrichment table state on configuration reload.
@@ -136,6 +145,34 @@ pub struct MemorySourceConfig {
     pub source_key: String,
 }
 
+/// Configuration for memory enrichment table snapshots.
+///
+/// When enabled, the contents of the table are periodically written to disk, as well as when
+/// Vector shuts down, and restored from there when the table is added, such as when Vector starts.
+/// A table rebuilt by a configuration reload follows `reload_behavior` instead. The remaining TTL
+/// of each entry is preserved, and entries that expired while Vector was stopped are not restored.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct MemorySnapshotConfig {
+    /// The interval, in seconds, between writes of the table snapshot to disk.
+    #[serde(default = "default_snapshot_interval")]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    pub interval: NonZeroU64,
+    /// The directory used to persist the table snapshot.
+    ///
+    /// By default, the [global `data_dir` option][global_data_dir] is used.
+    /// Make sure the running user has write permissions to this directory.
+    ///
+    /// If this directory is specified, then Vector will attempt to create it.
+    ///
+    /// [global_data_dir]: https://vector.dev/docs/reference/configuration/global-options/#data_dir
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "/var/lib/vector"))]
+    #[configurable(metadata(docs::human_name = "Data Directory"))]
+    pub data_dir: Option<PathBuf>,
+}
+
 /// Configuration for memory enrichment table filter functionality.
 #[configurable_component]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -170,9 +207,11 @@ impl Default for MemoryConfig {
             memory: Arc::new(Mutex::new(None)),
             cuckoo: Arc::new(Mutex::new(None)),
             bloom: Arc::new(Mutex::new(None)),
+            component_key: None,
             max_byte_size: None,
             log_namespace: None,
             source_config: None,
+            snapshot: None,
             internal_metrics: InternalMetricsConfig::default(),
             ttl_field: OptionalValuePath::none(),
             reload_behavior: Default::default(),
@@ -189,6 +228,10 @@ const fn default_scan_interval() -> NonZeroU64 {
     unsafe { NonZeroU64::new_unchecked(30) }
 }
 
+const fn default_snapshot_interval() -> NonZeroU64 {
+    unsafe { NonZeroU64::new_unchecked(60) }
+}
+
 impl MemoryConfig {
     pub(super) async fn get_or_build_memory(
         &self,
@@ -263,6 +306,9 @@ impl EnrichmentTableConfig for MemoryConfig {
         _globals: &crate::config::GlobalOptions,
         prev_state: Option<Box<dyn std::any::Any + Send + Sync>>,
     ) -> crate::Result<Box<dyn Table + Send + Sync>> {
+        if self.filter.is_some() && self.snapshot.is_some() {
+            return Err("Snapshots are not supported for probabilistic filters.".into());
+        }
         match &self.filter {
             Some(TableFilter::Cuckoo(_)) => {
                 if self.source_config.is_some() {
@@ -294,7 +340,9 @@ impl EnrichmentTableConfig for MemoryConfig {
         &self,
         default_key: &ComponentKey,
     ) -> Option<(ComponentKey, Box<dyn SinkConfig>)> {
-        Some((default_key.clone(), Box::new(self.clone())))
+        let mut config = self.clone();
+        config.component_key = Some(default_key.clone());
+        Some((default_key.clone(), Box::new(config)))
     }
 
     fn source_config(
@@ -318,7 +366,7 @@ impl EnrichmentTableConfig for MemoryConfig {
 #[async_trait]
 #[typetag::serde(name = "memory_enrichment_table")]
 impl SinkConfig for MemoryConfig {
-    async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+    async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
         let sink = match &self.filter {
             Some(TableFilter::Cuckoo(_)) => {
                 VectorSink::from_event_streamsink(self.get_or_build_cuckoo(None).await?)
@@ -326,7 +374,23 @@ impl SinkConfig for MemoryConfig {
             Some(TableFilter::Bloom(_)) => {
                 VectorSink::from_event_streamsink(self.get_or_build_bloom(None).await?)
             }
-            None => VectorSink::from_event_streamsink(self.get_or_build_memory(None).await),
+            None => {
+                let mut memory = self.get_or_build_memory(None).await;
+                if let Some(snapshot) = &self.snapshot {
+                    let key = self
+                        .component_key
+                        .as_ref()
+                        .ok_or("Snapshots require the memory enrichment table key.")?;
+                    let path = cx
+                        .globals
+                        .resolve_and_make_data_subdir(snapshot.data_dir.as_ref(), key.id())?
+                        .join(SNAPSHOT_FILENAME);
+                    // A table rebuilt by a reload starts empty, or with its previous state, as
+                    // `reload_behavior` says.
+                    memory = memory.with_snapshot(path, cx.is_new).await;
+                }
+                VectorSink::from_event_streamsink(memory)
+            }
         };
 
         Ok((sink, future::ok(()).boxed()))
@@ -396,6 +460,7 @@ impl std::fmt::Debug for MemoryConfig {
             .field("scan_interval", &self.scan_interval)
             .field("flush_interval", &self.flush_interval)
             .field("max_byte_size", &self.max_byte_size)
+            .field("snapshot", &self.snapshot)
             .finish()
     }
 }
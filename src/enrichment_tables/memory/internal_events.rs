@@ -1,8 +1,10 @@
+use std::path::Path;
+
 use vector_lib::{
     NamedInternalEvent,
     configurable::configurable_component,
     counter, gauge,
-    internal_event::{CounterName, GaugeName, InternalEvent},
+    internal_event::{CounterName, GaugeName, InternalEvent, error_stage, error_type},
 };
 
 /// Configuration of internal metrics for enrichment memory table.
@@ -162,3 +164,29 @@ impl InternalEvent for MemoryEnrichmentTableInsertFailed<'_> {
         }
     }
 }
+
+#[derive(Debug, NamedInternalEvent)]
+pub(crate) struct MemoryEnrichmentTableSnapshotError<'a> {
+    pub error: std::io::Error,
+    pub path: &'a Path,
+    pub operation: &'static str,
+}
+
+impl InternalEvent for MemoryEnrichmentTableSnapshotError<'_> {
+    fn emit(self) {
+        error!(
+            message = "Failed to access memory enrichment table snapshot.",
+            path = ?self.path,
+            operation = self.operation,
+            error = %self.error,
+            error_type = error_type::IO_FAILED,
+            stage = error_stage::PROCESSING,
+        );
+        counter!(
+            CounterName::ComponentErrorsTotal,
+            "stage" => error_stage::PROCESSING,
+            "error_type" => error_type::IO_FAILED,
+        )
+        .increment(1);
+    }
+}
@@ -4,6 +4,7 @@ mod bloom_table;
 mod config;
 mod cuckoo_table;
 mod internal_events;
+mod snapshot;
 mod source;
 mod table;
 
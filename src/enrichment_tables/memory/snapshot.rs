@@ -0,0 +1,73 @@
+//! Persistence of memory enrichment table contents across restarts.
+
+use std::{
+    io,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Name of the snapshot file inside of the table's data directory.
+pub(super) const SNAPSHOT_FILENAME: &str = "snapshot.json";
+
+/// A single table entry as stored in a snapshot.
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub(super) struct SnapshotEntry {
+    pub(super) key: String,
+    /// The JSON-encoded value of the entry.
+    pub(super) value: String,
+    /// The remaining TTL of the entry, in seconds, at the time the snapshot was written.
+    pub(super) ttl: u64,
+}
+
+/// The contents of a memory enrichment table at a point in time.
+#[derive(Debug, Deserialize, Serialize)]
+pub(super) struct Snapshot {
+    /// Time the snapshot was written at, in seconds since the Unix epoch.
+    pub(super) written_at: u64,
+    pub(super) entries: Vec<SnapshotEntry>,
+}
+
+impl Snapshot {
+    pub(super) fn new(entries: Vec<SnapshotEntry>) -> Self {
+        Self {
+            written_at: unix_now(),
+            entries,
+        }
+    }
+
+    /// Returns the number of seconds that have passed since the snapshot was written.
+    pub(super) fn age(&self) -> u64 {
+        unix_now().saturating_sub(self.written_at)
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Reads the snapshot at `path`, returning `None` if there is none.
+pub(super) async fn read(path: &Path) -> io::Result<Option<Snapshot>> {
+    match tokio::fs::read(path).await {
+        Ok(data) => serde_json::from_slice(&data)
+            .map(Some)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Writes the snapshot to `path`.
+///
+/// The snapshot is first written to a temporary file which then replaces the previous snapshot,
+/// so a crash while writing never leaves a partial snapshot behind.
+pub(super) async fn write(path: &Path, snapshot: &Snapshot) -> io::Result<()> {
+    let data = serde_json::to_vec(snapshot)?;
+    let tmp_path = path.with_extension("json.tmp");
+    tokio::fs::write(&tmp_path, data).await?;
+    tokio::fs::rename(&tmp_path, path).await
+}
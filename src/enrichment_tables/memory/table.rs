@@ -2,6 +2,7 @@
 
 use std::{
     num::NonZeroU64,
+    path::PathBuf,
     pin::Pin,
     sync::{Arc, Mutex, MutexGuard},
     time::{Duration, Instant},
@@ -21,7 +22,7 @@ use futures::{
 use thread_local::ThreadLocal;
 use tokio::{
     sync::broadcast::{Receiver, Sender},
-    time::interval,
+    time::{interval, interval_at},
 };
 use tokio_stream::wrappers::IntervalStream;
 use vector_lib::{
@@ -37,7 +38,10 @@ use vector_lib::{
 };
 use vrl::value::{KeyString, ObjectMap, Value};
 
-use super::source::MemorySource;
+use super::{
+    snapshot::{self, Snapshot, SnapshotEntry},
+    source::MemorySource,
+};
 use crate::{
     SourceSender,
     enrichment_tables::memory::{
@@ -45,7 +49,8 @@ use crate::{
         internal_events::{
             MemoryEnrichmentTableFlushed, MemoryEnrichmentTableInsertFailed,
             MemoryEnrichmentTableInserted, MemoryEnrichmentTableRead,
-            MemoryEnrichmentTableReadFailed, MemoryEnrichmentTableTtlExpired,
+            MemoryEnrichmentTableReadFailed, MemoryEnrichmentTableSnapshotError,
+            MemoryEnrichmentTableTtlExpired,
         },
     },
 };
@@ -66,9 +71,7 @@ impl ByteSizeOf for MemoryEntry {
 
 impl MemoryEntry {
     pub(super) fn as_object_map(&self, now: Instant, key: &str) -> Result<ObjectMap, Error> {
-        let ttl = self
-            .ttl
-            .saturating_sub(now.duration_since(*self.update_time).as_secs());
+        let ttl = self.remaining_ttl(now);
         Ok(ObjectMap::from([
             (
                 KeyString::from("key"),
@@ -90,6 +93,11 @@ impl MemoryEntry {
         ]))
     }
 
+    fn remaining_ttl(&self, now: Instant) -> u64 {
+        self.ttl
+            .saturating_sub(now.duration_since(*self.update_time).as_secs())
+    }
+
     fn expired(&self, now: Instant) -> bool {
         now.duration_since(*self.update_time).as_secs() > self.ttl
     }
@@ -124,6 +132,7 @@ pub struct Memory {
     #[allow(dead_code)]
     expired_items_receiver: Receiver<Vec<MemoryEntryPair>>,
     expired_items_sender: Sender<Vec<MemoryEntryPair>>,
+    snapshot_path: Option<PathBuf>,
 }
 
 impl Memory {
@@ -144,6 +153,7 @@ impl Memory {
             })),
             expired_items_sender: expired_tx,
             expired_items_receiver: expired_rx,
+            snapshot_path: None,
         }
     }
 
@@ -160,6 +170,7 @@ impl Memory {
                 write_handle: prev_memory.write_handle,
                 expired_items_sender: prev_memory.expired_items_sender,
                 expired_items_receiver: prev_memory.expired_items_receiver,
+                snapshot_path: None,
             }
         } else {
             Self::new(config)
@@ -313,6 +324,107 @@ impl Memory {
         }
     }
 
+    /// Persists snapshots of this table to `path` while it runs as a sink, first restoring the
+    /// table from the snapshot there if `restore` is set.
+    pub(super) async fn with_snapshot(mut self, path: PathBuf, restore: bool) -> Self {
+        if restore {
+            match snapshot::read(&path).await {
+                Ok(Some(snapshot)) => {
+                    let count = self.restore_snapshot(snapshot);
+                    info!(
+                        message = "Restored memory enrichment table from snapshot.",
+                        count,
+                        path = ?path,
+                    );
+                }
+                Ok(None) => {}
+                Err(error) => emit!(MemoryEnrichmentTableSnapshotError {
+                    error,
+                    path: &path,
+                    operation: "read",
+                }),
+            }
+        }
+        self.snapshot_path = Some(path);
+        self
+    }
+
+    /// Inserts all unexpired entries of the snapshot into the table, returning the number of
+    /// entries restored.
+    fn restore_snapshot(&self, snapshot: Snapshot) -> usize {
+        let mut writer = self.write_handle.lock().expect("mutex poisoned");
+        let age = snapshot.age();
+        let now = Instant::now();
+        let mut restored = 0;
+
+        for SnapshotEntry { key, value, ttl } in snapshot.entries {
+            // Time keeps passing while Vector is stopped, so entries are expired accordingly.
+            if age > ttl {
+                continue;
+            }
+            let entry = MemoryEntry {
+                value,
+                update_time: now.into(),
+                ttl: ttl - age,
+            };
+            let entry_size = (key.size_of() + entry.size_of()) as u64;
+            if let Some(max_byte_size) = self.config.max_byte_size
+                && writer.metadata.byte_size.saturating_add(entry_size) > max_byte_size
+            {
+                emit!(MemoryEnrichmentTableInsertFailed {
+                    key: &key,
+                    include_key_metric_tag: self.config.internal_metrics.include_key_tag
+                });
+                continue;
+            }
+            writer.metadata.byte_size = writer.metadata.byte_size.saturating_add(entry_size);
+            writer.write_handle.update(key, entry);
+            restored += 1;
+        }
+
+        self.flush(writer);
+        restored
+    }
+
+    /// Captures all unexpired entries currently visible in the table.
+    fn take_snapshot(&self) -> Snapshot {
+        let now = Instant::now();
+        let entries = self
+            .get_read_handle()
+            .read()
+            .map(|reader| {
+                reader
+                    .iter()
+                    .filter_map(|(key, values)| {
+                        values
+                            .get_one()
+                            .filter(|entry| !entry.expired(now))
+                            .map(|entry| SnapshotEntry {
+                                key: key.clone(),
+                                value: entry.value.clone(),
+                                ttl: entry.remaining_ttl(now),
+                            })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Snapshot::new(entries)
+    }
+
+    async fn write_snapshot(&self) {
+        let Some(path) = &self.snapshot_path else {
+            return;
+        };
+        let snapshot = self.take_snapshot();
+        if let Err(error) = snapshot::write(path, &snapshot).await {
+            emit!(MemoryEnrichmentTableSnapshotError {
+                error,
+                path,
+                operation: "write",
+            });
+        }
+    }
+
     pub(crate) fn as_source(
         &self,
         shutdown: ShutdownSignal,
@@ -337,6 +449,7 @@ impl Clone for Memory {
             config: self.config.clone(),
             expired_items_sender: self.expired_items_sender.clone(),
             expired_items_receiver: self.expired_items_sender.subscribe(),
+            snapshot_path: self.snapshot_path.clone(),
         }
     }
 }
@@ -441,6 +554,17 @@ impl StreamSink<Event> for Memory {
         let mut scan_interval = IntervalStream::new(interval(Duration::from_secs(
             self.config.scan_interval.into(),
         )));
+        let mut snapshot_interval: Pin<Box<dyn Stream<Item = tokio::time::Instant> + Send>> =
+            match (&self.snapshot_path, &self.config.snapshot) {
+                (Some(_), Some(snapshot)) => {
+                    let period = Duration::from_secs(snapshot.interval.get());
+                    Box::pin(IntervalStream::new(interval_at(
+                        tokio::time::Instant::now() + period,
+                        period,
+                    )))
+                }
+                _ => Box::pin(stream::empty()),
+            };
 
         loop {
             tokio::select! {
@@ -475,8 +599,19 @@ impl StreamSink<Event> for Memory {
                     let writer = self.write_handle.lock().expect("mutex poisoned");
                     self.scan(writer);
                 }
+
+                Some(_) = snapshot_interval.next() => {
+                    self.write_snapshot().await;
+                }
             }
         }
+
+        if self.snapshot_path.is_some() {
+            // Make pending writes visible, so they are included in the final snapshot.
+            let writer = self.write_handle.lock().expect("mutex poisoned");
+            self.flush(writer);
+            self.write_snapshot().await;
+        }
         Ok(())
     }
 }
@@ -877,6 +1012,98 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    async fn restores_snapshot_written_on_shutdown() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let path = data_dir.path().join(snapshot::SNAPSHOT_FILENAME);
+        let memory = Memory::new(build_memory_config(|c| {
+            c.flush_interval = Some(NonZeroU64::new(1000).unwrap());
+        }))
+        .with_snapshot(path.clone(), true)
+        .await;
+        let event = Event::Log(LogEvent::from(ObjectMap::from([(
+            "test_key".into(),
+            Value::from(5),
+        )])));
+
+        Box::new(memory)
+            .run(Box::pin(stream::once(ready(event))))
+            .await
+            .unwrap();
+        assert!(path.exists());
+
+        let restored = Memory::new(Default::default())
+            .with_snapshot(path, true)
+            .await;
+
+        let condition = Condition::Equals {
+            field: "key",
+            value: Value::from("test_key"),
+        };
+        let row = restored
+            .find_table_row(Case::Sensitive, &[condition], None, None, None)
+            .unwrap();
+        assert_eq!(Some(&Value::from(5)), row.get("value"));
+        let ttl = row.get("ttl").and_then(Value::as_integer).unwrap();
+        assert!((599..=600).contains(&ttl));
+    }
+
+    #[test]
+    fn skips_expired_snapshot_entries() {
+        let memory = Memory::new(Default::default());
+        let snapshot = Snapshot {
+            written_at: Snapshot::new(Vec::new()).written_at - 100,
+            entries: vec![
+                SnapshotEntry {
+                    key: "expired_key".to_string(),
+                    value: "1".to_string(),
+                    ttl: 50,
+                },
+                SnapshotEntry {
+                    key: "test_key".to_string(),
+                    value: "2".to_string(),
+                    ttl: 300,
+                },
+            ],
+        };
+
+        assert_eq!(1, memory.restore_snapshot(snapshot));
+        assert!(memory.get_read_handle().get_one("expired_key").is_none());
+
+        let condition = Condition::Equals {
+            field: "key",
+            value: Value::from("test_key"),
+        };
+        let row = memory
+            .find_table_row(Case::Sensitive, &[condition], None, None, None)
+            .unwrap();
+        assert_eq!(Some(&Value::from(2)), row.get("value"));
+        // Allow for the clock ticking over between writing and restoring the snapshot.
+        let ttl = row.get("ttl").and_then(Value::as_integer).unwrap();
+        assert!((199..=200).contains(&ttl));
+    }
+
+    #[tokio::test]
+    async fn restores_snapshot_only_when_asked() {
+        let data_dir = tempfile::tempdir().unwrap();
+        let path = data_dir.path().join(snapshot::SNAPSHOT_FILENAME);
+        let memory = Memory::new(Default::default());
+        memory.handle_value(ObjectMap::from([("test_key".into(), Value::from(5))]));
+        snapshot::write(&path, &memory.take_snapshot())
+            .await
+            .unwrap();
+
+        let added = Memory::new(Default::default())
+            .with_snapshot(path.clone(), true)
+            .await;
+        assert!(added.get_read_handle().get_one("test_key").is_some());
+
+        let reloaded = Memory::new(Default::default())
+            .with_snapshot(path, false)
+            .await;
+        assert!(reloaded.get_read_handle().get_one("test_key").is_none());
+    }
+
     #[tokio::test]
     async fn flush_metrics_without_interval() {
         let event = Event::Log(LogEvent::from(ObjectMap::from([(
@@ -29,3 +29,12 @@ impl InternalEvent for AggregateUpdateFailed {
         counter!(CounterName::AggregateFailedUpdates).increment(1);
     }
 }
+
+#[derive(Debug, NamedInternalEvent)]
+pub struct AggregateLateEvent;
+
+impl InternalEvent for AggregateLateEvent {
+    fn emit(self) {
+        counter!(CounterName::AggregateLateEventsTotal).increment(1);
+    }
+}
@@ -86,3 +86,27 @@ impl InternalEvent for HttpClientHttpError {
         .increment(1);
     }
 }
+
+#[derive(Debug, NamedInternalEvent)]
+pub struct HttpClientCheckpointError {
+    pub error: std::io::Error,
+    pub path: String,
+}
+
+impl InternalEvent for HttpClientCheckpointError {
+    fn emit(self) {
+        error!(
+            message = "Could not write pagination checkpoint.",
+            path = ?self.path,
+            error = %self.error,
+            error_type = error_type::IO_FAILED,
+            stage = error_stage::PROCESSING,
+        );
+        counter!(
+            CounterName::ComponentErrorsTotal,
+            "stage" => error_stage::PROCESSING,
+            "error_type" => error_type::IO_FAILED,
+        )
+        .increment(1);
+    }
+}
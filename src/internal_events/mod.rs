@@ -106,6 +106,8 @@ mod mongodb_metrics;
 mod mqtt;
 #[cfg(feature = "sources-nginx_metrics")]
 mod nginx_metrics;
+#[cfg(feature = "sources-okta")]
+mod okta;
 mod open;
 #[cfg(any(
     feature = "sources-kubernetes_logs",
@@ -258,6 +260,8 @@ pub(crate) use self::metric_to_log::*;
 pub(crate) use self::mqtt::*;
 #[cfg(feature = "sources-nginx_metrics")]
 pub(crate) use self::nginx_metrics::*;
+#[cfg(feature = "sources-okta")]
+pub(crate) use self::okta::*;
 #[cfg(any(
     feature = "sources-kubernetes_logs",
     feature = "transforms-log_to_metric",
@@ -0,0 +1,28 @@
+use vector_lib::{
+    NamedInternalEvent, counter,
+    internal_event::{CounterName, InternalEvent, error_stage, error_type},
+};
+
+#[derive(Debug, NamedInternalEvent)]
+pub struct OktaCheckpointSetError {
+    pub error: std::io::Error,
+    pub filename: String,
+}
+
+impl InternalEvent for OktaCheckpointSetError {
+    fn emit(self) {
+        error!(
+            message = "Could not set okta checkpoint.",
+            filename = ?self.filename,
+            error = %self.error,
+            error_type = error_type::IO_FAILED,
+            stage = error_stage::PROCESSING,
+        );
+        counter!(
+            CounterName::ComponentErrorsTotal,
+            "stage" => error_stage::PROCESSING,
+            "error_type" => error_type::IO_FAILED,
+        )
+        .increment(1);
+    }
+}
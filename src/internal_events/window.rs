@@ -12,3 +12,16 @@ vector_lib::registered_event!(
         self.events_dropped.emit(data);
     }
 );
+
+vector_lib::registered_event!(
+    WindowStaleEventsDropped => {
+        events_dropped: Registered<ComponentEventsDropped<'static, INTENTIONAL>>
+            = register!(ComponentEventsDropped::<INTENTIONAL>::from(
+                "The window for the key was expired or evicted"
+            )),
+    }
+
+    fn emit(&self, data: Count) {
+        self.events_dropped.emit(data);
+    }
+);
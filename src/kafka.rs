@@ -1,7 +1,14 @@
 #![allow(missing_docs)]
-use std::path::{Path, PathBuf};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, LazyLock, RwLock},
+};
 
-use rdkafka::{ClientConfig, ClientContext, Statistics, consumer::ConsumerContext};
+use rdkafka::{
+    ClientConfig, ClientContext, Statistics,
+    consumer::{ConsumerContext, ConsumerGroupMetadata},
+};
 use snafu::Snafu;
 use tracing::Span;
 use vector_lib::{configurable::configurable_component, sensitive_string::SensitiveString};
@@ -183,3 +190,110 @@ impl ClientContext for KafkaStatisticsContext {
 }
 
 impl ConsumerContext for KafkaStatisticsContext {}
+
+/// The consumer groups of the `kafka` sources in this process, keyed by group ID.
+///
+/// The `kafka` sink uses their metadata to commit the offsets of the events that a source consumed
+/// within its transactions. A group has no metadata until partitions are first assigned to it, and
+/// the metadata changes with each rebalance of the group, so sources update their entry whenever
+/// partitions are assigned to them.
+static CONSUMER_GROUPS: LazyLock<RwLock<HashMap<String, ConsumerGroup>>> =
+    LazyLock::new(Default::default);
+
+#[derive(Default)]
+struct ConsumerGroup {
+    /// The number of registrations of the group that haven't been dropped yet.
+    ///
+    /// On a reload, the new source registers the group before the old one is dropped.
+    registrations: usize,
+
+    /// Whether the source that registered the group last has acknowledgements enabled.
+    acknowledgements: bool,
+
+    metadata: Option<Arc<ConsumerGroupMetadata>>,
+}
+
+/// Registers the consumer group of a `kafka` source until it's dropped.
+#[cfg(feature = "sources-kafka")]
+pub(crate) struct ConsumerGroupRegistration {
+    group_id: String,
+}
+
+#[cfg(feature = "sources-kafka")]
+impl ConsumerGroupRegistration {
+    pub(crate) fn new(group_id: &str, acknowledgements: bool) -> Self {
+        let mut groups = CONSUMER_GROUPS.write().expect("poisoned lock");
+        let group = groups.entry(group_id.to_string()).or_default();
+        group.registrations += 1;
+        group.acknowledgements = acknowledgements;
+
+        Self {
+            group_id: group_id.to_string(),
+        }
+    }
+
+    pub(crate) fn update(&self, metadata: ConsumerGroupMetadata) {
+        if let Some(group) = CONSUMER_GROUPS
+            .write()
+            .expect("poisoned lock")
+            .get_mut(&self.group_id)
+        {
+            group.metadata = Some(Arc::new(metadata));
+        }
+    }
+}
+
+#[cfg(feature = "sources-kafka")]
+impl Drop for ConsumerGroupRegistration {
+    fn drop(&mut self) {
+        let mut groups = CONSUMER_GROUPS.write().expect("poisoned lock");
+        if let Some(group) = groups.get_mut(&self.group_id) {
+            group.registrations -= 1;
+            if group.registrations == 0 {
+                groups.remove(&self.group_id);
+            }
+        }
+    }
+}
+
+/// Returns whether the `kafka` source that consumes with the group has acknowledgements enabled,
+/// or `None` if no source consumes with it.
+#[cfg(feature = "sinks-kafka")]
+pub(crate) fn consumer_group_acknowledgements(group_id: &str) -> Option<bool> {
+    CONSUMER_GROUPS
+        .read()
+        .expect("poisoned lock")
+        .get(group_id)
+        .map(|group| group.acknowledgements)
+}
+
+#[cfg(feature = "sinks-kafka")]
+pub(crate) fn consumer_group_metadata(group_id: &str) -> Option<Arc<ConsumerGroupMetadata>> {
+    CONSUMER_GROUPS
+        .read()
+        .expect("poisoned lock")
+        .get(group_id)
+        .and_then(|group| group.metadata.clone())
+}
+
+#[cfg(all(test, feature = "sources-kafka", feature = "sinks-kafka"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consumer_group_registrations() {
+        let group_id = "consumer-group-registrations";
+        assert_eq!(consumer_group_acknowledgements(group_id), None);
+
+        // A reload registers the group of the new source before the old one is dropped.
+        let old = ConsumerGroupRegistration::new(group_id, false);
+        assert_eq!(consumer_group_acknowledgements(group_id), Some(false));
+        let new = ConsumerGroupRegistration::new(group_id, true);
+        assert_eq!(consumer_group_acknowledgements(group_id), Some(true));
+
+        drop(old);
+        assert_eq!(consumer_group_acknowledgements(group_id), Some(true));
+        drop(new);
+        assert_eq!(consumer_group_acknowledgements(group_id), None);
+    }
+}
@@ -73,6 +73,7 @@ pub mod app;
 pub mod async_read;
 #[cfg(feature = "aws-config")]
 pub mod aws;
+pub mod buffer;
 pub mod common;
 pub mod completion;
 mod convert_config;
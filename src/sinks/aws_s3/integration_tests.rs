@@ -45,7 +45,7 @@ use tempfile::TempDir;
 use tokio_stream::StreamExt;
 use vector_common::decompression::CappedDecoder;
 use vector_lib::{
-    buffers::{BufferConfig, BufferType, WhenFull},
+    buffers::{BufferConfig, BufferType, DiskBufferCompression, WhenFull},
     codecs::{TextSerializerConfig, encoding::FramingConfig},
     config::{ComponentKey, proxy::ProxyConfig},
     event::{BatchNotifier, BatchStatus, BatchStatusReceiver, Event, EventArray, LogEvent},
@@ -600,6 +600,9 @@ async fn s3_disk_buffer_reload_delivers_all_events() {
     old_config.sinks[&sink_key].buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: NonZeroU64::new(268435488).unwrap(),
         when_full: WhenFull::Block,
+        compression: DiskBufferCompression::None,
+        encryption: None,
+        max_age: None,
     });
 
     // Clone config before building so we can create the reload config.
@@ -607,6 +610,9 @@ async fn s3_disk_buffer_reload_delivers_all_events() {
     new_config.sinks[&sink_key].buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: NonZeroU64::new(536870912).unwrap(),
         when_full: WhenFull::Block,
+        compression: DiskBufferCompression::None,
+        encryption: None,
+        max_age: None,
     });
 
     // 1. Start topology with initial disk buffer config.
@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     convert::TryFrom,
     num::NonZeroU64,
     path::{Path, PathBuf},
@@ -16,6 +17,7 @@ use serde_with::serde_as;
 use tokio::{
     fs::{self, File},
     io::AsyncWriteExt,
+    task::JoinHandle,
 };
 use tokio_util::{codec::Encoder as _, time::delay_queue::Expired};
 use vector_lib::{
@@ -46,8 +48,10 @@ use crate::{
 };
 
 mod bytes_path;
+mod rotation;
 
 use bytes_path::BytesPath;
+pub use rotation::{FileRotationConfig, RotationNaming};
 
 /// Configuration for the `file` sink.
 #[serde_as]
@@ -119,6 +123,10 @@ pub struct FileSinkConfig {
     #[configurable(derived)]
     #[serde(default)]
     pub truncate: FileTruncateConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub rotation: FileRotationConfig,
 }
 
 /// Configuration for truncating files.
@@ -148,6 +156,7 @@ impl GenerateConfig for FileSinkConfig {
             timezone: Default::default(),
             internal_metrics: Default::default(),
             truncate: Default::default(),
+            rotation: Default::default(),
             base_dir: None,
             confinement: ConfinementConfig::default(),
         })
@@ -183,6 +192,7 @@ pub enum Compression {
 
 struct OutFile {
     created_at: Instant,
+    bytes_written: u64,
     inner: OutFileInner,
 }
 
@@ -193,9 +203,10 @@ enum OutFileInner {
 }
 
 impl OutFile {
-    fn new(file: File, compression: Compression) -> Self {
+    fn new(file: File, compression: Compression, bytes_written: u64) -> Self {
         Self {
             created_at: Instant::now(),
+            bytes_written,
             inner: match compression {
                 Compression::None => OutFileInner::Regular(file),
                 Compression::Gzip => OutFileInner::Gzip(GzipEncoder::new(file)),
@@ -225,13 +236,20 @@ impl OutFile {
             OutFileInner::Regular(file) => file.write_all(src).await,
             OutFileInner::Gzip(gzip) => gzip.write_all(src).await,
             OutFileInner::Zstd(zstd) => zstd.write_all(src).await,
-        }
+        }?;
+        self.bytes_written += src.len() as u64;
+        Ok(())
     }
 
     const fn created_at(&self) -> Instant {
         self.created_at
     }
 
+    /// The size of the file, plus the uncompressed bytes written to it since it was opened.
+    const fn bytes_written(&self) -> u64 {
+        self.bytes_written
+    }
+
     /// Shutdowns by flushing data, writing headers, and syncing all of that
     /// data and metadata to the filesystem.
     async fn close(&mut self) -> Result<(), std::io::Error> {
@@ -277,6 +295,14 @@ pub struct FileSink {
     events_sent: Registered<EventsSent>,
     include_file_metric_tag: bool,
     truncation_config: FileTruncateConfig,
+    rotation: FileRotationConfig,
+    /// When the files were created, which `rotation.interval_secs` counts from. Paths keep their
+    /// entry while their file is closed for being idle, so reopening it doesn't reset its age,
+    /// until the file is due for rotation. Reopening a file after that counts from when it was
+    /// created on disk, if the filesystem records it.
+    created_at: HashMap<Bytes, tokio::time::Instant>,
+    /// The background tasks that finish the latest rotation of each path.
+    rotations: HashMap<Bytes, JoinHandle<()>>,
     confinement: Option<PathConfinement>,
 }
 
@@ -303,6 +329,12 @@ impl FileSink {
             ));
         }
 
+        if config.rotation.compression != Compression::None
+            && config.compression != Compression::None
+        {
+            return Err("`rotation.compression` can't be combined with `compression`, as files are already compressed as they're written.".into());
+        }
+
         let confinement = if config
             .confinement
             .dangerously_allow_unconfined_template_resolution
@@ -324,6 +356,9 @@ impl FileSink {
             events_sent: register!(EventsSent::from(Output(None))),
             include_file_metric_tag: config.internal_metrics.include_file_tag,
             truncation_config: config.truncate.clone(),
+            rotation: config.rotation.clone(),
+            created_at: HashMap::new(),
+            rotations: HashMap::new(),
             confinement,
         })
     }
@@ -362,7 +397,8 @@ impl FileSink {
     }
 
     fn deadline_at(&self) -> Instant {
-        Instant::now()
+        tokio::time::Instant::now()
+            .into_std()
             .checked_add(self.idle_timeout)
             .expect("unable to compute next deadline")
     }
@@ -397,6 +433,11 @@ impl FileSink {
                                 count: 0
                             });
 
+                            // Let the files that were rotated last finish compressing.
+                            for (_, task) in self.rotations.drain() {
+                                _ = task.await;
+                            }
+
                             break;
                         }
                     }
@@ -409,7 +450,7 @@ impl FileSink {
                         Some((expired_file, path)) => {
                             // We got an expired file. All we really want is to
                             // flush and close it.
-                            self.close_file(expired_file, path).await;
+                            self.close_idle_file(expired_file, path).await;
                         }
                     }
                 }
@@ -437,52 +478,30 @@ impl FileSink {
 
         let bytes_path = BytesPath::new(path.clone());
         let truncate = self.should_truncate(&bytes_path, &path).await;
-        let file = if !truncate && let Some(file) = self.files.reset_at(&path, next_deadline) {
+        if !truncate && self.files.reset_at(&path, next_deadline).is_some() {
             trace!(message = "Working with an already opened file.", path = ?path);
-            file
-        } else {
-            trace!(message = "Opening new file.", ?path);
-            let file = match open_file(bytes_path, truncate, self.confinement.as_mut()).await {
-                Ok(file) => file,
-                Err(OpenError::Io(error)) => {
-                    // We couldn't open the file for this event.
-                    // Maybe other events will work though! Just log
-                    // the error and skip this event.
-                    emit!(FileIoError {
-                        code: "failed_opening_file",
-                        message: "Unable to open the file.",
-                        error,
-                        path: &path,
-                        dropped_events: 1,
-                    });
-                    event.metadata().update_status(EventStatus::Errored);
-                    return;
-                }
-                Err(OpenError::Confine(error)) => {
-                    let rendered = bytes_to_path(&path);
-                    let base = self
-                        .confinement
-                        .as_ref()
-                        .map(|c| c.base_dir().to_path_buf())
-                        .unwrap_or_default();
-                    emit!(FilePathOutsideBaseDirError {
-                        path: &rendered,
-                        base_dir: &base,
-                        error,
-                    });
-                    event.metadata().update_status(EventStatus::Errored);
-                    return;
-                }
-            };
-
-            let outfile = OutFile::new(file, self.compression);
+        } else if !self.open(&path, truncate, next_deadline).await {
+            event.metadata().update_status(EventStatus::Errored);
+            return;
+        }
 
-            self.files.insert_at(path.clone(), outfile, next_deadline);
-            emit!(FileOpen {
-                count: self.files.len()
-            });
-            self.files.get_mut(&path).unwrap()
-        };
+        // Files are checked once they're opened too, so that files that are closed for being idle
+        // between events are still rotated.
+        if self.rotation.is_enabled()
+            && self.files.get(&path).is_some_and(|file| {
+                self.rotation
+                    .is_due(file, self.created_at.get(&path).copied())
+            })
+            && let Some((file, expired_path)) = self.files.remove(&path)
+        {
+            self.close_file(file, expired_path).await;
+            self.rotate_file(&path).await;
+            if !self.open(&path, false, next_deadline).await {
+                event.metadata().update_status(EventStatus::Errored);
+                return;
+            }
+        }
+        let file = self.files.get_mut(&path).unwrap();
 
         trace!(message = "Writing an event to file.", path = ?path);
         let event_size = event.estimated_json_encoded_size_of();
@@ -510,6 +529,72 @@ impl FileSink {
         }
     }
 
+    /// Opens the file for `path` until `deadline`, returning whether it was opened. Errors are
+    /// reported here, so the caller only needs to fail the event.
+    async fn open(&mut self, path: &Bytes, truncate: bool, deadline: Instant) -> bool {
+        trace!(message = "Opening new file.", ?path);
+        let bytes_path = BytesPath::new(path.clone());
+        let file = match open_file(bytes_path, truncate, self.confinement.as_mut()).await {
+            Ok(file) => file,
+            Err(OpenError::Io(error)) => {
+                // We couldn't open the file for this event.
+                // Maybe other events will work though! Just log
+                // the error and skip this event.
+                emit!(FileIoError {
+                    code: "failed_opening_file",
+                    message: "Unable to open the file.",
+                    error,
+                    path,
+                    dropped_events: 1,
+                });
+                return false;
+            }
+            Err(OpenError::Confine(error)) => {
+                let rendered = bytes_to_path(path);
+                let base = self
+                    .confinement
+                    .as_ref()
+                    .map(|c| c.base_dir().to_path_buf())
+                    .unwrap_or_default();
+                emit!(FilePathOutsideBaseDirError {
+                    path: &rendered,
+                    base_dir: &base,
+                    error,
+                });
+                return false;
+            }
+        };
+
+        let metadata = file.metadata().await.ok();
+        if self.rotation.interval_secs.is_some() {
+            let now = tokio::time::Instant::now();
+            if truncate {
+                self.created_at.insert(path.clone(), now);
+            } else {
+                // Files that already existed, such as before a restart, count from when they were
+                // created.
+                self.created_at.entry(path.clone()).or_insert_with(|| {
+                    metadata
+                        .as_ref()
+                        .and_then(|metadata| metadata.created().ok())
+                        .and_then(|created| created.elapsed().ok())
+                        .and_then(|age| now.checked_sub(age))
+                        .unwrap_or(now)
+                });
+            }
+        }
+
+        // Appending to an existing file counts its size towards `rotation.max_bytes`.
+        let size = metadata.map_or(0, |metadata| metadata.len());
+        let outfile = OutFile::new(file, self.compression, size);
+
+        self.files.insert_at(path.clone(), outfile, deadline);
+        emit!(FileOpen {
+            count: self.files.len()
+        });
+        true
+    }
+
     async fn should_truncate(&mut self, bytes_path: &BytesPath, path: &bytes::Bytes) -> bool {
         let mut truncate = false;
 
@@ -549,6 +634,86 @@ impl FileSink {
         truncate
     }
 
+    async fn rotate_file(&mut self, path: &Bytes) {
+        let rendered = bytes_to_path(path);
+        let (Some(parent), Some(file_name)) = (rendered.parent(), rendered.file_name()) else {
+            return;
+        };
+
+        // Rotation renames and deletes files next to the rendered path, so the directory is
+        // verified to be inside `base_dir` just like when opening a file. If it isn't, opening the
+        // file for the event fails the same way and reports it.
+        let dir = match self.confinement.as_mut() {
+            Some(confinement) => match confinement.verify_parent(parent).await {
+                Ok(dir) => dir,
+                Err(_) => return,
+            },
+            None => parent.to_path_buf(),
+        };
+
+        // The previous rotation of the path must be done compressing before its rotated files are
+        // renamed again.
+        if let Some(task) = self.rotations.remove(path) {
+            _ = task.await;
+        }
+        self.rotations.retain(|_, task| !task.is_finished());
+
+        let rotated = match rotation::rotate(&dir, file_name, &self.rotation).await {
+            Ok(Some(rotated)) => rotated,
+            Ok(None) => return,
+            Err(error) => {
+                emit!(FileIoError {
+                    error,
+                    code: "failed_rotating_file",
+                    message: "Failed to rotate the file.",
+                    path,
+                    dropped_events: 0,
+                });
+                return;
+            }
+        };
+        self.created_at.remove(path);
+
+        let file_name = file_name.to_os_string();
+        let config = self.rotation.clone();
+        let task_path = path.clone();
+        let task = crate::spawn_in_current_span(async move {
+            if let Err(error) = rotation::finish_rotation(&dir, &file_name, &rotated, &config).await
+            {
+                emit!(FileIoError {
+                    error,
+                    code: "failed_rotating_file",
+                    message: "Failed to compress or prune rotated files.",
+                    path: &task_path,
+                    dropped_events: 0,
+                });
+            }
+        });
+        self.rotations.insert(path.clone(), task);
+    }
+
+    /// Closes a file that's been idle, rotating it if it's due.
+    ///
+    /// The ages of the other closed files that are due are dropped too, as they're only kept so
+    /// that reopening a file before it's due doesn't reset its age.
+    async fn close_idle_file(&mut self, file: OutFile, path: Expired<Bytes>) {
+        let due = self.rotation.is_enabled()
+            && self
+                .rotation
+                .is_due(&file, self.created_at.get(path.get_ref()).copied());
+        let rendered = path.get_ref().clone();
+        self.close_file(file, path).await;
+
+        if due {
+            self.rotate_file(&rendered).await;
+        }
+        let files = &self.files;
+        let rotation = &self.rotation;
+        self.created_at.retain(|path, created_at| {
+            files.get(path).is_some() || !rotation.is_due_by_age(*created_at)
+        });
+    }
+
     async fn close_file(&self, mut file: OutFile, path: Expired<Bytes>) {
         if let Err(error) = file.close().await {
             emit!(FileIoError {
@@ -740,7 +905,7 @@ impl StreamSink<Event> for FileSink {
 
 #[cfg(test)]
 mod tests {
-    use std::convert::TryInto;
+    use std::{convert::TryInto, num::NonZeroUsize};
 
     use chrono::{SubsecRound, Utc};
     use futures::{SinkExt, stream};
@@ -783,6 +948,7 @@ mod tests {
                 include_file_tag: true,
             },
             truncate: Default::default(),
+            rotation: Default::default(),
             base_dir: None,
             confinement: ConfinementConfig::default(),
         };
@@ -812,6 +978,7 @@ mod tests {
                 include_file_tag: true,
             },
             truncate: Default::default(),
+            rotation: Default::default(),
             base_dir: None,
             confinement: ConfinementConfig::default(),
         };
@@ -841,6 +1008,7 @@ mod tests {
                 include_file_tag: true,
             },
             truncate: Default::default(),
+            rotation: Default::default(),
             base_dir: None,
             confinement: ConfinementConfig::default(),
         };
@@ -875,6 +1043,7 @@ mod tests {
                 include_file_tag: true,
             },
             truncate: Default::default(),
+            rotation: Default::default(),
             base_dir: None,
             confinement: ConfinementConfig::default(),
         };
@@ -986,6 +1155,7 @@ mod tests {
                 include_file_tag: true,
             },
             truncate: Default::default(),
+            rotation: Default::default(),
             base_dir: None,
             confinement: ConfinementConfig::default(),
         };
@@ -1045,6 +1215,7 @@ mod tests {
                 include_file_tag: true,
             },
             truncate: Default::default(),
+            rotation: Default::default(),
             base_dir: None,
             confinement: ConfinementConfig::default(),
         };
@@ -1079,6 +1250,7 @@ mod tests {
                 include_file_tag: true,
             },
             truncate: Default::default(),
+            rotation: Default::default(),
             base_dir: None,
             confinement: ConfinementConfig::default(),
         };
@@ -1133,6 +1305,7 @@ mod tests {
                 include_file_tag: true,
             },
             truncate: Default::default(),
+            rotation: Default::default(),
             base_dir: None,
             confinement: ConfinementConfig::default(),
         };
@@ -1147,6 +1320,96 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn log_rotation_by_size() {
+        let template = temp_file();
+
+        let mut config = base_config(template.to_str().unwrap());
+        config.rotation = FileRotationConfig {
+            max_bytes: NonZeroU64::new(1),
+            max_files: NonZeroUsize::new(2),
+            ..Default::default()
+        };
+
+        let input = vec!["first", "second", "third", "fourth"];
+        let events = input.iter().map(|line| Event::Log(LogEvent::from(*line)));
+
+        run_assert_sink(&config, events).await;
+
+        let rotated = |number: usize| PathBuf::from(format!("{}.{number}", template.display()));
+        assert_eq!(lines_from_file(&template), ["fourth"]);
+        assert_eq!(lines_from_file(rotated(1)), ["third"]);
+        assert_eq!(lines_from_file(rotated(2)), ["second"]);
+        assert!(!rotated(3).exists());
+    }
+
+    #[tokio::test]
+    async fn log_rotation_compresses_in_background() {
+        let template = temp_file();
+
+        let mut config = base_config(template.to_str().unwrap());
+        config.rotation = FileRotationConfig {
+            max_bytes: NonZeroU64::new(1),
+            max_files: NonZeroUsize::new(2),
+            compression: Compression::Gzip,
+            ..Default::default()
+        };
+
+        let input = vec!["first", "second", "third", "fourth"];
+        let events = input.iter().map(|line| Event::Log(LogEvent::from(*line)));
+
+        // The sink waits for the last rotated file to be compressed before it stops.
+        run_assert_sink(&config, events).await;
+
+        let rotated = |name: &str| PathBuf::from(format!("{}.{name}", template.display()));
+        assert_eq!(lines_from_file(&template), ["fourth"]);
+        assert_eq!(lines_from_gzip_file(rotated("1.gz")), ["third"]);
+        assert_eq!(lines_from_gzip_file(rotated("2.gz")), ["second"]);
+        assert!(!rotated("1").exists());
+        assert!(!rotated("3.gz").exists());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn log_rotation_by_age_survives_idle_close() {
+        trace_init();
+
+        let template = temp_file();
+
+        let mut config = base_config(template.to_str().unwrap());
+        config.idle_timeout = Duration::from_secs(1);
+        config.rotation = FileRotationConfig {
+            interval_secs: NonZeroU64::new(2),
+            ..Default::default()
+        };
+
+        let (mut tx, rx) = futures::channel::mpsc::channel(0);
+
+        let sink_handle = tokio::spawn(async move {
+            assert_sink_compliance(&FILE_SINK_TAGS, async move {
+                let sink = FileSink::new(&config, SinkContext::default()).unwrap();
+                VectorSink::from_event_streamsink(sink)
+                    .run(Box::pin(rx.map(Into::into)))
+                    .await
+                    .expect("Running sink failed");
+            })
+            .await
+        });
+
+        // Each event comes after the file was closed for being idle. The file is due when it's
+        // closed after the second event, so the last one is written to a new file.
+        for line in ["first", "second", "third"] {
+            tx.send(Event::Log(LogEvent::from(line))).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(1500)).await;
+        }
+
+        drop(tx);
+        sink_handle.await.unwrap();
+
+        let rotated = PathBuf::from(format!("{}.1", template.display()));
+        assert_eq!(lines_from_file(&template), ["third"]);
+        assert_eq!(lines_from_file(rotated), ["first", "second"]);
+    }
+
     fn base_config(path: &str) -> FileSinkConfig {
         FileSinkConfig {
             path: path.try_into().unwrap(),
@@ -1157,6 +1420,7 @@ mod tests {
             timezone: Default::default(),
             internal_metrics: Default::default(),
             truncate: Default::default(),
+            rotation: Default::default(),
             base_dir: None,
             confinement: ConfinementConfig::default(),
         }
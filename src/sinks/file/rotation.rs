@@ -0,0 +1,445 @@
+use std::{
+    ffi::{OsStr, OsString},
+    io,
+    num::{NonZeroU64, NonZeroUsize},
+    path::{Path, PathBuf},
+};
+
+use chrono::{NaiveDateTime, TimeDelta, Utc};
+use tokio::{
+    fs::{self, File},
+    io::AsyncReadExt,
+    time::Instant,
+};
+use vector_lib::configurable::configurable_component;
+
+use super::{Compression, OutFile};
+
+/// The format of the time appended to timestamped rotated files.
+const TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%S%.3fZ";
+
+/// Configuration for rotating files.
+///
+/// When a file is due for rotation, it's closed and renamed, and the next event for its path opens
+/// a new file. Files are checked for rotation when an event is written to them, and when they're
+/// closed for being idle.
+#[configurable_component]
+#[derive(Clone, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct FileRotationConfig {
+    /// Rotates a file once this many bytes have been written to it.
+    ///
+    /// When `compression` is set, this counts the bytes before compression.
+    #[serde(default)]
+    #[configurable(metadata(docs::type_unit = "bytes"))]
+    #[configurable(metadata(docs::examples = 104857600))]
+    pub max_bytes: Option<NonZeroU64>,
+
+    /// Rotates a file once this many seconds have passed since it was created.
+    ///
+    /// Closing an idle file doesn't reset its age. Files that already exist when the sink starts
+    /// count from their creation time, if the filesystem records it.
+    #[serde(default)]
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    #[configurable(metadata(docs::examples = 900))]
+    pub interval_secs: Option<NonZeroU64>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub naming: RotationNaming,
+
+    /// Compresses files once they're rotated.
+    ///
+    /// Files are compressed in the background, so the sink keeps writing events meanwhile.
+    ///
+    /// This can't be combined with the sink's `compression` option, which compresses files as
+    /// they're written.
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub compression: Compression,
+
+    /// The maximum number of rotated files to keep for each path.
+    ///
+    /// Once there are more, the oldest rotated files are deleted. Only files in the same directory
+    /// as the path are deleted, and only within `base_dir` if the path is confined.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = 10))]
+    pub max_files: Option<NonZeroUsize>,
+}
+
+/// How rotated files are named.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RotationNaming {
+    /// Appends a number to the file name, where `1` is the most recently rotated file.
+    ///
+    /// Older rotated files are renumbered on each rotation, as `logrotate` does.
+    #[default]
+    Numbered,
+
+    /// Appends the UTC time of the rotation to the file name, such as `20240601T120000.000Z`.
+    Timestamp,
+}
+
+impl FileRotationConfig {
+    pub(super) const fn is_enabled(&self) -> bool {
+        self.max_bytes.is_some() || self.interval_secs.is_some()
+    }
+
+    /// Whether a file is due for rotation, given when it was created, if that's tracked.
+    pub(super) fn is_due(&self, file: &OutFile, created_at: Option<Instant>) -> bool {
+        self.max_bytes
+            .is_some_and(|max_bytes| file.bytes_written() >= max_bytes.get())
+            || created_at.is_some_and(|created_at| self.is_due_by_age(created_at))
+    }
+
+    /// Whether a file created at `created_at` is due for rotation by its age alone.
+    pub(super) fn is_due_by_age(&self, created_at: Instant) -> bool {
+        self.interval_secs
+            .is_some_and(|interval_secs| created_at.elapsed().as_secs() >= interval_secs.get())
+    }
+}
+
+impl Compression {
+    const fn extension(self) -> &'static str {
+        match self {
+            Self::Gzip => ".gz",
+            Self::Zstd => ".zst",
+            Self::None => "",
+        }
+    }
+}
+
+/// Rotates the closed file `file_name` in `dir` by renaming it, returning the path of the rotated
+/// file if there was one to rotate.
+///
+/// The rotated file is left for `finish_rotation` to compress. `dir` must already be verified to be
+/// inside the sink's base directory, if it has one. Only regular files whose names match the
+/// rotated names of `file_name` are renamed or deleted, and no file is ever overwritten.
+pub(super) async fn rotate(
+    dir: &Path,
+    file_name: &OsStr,
+    config: &FileRotationConfig,
+) -> io::Result<Option<PathBuf>> {
+    let current = dir.join(file_name);
+    match fs::symlink_metadata(&current).await {
+        Ok(metadata) if metadata.is_file() => {}
+        // Nothing to rotate if the file was removed, or replaced with something else.
+        Ok(_) => return Ok(None),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(error),
+    }
+
+    let extension = config.compression.extension();
+    let suffix = match config.naming {
+        RotationNaming::Numbered => {
+            for (number, name) in rotated_files(dir, file_name, extension, parse_number)
+                .await?
+                .into_iter()
+                .rev()
+            {
+                if config.max_files.is_some_and(|max| number >= max.get()) {
+                    fs::remove_file(dir.join(name)).await?;
+                } else {
+                    let renumbered = rotated_name(file_name, &(number + 1).to_string(), extension);
+                    fs::rename(dir.join(name), dir.join(renumbered)).await?;
+                }
+            }
+            "1".to_string()
+        }
+        RotationNaming::Timestamp => {
+            // Files rotated within the same millisecond would share a name, so later ones take the
+            // next free millisecond instead.
+            let mut time = Utc::now();
+            loop {
+                let suffix = time.format(TIMESTAMP_FORMAT).to_string();
+                if !exists(&dir.join(rotated_name(file_name, &suffix, ""))).await?
+                    && !exists(&dir.join(rotated_name(file_name, &suffix, extension))).await?
+                {
+                    break suffix;
+                }
+                time += TimeDelta::milliseconds(1);
+            }
+        }
+    };
+
+    let rotated = dir.join(rotated_name(file_name, &suffix, ""));
+    // Renumbering frees the name of a numbered file, unless a file that was never compressed, such
+    // as one whose compression was interrupted by a crash, still holds it.
+    if exists(&rotated).await? {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("rotated file {} already exists", rotated.display()),
+        ));
+    }
+    fs::rename(&current, &rotated).await?;
+    Ok(Some(rotated))
+}
+
+/// Compresses the file that `rotate` renamed to `rotated`, then deletes the rotated files of
+/// `file_name` that exceed the retention limit.
+///
+/// This is the slow part of a rotation, so the sink runs it in the background. It must finish
+/// before `file_name` is rotated again.
+pub(super) async fn finish_rotation(
+    dir: &Path,
+    file_name: &OsStr,
+    rotated: &Path,
+    config: &FileRotationConfig,
+) -> io::Result<()> {
+    if config.compression != Compression::None {
+        compress(rotated, config.compression).await?;
+    }
+
+    if config.naming == RotationNaming::Timestamp
+        && let Some(max_files) = config.max_files
+    {
+        let extension = config.compression.extension();
+        let rotated = rotated_files(dir, file_name, extension, parse_timestamp).await?;
+        let excess = rotated.len().saturating_sub(max_files.get());
+        for (_, name) in rotated.into_iter().take(excess) {
+            fs::remove_file(dir.join(name)).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether anything exists at `path`, without following symlinks.
+async fn exists(path: &Path) -> io::Result<bool> {
+    match fs::symlink_metadata(path).await {
+        Ok(_) => Ok(true),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(error) => Err(error),
+    }
+}
+
+fn rotated_name(file_name: &OsStr, suffix: &str, extension: &str) -> OsString {
+    let mut name = file_name.to_os_string();
+    name.push(format!(".{suffix}{extension}"));
+    name
+}
+
+fn parse_number(suffix: &str) -> Option<usize> {
+    suffix
+        .bytes()
+        .all(|byte| byte.is_ascii_digit())
+        .then(|| suffix.parse().ok())
+        .flatten()
+        .filter(|number| *number > 0)
+}
+
+fn parse_timestamp(suffix: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(suffix, TIMESTAMP_FORMAT).ok()
+}
+
+/// Lists the rotated files of `file_name` in `dir`, ordered by their parsed suffixes.
+async fn rotated_files<K: Ord>(
+    dir: &Path,
+    file_name: &OsStr,
+    extension: &str,
+    parse: impl Fn(&str) -> Option<K>,
+) -> io::Result<Vec<(K, OsString)>> {
+    let Some(prefix) = file_name.to_str().map(|name| format!("{name}.")) else {
+        return Ok(Vec::new());
+    };
+
+    let mut rotated = Vec::new();
+    let mut entries = fs::read_dir(dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        // `file_type` doesn't follow symlinks, so links out of the directory are never touched.
+        if !entry.file_type().await?.is_file() {
+            continue;
+        }
+        let name = entry.file_name();
+        if let Some(key) = name
+            .to_str()
+            .and_then(|name| name.strip_prefix(&prefix))
+            .and_then(|suffix| suffix.strip_suffix(extension))
+            .and_then(&parse)
+        {
+            rotated.push((key, name));
+        }
+    }
+    rotated.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(rotated)
+}
+
+/// Compresses a rotated file into a new file with the extension of the compression, then deletes it.
+async fn compress(path: &Path, compression: Compression) -> io::Result<()> {
+    let mut compressed_path = PathBuf::from(path).into_os_string();
+    compressed_path.push(compression.extension());
+
+    let mut source = File::open(path).await?;
+    let mut compressed = OutFile::new(File::create(compressed_path).await?, compression, 0);
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = source.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        compressed.write_all(&buffer[..read]).await?;
+    }
+    compressed.close().await?;
+
+    fs::remove_file(path).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{lines_from_gzip_file, temp_dir};
+
+    async fn file_names(dir: &Path) -> Vec<String> {
+        let mut names = Vec::new();
+        let mut entries = fs::read_dir(dir).await.unwrap();
+        while let Some(entry) = entries.next_entry().await.unwrap() {
+            names.push(entry.file_name().into_string().unwrap());
+        }
+        names.sort();
+        names
+    }
+
+    async fn rotate_and_finish(dir: &Path, config: &FileRotationConfig) {
+        let file_name = OsStr::new("app.log");
+        if let Some(rotated) = rotate(dir, file_name, config).await.unwrap() {
+            finish_rotation(dir, file_name, &rotated, config)
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn numbered_rotation_renumbers_and_prunes() {
+        let dir = temp_dir();
+        fs::create_dir_all(&dir).await.unwrap();
+        let config = FileRotationConfig {
+            max_files: NonZeroUsize::new(2),
+            ..Default::default()
+        };
+
+        for content in ["first", "second", "third"] {
+            fs::write(dir.join("app.log"), content).await.unwrap();
+            rotate_and_finish(&dir, &config).await;
+        }
+
+        assert_eq!(file_names(&dir).await, ["app.log.1", "app.log.2"]);
+        assert_eq!(
+            fs::read_to_string(dir.join("app.log.1")).await.unwrap(),
+            "third"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("app.log.2")).await.unwrap(),
+            "second"
+        );
+    }
+
+    #[tokio::test]
+    async fn timestamped_rotation_compresses_and_prunes() {
+        let dir = temp_dir();
+        fs::create_dir_all(&dir).await.unwrap();
+        fs::write(dir.join("app.log.20000101T000000.000Z.gz"), "old")
+            .await
+            .unwrap();
+        fs::write(dir.join("other.log.20000101T000000.000Z.gz"), "other")
+            .await
+            .unwrap();
+        let config = FileRotationConfig {
+            naming: RotationNaming::Timestamp,
+            compression: Compression::Gzip,
+            max_files: NonZeroUsize::new(1),
+            ..Default::default()
+        };
+
+        fs::write(dir.join("app.log"), "line\n").await.unwrap();
+        rotate_and_finish(&dir, &config).await;
+
+        let names = file_names(&dir).await;
+        assert_eq!(names.len(), 2);
+        assert!(names.contains(&"other.log.20000101T000000.000Z.gz".to_string()));
+        let rotated = names
+            .iter()
+            .find(|name| name.starts_with("app.log.") && name.ends_with(".gz"))
+            .unwrap();
+        assert!(parse_timestamp(&rotated["app.log.".len()..rotated.len() - 3]).is_some());
+        assert_eq!(lines_from_gzip_file(dir.join(rotated)), ["line"]);
+    }
+
+    #[tokio::test]
+    async fn timestamped_rotation_never_overwrites() {
+        let dir = temp_dir();
+        fs::create_dir_all(&dir).await.unwrap();
+        let config = FileRotationConfig {
+            naming: RotationNaming::Timestamp,
+            ..Default::default()
+        };
+
+        // Rotations this close together usually fall within the same millisecond.
+        for content in ["first", "second", "third"] {
+            fs::write(dir.join("app.log"), content).await.unwrap();
+            rotate_and_finish(&dir, &config).await;
+        }
+
+        let names = file_names(&dir).await;
+        let mut contents = Vec::new();
+        for name in &names {
+            assert!(parse_timestamp(&name["app.log.".len()..]).is_some());
+            contents.push(fs::read_to_string(dir.join(name)).await.unwrap());
+        }
+        assert_eq!(contents, ["first", "second", "third"]);
+    }
+
+    #[tokio::test]
+    async fn numbered_rotation_never_overwrites() {
+        let dir = temp_dir();
+        fs::create_dir_all(&dir).await.unwrap();
+        fs::write(dir.join("app.log.1"), "uncompressed")
+            .await
+            .unwrap();
+        let config = FileRotationConfig {
+            compression: Compression::Gzip,
+            ..Default::default()
+        };
+
+        fs::write(dir.join("app.log"), "new").await.unwrap();
+        let error = rotate(&dir, OsStr::new("app.log"), &config)
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(file_names(&dir).await, ["app.log", "app.log.1"]);
+        assert_eq!(
+            fs::read_to_string(dir.join("app.log.1")).await.unwrap(),
+            "uncompressed"
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn rotation_ignores_symlinks() {
+        let dir = temp_dir();
+        let outside = temp_dir();
+        fs::create_dir_all(&dir).await.unwrap();
+        fs::create_dir_all(&outside).await.unwrap();
+        fs::write(outside.join("target"), "keep").await.unwrap();
+        fs::symlink(outside.join("target"), dir.join("app.log.1"))
+            .await
+            .unwrap();
+        let config = FileRotationConfig {
+            max_files: NonZeroUsize::new(1),
+            ..Default::default()
+        };
+
+        fs::write(dir.join("app.log"), "new").await.unwrap();
+        rotate_and_finish(&dir, &config).await;
+
+        assert_eq!(
+            fs::read_to_string(outside.join("target")).await.unwrap(),
+            "keep"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("app.log.1")).await.unwrap(),
+            "new"
+        );
+    }
+}
@@ -232,7 +232,9 @@ fn effective_framer_config(encoding: &EncodingConfigWithFraming) -> FramingConfi
             SerializerConfig::Json(_) => {
                 FramingConfig::CharacterDelimited(CharacterDelimitedEncoderConfig::new(b','))
             }
-            SerializerConfig::Avro { .. } | SerializerConfig::Native => {
+            SerializerConfig::Avro { .. }
+            | SerializerConfig::Msgpack
+            | SerializerConfig::Native => {
                 FramingConfig::LengthDelimited(LengthDelimitedEncoderConfig::default())
             }
             SerializerConfig::Gelf(_) => {
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, time::Duration};
+use std::{collections::HashMap, num::NonZeroUsize, time::Duration};
 
 use futures::FutureExt;
 use rdkafka::ClientConfig;
@@ -124,6 +124,10 @@ pub struct KafkaSinkConfig {
     #[configurable(metadata(docs::examples = "headers"))]
     pub headers_key: Option<ConfigTargetPath>,
 
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<KafkaTransactionConfig>,
+
     #[configurable(derived)]
     #[serde(
         default,
@@ -137,6 +141,70 @@ pub struct KafkaSinkConfig {
     pub confinement: ConfinementConfig,
 }
 
+/// Produces events within Kafka transactions.
+///
+/// Consumers that read with the `read_committed` isolation level only see the events of committed
+/// transactions, so they see each event exactly once, even if Vector has to retry writing it.
+#[serde_as]
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct KafkaTransactionConfig {
+    /// The transactional ID of the producer.
+    ///
+    /// It must be unique to this sink and stay the same when Vector restarts, so that Kafka can
+    /// fence off the previous instance of the producer and abort its unfinished transactions.
+    #[configurable(metadata(docs::examples = "vector-kafka-sink"))]
+    pub transactional_id: String,
+
+    /// The maximum number of events that are produced within a single transaction.
+    #[serde(default = "default_transaction_max_events")]
+    #[configurable(metadata(docs::type_unit = "events"))]
+    pub max_events: NonZeroUsize,
+
+    /// The maximum amount of time, in milliseconds, that a transaction collects events for before
+    /// it's committed.
+    #[serde_as(as = "serde_with::DurationMilliSeconds<u64>")]
+    #[serde(default = "default_transaction_timeout_ms")]
+    #[configurable(metadata(docs::human_name = "Transaction Timeout"))]
+    pub timeout_ms: Duration,
+
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_offsets: Option<KafkaSourceOffsetsConfig>,
+}
+
+/// Commits the offsets of the events that a `kafka` source consumed within each transaction.
+///
+/// This makes consuming an event and producing it a single atomic operation, so a pipeline from a
+/// `kafka` source to this sink delivers each event exactly once. The source must run in the same
+/// Vector instance as this sink, and have end-to-end acknowledgements enabled, so that it doesn't
+/// commit the offsets of events before they're produced. The sink fails to start if no `kafka`
+/// source consumes with the group ID, or if that source doesn't have acknowledgements enabled.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct KafkaSourceOffsetsConfig {
+    /// The consumer group ID of the `kafka` source.
+    #[configurable(metadata(docs::examples = "consumer-group-name"))]
+    pub group_id: String,
+
+    /// The field that holds the topic that an event was consumed from.
+    #[serde(default = "default_source_topic_field")]
+    #[configurable(metadata(docs::examples = "%kafka.topic"))]
+    pub topic_field: ConfigTargetPath,
+
+    /// The field that holds the partition that an event was consumed from.
+    #[serde(default = "default_source_partition_field")]
+    #[configurable(metadata(docs::examples = "%kafka.partition"))]
+    pub partition_field: ConfigTargetPath,
+
+    /// The field that holds the offset of an event within its partition.
+    #[serde(default = "default_source_offset_field")]
+    #[configurable(metadata(docs::examples = "%kafka.offset"))]
+    pub offset_field: ConfigTargetPath,
+}
+
 const fn default_socket_timeout_ms() -> Duration {
     Duration::from_millis(60000) // default in librdkafka
 }
@@ -153,6 +221,26 @@ const fn default_rate_limit_num() -> u64 {
     i64::MAX as u64 // i64 avoids TOML deserialize issue
 }
 
+const fn default_transaction_max_events() -> NonZeroUsize {
+    NonZeroUsize::new(1000).unwrap()
+}
+
+const fn default_transaction_timeout_ms() -> Duration {
+    Duration::from_millis(1000)
+}
+
+fn default_source_topic_field() -> ConfigTargetPath {
+    ConfigTargetPath::from("topic")
+}
+
+fn default_source_partition_field() -> ConfigTargetPath {
+    ConfigTargetPath::from("partition")
+}
+
+fn default_source_offset_field() -> ConfigTargetPath {
+    ConfigTargetPath::from("offset")
+}
+
 fn example_librdkafka_options() -> HashMap<String, String> {
     HashMap::<_, _>::from_iter([
         ("client.id".to_string(), "${ENV_VAR}".to_string()),
@@ -242,6 +330,17 @@ impl KafkaSinkConfig {
             client_config.set(key, value.to_string());
         }
 
+        if let Some(transaction) = &self.transaction {
+            // Messages can't outlive the transaction that they're produced in, so the transaction
+            // timeout must be at least the message timeout.
+            client_config
+                .set("transactional.id", &transaction.transactional_id)
+                .set(
+                    "transaction.timeout.ms",
+                    self.message_timeout_ms.as_millis().to_string(),
+                );
+        }
+
         for (key, value) in self.librdkafka_options.iter() {
             debug!(option = %key, value = %value, "Setting librdkafka option.");
             client_config.set(key.as_str(), value.as_str());
@@ -268,6 +367,7 @@ impl GenerateConfig for KafkaSinkConfig {
             rate_limit_num: default_rate_limit_num(),
             librdkafka_options: Default::default(),
             headers_key: None,
+            transaction: None,
             acknowledgements: Default::default(),
             confinement: ConfinementConfig::default(),
         })
@@ -338,4 +438,27 @@ mod tests {
         let result = template.confine(&config, "kafka", "topic");
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn source_offsets_require_a_kafka_source() {
+        let config = toml::from_str::<KafkaSinkConfig>(
+            r#"
+            bootstrap_servers = "localhost:9092"
+            topic = "events"
+            encoding.codec = "json"
+
+            [transaction]
+            transactional_id = "vector"
+
+            [transaction.source_offsets]
+            group_id = "no-such-group"
+            "#,
+        )
+        .unwrap();
+
+        let Err(error) = config.build(SinkContext::default()).await else {
+            panic!("sink built without a kafka source for its consumer group");
+        };
+        assert!(error.to_string().contains("no-such-group"));
+    }
 }
@@ -5,7 +5,7 @@ use vector_lib::lookup::OwnedTargetPath;
 use crate::{
     internal_events::KafkaHeaderExtractionError,
     sinks::{
-        kafka::service::{KafkaRequest, KafkaRequestMetadata},
+        kafka::service::{KafkaRequest, KafkaRequestMetadata, SourceOffset},
         prelude::*,
     },
 };
@@ -13,9 +13,17 @@ use crate::{
 pub struct KafkaRequestBuilder {
     pub key_field: Option<OwnedTargetPath>,
     pub headers_key: Option<OwnedTargetPath>,
+    pub source_offset_fields: Option<SourceOffsetFields>,
     pub encoder: (Transformer, Encoder<()>),
 }
 
+/// The fields that hold where a `kafka` source consumed an event from.
+pub struct SourceOffsetFields {
+    pub topic: OwnedTargetPath,
+    pub partition: OwnedTargetPath,
+    pub offset: OwnedTargetPath,
+}
+
 impl RequestBuilder<(String, Event)> for KafkaRequestBuilder {
     type Metadata = KafkaRequestMetadata;
     type Events = Event;
@@ -44,6 +52,10 @@ impl RequestBuilder<(String, Event)> for KafkaRequestBuilder {
             key: get_key(&event, self.key_field.as_ref()),
             timestamp_millis: get_timestamp_millis(&event),
             headers: get_headers(&event, self.headers_key.as_ref()),
+            source_offset: self
+                .source_offset_fields
+                .as_ref()
+                .and_then(|fields| get_source_offset(&event, fields)),
             topic,
         };
 
@@ -125,6 +137,20 @@ fn get_headers(event: &Event, headers_key: Option<&OwnedTargetPath>) -> Option<O
     })
 }
 
+fn get_source_offset(event: &Event, fields: &SourceOffsetFields) -> Option<SourceOffset> {
+    let get = |path: &OwnedTargetPath| match event {
+        Event::Log(log) => log.get(path),
+        Event::Trace(trace) => trace.get(path),
+        Event::Metric(_) => None,
+    };
+
+    Some(SourceOffset {
+        topic: get(&fields.topic)?.as_str()?.into_owned(),
+        partition: i32::try_from(get(&fields.partition)?.as_integer()?).ok()?,
+        offset: get(&fields.offset)?.as_integer()?,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::Bytes;
@@ -166,4 +192,29 @@ mod tests {
         assert_eq!(headers.get(1).key, "b-key");
         assert_eq!(headers.get(1).value.unwrap(), "b-value".as_bytes());
     }
+
+    #[test]
+    fn kafka_get_source_offset() {
+        let fields = SourceOffsetFields {
+            topic: OwnedTargetPath::try_from("topic".to_string()).unwrap(),
+            partition: OwnedTargetPath::try_from("partition".to_string()).unwrap(),
+            offset: OwnedTargetPath::try_from("%kafka.offset".to_string()).unwrap(),
+        };
+
+        let mut log = LogEvent::from("hello");
+        log.insert(&fields.topic, "logs");
+        log.insert(&fields.partition, 3);
+        let mut event = Event::Log(log);
+        assert_eq!(get_source_offset(&event, &fields), None);
+
+        event.as_mut_log().insert(&fields.offset, 42);
+        assert_eq!(
+            get_source_offset(&event, &fields),
+            Some(SourceOffset {
+                topic: "logs".to_string(),
+                partition: 3,
+                offset: 42,
+            })
+        );
+    }
 }
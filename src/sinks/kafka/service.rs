@@ -11,7 +11,7 @@ use bytes::Bytes;
 use rdkafka::{
     error::KafkaError,
     message::OwnedHeaders,
-    producer::{FutureProducer, FutureRecord},
+    producer::{DeliveryFuture, FutureProducer, FutureRecord},
     types::RDKafkaErrorCode,
 };
 use vector_lib::config;
@@ -30,12 +30,21 @@ pub struct KafkaRequestMetadata {
     pub timestamp_millis: Option<i64>,
     pub headers: Option<OwnedHeaders>,
     pub topic: String,
+    pub source_offset: Option<SourceOffset>,
+}
+
+/// Where a `kafka` source consumed an event from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceOffset {
+    pub topic: String,
+    pub partition: i32,
+    pub offset: i64,
 }
 
 pub struct KafkaResponse {
-    event_byte_size: GroupedCountByteSize,
-    raw_byte_size: usize,
-    event_status: EventStatus,
+    pub(super) event_byte_size: GroupedCountByteSize,
+    pub(super) raw_byte_size: usize,
+    pub(super) event_status: EventStatus,
 }
 
 impl DriverResponse for KafkaResponse {
@@ -124,82 +133,96 @@ impl Service<KafkaRequest> for KafkaService {
         Box::pin(async move {
             let raw_byte_size =
                 request.body.len() + request.metadata.key.as_ref().map_or(0, |x| x.len());
+
+            let result = enqueue(&this.kafka_producer, &this.records_blocked, &request).await;
             let event_byte_size = request
                 .request_metadata
                 .into_events_estimated_json_encoded_byte_size();
-
-            let mut record =
-                FutureRecord::to(&request.metadata.topic).payload(request.body.as_ref());
-            if let Some(key) = &request.metadata.key {
-                record = record.key(&key[..]);
-            }
-            if let Some(timestamp) = request.metadata.timestamp_millis {
-                record = record.timestamp(timestamp);
-            }
-            if let Some(headers) = request.metadata.headers {
-                record = record.headers(headers);
+            match result {
+                Ok(fut) => fut
+                    .await
+                    .expect("producer unexpectedly dropped")
+                    .map(|_| KafkaResponse {
+                        event_byte_size,
+                        raw_byte_size,
+                        event_status: EventStatus::Delivered,
+                    })
+                    .map_err(|(err, _)| err),
+                // A final/non-retriable error occurred.
+                Err(err) if is_permanent(&err) => Err(err),
+                // A different error occurred. Set event status to Errored not Rejected.
+                Err(_) => Ok(KafkaResponse {
+                    event_byte_size: config::telemetry().create_request_count_byte_size(),
+                    raw_byte_size: 0,
+                    event_status: EventStatus::Errored,
+                }),
             }
+        })
+    }
+}
 
-            // Manually poll [FutureProducer::send_result] instead of [FutureProducer::send] to track
-            // records that fail to be enqueued on the producer.
-            let mut blocked_state: Option<BlockedRecordState> = None;
-            loop {
-                match this.kafka_producer.send_result(record) {
-                    // Record was successfully enqueued on the producer.
-                    Ok(fut) => {
-                        // Drop the blocked state (if any), as the producer is no longer blocked.
-                        drop(blocked_state.take());
-                        return fut
-                            .await
-                            .expect("producer unexpectedly dropped")
-                            .map(|_| KafkaResponse {
-                                event_byte_size,
-                                raw_byte_size,
-                                event_status: EventStatus::Delivered,
-                            })
-                            .map_err(|(err, _)| err);
-                    }
-                    // Producer queue is full or a policy has been violated and the request should
-                    // be retried
-                    Err((
-                        KafkaError::MessageProduction(
-                            RDKafkaErrorCode::QueueFull | RDKafkaErrorCode::PolicyViolation,
-                        ),
-                        original_record,
-                    )) => {
-                        if blocked_state.is_none() {
-                            blocked_state =
-                                Some(BlockedRecordState::new(Arc::clone(&this.records_blocked)));
-                        }
-                        record = original_record;
-                        tokio::time::sleep(Duration::from_millis(100)).await;
-                    }
-                    // A final/non-retriable error occurred.
-                    Err((
-                        err @ KafkaError::MessageProduction(
-                            RDKafkaErrorCode::InvalidMessage
-                            | RDKafkaErrorCode::InvalidMessageSize
-                            | RDKafkaErrorCode::MessageSizeTooLarge
-                            | RDKafkaErrorCode::UnknownTopicOrPartition
-                            | RDKafkaErrorCode::InvalidRecord
-                            | RDKafkaErrorCode::InvalidRequiredAcks
-                            | RDKafkaErrorCode::TopicAuthorizationFailed
-                            | RDKafkaErrorCode::UnsupportedForMessageFormat
-                            | RDKafkaErrorCode::ClusterAuthorizationFailed,
-                        ),
-                        _,
-                    )) => return Err(err),
-
-                    // A different error occurred. Set event status to Errored not Rejected.
-                    Err(_) => {
-                        return Ok(KafkaResponse {
-                            event_byte_size: config::telemetry().create_request_count_byte_size(),
-                            raw_byte_size: 0,
-                            event_status: EventStatus::Errored,
-                        });
-                    }
-                };
+/// Enqueues the record of a request on the producer, returning the future of its delivery.
+///
+/// Manually polls [FutureProducer::send_result] instead of [FutureProducer::send] to track records
+/// that fail to be enqueued on the producer.
+pub(super) async fn enqueue(
+    producer: &FutureProducer<KafkaStatisticsContext>,
+    records_blocked: &Arc<AtomicUsize>,
+    request: &KafkaRequest,
+) -> Result<DeliveryFuture, KafkaError> {
+    let mut record = FutureRecord::to(&request.metadata.topic).payload(request.body.as_ref());
+    if let Some(key) = &request.metadata.key {
+        record = record.key(&key[..]);
+    }
+    if let Some(timestamp) = request.metadata.timestamp_millis {
+        record = record.timestamp(timestamp);
+    }
+    if let Some(headers) = &request.metadata.headers {
+        record = record.headers(headers.clone());
+    }
+
+    let mut blocked_state: Option<BlockedRecordState> = None;
+    loop {
+        match producer.send_result(record) {
+            // Record was successfully enqueued on the producer.
+            Ok(fut) => {
+                // Drop the blocked state (if any), as the producer is no longer blocked.
+                drop(blocked_state.take());
+                return Ok(fut);
             }
-        })
+            // Producer queue is full or a policy has been violated and the request should
+            // be retried
+            Err((
+                KafkaError::MessageProduction(
+                    RDKafkaErrorCode::QueueFull | RDKafkaErrorCode::PolicyViolation,
+                ),
+                original_record,
+            )) => {
+                if blocked_state.is_none() {
+                    blocked_state = Some(BlockedRecordState::new(Arc::clone(records_blocked)));
+                }
+                record = original_record;
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+            Err((err, _)) => return Err(err),
+        }
     }
 }
+
+/// Returns whether producing a record failed in a way that retrying it can't fix.
+pub(super) const fn is_permanent(error: &KafkaError) -> bool {
+    matches!(
+        error,
+        KafkaError::MessageProduction(
+            RDKafkaErrorCode::InvalidMessage
+                | RDKafkaErrorCode::InvalidMessageSize
+                | RDKafkaErrorCode::MessageSizeTooLarge
+                | RDKafkaErrorCode::UnknownTopicOrPartition
+                | RDKafkaErrorCode::InvalidRecord
+                | RDKafkaErrorCode::InvalidRequiredAcks
+                | RDKafkaErrorCode::TopicAuthorizationFailed
+                | RDKafkaErrorCode::UnsupportedForMessageFormat
+                | RDKafkaErrorCode::ClusterAuthorizationFailed,
+        )
+    )
+}
@@ -6,16 +6,19 @@ use rdkafka::{
     producer::{BaseProducer, FutureProducer, Producer},
 };
 use snafu::{ResultExt, Snafu};
-use tower::limit::RateLimit;
 use tracing::Span;
 use vrl::path::OwnedTargetPath;
 
-use super::config::KafkaSinkConfig;
+use super::config::{KafkaSinkConfig, KafkaTransactionConfig};
 use crate::{
     config::SinkHealthcheckOptions,
-    kafka::KafkaStatisticsContext,
+    kafka::{self, KafkaStatisticsContext},
     sinks::{
-        kafka::{request_builder::KafkaRequestBuilder, service::KafkaService},
+        kafka::{
+            request_builder::{KafkaRequestBuilder, SourceOffsetFields},
+            service::KafkaService,
+            transaction::{KafkaTransactionRequest, KafkaTransactionService},
+        },
         prelude::*,
     },
 };
@@ -25,15 +28,29 @@ use crate::{
 pub(super) enum BuildError {
     #[snafu(display("creating kafka producer failed: {}", source))]
     KafkaCreateFailed { source: KafkaError },
+    #[snafu(display(
+        "no kafka source consumes with group ID {:?}, which transactions commit offsets for",
+        group_id
+    ))]
+    MissingConsumerGroup { group_id: String },
+    #[snafu(display(
+        "the kafka source that consumes with group ID {:?} must have acknowledgements enabled for transactions to commit its offsets",
+        group_id
+    ))]
+    UnacknowledgedConsumerGroup { group_id: String },
 }
 
 pub struct KafkaSink {
     transformer: Transformer,
     encoder: Encoder<()>,
-    service: RateLimit<KafkaService>,
+    producer: FutureProducer<KafkaStatisticsContext>,
+    rate_limit_num: u64,
+    rate_limit_duration: Duration,
+    socket_timeout: Duration,
     topic: ConfinedTemplate,
     key_field: Option<OwnedTargetPath>,
     headers_key: Option<OwnedTargetPath>,
+    transaction: Option<KafkaTransactionConfig>,
 }
 
 pub(crate) fn create_producer(
@@ -50,6 +67,24 @@ pub(crate) fn create_producer(
 
 impl KafkaSink {
     pub(crate) fn new(config: KafkaSinkConfig, topic: ConfinedTemplate) -> crate::Result<Self> {
+        // Sources are built before sinks, so the source that consumes with this group has
+        // registered it by now. Without acknowledgements, the source would commit the offsets of
+        // events before they're produced, so they wouldn't be delivered exactly once.
+        if let Some(source_offsets) = config
+            .transaction
+            .as_ref()
+            .and_then(|transaction| transaction.source_offsets.as_ref())
+        {
+            let group_id = source_offsets.group_id.clone();
+            match kafka::consumer_group_acknowledgements(&group_id) {
+                None => return Err(BuildError::MissingConsumerGroup { group_id }.into()),
+                Some(false) => {
+                    return Err(BuildError::UnacknowledgedConsumerGroup { group_id }.into());
+                }
+                Some(true) => {}
+            }
+        }
+
         let producer_config = config.to_rdkafka()?;
         let producer = create_producer(producer_config)?;
         let transformer = config.encoding.transformer();
@@ -60,25 +95,33 @@ impl KafkaSink {
             headers_key: config.headers_key.map(|key| key.0),
             transformer,
             encoder,
-            service: ServiceBuilder::new()
-                .rate_limit(
-                    config.rate_limit_num,
-                    Duration::from_secs(config.rate_limit_duration_secs),
-                )
-                .service(KafkaService::new(producer)),
+            producer,
+            rate_limit_num: config.rate_limit_num,
+            rate_limit_duration: Duration::from_secs(config.rate_limit_duration_secs),
+            socket_timeout: config.socket_timeout_ms,
             topic,
             key_field: config.key_field.map(|key| key.0),
+            transaction: config.transaction,
         })
     }
 
     async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let source_offsets = self
+            .transaction
+            .as_ref()
+            .and_then(|transaction| transaction.source_offsets.as_ref());
         let request_builder = KafkaRequestBuilder {
             key_field: self.key_field,
             headers_key: self.headers_key,
+            source_offset_fields: source_offsets.map(|source_offsets| SourceOffsetFields {
+                topic: source_offsets.topic_field.0.clone(),
+                partition: source_offsets.partition_field.0.clone(),
+                offset: source_offsets.offset_field.0.clone(),
+            }),
             encoder: (self.transformer, self.encoder),
         };
 
-        input
+        let requests = input
             .filter_map(|event| {
                 // Compute the topic.
                 future::ready(
@@ -104,11 +147,40 @@ impl KafkaSink {
                     }
                     Ok(req) => Some(req),
                 }
-            })
-            .into_driver(self.service)
-            .protocol("kafka")
-            .run()
-            .await
+            });
+
+        let service =
+            ServiceBuilder::new().rate_limit(self.rate_limit_num, self.rate_limit_duration);
+        let Some(transaction) = self.transaction else {
+            return requests
+                .into_driver(service.service(KafkaService::new(self.producer)))
+                .protocol("kafka")
+                .run()
+                .await;
+        };
+
+        let transaction_service = KafkaTransactionService::new(
+            self.producer,
+            self.socket_timeout,
+            transaction
+                .source_offsets
+                .map(|source_offsets| source_offsets.group_id),
+        )
+        .await
+        .map_err(|error| error!(message = "Failed to initialize Kafka transactions.", %error))?;
+
+        // Transactions are committed one at a time, each with the records of up to `max_events`
+        // events.
+        tokio_stream::StreamExt::chunks_timeout(
+            requests,
+            transaction.max_events.get(),
+            transaction.timeout_ms,
+        )
+        .map(KafkaTransactionRequest::new)
+        .into_driver(service.concurrency_limit(1).service(transaction_service))
+        .protocol("kafka")
+        .run()
+        .await
     }
 }
 
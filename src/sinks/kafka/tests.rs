@@ -3,7 +3,7 @@
 #[cfg(feature = "kafka-integration-tests")]
 #[cfg(test)]
 mod integration_test {
-    use std::{collections::HashMap, future::ready, thread, time::Duration};
+    use std::{collections::HashMap, future::ready, num::NonZeroUsize, thread, time::Duration};
 
     use bytes::Bytes;
     use futures::{StreamExt, stream};
@@ -20,7 +20,11 @@ mod integration_test {
     };
     use vrl::event_path;
 
-    use super::super::{config::KafkaSinkConfig, sink::KafkaSink, *};
+    use super::super::{
+        config::{KafkaSinkConfig, KafkaTransactionConfig},
+        sink::KafkaSink,
+        *,
+    };
     use crate::{
         event::{ObjectMap, TraceEvent, Value},
         kafka::{KafkaAuthConfig, KafkaCompression, KafkaSaslConfig},
@@ -77,6 +81,7 @@ mod integration_test {
             rate_limit_num: i64::MAX as u64,
             librdkafka_options: HashMap::new(),
             headers_key: None,
+            transaction: None,
             acknowledgements: Default::default(),
             confinement: Default::default(),
         };
@@ -107,6 +112,7 @@ mod integration_test {
             rate_limit_num: i64::MAX as u64,
             librdkafka_options: HashMap::new(),
             headers_key: None,
+            transaction: None,
             acknowledgements: Default::default(),
             confinement: Default::default(),
         };
@@ -212,6 +218,7 @@ mod integration_test {
             batch,
             librdkafka_options,
             headers_key: None,
+            transaction: None,
             acknowledgements: Default::default(),
             confinement: Default::default(),
         };
@@ -360,6 +367,7 @@ mod integration_test {
                 rate_limit_num: i64::MAX as u64,
                 librdkafka_options: HashMap::new(),
                 headers_key: Some(headers_key.clone()),
+                transaction: None,
                 acknowledgements: Default::default(),
                 confinement: Default::default(),
             };
@@ -494,6 +502,7 @@ mod integration_test {
             rate_limit_num: i64::MAX as u64,
             librdkafka_options: HashMap::new(),
             headers_key: Some(headers_key.clone()),
+            transaction: None,
             acknowledgements: Default::default(),
             confinement: Default::default(),
         };
@@ -595,4 +604,95 @@ mod integration_test {
         assert_eq!(out.len(), input.len());
         assert_eq!(out, input);
     }
+
+    #[tokio::test]
+    async fn kafka_transactions_are_read_committed() {
+        crate::test_util::trace_init();
+
+        let server = kafka_address(9091);
+        let topic = format!("test-{}", random_string(10));
+        let config = KafkaSinkConfig {
+            bootstrap_servers: server.clone(),
+            topic: Template::try_from(topic.clone()).unwrap(),
+            healthcheck_topic: None,
+            key_field: None,
+            encoding: TextSerializerConfig::default().into(),
+            batch: BatchConfig::default(),
+            compression: KafkaCompression::None,
+            auth: KafkaAuthConfig::default(),
+            socket_timeout_ms: Duration::from_millis(60000),
+            message_timeout_ms: Duration::from_millis(300000),
+            rate_limit_duration_secs: 1,
+            rate_limit_num: i64::MAX as u64,
+            librdkafka_options: HashMap::new(),
+            headers_key: None,
+            transaction: Some(KafkaTransactionConfig {
+                transactional_id: format!("vector-{}", random_string(10)),
+                max_events: NonZeroUsize::new(100).unwrap(),
+                timeout_ms: Duration::from_millis(100),
+                source_offsets: None,
+            }),
+            acknowledgements: Default::default(),
+            confinement: Default::default(),
+        };
+
+        let num_events = 1000;
+        let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+        let (input, events) = random_lines_with_stream(100, num_events, Some(batch));
+
+        let topic_template = confined_topic(config.topic.clone());
+        assert_sink_compliance(&SINK_TAGS, async move {
+            let sink = KafkaSink::new(config, topic_template).unwrap();
+            let sink = VectorSink::from_event_streamsink(sink);
+            sink.run(events).await
+        })
+        .await
+        .expect("Running sink failed");
+        assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+        // Only the records of committed transactions are visible to this consumer.
+        let mut client_config = rdkafka::ClientConfig::new();
+        client_config.set("bootstrap.servers", server.as_str());
+        client_config.set("group.id", random_string(10));
+        client_config.set("enable.partition.eof", "true");
+        client_config.set("isolation.level", "read_committed");
+
+        let mut tpl = TopicPartitionList::new();
+        tpl.add_partition(&topic, 0)
+            .set_offset(Offset::Beginning)
+            .unwrap();
+
+        let consumer: BaseConsumer = client_config.create().unwrap();
+        consumer.assign(&tpl).unwrap();
+
+        // The commit markers of the transactions take up offsets too.
+        wait_for(
+            || match consumer.fetch_watermarks(&topic, 0, Duration::from_secs(3)) {
+                Ok((_low, high)) => ready(high > num_events as i64),
+                Err(err) => {
+                    println!("retrying due to error fetching watermarks: {err}");
+                    ready(false)
+                }
+            },
+        )
+        .await;
+
+        let mut failures = 0;
+        let mut out = Vec::new();
+        while failures < 100 {
+            match consumer.poll(Duration::from_secs(3)) {
+                Some(Ok(msg)) => {
+                    let s: &str = msg.payload_view().unwrap().unwrap();
+                    out.push(s.to_owned());
+                }
+                None if out.len() >= input.len() => break,
+                _ => {
+                    failures += 1;
+                    thread::sleep(Duration::from_millis(50));
+                }
+            }
+        }
+
+        assert_eq!(out, input);
+    }
 }
@@ -0,0 +1,514 @@
+//! Producing records within Kafka transactions.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, atomic::AtomicUsize},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::future::join_all;
+use rdkafka::{
+    Offset, TopicPartitionList,
+    error::{KafkaError, KafkaResult},
+    producer::{FutureProducer, Producer},
+};
+use snafu::Snafu;
+use tokio::time::{Instant, sleep};
+use vector_lib::internal_event::{ComponentEventsDropped, UNINTENTIONAL};
+
+use super::service::{KafkaRequest, KafkaResponse, enqueue, is_permanent};
+use crate::{
+    common::backoff::ExponentialBackoff, kafka, kafka::KafkaStatisticsContext, sinks::prelude::*,
+};
+
+/// The records that are produced within a single transaction.
+pub struct KafkaTransactionRequest {
+    records: Vec<KafkaRequest>,
+    request_metadata: RequestMetadata,
+}
+
+impl KafkaTransactionRequest {
+    pub fn new(records: Vec<KafkaRequest>) -> Self {
+        let request_metadata = RequestMetadata::from_batch(
+            records.iter().map(|record| record.request_metadata.clone()),
+        );
+        Self {
+            records,
+            request_metadata,
+        }
+    }
+}
+
+impl Finalizable for KafkaTransactionRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        // The records of a transaction don't necessarily share a status, as records that can never
+        // be produced are rejected while the rest are retried, so the service finalizes them
+        // itself.
+        EventFinalizers::default()
+    }
+}
+
+impl MetaDescriptive for KafkaTransactionRequest {
+    fn get_metadata(&self) -> &RequestMetadata {
+        &self.request_metadata
+    }
+
+    fn metadata_mut(&mut self) -> &mut RequestMetadata {
+        &mut self.request_metadata
+    }
+}
+
+/// How long transactions wait for partitions to be assigned to the consumer group of the `kafka`
+/// source before giving up, which matches the default `max.poll.interval.ms` of the consumer.
+const CONSUMER_GROUP_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Why a transaction was aborted.
+enum AbortReason {
+    /// The records at these indices can never be produced.
+    Rejected(Vec<usize>),
+
+    /// The consumer group of the `kafka` source hasn't been assigned any partitions yet.
+    MissingConsumerGroup(String),
+
+    Kafka(KafkaError),
+}
+
+#[derive(Debug, Snafu)]
+pub enum TransactionError {
+    #[snafu(display("transaction failed: {}", source))]
+    Kafka { source: KafkaError },
+
+    #[snafu(display(
+        "consumer group {:?} was not assigned any partitions within {:?}",
+        group_id,
+        CONSUMER_GROUP_TIMEOUT
+    ))]
+    ConsumerGroupUnavailable { group_id: String },
+}
+
+impl From<KafkaError> for AbortReason {
+    fn from(error: KafkaError) -> Self {
+        Self::Kafka(error)
+    }
+}
+
+#[derive(Clone)]
+pub struct KafkaTransactionService {
+    kafka_producer: FutureProducer<KafkaStatisticsContext>,
+
+    /// The number of records blocked from being enqueued on the producer.
+    records_blocked: Arc<AtomicUsize>,
+
+    /// The timeout of the blocking transactional operations.
+    timeout: Duration,
+
+    /// The consumer group of the `kafka` source to commit offsets for, if any.
+    group_id: Option<String>,
+}
+
+impl KafkaTransactionService {
+    /// Creates the service, fencing off any previous producer with the same transactional ID.
+    pub(crate) async fn new(
+        kafka_producer: FutureProducer<KafkaStatisticsContext>,
+        timeout: Duration,
+        group_id: Option<String>,
+    ) -> KafkaResult<Self> {
+        let service = Self {
+            kafka_producer,
+            records_blocked: Arc::new(AtomicUsize::new(0)),
+            timeout,
+            group_id,
+        };
+        service
+            .blocking(|producer, timeout| producer.init_transactions(timeout))
+            .await?;
+        Ok(service)
+    }
+
+    /// Runs a blocking operation of the producer on the blocking thread pool.
+    async fn blocking<F>(&self, operation: F) -> KafkaResult<()>
+    where
+        F: FnOnce(&FutureProducer<KafkaStatisticsContext>, Duration) -> KafkaResult<()>
+            + Send
+            + 'static,
+    {
+        let producer = self.kafka_producer.clone();
+        let timeout = self.timeout;
+        tokio::task::spawn_blocking(move || operation(&producer, timeout))
+            .await
+            .expect("transactional operation panicked")
+    }
+
+    async fn produce(&self, records: &[KafkaRequest]) -> Result<(), AbortReason> {
+        let mut rejected = Vec::new();
+        let mut error = None;
+
+        let mut deliveries = Vec::with_capacity(records.len());
+        for (index, record) in records.iter().enumerate() {
+            match enqueue(&self.kafka_producer, &self.records_blocked, record).await {
+                Ok(delivery) => deliveries.push((index, delivery)),
+                Err(err) if is_permanent(&err) => rejected.push(index),
+                Err(err) => error = Some(err),
+            }
+        }
+        let (indices, deliveries): (Vec<_>, Vec<_>) = deliveries.into_iter().unzip();
+        for (index, delivery) in indices.into_iter().zip(join_all(deliveries).await) {
+            match delivery.expect("producer unexpectedly dropped") {
+                Ok(_) => {}
+                Err((err, _)) if is_permanent(&err) => rejected.push(index),
+                Err((err, _)) => error = Some(err),
+            }
+        }
+
+        if !rejected.is_empty() {
+            return Err(AbortReason::Rejected(rejected));
+        }
+        if let Some(error) = error {
+            return Err(error.into());
+        }
+
+        if let Some(group_id) = &self.group_id {
+            let offsets = source_offsets(records)?;
+            // Records of events that weren't consumed by the source have no offsets to commit.
+            if offsets.count() > 0 {
+                let metadata = kafka::consumer_group_metadata(group_id)
+                    .ok_or_else(|| AbortReason::MissingConsumerGroup(group_id.clone()))?;
+                self.blocking(move |producer, timeout| {
+                    producer.send_offsets_to_transaction(&offsets, &metadata, timeout)
+                })
+                .await?;
+            }
+        }
+
+        self.blocking(|producer, timeout| producer.commit_transaction(timeout))
+            .await?;
+        Ok(())
+    }
+}
+
+/// Produces records within a single transaction.
+trait Transact {
+    async fn transact(&self, records: &[KafkaRequest]) -> Result<(), AbortReason>;
+}
+
+impl Transact for KafkaTransactionService {
+    /// Produces the records within a transaction, aborting it if any of them fails.
+    async fn transact(&self, records: &[KafkaRequest]) -> Result<(), AbortReason> {
+        self.blocking(|producer, _| producer.begin_transaction())
+            .await?;
+
+        let result = self.produce(records).await;
+        if result.is_err()
+            && let Err(error) = self
+                .blocking(|producer, timeout| producer.abort_transaction(timeout))
+                .await
+        {
+            debug!(message = "Failed to abort transaction.", %error);
+        }
+        result
+    }
+}
+
+/// Retries the transaction of the records until it's committed, rejecting the records that can
+/// never be produced along the way.
+///
+/// On error, the records that remain haven't been produced.
+async fn transact_until_committed(
+    transactor: &impl Transact,
+    records: &mut Vec<KafkaRequest>,
+) -> Result<(), TransactionError> {
+    let mut backoff = ExponentialBackoff::default();
+    let mut missing_group_since = None;
+
+    while !records.is_empty() {
+        match transactor.transact(records).await {
+            Ok(()) => break,
+            Err(AbortReason::Rejected(indices)) => {
+                for index in indices.iter().rev() {
+                    let mut record = records.remove(*index);
+                    record
+                        .take_finalizers()
+                        .update_status(EventStatus::Rejected);
+                }
+                emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+                    count: indices.len(),
+                    reason: "Records could not be produced.",
+                });
+            }
+            Err(AbortReason::MissingConsumerGroup(group_id)) => {
+                let since = *missing_group_since.get_or_insert_with(Instant::now);
+                if since.elapsed() >= CONSUMER_GROUP_TIMEOUT {
+                    return Err(TransactionError::ConsumerGroupUnavailable { group_id });
+                }
+                warn!(
+                    message = "Consumer group has no assigned partitions yet, retrying transaction.",
+                    %group_id,
+                );
+                sleep(backoff.next().unwrap()).await;
+            }
+            Err(AbortReason::Kafka(KafkaError::Transaction(error))) if error.is_fatal() => {
+                return Err(TransactionError::Kafka {
+                    source: KafkaError::Transaction(error),
+                });
+            }
+            Err(AbortReason::Kafka(error)) => {
+                warn!(message = "Transaction aborted, retrying.", %error);
+                sleep(backoff.next().unwrap()).await;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Builds the offsets to commit for the source events of the records, which are the offsets that
+/// follow the last event consumed from each partition.
+fn source_offsets(records: &[KafkaRequest]) -> KafkaResult<TopicPartitionList> {
+    let mut last_offsets = HashMap::<(&str, i32), i64>::new();
+    for source in records
+        .iter()
+        .filter_map(|record| record.metadata.source_offset.as_ref())
+    {
+        let offset = last_offsets
+            .entry((&source.topic, source.partition))
+            .or_insert(source.offset);
+        *offset = (*offset).max(source.offset);
+    }
+
+    let mut offsets = TopicPartitionList::with_capacity(last_offsets.len());
+    for ((topic, partition), offset) in last_offsets {
+        offsets.add_partition_offset(topic, partition, Offset::Offset(offset + 1))?;
+    }
+    Ok(offsets)
+}
+
+impl Service<KafkaTransactionRequest> for KafkaTransactionService {
+    type Response = KafkaResponse;
+    type Error = TransactionError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: KafkaTransactionRequest) -> Self::Future {
+        let this = self.clone();
+
+        Box::pin(async move {
+            let mut records = request.records;
+            if let Err(error) = transact_until_committed(&this, &mut records).await {
+                for mut record in records {
+                    record.take_finalizers().update_status(EventStatus::Errored);
+                }
+                return Err(error);
+            }
+
+            let raw_byte_size = records
+                .iter()
+                .map(|record| {
+                    record.body.len() + record.metadata.key.as_ref().map_or(0, |key| key.len())
+                })
+                .sum();
+            let request_metadata = RequestMetadata::from_batch(
+                records
+                    .iter_mut()
+                    .map(|record| std::mem::take(&mut record.request_metadata)),
+            );
+            for mut record in records {
+                record
+                    .take_finalizers()
+                    .update_status(EventStatus::Delivered);
+            }
+
+            Ok(KafkaResponse {
+                event_byte_size: request_metadata.into_events_estimated_json_encoded_byte_size(),
+                raw_byte_size,
+                event_status: EventStatus::Delivered,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::VecDeque, sync::Mutex};
+
+    use bytes::Bytes;
+    use rdkafka::types::RDKafkaErrorCode;
+    use vector_lib::event::{BatchNotifier, BatchStatus, BatchStatusReceiver, EventFinalizer};
+
+    use super::*;
+    use crate::sinks::kafka::service::{KafkaRequestMetadata, SourceOffset};
+
+    fn record(source_offset: Option<(&str, i32, i64)>) -> (KafkaRequest, BatchStatusReceiver) {
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        let record = KafkaRequest {
+            body: Bytes::from_static(b"event"),
+            metadata: KafkaRequestMetadata {
+                finalizers: EventFinalizers::new(EventFinalizer::new(batch)),
+                key: None,
+                timestamp_millis: None,
+                headers: None,
+                topic: "topic".to_string(),
+                source_offset: source_offset.map(|(topic, partition, offset)| SourceOffset {
+                    topic: topic.to_string(),
+                    partition,
+                    offset,
+                }),
+            },
+            request_metadata: RequestMetadata::default(),
+        };
+        (record, receiver)
+    }
+
+    fn records(count: usize) -> (Vec<KafkaRequest>, Vec<BatchStatusReceiver>) {
+        (0..count).map(|_| record(None)).unzip()
+    }
+
+    /// Aborts transactions for the scripted reasons, then commits them.
+    struct ScriptedTransactor {
+        aborts: Mutex<VecDeque<AbortReason>>,
+        attempts: Mutex<Vec<usize>>,
+    }
+
+    impl ScriptedTransactor {
+        fn new(aborts: impl IntoIterator<Item = AbortReason>) -> Self {
+            Self {
+                aborts: Mutex::new(aborts.into_iter().collect()),
+                attempts: Mutex::default(),
+            }
+        }
+
+        /// The number of records of each transaction.
+        fn attempts(&self) -> Vec<usize> {
+            self.attempts.lock().unwrap().clone()
+        }
+    }
+
+    impl Transact for ScriptedTransactor {
+        async fn transact(&self, records: &[KafkaRequest]) -> Result<(), AbortReason> {
+            self.attempts.lock().unwrap().push(records.len());
+            match self.aborts.lock().unwrap().pop_front() {
+                Some(reason) => Err(reason),
+                None => Ok(()),
+            }
+        }
+    }
+
+    /// Aborts every transaction for want of the consumer group's metadata.
+    struct MissingGroupTransactor;
+
+    impl Transact for MissingGroupTransactor {
+        async fn transact(&self, _records: &[KafkaRequest]) -> Result<(), AbortReason> {
+            Err(AbortReason::MissingConsumerGroup("group".to_string()))
+        }
+    }
+
+    fn finalize(records: Vec<KafkaRequest>, status: EventStatus) {
+        for mut record in records {
+            record.take_finalizers().update_status(status);
+        }
+    }
+
+    #[test]
+    fn source_offsets_follow_the_last_offset_of_each_partition() {
+        let records = [
+            record(Some(("logs", 0, 7))),
+            record(Some(("logs", 0, 3))),
+            record(Some(("logs", 1, 12))),
+            record(Some(("metrics", 0, 1))),
+            record(None),
+        ]
+        .map(|(record, _)| record);
+
+        let offsets = source_offsets(&records).unwrap();
+
+        assert_eq!(offsets.count(), 3);
+        for (topic, partition, offset) in [("logs", 0, 8), ("logs", 1, 13), ("metrics", 0, 2)] {
+            let element = offsets.find_partition(topic, partition).unwrap();
+            assert_eq!(element.offset(), Offset::Offset(offset));
+        }
+    }
+
+    #[test]
+    fn source_offsets_are_empty_without_source_events() {
+        let (records, _receivers) = records(2);
+
+        assert_eq!(source_offsets(&records).unwrap().count(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retries_without_the_rejected_records() {
+        let (mut records, mut receivers) = records(4);
+        let transactor = ScriptedTransactor::new([AbortReason::Rejected(vec![1, 3])]);
+
+        transact_until_committed(&transactor, &mut records)
+            .await
+            .unwrap();
+        finalize(records, EventStatus::Delivered);
+
+        assert_eq!(transactor.attempts(), [4, 2]);
+        let statuses: Vec<_> = receivers
+            .iter_mut()
+            .map(|receiver| receiver.try_recv().unwrap())
+            .collect();
+        assert_eq!(
+            statuses,
+            [
+                BatchStatus::Delivered,
+                BatchStatus::Rejected,
+                BatchStatus::Delivered,
+                BatchStatus::Rejected,
+            ]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stops_once_every_record_is_rejected() {
+        let (mut records, mut receivers) = records(2);
+        let transactor = ScriptedTransactor::new([AbortReason::Rejected(vec![0, 1])]);
+
+        transact_until_committed(&transactor, &mut records)
+            .await
+            .unwrap();
+
+        assert!(records.is_empty());
+        assert_eq!(transactor.attempts(), [2]);
+        for receiver in &mut receivers {
+            assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retries_aborted_transactions() {
+        let (mut records, _receivers) = records(3);
+        let transactor = ScriptedTransactor::new([
+            AbortReason::Kafka(KafkaError::Flush(RDKafkaErrorCode::RequestTimedOut)),
+            AbortReason::MissingConsumerGroup("group".to_string()),
+        ]);
+
+        transact_until_committed(&transactor, &mut records)
+            .await
+            .unwrap();
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(transactor.attempts(), [3, 3, 3]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn gives_up_waiting_for_the_consumer_group() {
+        let (mut records, _receivers) = records(2);
+        let started = Instant::now();
+
+        let error = transact_until_committed(&MissingGroupTransactor, &mut records)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            TransactionError::ConsumerGroupUnavailable { group_id } if group_id == "group"
+        ));
+        assert!(started.elapsed() >= CONSUMER_GROUP_TIMEOUT);
+        assert_eq!(records.len(), 2);
+    }
+}
@@ -108,6 +108,8 @@ pub mod sematext;
 pub mod socket;
 #[cfg(feature = "sinks-splunk_hec")]
 pub mod splunk_hec;
+#[cfg(feature = "sinks-sql")]
+pub mod sql;
 #[cfg(feature = "sinks-statsd")]
 pub mod statsd;
 #[cfg(feature = "sinks-vector")]
@@ -0,0 +1,260 @@
+//! Encoding of events for `COPY ... FROM STDIN`.
+
+use bytes::{BufMut, BytesMut};
+use chrono::DateTime;
+use serde_json::Value;
+use snafu::Snafu;
+
+use super::{config::PostgresCopyFormat, service::Column};
+
+/// The signature, flags, and header extension length that binary `COPY` data starts with.
+const BINARY_HEADER: &[u8] = b"PGCOPY\n\xff\r\n\0\0\0\0\0\0\0\0\0";
+
+/// The field count that marks the end of binary `COPY` data.
+const BINARY_TRAILER: i16 = -1;
+
+/// Microseconds between the Unix epoch and the PostgreSQL epoch, 2000-01-01.
+const POSTGRES_EPOCH_MICROS: i64 = 946_684_800_000_000;
+
+/// The version of the `jsonb` binary format.
+const JSONB_VERSION: u8 = 1;
+
+#[derive(Debug, Snafu)]
+pub enum CopyError {
+    #[snafu(display(
+        "Column {column:?} has type {type_name}, which binary COPY doesn't support. Use the `csv` format instead."
+    ))]
+    UnsupportedType { column: String, type_name: String },
+
+    #[snafu(display("Value {value} can't be written to column {column:?} of type {type_name}."))]
+    InvalidValue {
+        column: String,
+        type_name: String,
+        value: String,
+    },
+}
+
+/// Builds the `COPY` statement for writing the given columns of a table.
+pub fn copy_statement(table: &str, columns: &[&Column], format: PostgresCopyFormat) -> String {
+    let columns = columns
+        .iter()
+        .map(|column| quote_identifier(&column.name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let format = match format {
+        PostgresCopyFormat::Csv => "csv",
+        PostgresCopyFormat::Binary => "binary",
+    };
+    format!("COPY {table} ({columns}) FROM STDIN WITH (FORMAT {format})")
+}
+
+/// Encodes events, serialized as JSON objects, as the rows of the given columns.
+pub fn encode(
+    events: &[Value],
+    columns: &[&Column],
+    format: PostgresCopyFormat,
+) -> Result<BytesMut, CopyError> {
+    let mut buffer = BytesMut::new();
+    match format {
+        PostgresCopyFormat::Csv => {
+            for event in events {
+                encode_csv_row(&mut buffer, event, columns);
+            }
+        }
+        PostgresCopyFormat::Binary => {
+            buffer.put_slice(BINARY_HEADER);
+            for event in events {
+                encode_binary_row(&mut buffer, event, columns)?;
+            }
+            buffer.put_i16(BINARY_TRAILER);
+        }
+    }
+    Ok(buffer)
+}
+
+/// Quotes an identifier so that it's interpolated in a statement verbatim.
+pub fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+fn encode_csv_row(buffer: &mut BytesMut, event: &Value, columns: &[&Column]) {
+    for (i, column) in columns.iter().enumerate() {
+        if i > 0 {
+            buffer.put_u8(b',');
+        }
+        match event.get(&column.name) {
+            // An unquoted empty field is `NULL`.
+            None | Some(Value::Null) => {}
+            Some(Value::Bool(value)) => buffer.put_slice(value.to_string().as_bytes()),
+            Some(Value::Number(value)) => buffer.put_slice(value.to_string().as_bytes()),
+            Some(Value::String(value)) => put_csv_quoted(buffer, value),
+            Some(value) => put_csv_quoted(buffer, &value.to_string()),
+        }
+    }
+    buffer.put_u8(b'\n');
+}
+
+fn put_csv_quoted(buffer: &mut BytesMut, value: &str) {
+    buffer.put_u8(b'"');
+    buffer.put_slice(value.replace('"', "\"\"").as_bytes());
+    buffer.put_u8(b'"');
+}
+
+fn encode_binary_row(
+    buffer: &mut BytesMut,
+    event: &Value,
+    columns: &[&Column],
+) -> Result<(), CopyError> {
+    buffer.put_i16(columns.len() as i16);
+    for column in columns {
+        match event.get(&column.name) {
+            None | Some(Value::Null) => buffer.put_i32(-1),
+            Some(value) => {
+                let field = encode_binary_field(column, value)?;
+                buffer.put_i32(field.len() as i32);
+                buffer.put_slice(&field);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn encode_binary_field(column: &Column, value: &Value) -> Result<Vec<u8>, CopyError> {
+    let invalid = || CopyError::InvalidValue {
+        column: column.name.clone(),
+        type_name: column.type_name.clone(),
+        value: value.to_string(),
+    };
+
+    let field = match column.type_name.as_str() {
+        "text" | "varchar" | "bpchar" => match value {
+            Value::String(value) => value.as_bytes().to_vec(),
+            value => value.to_string().into_bytes(),
+        },
+        "bool" => vec![u8::from(value.as_bool().ok_or_else(invalid)?)],
+        "int2" => value
+            .as_i64()
+            .and_then(|value| i16::try_from(value).ok())
+            .ok_or_else(invalid)?
+            .to_be_bytes()
+            .to_vec(),
+        "int4" => value
+            .as_i64()
+            .and_then(|value| i32::try_from(value).ok())
+            .ok_or_else(invalid)?
+            .to_be_bytes()
+            .to_vec(),
+        "int8" => value.as_i64().ok_or_else(invalid)?.to_be_bytes().to_vec(),
+        "float4" => (value.as_f64().ok_or_else(invalid)? as f32)
+            .to_be_bytes()
+            .to_vec(),
+        "float8" => value.as_f64().ok_or_else(invalid)?.to_be_bytes().to_vec(),
+        "json" => value.to_string().into_bytes(),
+        "jsonb" => {
+            let mut field = vec![JSONB_VERSION];
+            field.extend_from_slice(value.to_string().as_bytes());
+            field
+        }
+        "timestamp" | "timestamptz" => {
+            let timestamp = value
+                .as_str()
+                .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+                .ok_or_else(invalid)?;
+            (timestamp.timestamp_micros() - POSTGRES_EPOCH_MICROS)
+                .to_be_bytes()
+                .to_vec()
+        }
+        "uuid" => value
+            .as_str()
+            .and_then(|value| uuid::Uuid::parse_str(value).ok())
+            .ok_or_else(invalid)?
+            .as_bytes()
+            .to_vec(),
+        "bytea" => value.as_str().ok_or_else(invalid)?.as_bytes().to_vec(),
+        type_name => {
+            return Err(CopyError::UnsupportedType {
+                column: column.name.clone(),
+                type_name: type_name.to_string(),
+            });
+        }
+    };
+    Ok(field)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn column(name: &str, type_name: &str) -> Column {
+        Column {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+        }
+    }
+
+    #[test]
+    fn csv_rows() {
+        let id = column("id", "int8");
+        let message = column("message", "text");
+        let payload = column("payload", "jsonb");
+        let events = [
+            json!({"id": 1, "message": "say \"hi\", twice", "payload": {"a": [1]}}),
+            json!({"id": 2, "payload": null}),
+        ];
+
+        let encoded = encode(&events, &[&id, &message, &payload], PostgresCopyFormat::Csv).unwrap();
+
+        assert_eq!(
+            &encoded[..],
+            b"1,\"say \"\"hi\"\", twice\",\"{\"\"a\"\":[1]}\"\n2,,\n"
+        );
+    }
+
+    #[test]
+    fn binary_rows() {
+        let id = column("id", "int4");
+        let message = column("message", "text");
+        let timestamp = column("timestamp", "timestamptz");
+        let events = [json!({"id": 1, "message": "hi", "timestamp": "2000-01-01T00:00:01Z"})];
+
+        let encoded = encode(
+            &events,
+            &[&id, &message, &timestamp],
+            PostgresCopyFormat::Binary,
+        )
+        .unwrap();
+
+        let mut expected = BINARY_HEADER.to_vec();
+        expected.extend_from_slice(&3i16.to_be_bytes());
+        expected.extend_from_slice(&4i32.to_be_bytes());
+        expected.extend_from_slice(&1i32.to_be_bytes());
+        expected.extend_from_slice(&2i32.to_be_bytes());
+        expected.extend_from_slice(b"hi");
+        expected.extend_from_slice(&8i32.to_be_bytes());
+        expected.extend_from_slice(&1_000_000i64.to_be_bytes());
+        expected.extend_from_slice(&(-1i16).to_be_bytes());
+        assert_eq!(&encoded[..], &expected[..]);
+    }
+
+    #[test]
+    fn binary_rejects_unsupported_columns() {
+        let amount = column("amount", "numeric");
+        let events = [json!({"amount": 1.5})];
+
+        let error = encode(&events, &[&amount], PostgresCopyFormat::Binary).unwrap_err();
+
+        assert!(matches!(error, CopyError::UnsupportedType { .. }));
+    }
+
+    #[test]
+    fn binary_rejects_invalid_values() {
+        let id = column("id", "int2");
+        let events = [json!({"id": 100_000})];
+
+        let error = encode(&events, &[&id], PostgresCopyFormat::Binary).unwrap_err();
+
+        assert!(matches!(error, CopyError::InvalidValue { .. }));
+    }
+}
@@ -14,7 +14,13 @@ use vrl::event_path;
 use crate::{
     config::{SinkConfig, SinkContext},
     event::{ObjectMap, TraceEvent, Value},
-    sinks::{postgres::PostgresConfig, util::test::load_sink},
+    sinks::{
+        postgres::{
+            PostgresConfig,
+            config::{PostgresCopyFormat, PostgresWriteConfig},
+        },
+        util::test::load_sink,
+    },
     test_util::{
         addr::next_addr,
         components::{
@@ -477,7 +483,7 @@ async fn insertion_fails_missing_table() {
 
     let table = "missing_table".to_string();
     let (mut config, _, _) = prepare_config().await;
-    config.table = table.clone();
+    config.table = table.as_str().try_into().unwrap();
 
     let (sink, _hc) = config.build(SinkContext::default()).await.unwrap();
     let (input_event, mut receiver) = create_event_with_notifier(0);
@@ -514,3 +520,176 @@ async fn insertion_fails_primary_key_violation() {
     )
     .await;
 }
+
+async fn write_multiple_events(write: PostgresWriteConfig) {
+    let (mut config, table, mut connection) = prepare_config().await;
+    config.write = write;
+    config.batch.max_events = Some(10);
+    let (sink, _hc) = config.build(SinkContext::default()).await.unwrap();
+    let create_table_sql = format!(
+        "CREATE TABLE {table} (id BIGINT, host TEXT, timestamp TIMESTAMPTZ, message TEXT, payload JSONB)"
+    );
+    sqlx::query(&create_table_sql)
+        .execute(&mut connection)
+        .await
+        .unwrap();
+
+    let (input_events, mut receiver) = create_events(100);
+    let input_log_events = input_events
+        .clone()
+        .into_iter()
+        .map(Event::into_log)
+        .collect::<Vec<_>>();
+    let expected_values = input_log_events
+        .iter()
+        .map(|event| serde_json::to_value(event).unwrap())
+        .collect::<Vec<_>>();
+    run_and_assert_sink_compliance(sink, stream::iter(input_events), &POSTGRES_SINK_TAGS).await;
+    // We drop the event to notify the receiver that the batch was delivered.
+    std::mem::drop(input_log_events);
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+    let select_all_sql = format!("SELECT * FROM {table} ORDER BY id");
+    let actual_events: Vec<TestEvent> = sqlx::query_as(&select_all_sql)
+        .fetch_all(&mut connection)
+        .await
+        .unwrap();
+    let actual_values = actual_events
+        .iter()
+        .map(|event| serde_json::to_value(event).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(expected_values, actual_values);
+}
+
+#[tokio::test]
+async fn copy_csv_events() {
+    trace_init();
+
+    write_multiple_events(PostgresWriteConfig::Copy {
+        format: PostgresCopyFormat::Csv,
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn copy_binary_events() {
+    trace_init();
+
+    write_multiple_events(PostgresWriteConfig::Copy {
+        format: PostgresCopyFormat::Binary,
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn copy_uses_default_values() {
+    trace_init();
+
+    let (mut config, table, mut connection) = prepare_config().await;
+    config.write = PostgresWriteConfig::Copy {
+        format: PostgresCopyFormat::Csv,
+    };
+    let (sink, _hc) = config.build(SinkContext::default()).await.unwrap();
+    let create_table_sql = format!(
+        "CREATE TABLE {table} (id BIGINT, not_existing_field TEXT DEFAULT 'default_value')"
+    );
+    sqlx::query(&create_table_sql)
+        .execute(&mut connection)
+        .await
+        .unwrap();
+
+    run_and_assert_sink_compliance(
+        sink,
+        stream::once(ready(create_event(0))),
+        &POSTGRES_SINK_TAGS,
+    )
+    .await;
+
+    let select_all_sql = format!("SELECT not_existing_field FROM {table}");
+    let (value,): (String,) = sqlx::query_as(&select_all_sql)
+        .fetch_one(&mut connection)
+        .await
+        .unwrap();
+    assert_eq!(value, "default_value");
+}
+
+#[tokio::test]
+async fn upsert_updates_existing_rows() {
+    trace_init();
+
+    let (mut config, table, mut connection) = prepare_config().await;
+    config.write = PostgresWriteConfig::Upsert {
+        conflict_columns: vec!["id".to_string()],
+        update_columns: None,
+    };
+    config.batch.max_events = Some(10);
+    let (sink, _hc) = config.build(SinkContext::default()).await.unwrap();
+    let create_table_sql =
+        format!("CREATE TABLE {table} (id BIGINT PRIMARY KEY, host TEXT, message TEXT)");
+    sqlx::query(&create_table_sql)
+        .execute(&mut connection)
+        .await
+        .unwrap();
+    let insert_sql = format!("INSERT INTO {table} VALUES (0, 'old.example.com', 'old')");
+    sqlx::query(&insert_sql)
+        .execute(&mut connection)
+        .await
+        .unwrap();
+
+    let mut first = create_event(1).into_log();
+    first.insert(event_path!("message"), "first");
+    let mut second = create_event(1).into_log();
+    second.insert(event_path!("message"), "second");
+    // Both events with `id` 1 land in the same batch, so only the last one is written.
+    let events = vec![create_event(0), first.into(), second.into()];
+    run_and_assert_sink_compliance(sink, stream::iter(events), &POSTGRES_SINK_TAGS).await;
+
+    let select_all_sql = format!("SELECT id, host, message FROM {table} ORDER BY id");
+    let rows: Vec<(i64, String, String)> = sqlx::query_as(&select_all_sql)
+        .fetch_all(&mut connection)
+        .await
+        .unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            (0, "example.com".to_string(), "raw log line".to_string()),
+            (1, "example.com".to_string(), "second".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn insert_into_templated_tables() {
+    trace_init();
+
+    let (mut config, table, mut connection) = prepare_config().await;
+    config.table = format!("{table}_{{{{ host }}}}").try_into().unwrap();
+    let (sink, _hc) = config.build(SinkContext::default()).await.unwrap();
+    for host in ["a", "b"] {
+        let create_table_sql = format!("CREATE TABLE {table}_{host} (id BIGINT, host TEXT)");
+        sqlx::query(&create_table_sql)
+            .execute(&mut connection)
+            .await
+            .unwrap();
+    }
+
+    let events = ["a", "b", "b"]
+        .into_iter()
+        .enumerate()
+        .map(|(id, host)| {
+            let mut event = create_event(id as i64).into_log();
+            event.insert(event_path!("host"), host);
+            Event::from(event)
+        })
+        .collect::<Vec<_>>();
+    run_and_assert_sink_compliance(sink, stream::iter(events), &POSTGRES_SINK_TAGS).await;
+
+    for (host, expected_ids) in [("a", vec![0]), ("b", vec![1, 2])] {
+        let select_all_sql = format!("SELECT id FROM {table}_{host} ORDER BY id");
+        let ids: Vec<i64> = sqlx::query_scalar(&select_all_sql)
+            .fetch_all(&mut connection)
+            .await
+            .unwrap();
+        assert_eq!(ids, expected_ids);
+    }
+}
@@ -1,4 +1,5 @@
 mod config;
+mod copy;
 #[cfg(all(test, feature = "postgres_sink-integration-tests"))]
 mod integration_tests;
 mod service;
@@ -1,11 +1,17 @@
 use std::{
+    collections::{HashMap, hash_map::Entry},
     num::NonZeroUsize,
+    sync::Arc,
     task::{Context, Poll},
 };
 
 use futures::future::BoxFuture;
 use snafu::{ResultExt, Snafu};
-use sqlx::{Pool, Postgres, types::Json};
+use sqlx::{
+    Pool, Postgres,
+    postgres::PgPoolCopyExt,
+    types::{Json, JsonValue},
+};
 use tower::Service;
 use vector_lib::{
     EstimatedJsonEncodedSizeOf,
@@ -15,13 +21,26 @@ use vector_lib::{
     stream::DriverResponse,
 };
 
+use super::{
+    config::{PostgresCopyFormat, PostgresWriteConfig},
+    copy::{self, CopyError, quote_identifier},
+};
 use crate::{
     internal_events::EndpointBytesSent,
-    sinks::prelude::{RequestMetadataBuilder, RetryLogic},
+    sinks::{
+        prelude::{RequestMetadataBuilder, RetryLogic},
+        util::sql::ColumnCache,
+    },
 };
 
 const POSTGRES_PROTOCOL: &str = "postgres";
 
+/// Lists the columns of a table, which is passed as a possibly schema-qualified name.
+const COLUMNS_QUERY: &str = "SELECT a.attname::text, t.typname::text FROM pg_attribute a \
+    JOIN pg_type t ON t.oid = a.atttypid \
+    WHERE a.attrelid = $1::regclass AND a.attnum > 0 AND NOT a.attisdropped \
+    ORDER BY a.attnum";
+
 #[derive(Clone)]
 pub struct PostgresRetryLogic;
 
@@ -45,40 +64,200 @@ impl RetryLogic for PostgresRetryLogic {
     }
 }
 
+/// A column of a table, as listed in the system catalog.
+#[derive(Clone, Debug)]
+pub struct Column {
+    pub name: String,
+    pub type_name: String,
+}
+
 #[derive(Clone)]
 pub struct PostgresService {
     connection_pool: Pool<Postgres>,
-    table: String,
+    write: PostgresWriteConfig,
     endpoint: String,
+    columns: ColumnCache<Column>,
 }
 
 impl PostgresService {
-    pub const fn new(connection_pool: Pool<Postgres>, table: String, endpoint: String) -> Self {
+    pub fn new(
+        connection_pool: Pool<Postgres>,
+        write: PostgresWriteConfig,
+        endpoint: String,
+    ) -> Self {
         Self {
             connection_pool,
-            table,
+            write,
             endpoint,
+            columns: ColumnCache::default(),
+        }
+    }
+
+    async fn write(&self, table: &str, values: Vec<JsonValue>) -> Result<(), PostgresServiceError> {
+        match &self.write {
+            PostgresWriteConfig::Insert => {
+                sqlx::query(&format!(
+                    "INSERT INTO {table} SELECT * FROM jsonb_populate_recordset(NULL::{table}, $1)"
+                ))
+                .bind(Json(values))
+                .execute(&self.connection_pool)
+                .await
+                .context(PostgresSnafu)?;
+            }
+            PostgresWriteConfig::Upsert {
+                conflict_columns,
+                update_columns,
+            } => {
+                let update_columns = match update_columns {
+                    Some(update_columns) => update_columns.clone(),
+                    None => self
+                        .columns(table)
+                        .await
+                        .context(PostgresSnafu)?
+                        .iter()
+                        .filter(|column| !conflict_columns.contains(&column.name))
+                        .map(|column| column.name.clone())
+                        .collect(),
+                };
+                let values = deduplicate(values, conflict_columns);
+                sqlx::query(&upsert_statement(table, conflict_columns, &update_columns))
+                    .bind(Json(values))
+                    .execute(&self.connection_pool)
+                    .await
+                    .context(PostgresSnafu)?;
+            }
+            PostgresWriteConfig::Copy { format } => {
+                let columns = self.columns(table).await.context(PostgresSnafu)?;
+                self.copy(table, &columns, values, *format).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Lists the columns of a table from the system catalog, unless they're already cached.
+    async fn columns(&self, table: &str) -> Result<Arc<Vec<Column>>, sqlx::Error> {
+        self.columns
+            .get(table, || async {
+                let columns = sqlx::query_as::<_, (String, String)>(COLUMNS_QUERY)
+                    .bind(table)
+                    .fetch_all(&self.connection_pool)
+                    .await?;
+                Ok::<_, sqlx::Error>(
+                    columns
+                        .into_iter()
+                        .map(|(name, type_name)| Column { name, type_name })
+                        .collect(),
+                )
+            })
+            .await
+    }
+
+    async fn copy(
+        &self,
+        table: &str,
+        columns: &[Column],
+        values: Vec<JsonValue>,
+        format: PostgresCopyFormat,
+    ) -> Result<(), PostgresServiceError> {
+        let values = values
+            .into_iter()
+            .filter(|value| {
+                columns
+                    .iter()
+                    .any(|column| value.get(&column.name).is_some())
+            })
+            .collect::<Vec<_>>();
+        let columns = columns
+            .iter()
+            .filter(|column| values.iter().any(|value| value.get(&column.name).is_some()))
+            .collect::<Vec<_>>();
+        if values.is_empty() {
+            return Ok(());
         }
+
+        let data = copy::encode(&values, &columns, format).context(CopySnafu)?;
+        let mut copy_in = self
+            .connection_pool
+            .copy_in_raw(&copy::copy_statement(table, &columns, format))
+            .await
+            .context(PostgresSnafu)?;
+        copy_in.send(data).await.context(PostgresSnafu)?;
+        copy_in.finish().await.context(PostgresSnafu)?;
+        Ok(())
     }
 }
 
+/// Builds the statement that upserts a JSON array of rows into a table.
+fn upsert_statement(table: &str, conflict_columns: &[String], update_columns: &[String]) -> String {
+    let conflict_columns = conflict_columns
+        .iter()
+        .map(|column| quote_identifier(column))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let action = if update_columns.is_empty() {
+        "NOTHING".to_string()
+    } else {
+        let assignments = update_columns
+            .iter()
+            .map(|column| {
+                let column = quote_identifier(column);
+                format!("{column} = EXCLUDED.{column}")
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("UPDATE SET {assignments}")
+    };
+    format!(
+        "INSERT INTO {table} SELECT * FROM jsonb_populate_recordset(NULL::{table}, $1) \
+        ON CONFLICT ({conflict_columns}) DO {action}"
+    )
+}
+
+/// Keeps only the last of the rows that have the same values for the conflict columns, as a
+/// single upsert can't affect the same row twice.
+///
+/// Rows with a `NULL` conflict column never conflict, so they're all kept.
+fn deduplicate(values: Vec<JsonValue>, conflict_columns: &[String]) -> Vec<JsonValue> {
+    let mut positions = HashMap::new();
+    let mut deduplicated: Vec<JsonValue> = Vec::with_capacity(values.len());
+    for value in values {
+        let Some(key) = conflict_columns
+            .iter()
+            .map(|column| value.get(column).filter(|value| !value.is_null()))
+            .collect::<Option<Vec<_>>>()
+        else {
+            deduplicated.push(value);
+            continue;
+        };
+        let key = serde_json::to_string(&key).expect("JSON values are serializable");
+        match positions.entry(key) {
+            Entry::Occupied(entry) => deduplicated[*entry.get()] = value,
+            Entry::Vacant(entry) => {
+                entry.insert(deduplicated.len());
+                deduplicated.push(value);
+            }
+        }
+    }
+    deduplicated
+}
+
 #[derive(Clone)]
 pub struct PostgresRequest {
+    pub table: String,
     pub events: Vec<Event>,
     pub finalizers: EventFinalizers,
     pub metadata: RequestMetadata,
 }
 
-impl TryFrom<Vec<Event>> for PostgresRequest {
-    type Error = String;
-
-    fn try_from(mut events: Vec<Event>) -> Result<Self, Self::Error> {
+impl PostgresRequest {
+    pub fn new(table: String, mut events: Vec<Event>) -> Result<Self, String> {
         let finalizers = events.take_finalizers();
         let metadata_builder = RequestMetadataBuilder::from_events(&events);
         let events_size = NonZeroUsize::new(events.estimated_json_encoded_size_of().get())
             .ok_or("payload should never be zero length")?;
         let metadata = metadata_builder.with_request_size(events_size);
         Ok(PostgresRequest {
+            table,
             events,
             finalizers,
             metadata,
@@ -127,6 +306,9 @@ pub enum PostgresServiceError {
 
     #[snafu(display("Serialization error: {source}"))]
     VectorCommon { source: vector_common::Error },
+
+    #[snafu(display("COPY encoding error: {source}"))]
+    Copy { source: CopyError },
 }
 
 impl Service<PostgresRequest> for PostgresService {
@@ -141,7 +323,7 @@ impl Service<PostgresRequest> for PostgresService {
     fn call(&mut self, request: PostgresRequest) -> Self::Future {
         let service = self.clone();
         let future = async move {
-            let table = service.table;
+            let table = request.table;
             let metadata = request.metadata;
             let json_serializer = JsonSerializerConfig::default().build();
             let serialized_values = request
@@ -151,13 +333,10 @@ impl Service<PostgresRequest> for PostgresService {
                 .collect::<Result<Vec<_>, _>>()
                 .context(VectorCommonSnafu)?;
 
-            sqlx::query(&format!(
-                "INSERT INTO {table} SELECT * FROM jsonb_populate_recordset(NULL::{table}, $1)"
-            ))
-            .bind(Json(serialized_values))
-            .execute(&service.connection_pool)
-            .await
-            .context(PostgresSnafu)?;
+            if let Err(error) = service.write(&table, serialized_values).await {
+                service.columns.invalidate(&table);
+                return Err(error);
+            }
 
             emit!(EndpointBytesSent {
                 byte_size: metadata.request_encoded_size(),
@@ -171,3 +350,54 @@ impl Service<PostgresRequest> for PostgresService {
         Box::pin(future)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn upsert_statement_updates_columns() {
+        let statement = upsert_statement(
+            "state",
+            &["id".to_string()],
+            &["status".to_string(), "updatedAt".to_string()],
+        );
+
+        assert_eq!(
+            statement,
+            "INSERT INTO state SELECT * FROM jsonb_populate_recordset(NULL::state, $1) \
+            ON CONFLICT (\"id\") DO UPDATE SET \"status\" = EXCLUDED.\"status\", \
+            \"updatedAt\" = EXCLUDED.\"updatedAt\""
+        );
+    }
+
+    #[test]
+    fn upsert_statement_without_update_columns() {
+        let statement = upsert_statement("state", &["id".to_string()], &[]);
+
+        assert!(statement.ends_with("ON CONFLICT (\"id\") DO NOTHING"));
+    }
+
+    #[test]
+    fn deduplicate_keeps_last_row() {
+        let values = vec![
+            json!({"id": 1, "status": "a"}),
+            json!({"id": 2, "status": "b"}),
+            json!({"id": 1, "status": "c"}),
+            json!({"status": "d"}),
+            json!({"id": null, "status": "e"}),
+        ];
+
+        assert_eq!(
+            deduplicate(values, &["id".to_string()]),
+            vec![
+                json!({"id": 1, "status": "c"}),
+                json!({"id": 2, "status": "b"}),
+                json!({"status": "d"}),
+                json!({"id": null, "status": "e"}),
+            ]
+        );
+    }
+}
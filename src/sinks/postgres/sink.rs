@@ -1,27 +1,40 @@
 use super::service::{PostgresRequest, PostgresRetryLogic, PostgresService};
-use crate::sinks::prelude::*;
+use crate::sinks::{prelude::*, util::sql::TablePartitioner};
 
 pub struct PostgresSink {
     service: Svc<PostgresService, PostgresRetryLogic>,
     batch_settings: BatcherSettings,
+    table: ConfinedTemplate,
+    literal_prefix: String,
 }
 
 impl PostgresSink {
     pub const fn new(
         service: Svc<PostgresService, PostgresRetryLogic>,
         batch_settings: BatcherSettings,
+        table: ConfinedTemplate,
+        literal_prefix: String,
     ) -> Self {
         Self {
             service,
             batch_settings,
+            table,
+            literal_prefix,
         }
     }
 
     async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let batch_settings = self.batch_settings;
+
         input
-            .batched(self.batch_settings.as_byte_size_config())
-            .filter_map(|events| async move {
-                match PostgresRequest::try_from(events) {
+            .batched_partitioned(
+                TablePartitioner::new(self.table, self.literal_prefix),
+                batch_settings.timeout,
+                |_| batch_settings.as_byte_size_config(),
+            )
+            .filter_map(|(table, events)| async move {
+                let table = table?;
+                match PostgresRequest::new(table, events) {
                     Ok(request) => Some(request),
                     Err(e) => {
                         warn!(
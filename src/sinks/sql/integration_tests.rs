@@ -0,0 +1,147 @@
+use futures::stream;
+use sqlx::{Connection, MySqlConnection};
+use vector_lib::event::{BatchNotifier, BatchStatus, Event, LogEvent};
+use vrl::event_path;
+
+use super::SqlSinkConfig;
+use crate::{
+    config::{SinkConfig, SinkContext},
+    sinks::util::test::load_sink,
+    test_util::{
+        components::{
+            COMPONENT_ERROR_TAGS, run_and_assert_sink_compliance, run_and_assert_sink_error,
+        },
+        random_table_name, trace_init,
+    },
+};
+
+const SQL_SINK_TAGS: [&str; 2] = ["endpoint", "protocol"];
+
+fn mysql_url() -> String {
+    std::env::var("MYSQL_URL").unwrap_or_else(|_| {
+        let host = std::env::var("MYSQL_HOST").unwrap_or_else(|_| "localhost".into());
+        format!("mysql://vector:vector@{host}:3306/vector")
+    })
+}
+
+async fn prepare_config(extra: &str) -> (SqlSinkConfig, String, MySqlConnection) {
+    let table = random_table_name();
+    let endpoint = mysql_url();
+    let (config, _) = load_sink::<SqlSinkConfig>(&format!(
+        r#"
+            endpoint = "{endpoint}"
+            table = "{table}"
+            {extra}
+        "#
+    ))
+    .unwrap();
+
+    let connection = MySqlConnection::connect(&endpoint)
+        .await
+        .expect("Failed to connect to MySQL");
+
+    (config, table, connection)
+}
+
+fn create_event(id: i64) -> Event {
+    let mut log = LogEvent::from(format!("message {id}"));
+    log.insert(event_path!("id"), id);
+    log.insert(event_path!("host"), "example.com");
+    log.insert(event_path!("payload", "user"), "alice");
+    log.into()
+}
+
+#[tokio::test]
+async fn healthcheck_passes() {
+    trace_init();
+
+    let (config, _table, _connection) = prepare_config("").await;
+    let (_sink, healthcheck) = config.build(SinkContext::default()).await.unwrap();
+    assert!(healthcheck.await.is_ok());
+}
+
+#[tokio::test]
+async fn insert_events() {
+    trace_init();
+
+    let (config, table, mut connection) = prepare_config("").await;
+    let (sink, _healthcheck) = config.build(SinkContext::default()).await.unwrap();
+    sqlx::query(&format!(
+        "CREATE TABLE {table} (id BIGINT PRIMARY KEY, host TEXT, message TEXT, payload JSON, \
+        level VARCHAR(16) DEFAULT 'info')"
+    ))
+    .execute(&mut connection)
+    .await
+    .unwrap();
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let events = (0..100)
+        .map(|id| create_event(id).with_batch_notifier(&batch))
+        .collect::<Vec<_>>();
+    drop(batch);
+
+    run_and_assert_sink_compliance(sink, stream::iter(events), &SQL_SINK_TAGS).await;
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+    let rows: Vec<(i64, String, String, String)> = sqlx::query_as(&format!(
+        "SELECT id, message, CAST(payload->>'$.user' AS CHAR), level FROM {table} ORDER BY id"
+    ))
+    .fetch_all(&mut connection)
+    .await
+    .unwrap();
+    assert_eq!(rows.len(), 100);
+    for (id, row) in rows.into_iter().enumerate() {
+        assert_eq!(
+            row,
+            (
+                id as i64,
+                format!("message {id}"),
+                "alice".to_string(),
+                "info".to_string()
+            )
+        );
+    }
+}
+
+#[tokio::test]
+async fn insert_mapped_columns() {
+    trace_init();
+
+    let (config, table, mut connection) = prepare_config(
+        r#"
+            columns.event_id = "id"
+            columns.user_name = "payload.user"
+        "#,
+    )
+    .await;
+    let (sink, _healthcheck) = config.build(SinkContext::default()).await.unwrap();
+    sqlx::query(&format!(
+        "CREATE TABLE {table} (event_id BIGINT, user_name VARCHAR(64))"
+    ))
+    .execute(&mut connection)
+    .await
+    .unwrap();
+
+    run_and_assert_sink_compliance(sink, stream::iter([create_event(1)]), &SQL_SINK_TAGS).await;
+
+    let row: (i64, String) = sqlx::query_as(&format!("SELECT event_id, user_name FROM {table}"))
+        .fetch_one(&mut connection)
+        .await
+        .unwrap();
+    assert_eq!(row, (1, "alice".to_string()));
+}
+
+#[tokio::test]
+async fn missing_table_rejects_events() {
+    trace_init();
+
+    let (config, _table, _connection) = prepare_config("").await;
+    let (sink, _healthcheck) = config.build(SinkContext::default()).await.unwrap();
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let event = create_event(0).with_batch_notifier(&batch);
+    drop(batch);
+
+    run_and_assert_sink_error(sink, stream::iter([event]), &COMPONENT_ERROR_TAGS).await;
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+}
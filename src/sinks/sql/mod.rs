@@ -0,0 +1,9 @@
+mod config;
+#[cfg(all(test, feature = "sql_sink-integration-tests"))]
+mod integration_tests;
+mod service;
+mod sink;
+#[cfg(test)]
+mod tests;
+
+pub use self::config::SqlSinkConfig;
@@ -0,0 +1,545 @@
+use std::{
+    collections::BTreeMap,
+    num::NonZeroUsize,
+    task::{Context, Poll},
+};
+
+use futures::future::BoxFuture;
+use indexmap::IndexMap;
+use snafu::{ResultExt, Snafu};
+use sqlx::{Any, AnyPool, any::AnyArguments, query::Query};
+use tower::Service;
+use vector_lib::{
+    EstimatedJsonEncodedSizeOf,
+    codecs::JsonSerializerConfig,
+    event::{Event, EventFinalizers, EventStatus, Finalizable, LogEvent, Value},
+    lookup::lookup_v2::ConfigValuePath,
+    request_metadata::{GroupedCountByteSize, MetaDescriptive, RequestMetadata},
+    stream::DriverResponse,
+};
+use vrl::path::PathPrefix;
+
+use crate::{
+    internal_events::EndpointBytesSent,
+    sinks::{
+        prelude::{RequestMetadataBuilder, RetryLogic},
+        util::sql::ColumnCache,
+    },
+};
+
+/// The databases that the sink can write to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SqlDialect {
+    MySql,
+    Sqlite,
+}
+
+impl SqlDialect {
+    /// Returns the dialect of the connection string scheme, which `sqlx` uses to pick a driver.
+    pub fn from_scheme(scheme: &str) -> crate::Result<Self> {
+        match scheme {
+            "mysql" | "mariadb" => Ok(Self::MySql),
+            "sqlite" => Ok(Self::Sqlite),
+            _ => Err(format!(
+                "Unsupported database `{scheme}`, the endpoint must start with `mysql://` or `sqlite://`."
+            )
+            .into()),
+        }
+    }
+
+    const fn protocol(self) -> &'static str {
+        match self {
+            Self::MySql => "mysql",
+            Self::Sqlite => "sqlite",
+        }
+    }
+
+    /// The maximum number of parameters of a single statement.
+    const fn max_parameters(self) -> usize {
+        match self {
+            Self::MySql => 65_535,
+            Self::Sqlite => 32_766,
+        }
+    }
+
+    fn quote_identifier(self, identifier: &str) -> String {
+        match self {
+            Self::MySql => format!("`{}`", identifier.replace('`', "``")),
+            Self::Sqlite => format!("\"{}\"", identifier.replace('"', "\"\"")),
+        }
+    }
+
+    /// Lists the columns of a table, which is passed as a possibly schema-qualified name.
+    async fn list_columns(
+        self,
+        connection_pool: &AnyPool,
+        table: &str,
+    ) -> Result<Vec<String>, sqlx::Error> {
+        let unquote = |name: &str| name.trim_matches(['`', '"']).to_string();
+        let (schema, table) = match table.rsplit_once('.') {
+            Some((schema, table)) => (Some(unquote(schema)), unquote(table)),
+            None => (None, unquote(table)),
+        };
+
+        let query = match (self, schema) {
+            (Self::MySql, schema) => sqlx::query_scalar(
+                "SELECT CAST(COLUMN_NAME AS CHAR) FROM information_schema.COLUMNS \
+                WHERE TABLE_SCHEMA = COALESCE(?, DATABASE()) AND TABLE_NAME = ? \
+                ORDER BY ORDINAL_POSITION",
+            )
+            .bind(schema)
+            .bind(table),
+            (Self::Sqlite, Some(schema)) => {
+                sqlx::query_scalar("SELECT name FROM pragma_table_info(?, ?) ORDER BY cid")
+                    .bind(table)
+                    .bind(schema)
+            }
+            (Self::Sqlite, None) => {
+                sqlx::query_scalar("SELECT name FROM pragma_table_info(?) ORDER BY cid").bind(table)
+            }
+        };
+        query.fetch_all(connection_pool).await
+    }
+}
+
+#[derive(Clone)]
+pub struct SqlRetryLogic;
+
+impl RetryLogic for SqlRetryLogic {
+    type Error = SqlServiceError;
+    type Request = SqlRequest;
+    type Response = SqlResponse;
+
+    fn is_retriable_error(&self, error: &Self::Error) -> bool {
+        let SqlServiceError::Database { source } = error else {
+            return false;
+        };
+
+        match source {
+            sqlx::Error::Io(_) | sqlx::Error::PoolTimedOut => true,
+            // A deadlock in MySQL, or a locked database in SQLite.
+            sqlx::Error::Database(error) => {
+                matches!(error.code().as_deref(), Some("40001" | "5" | "6"))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A value bound to a parameter of the insert statement.
+#[derive(Clone, Debug, PartialEq)]
+enum Parameter {
+    Null,
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl From<Option<&Value>> for Parameter {
+    fn from(value: Option<&Value>) -> Self {
+        match value {
+            None | Some(Value::Null) => Self::Null,
+            Some(Value::Boolean(value)) => Self::Boolean(*value),
+            Some(Value::Integer(value)) => Self::Integer(*value),
+            Some(Value::Float(value)) => Self::Float(value.into_inner()),
+            Some(Value::Bytes(value)) => Self::Text(String::from_utf8_lossy(value).into_owned()),
+            // This format is accepted by both the `DATETIME` and `TIMESTAMP` types of MySQL, and
+            // sorts correctly as text in SQLite.
+            Some(Value::Timestamp(value)) => {
+                Self::Text(value.format("%Y-%m-%d %H:%M:%S%.6f").to_string())
+            }
+            Some(Value::Regex(value)) => Self::Text(value.to_string()),
+            Some(value @ (Value::Object(_) | Value::Array(_))) => Self::Text(
+                serde_json::to_string(value).expect("event values are serializable to JSON"),
+            ),
+        }
+    }
+}
+
+impl Parameter {
+    fn bind<'q>(self, query: Query<'q, Any, AnyArguments<'q>>) -> Query<'q, Any, AnyArguments<'q>> {
+        match self {
+            Self::Null => query.bind(None::<String>),
+            Self::Boolean(value) => query.bind(value),
+            Self::Integer(value) => query.bind(value),
+            Self::Float(value) => query.bind(value),
+            Self::Text(value) => query.bind(value),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SqlService {
+    connection_pool: AnyPool,
+    dialect: SqlDialect,
+    columns: Option<BTreeMap<String, ConfigValuePath>>,
+    endpoint: String,
+    table_columns: ColumnCache<String>,
+}
+
+impl SqlService {
+    pub fn new(
+        connection_pool: AnyPool,
+        dialect: SqlDialect,
+        columns: Option<BTreeMap<String, ConfigValuePath>>,
+        endpoint: String,
+    ) -> Self {
+        Self {
+            connection_pool,
+            dialect,
+            columns,
+            endpoint,
+            table_columns: ColumnCache::default(),
+        }
+    }
+
+    /// Inserts the events into a table within a single transaction.
+    async fn write(&self, table: &str, logs: Vec<LogEvent>) -> Result<(), SqlServiceError> {
+        let table_columns;
+        let columns = match &self.columns {
+            Some(columns) => columns
+                .iter()
+                .map(|(column, path)| (column.as_str(), Some(path)))
+                .collect::<Vec<_>>(),
+            None => {
+                table_columns = self
+                    .table_columns
+                    .get(table, || {
+                        self.dialect.list_columns(&self.connection_pool, table)
+                    })
+                    .await
+                    .context(DatabaseSnafu)?;
+                if table_columns.is_empty() {
+                    return Err(SqlServiceError::MissingColumns {
+                        table: table.to_string(),
+                    });
+                }
+                table_columns
+                    .iter()
+                    .map(|column| (column.as_str(), None))
+                    .collect()
+            }
+        };
+
+        let Some(groups) = rows(&logs, &columns) else {
+            return Err(SqlServiceError::MissingFields {
+                table: table.to_string(),
+            });
+        };
+
+        let mut transaction = self.connection_pool.begin().await.context(DatabaseSnafu)?;
+        for RowGroup { columns, rows } in groups {
+            let column_count = columns.len();
+            let columns = columns
+                .iter()
+                .map(|column| self.dialect.quote_identifier(column))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let rows_per_statement = (self.dialect.max_parameters() / column_count).max(1);
+
+            for chunk in rows.chunks(rows_per_statement) {
+                let statement = insert_statement(table, &columns, column_count, chunk.len());
+                let mut query = sqlx::query(&statement);
+                for parameter in chunk.iter().flatten() {
+                    query = parameter.clone().bind(query);
+                }
+                query
+                    .execute(&mut *transaction)
+                    .await
+                    .context(DatabaseSnafu)?;
+            }
+        }
+        transaction.commit().await.context(DatabaseSnafu)?;
+        Ok(())
+    }
+}
+
+/// The rows of the events that have fields for the same columns, which are inserted together.
+#[derive(Debug, PartialEq)]
+struct RowGroup<'a> {
+    columns: Vec<&'a str>,
+    rows: Vec<Vec<Parameter>>,
+}
+
+/// Looks up the values of the columns in each event, grouping the events by the columns that they
+/// have fields for.
+///
+/// Each event only sets the columns that it has a field for, so that its other columns get their
+/// default values, while a field that's `null` sets its column to `NULL`. Returns `None` if an
+/// event has no field for any column, as it can't be inserted.
+fn rows<'a>(
+    logs: &[LogEvent],
+    columns: &[(&'a str, Option<&ConfigValuePath>)],
+) -> Option<Vec<RowGroup<'a>>> {
+    let mut groups = IndexMap::<Vec<usize>, Vec<Vec<Parameter>>>::new();
+    for log in logs {
+        let (present, row): (Vec<_>, Vec<_>) = columns
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (column, path))| {
+                let value = match path {
+                    Some(path) => log.get((PathPrefix::Event, &path.0)),
+                    None => log
+                        .value()
+                        .as_object()
+                        .and_then(|fields| fields.get(*column)),
+                };
+                value.map(|value| (index, Parameter::from(Some(value))))
+            })
+            .unzip();
+        if present.is_empty() {
+            return None;
+        }
+        groups.entry(present).or_default().push(row);
+    }
+
+    Some(
+        groups
+            .into_iter()
+            .map(|(present, rows)| RowGroup {
+                columns: present.iter().map(|index| columns[*index].0).collect(),
+                rows,
+            })
+            .collect(),
+    )
+}
+
+/// Builds the statement that inserts `row_count` rows of `column_count` parameters each.
+fn insert_statement(table: &str, columns: &str, column_count: usize, row_count: usize) -> String {
+    let row = format!("({})", vec!["?"; column_count].join(", "));
+    let rows = vec![row.as_str(); row_count].join(", ");
+    format!("INSERT INTO {table} ({columns}) VALUES {rows}")
+}
+
+/// Converts an event into a log event, so that its fields can be looked up by path.
+fn into_log(event: Event) -> Result<LogEvent, vector_common::Error> {
+    match event {
+        Event::Log(log) => Ok(log),
+        Event::Trace(trace) => Ok(trace.into()),
+        Event::Metric(metric) => JsonSerializerConfig::default()
+            .build()
+            .to_json_value(Event::Metric(metric))
+            .and_then(LogEvent::try_from),
+    }
+}
+
+#[derive(Clone)]
+pub struct SqlRequest {
+    pub table: String,
+    pub events: Vec<Event>,
+    pub finalizers: EventFinalizers,
+    pub metadata: RequestMetadata,
+}
+
+impl SqlRequest {
+    pub fn new(table: String, mut events: Vec<Event>) -> Result<Self, String> {
+        let finalizers = events.take_finalizers();
+        let metadata_builder = RequestMetadataBuilder::from_events(&events);
+        let events_size = NonZeroUsize::new(events.estimated_json_encoded_size_of().get())
+            .ok_or("payload should never be zero length")?;
+        let metadata = metadata_builder.with_request_size(events_size);
+        Ok(SqlRequest {
+            table,
+            events,
+            finalizers,
+            metadata,
+        })
+    }
+}
+
+impl Finalizable for SqlRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        self.finalizers.take_finalizers()
+    }
+}
+
+impl MetaDescriptive for SqlRequest {
+    fn get_metadata(&self) -> &RequestMetadata {
+        &self.metadata
+    }
+
+    fn metadata_mut(&mut self) -> &mut RequestMetadata {
+        &mut self.metadata
+    }
+}
+
+pub struct SqlResponse {
+    metadata: RequestMetadata,
+}
+
+impl DriverResponse for SqlResponse {
+    fn event_status(&self) -> EventStatus {
+        EventStatus::Delivered
+    }
+
+    fn events_sent(&self) -> &GroupedCountByteSize {
+        self.metadata.events_estimated_json_encoded_byte_size()
+    }
+
+    fn bytes_sent(&self) -> Option<usize> {
+        Some(self.metadata.request_encoded_size())
+    }
+}
+
+#[derive(Debug, Snafu)]
+pub enum SqlServiceError {
+    #[snafu(display("Database error: {source}"))]
+    Database { source: sqlx::Error },
+
+    #[snafu(display("Serialization error: {source}"))]
+    VectorCommon { source: vector_common::Error },
+
+    #[snafu(display("Table {table} doesn't exist or has no columns"))]
+    MissingColumns { table: String },
+
+    #[snafu(display("An event has no field for any column of table {table}"))]
+    MissingFields { table: String },
+}
+
+impl Service<SqlRequest> for SqlService {
+    type Response = SqlResponse;
+    type Error = SqlServiceError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: SqlRequest) -> Self::Future {
+        let service = self.clone();
+        let future = async move {
+            let table = request.table;
+            let metadata = request.metadata;
+            let logs = request
+                .events
+                .into_iter()
+                .map(into_log)
+                .collect::<Result<Vec<_>, _>>()
+                .context(VectorCommonSnafu)?;
+
+            if let Err(error) = service.write(&table, logs).await {
+                service.table_columns.invalidate(&table);
+                return Err(error);
+            }
+
+            emit!(EndpointBytesSent {
+                byte_size: metadata.request_encoded_size(),
+                protocol: service.dialect.protocol(),
+                endpoint: &service.endpoint,
+            });
+
+            Ok(SqlResponse { metadata })
+        };
+
+        Box::pin(future)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use vrl::{btreemap, value};
+
+    use super::*;
+
+    fn path(path: &str) -> ConfigValuePath {
+        ConfigValuePath::try_from(path.to_string()).unwrap()
+    }
+
+    #[test]
+    fn quotes_identifiers() {
+        assert_eq!(SqlDialect::MySql.quote_identifier("a`b"), "`a``b`");
+        assert_eq!(SqlDialect::Sqlite.quote_identifier("a\"b"), "\"a\"\"b\"");
+    }
+
+    #[test]
+    fn builds_insert_statement() {
+        assert_eq!(
+            insert_statement("logs", "`host`, `message`", 2, 3),
+            "INSERT INTO logs (`host`, `message`) VALUES (?, ?), (?, ?), (?, ?)"
+        );
+    }
+
+    #[test]
+    fn converts_values_to_parameters() {
+        let timestamp = Utc.with_ymd_and_hms(2025, 3, 4, 14, 9, 25).unwrap();
+
+        assert_eq!(Parameter::from(None), Parameter::Null);
+        assert_eq!(Parameter::from(Some(&value!(42))), Parameter::Integer(42));
+        assert_eq!(
+            Parameter::from(Some(&Value::from(timestamp))),
+            Parameter::Text("2025-03-04 14:09:25.000000".to_string())
+        );
+        assert_eq!(
+            Parameter::from(Some(&value!({"a": [1, true]}))),
+            Parameter::Text(r#"{"a":[1,true]}"#.to_string())
+        );
+    }
+
+    #[test]
+    fn rows_group_events_by_their_columns() {
+        let logs = vec![
+            LogEvent::from(btreemap! {"message" => "a", "extra" => 1}),
+            LogEvent::from(btreemap! {"message" => "b", "host" => "example.com"}),
+            LogEvent::from(btreemap! {"message" => "c", "host" => Value::Null}),
+            LogEvent::from(btreemap! {"message" => "d"}),
+        ];
+        let columns = [("host", None), ("message", None), ("level", None)];
+
+        let groups = rows(&logs, &columns).unwrap();
+
+        assert_eq!(
+            groups,
+            [
+                RowGroup {
+                    columns: vec!["message"],
+                    rows: vec![
+                        vec![Parameter::Text("a".to_string())],
+                        vec![Parameter::Text("d".to_string())],
+                    ],
+                },
+                RowGroup {
+                    columns: vec!["host", "message"],
+                    rows: vec![
+                        vec![
+                            Parameter::Text("example.com".to_string()),
+                            Parameter::Text("b".to_string())
+                        ],
+                        vec![Parameter::Null, Parameter::Text("c".to_string())],
+                    ],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rows_map_columns_to_paths() {
+        let logs = vec![LogEvent::from(
+            btreemap! {"user" => btreemap! {"id" => 7}, "message" => "a"},
+        )];
+        let user_id = path("user.id");
+        let missing = path("missing");
+        let columns = [("user_id", Some(&user_id)), ("other", Some(&missing))];
+
+        let groups = rows(&logs, &columns).unwrap();
+
+        assert_eq!(
+            groups,
+            [RowGroup {
+                columns: vec!["user_id"],
+                rows: vec![vec![Parameter::Integer(7)]],
+            }]
+        );
+    }
+
+    #[test]
+    fn rows_require_a_field_in_each_event() {
+        let logs = vec![
+            LogEvent::from(btreemap! {"message" => "a"}),
+            LogEvent::from(btreemap! {"other" => "b"}),
+        ];
+
+        assert!(rows(&logs, &[("message", None)]).is_none());
+    }
+}
@@ -0,0 +1,59 @@
+use super::service::{SqlRequest, SqlRetryLogic, SqlService};
+use crate::sinks::{prelude::*, util::sql::TablePartitioner};
+
+pub struct SqlSink {
+    service: Svc<SqlService, SqlRetryLogic>,
+    batch_settings: BatcherSettings,
+    table: ConfinedTemplate,
+    literal_prefix: String,
+}
+
+impl SqlSink {
+    pub const fn new(
+        service: Svc<SqlService, SqlRetryLogic>,
+        batch_settings: BatcherSettings,
+        table: ConfinedTemplate,
+        literal_prefix: String,
+    ) -> Self {
+        Self {
+            service,
+            batch_settings,
+            table,
+            literal_prefix,
+        }
+    }
+
+    async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let batch_settings = self.batch_settings;
+
+        input
+            .batched_partitioned(
+                TablePartitioner::new(self.table, self.literal_prefix),
+                batch_settings.timeout,
+                |_| batch_settings.as_byte_size_config(),
+            )
+            .filter_map(|(table, events)| async move {
+                let table = table?;
+                match SqlRequest::new(table, events) {
+                    Ok(request) => Some(request),
+                    Err(e) => {
+                        warn!(
+                            message = "Error creating sql sink's request.",
+                            error = %e
+                        );
+                        None
+                    }
+                }
+            })
+            .into_driver(self.service)
+            .run()
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl StreamSink<Event> for SqlSink {
+    async fn run(mut self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        self.run_inner(input).await
+    }
+}
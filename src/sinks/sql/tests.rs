@@ -0,0 +1,167 @@
+//! End-to-end tests against SQLite, which doesn't need a database server.
+
+use futures::stream;
+use sqlx::SqlitePool;
+use vector_lib::event::{BatchNotifier, BatchStatus, Event, LogEvent};
+use vrl::event_path;
+
+use super::SqlSinkConfig;
+use crate::{
+    config::{SinkConfig, SinkContext},
+    sinks::util::test::load_sink,
+    test_util::{
+        components::{
+            COMPONENT_ERROR_TAGS, run_and_assert_sink_compliance, run_and_assert_sink_error,
+        },
+        temp_file, trace_init,
+    },
+};
+
+const SQL_SINK_TAGS: [&str; 2] = ["endpoint", "protocol"];
+
+async fn prepare(config: &str, schema: &[&str]) -> (SqlSinkConfig, SqlitePool) {
+    let endpoint = format!("sqlite://{}?mode=rwc", temp_file().display());
+    let (config, _) = load_sink::<SqlSinkConfig>(&format!(
+        r#"
+            endpoint = "{endpoint}"
+            {config}
+        "#
+    ))
+    .unwrap();
+
+    let pool = SqlitePool::connect(&endpoint).await.unwrap();
+    for statement in schema {
+        sqlx::query(statement).execute(&pool).await.unwrap();
+    }
+    (config, pool)
+}
+
+fn create_event(service: &str, message: &str) -> Event {
+    let mut log = LogEvent::from(message);
+    log.insert(event_path!("service"), service);
+    log.insert(event_path!("user", "id"), 7);
+    log.into()
+}
+
+#[tokio::test]
+async fn inserts_events_into_matching_columns() {
+    trace_init();
+
+    let (config, pool) = prepare(
+        r#"table = "logs""#,
+        &["CREATE TABLE logs (message TEXT, service TEXT, level TEXT DEFAULT 'info')"],
+    )
+    .await;
+    let (sink, _healthcheck) = config.build(SinkContext::default()).await.unwrap();
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let events = (0..3)
+        .map(|index| create_event("api", &format!("message {index}")).with_batch_notifier(&batch))
+        .collect::<Vec<_>>();
+    drop(batch);
+
+    run_and_assert_sink_compliance(sink, stream::iter(events), &SQL_SINK_TAGS).await;
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+    let rows: Vec<(String, String, String)> =
+        sqlx::query_as("SELECT message, service, level FROM logs ORDER BY message")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+    assert_eq!(
+        rows,
+        (0..3)
+            .map(|index| (
+                format!("message {index}"),
+                "api".to_string(),
+                "info".to_string()
+            ))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[tokio::test]
+async fn inserts_events_into_templated_tables_with_mapped_columns() {
+    trace_init();
+
+    let (config, pool) = prepare(
+        r#"
+            table = "logs_{{ service }}"
+            columns.text = "message"
+            columns.user_id = "user.id"
+        "#,
+        &[
+            "CREATE TABLE logs_api (text TEXT, user_id INTEGER)",
+            "CREATE TABLE logs_worker (text TEXT, user_id INTEGER)",
+        ],
+    )
+    .await;
+    let (sink, _healthcheck) = config.build(SinkContext::default()).await.unwrap();
+
+    let events = vec![
+        create_event("api", "first"),
+        create_event("worker", "second"),
+    ];
+    run_and_assert_sink_compliance(sink, stream::iter(events), &SQL_SINK_TAGS).await;
+
+    for (table, message) in [("logs_api", "first"), ("logs_worker", "second")] {
+        let row: (String, i64) = sqlx::query_as(&format!("SELECT text, user_id FROM {table}"))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(row, (message.to_string(), 7));
+    }
+}
+
+#[tokio::test]
+async fn rejects_batch_on_constraint_violation() {
+    trace_init();
+
+    let (config, pool) = prepare(
+        r#"table = "logs""#,
+        &["CREATE TABLE logs (message TEXT UNIQUE)"],
+    )
+    .await;
+    let (sink, _healthcheck) = config.build(SinkContext::default()).await.unwrap();
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let events = ["same", "same"]
+        .into_iter()
+        .map(|message| create_event("api", message).with_batch_notifier(&batch))
+        .collect::<Vec<_>>();
+    drop(batch);
+
+    run_and_assert_sink_error(sink, stream::iter(events), &COMPONENT_ERROR_TAGS).await;
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM logs")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}
+
+#[tokio::test]
+async fn rejects_batch_without_fields_for_any_column() {
+    trace_init();
+
+    let (config, pool) = prepare(
+        r#"table = "logs""#,
+        &["CREATE TABLE logs (level TEXT DEFAULT 'info')"],
+    )
+    .await;
+    let (sink, _healthcheck) = config.build(SinkContext::default()).await.unwrap();
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let events = vec![create_event("api", "message").with_batch_notifier(&batch)];
+    drop(batch);
+
+    run_and_assert_sink_error(sink, stream::iter(events), &COMPONENT_ERROR_TAGS).await;
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM logs")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}
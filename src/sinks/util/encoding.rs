@@ -485,6 +485,7 @@ mod tests {
                 desc_file: test_data_dir().join("test_proto.desc"),
                 message_type: "test_proto.User".to_string(),
                 use_json_names: false,
+                schema_registry: None,
             },
         };
 
@@ -540,6 +541,7 @@ mod tests {
                 desc_file: test_data_dir().join("test_proto.desc"),
                 message_type: "test_proto.User".to_string(),
                 use_json_names: false,
+                schema_registry: None,
             },
         };
 
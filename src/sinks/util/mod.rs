@@ -20,6 +20,8 @@ pub mod service;
 pub mod sink;
 pub mod snappy;
 pub mod socket_bytes_sink;
+#[cfg(any(feature = "sinks-postgres", feature = "sinks-sql"))]
+pub mod sql;
 pub mod statistic;
 pub mod tcp;
 #[cfg(any(test, feature = "test-utils"))]
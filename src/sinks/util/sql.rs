@@ -0,0 +1,191 @@
+//! Building blocks shared by the sinks that write events to SQL tables.
+
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    future::Future,
+    sync::{Arc, Mutex},
+};
+
+use vector_lib::{event::Event, partition::Partitioner};
+
+use crate::{
+    internal_events::TemplateRenderingError,
+    template::{ConfinedTemplate, TemplateRenderingError as RenderError, confined_preview},
+};
+
+/// Partitions events by the table that they are written to.
+pub struct TablePartitioner {
+    table: ConfinedTemplate,
+    literal_prefix: String,
+}
+
+impl TablePartitioner {
+    pub const fn new(table: ConfinedTemplate, literal_prefix: String) -> Self {
+        Self {
+            table,
+            literal_prefix,
+        }
+    }
+
+    /// Checks that the parts of a table name that were rendered from the event can't change the
+    /// meaning of the statement that the name is interpolated in.
+    fn validate(&self, table: String) -> Result<String, RenderError> {
+        let rendered = table.strip_prefix(&self.literal_prefix).unwrap_or(&table);
+        if rendered
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            Ok(table)
+        } else {
+            Err(RenderError::Confined {
+                rendered_preview: confined_preview(&table),
+                rendered_len: table.len(),
+                message: "table names may only contain ASCII letters, digits, and underscores"
+                    .to_string(),
+            })
+        }
+    }
+}
+
+impl Partitioner for TablePartitioner {
+    type Item = Event;
+    type Key = Option<String>;
+
+    fn partition(&self, item: &Self::Item) -> Self::Key {
+        self.table
+            .render_string(item)
+            .and_then(|table| self.validate(table))
+            .map_err(|error| {
+                emit!(TemplateRenderingError {
+                    error,
+                    field: Some("table"),
+                    drop_event: true,
+                });
+            })
+            .ok()
+    }
+}
+
+/// The columns of the tables that have been written to, keyed by table name.
+///
+/// The entry of a table should be invalidated when a write to it fails, so that the columns are
+/// listed again if the table was altered.
+pub struct ColumnCache<C>(Arc<Mutex<HashMap<String, Arc<Vec<C>>>>>);
+
+impl<C> ColumnCache<C> {
+    /// Returns the cached columns of `table`, listing them with `list` if they aren't cached.
+    pub async fn get<F, Fut, E>(&self, table: &str, list: F) -> Result<Arc<Vec<C>>, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Vec<C>, E>>,
+    {
+        if let Some(columns) = self.0.lock().expect("poisoned lock").get(table) {
+            return Ok(Arc::clone(columns));
+        }
+
+        let columns = list().await?;
+
+        let mut cache = self.0.lock().expect("poisoned lock");
+        Ok(match cache.entry(table.to_string()) {
+            Entry::Occupied(entry) => Arc::clone(entry.get()),
+            Entry::Vacant(entry) => Arc::clone(entry.insert(Arc::new(columns))),
+        })
+    }
+
+    pub fn invalidate(&self, table: &str) {
+        self.0.lock().expect("poisoned lock").remove(table);
+    }
+}
+
+impl<C> Clone for ColumnCache<C> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<C> Default for ColumnCache<C> {
+    fn default() -> Self {
+        Self(Arc::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::event::LogEvent;
+    use vrl::event_path;
+
+    use super::*;
+    use crate::template::{ConfinementConfig, Template};
+
+    fn partitioner(table: &str) -> TablePartitioner {
+        let table = Template::try_from(table).unwrap();
+        let literal_prefix = table.literal_prefix().to_string();
+        let table = table
+            .confine(&ConfinementConfig::default(), "postgres", "table")
+            .unwrap();
+        TablePartitioner::new(table, literal_prefix)
+    }
+
+    fn event(service: &str) -> Event {
+        let mut log = LogEvent::default();
+        log.insert(event_path!("service"), service);
+        log.into()
+    }
+
+    #[test]
+    fn partitions_by_rendered_table() {
+        let partitioner = partitioner("logs_{{ service }}");
+
+        assert_eq!(
+            partitioner.partition(&event("api")),
+            Some("logs_api".to_string())
+        );
+        assert_eq!(
+            partitioner.partition(&event("Worker_2")),
+            Some("logs_Worker_2".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_unsafe_table_names() {
+        let partitioner = partitioner("logs_{{ service }}");
+
+        assert_eq!(partitioner.partition(&event("api; DROP TABLE users")), None);
+        assert_eq!(partitioner.partition(&event("api.other")), None);
+        assert_eq!(partitioner.partition(&event("")), Some("logs_".to_string()));
+    }
+
+    #[test]
+    fn static_table_is_not_validated() {
+        let partitioner = partitioner("\"Logs\".events");
+
+        assert_eq!(
+            partitioner.partition(&event("api")),
+            Some("\"Logs\".events".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn column_cache_lists_columns_once() {
+        let cache = ColumnCache::default();
+
+        let columns = cache
+            .get("logs", || async { Ok::<_, ()>(vec!["message"]) })
+            .await
+            .unwrap();
+        assert_eq!(*columns, ["message"]);
+
+        let columns = cache
+            .get("logs", || async { Ok::<_, ()>(vec!["other"]) })
+            .await
+            .unwrap();
+        assert_eq!(*columns, ["message"]);
+
+        cache.invalidate("logs");
+        let columns = cache
+            .get("logs", || async { Ok::<_, ()>(vec!["other"]) })
+            .await
+            .unwrap();
+        assert_eq!(*columns, ["other"]);
+    }
+}
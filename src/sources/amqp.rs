@@ -13,7 +13,7 @@ use vector_lib::{
     EstimatedJsonEncodedSizeOf,
     codecs::{
         DecoderFramedRead,
-        decoding::{DeserializerConfig, FramingConfig},
+        decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     },
     config::{LegacyKey, LogNamespace, SourceAcknowledgementsConfig, log_schema},
     configurable::configurable_component,
@@ -99,6 +99,14 @@ pub struct AmqpSourceConfig {
     #[derivative(Default(value = "default_decoding()"))]
     pub(crate) decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub(crate) reroute_decode_errors: bool,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     pub(crate) acknowledgements: SourceAcknowledgementsConfig,
@@ -139,7 +147,9 @@ impl_generate_config_from_default!(AmqpSourceConfig);
 
 impl AmqpSourceConfig {
     fn decoder(&self, log_namespace: LogNamespace) -> vector_lib::Result<Decoder> {
-        DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace).build()
+        DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+            .with_decode_errors(self.reroute_decode_errors)
+            .build()
     }
 }
 
@@ -191,10 +201,14 @@ impl SourceConfig for AmqpSourceConfig {
                 None,
             );
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decode_errors {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -6,7 +6,7 @@ use tokio::net::TcpStream;
 use tower::ServiceBuilder;
 use tracing::Span;
 use vector_lib::{
-    codecs::decoding::{DeserializerConfig, FramingConfig},
+    codecs::decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     config::{LegacyKey, LogNamespace},
     configurable::configurable_component,
     lookup::owned_value_path,
@@ -91,6 +91,14 @@ pub struct AwsKinesisFirehoseConfig {
     #[serde(default = "default_decoding")]
     decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    reroute_decode_errors: bool,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: SourceAcknowledgementsConfig,
@@ -166,6 +174,7 @@ impl SourceConfig for AwsKinesisFirehoseConfig {
         let log_namespace = cx.log_namespace(self.log_namespace);
         let decoder =
             DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .with_decode_errors(self.reroute_decode_errors)
                 .build()?;
 
         let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
@@ -231,12 +240,13 @@ impl SourceConfig for AwsKinesisFirehoseConfig {
     }
 
     fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
+        let log_namespace = global_log_namespace.merge(self.log_namespace);
         let common_attributes_path = (!self.common_attributes.is_empty()).then_some(
             LegacyKey::InsertIfEmpty(owned_value_path!("common_attributes")),
         );
         let schema_definition = self
             .decoding
-            .schema_definition(global_log_namespace.merge(self.log_namespace))
+            .schema_definition(log_namespace)
             .with_standard_vector_source_metadata()
             .with_source_metadata(
                 Self::NAME,
@@ -264,10 +274,14 @@ impl SourceConfig for AwsKinesisFirehoseConfig {
                 None,
             );
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decode_errors {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn resources(&self) -> Vec<Resource> {
@@ -290,6 +304,7 @@ impl GenerateConfig for AwsKinesisFirehoseConfig {
             record_compression: Default::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
+            reroute_decode_errors: false,
             acknowledgements: Default::default(),
             log_namespace: None,
             keepalive: Default::default(),
@@ -400,6 +415,7 @@ mod tests {
                 record_compression,
                 framing: default_framing_message_based(),
                 decoding: default_decoding(),
+                reroute_decode_errors: false,
                 acknowledgements: true.into(),
                 log_namespace: Some(log_namespace),
                 keepalive: Default::default(),
@@ -11,6 +11,7 @@ use vector_lib::{
         NewlineDelimitedDecoderConfig,
         decoding::{
             DeserializerConfig, FramingConfig, NewlineDelimitedDecoderOptions, OversizedAction,
+            decode_errors_output,
         },
     },
     config::{LegacyKey, LogNamespace},
@@ -133,6 +134,14 @@ pub struct AwsS3Config {
     #[derivative(Default(value = "default_decoding()"))]
     pub decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub reroute_decode_errors: bool,
+
     /// Specifies which addressing style to use.
     ///
     /// This controls whether the bucket name is in the hostname, or part of the URL.
@@ -226,10 +235,14 @@ impl SourceConfig for AwsS3Config {
             schema_definition = schema_definition.unknown_fields(Kind::bytes());
         }
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decode_errors {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -262,6 +275,7 @@ impl AwsS3Config {
 
         let decoder =
             DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .with_decode_errors(self.reroute_decode_errors)
                 .build()?;
 
         match self.sqs {
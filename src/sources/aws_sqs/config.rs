@@ -1,7 +1,7 @@
 use std::num::NonZeroUsize;
 
 use vector_lib::{
-    codecs::decoding::{DeserializerConfig, FramingConfig},
+    codecs::decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     config::{LegacyKey, LogNamespace},
     configurable::configurable_component,
     lookup::owned_value_path,
@@ -91,6 +91,14 @@ pub struct AwsSqsConfig {
     #[derivative(Default(value = "default_decoding()"))]
     pub decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub reroute_decode_errors: bool,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     pub acknowledgements: SourceAcknowledgementsConfig,
@@ -113,6 +121,7 @@ impl SourceConfig for AwsSqsConfig {
         let client = self.build_client(&cx).await?;
         let decoder =
             DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .with_decode_errors(self.reroute_decode_errors)
                 .build()?;
         let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
 
@@ -136,9 +145,10 @@ impl SourceConfig for AwsSqsConfig {
     }
 
     fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
+        let log_namespace = global_log_namespace.merge(self.log_namespace);
         let schema_definition = self
             .decoding
-            .schema_definition(global_log_namespace.merge(self.log_namespace))
+            .schema_definition(log_namespace)
             .with_standard_vector_source_metadata()
             .with_source_metadata(
                 Self::NAME,
@@ -148,10 +158,14 @@ impl SourceConfig for AwsSqsConfig {
                 Some("timestamp"),
             );
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decode_errors {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -32,7 +32,7 @@ use tokio::net::TcpStream;
 use tower::ServiceBuilder;
 use tracing::Span;
 use vector_lib::{
-    codecs::decoding::{DeserializerConfig, FramingConfig},
+    codecs::decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     config::{LegacyKey, LogNamespace},
     configurable::configurable_component,
     event::{BatchNotifier, BatchStatus},
@@ -144,6 +144,14 @@ pub struct DatadogAgentConfig {
     #[serde(default = "default_decoding")]
     decoding: DeserializerConfig,
 
+    /// Routes logs that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    reroute_decode_errors: bool,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: SourceAcknowledgementsConfig,
@@ -173,6 +181,7 @@ impl GenerateConfig for DatadogAgentConfig {
             store_api_key: true,
             framing: default_framing_message_based(),
             decoding: default_decoding(),
+            reroute_decode_errors: false,
             acknowledgements: SourceAcknowledgementsConfig::default(),
             disable_logs: false,
             disable_metrics: false,
@@ -202,6 +211,7 @@ impl SourceConfig for DatadogAgentConfig {
 
         let decoder =
             DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .with_decode_errors(self.reroute_decode_errors)
                 .build()?;
 
         let tls = MaybeTlsSettings::from_config(self.tls.as_ref(), true)?;
@@ -337,6 +347,11 @@ impl SourceConfig for DatadogAgentConfig {
                 definition,
             ))
         }
+        if self.reroute_decode_errors && !self.disable_logs {
+            output.push(decode_errors_output(
+                global_log_namespace.merge(self.log_namespace),
+            ));
+        }
         output
     }
 
@@ -1621,6 +1621,7 @@ fn test_config_outputs_with_disabled_data_types() {
             log_namespace: Some(false),
             keepalive: Default::default(),
             send_timeout_secs: None,
+            reroute_decode_errors: false,
         };
 
         let outputs: Vec<DataType> = config
@@ -2065,6 +2066,7 @@ fn test_config_outputs() {
             log_namespace: Some(false),
             keepalive: Default::default(),
             send_timeout_secs: None,
+            reroute_decode_errors: false,
         };
 
         let mut outputs = config
@@ -2826,6 +2828,7 @@ impl ValidatableComponent for DatadogAgentConfig {
             log_namespace: Some(false),
             keepalive: Default::default(),
             send_timeout_secs: None,
+            reroute_decode_errors: false,
         };
 
         let log_namespace: LogNamespace = config.log_namespace.unwrap_or_default().into();
@@ -11,7 +11,7 @@ use vector_lib::{
     EstimatedJsonEncodedSizeOf,
     codecs::{
         DecoderFramedRead, StreamDecodingError,
-        decoding::{DeserializerConfig, FramingConfig},
+        decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     },
     config::{DataType, LegacyKey, LogNamespace},
     configurable::configurable_component,
@@ -72,6 +72,14 @@ pub struct DemoLogsConfig {
     #[serde(default = "default_decoding")]
     pub decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub reroute_decode_errors: bool,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[serde(default)]
     #[configurable(metadata(docs::hidden))]
@@ -294,6 +302,7 @@ impl SourceConfig for DemoLogsConfig {
         self.format.validate()?;
         let decoder =
             DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .with_decode_errors(self.reroute_decode_errors)
                 .build()?;
         Ok(Box::pin(demo_logs_source(
             self.interval,
@@ -323,10 +332,14 @@ impl SourceConfig for DemoLogsConfig {
                 Some("service"),
             );
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             DataType::Log,
             schema_definition,
-        )]
+        )];
+        if self.reroute_decode_errors {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
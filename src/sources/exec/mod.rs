@@ -15,7 +15,7 @@ use vector_lib::{
     EstimatedJsonEncodedSizeOf,
     codecs::{
         Decoder, DecoderFramedRead, DecodingConfig, StreamDecodingError,
-        decoding::{DeserializerConfig, FramingConfig},
+        decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     },
     config::{LegacyKey, LogNamespace, log_schema},
     configurable::configurable_component,
@@ -86,6 +86,14 @@ pub struct ExecConfig {
     #[serde(default = "default_decoding")]
     decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    reroute_decode_errors: bool,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[configurable(metadata(docs::hidden))]
     #[serde(default)]
@@ -155,6 +163,7 @@ impl Default for ExecConfig {
             maximum_buffer_size_bytes: default_maximum_buffer_size(),
             framing: None,
             decoding: default_decoding(),
+            reroute_decode_errors: false,
             log_namespace: None,
         }
     }
@@ -254,7 +263,9 @@ impl SourceConfig for ExecConfig {
             .framing
             .clone()
             .unwrap_or_else(|| self.decoding.default_stream_framing());
-        let decoder = DecodingConfig::new(framing, self.decoding.clone(), log_namespace).build()?;
+        let decoder = DecodingConfig::new(framing, self.decoding.clone(), log_namespace)
+            .with_decode_errors(self.reroute_decode_errors)
+            .build()?;
 
         match &self.mode {
             Mode::Scheduled => {
@@ -328,10 +339,14 @@ impl SourceConfig for ExecConfig {
                 None,
             );
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decode_errors {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
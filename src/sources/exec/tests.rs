@@ -184,6 +184,7 @@ fn test_build_command() {
         maximum_buffer_size_bytes: default_maximum_buffer_size(),
         framing: None,
         decoding: default_decoding(),
+        reroute_decode_errors: false,
         log_namespace: None,
     };
 
@@ -218,6 +219,7 @@ fn test_build_command_custom_environment() {
         maximum_buffer_size_bytes: default_maximum_buffer_size(),
         framing: None,
         decoding: default_decoding(),
+        reroute_decode_errors: false,
         log_namespace: None,
     };
 
@@ -248,6 +250,7 @@ fn test_build_command_clear_environment() {
         maximum_buffer_size_bytes: default_maximum_buffer_size(),
         framing: None,
         decoding: default_decoding(),
+        reroute_decode_errors: false,
         log_namespace: None,
     };
 
@@ -457,6 +460,7 @@ fn standard_streaming_test_config() -> ExecConfig {
         maximum_buffer_size_bytes: default_maximum_buffer_size(),
         framing: None,
         decoding: default_decoding(),
+        reroute_decode_errors: false,
         log_namespace: None,
     }
 }
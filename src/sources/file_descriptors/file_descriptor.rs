@@ -13,7 +13,9 @@ use vector_lib::{
 
 use super::{FileDescriptorConfig, outputs};
 use crate::{
-    config::{GenerateConfig, Resource, SourceConfig, SourceContext, SourceOutput},
+    config::{
+        GenerateConfig, Resource, SourceConfig, SourceContext, SourceOutput, TestInputDecoder,
+    },
     serde::default_decoding,
 };
 /// Configuration for the `file_descriptor` source.
@@ -41,6 +43,14 @@ pub struct FileDescriptorSourceConfig {
     #[serde(default = "default_decoding")]
     pub decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub reroute_decode_errors: bool,
+
     /// The file descriptor number to read from.
     #[configurable(metadata(docs::examples = 10))]
     #[configurable(metadata(docs::human_name = "File Descriptor Number"))]
@@ -65,6 +75,10 @@ impl FileDescriptorConfig for FileDescriptorSourceConfig {
         self.decoding.clone()
     }
 
+    fn reroute_decode_errors(&self) -> bool {
+        self.reroute_decode_errors
+    }
+
     fn description(&self) -> String {
         format!("file descriptor {}", self.fd)
     }
@@ -105,7 +119,13 @@ impl SourceConfig for FileDescriptorSourceConfig {
     fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
         let log_namespace = global_log_namespace.merge(self.log_namespace);
 
-        outputs(log_namespace, &self.host_key, &self.decoding, Self::NAME)
+        outputs(
+            log_namespace,
+            &self.host_key,
+            &self.decoding,
+            self.reroute_decode_errors,
+            Self::NAME,
+        )
     }
 
     fn resources(&self) -> Vec<Resource> {
@@ -115,6 +135,15 @@ impl SourceConfig for FileDescriptorSourceConfig {
     fn can_acknowledge(&self) -> bool {
         false
     }
+
+    fn build_decoder(
+        &self,
+        global_log_namespace: LogNamespace,
+    ) -> crate::Result<Option<Box<dyn TestInputDecoder>>> {
+        let log_namespace = global_log_namespace.merge(self.log_namespace);
+        let decoder = self.decoder(log_namespace)?;
+        Ok(Some(Box::new(decoder)))
+    }
 }
 
 #[cfg(test)]
@@ -149,6 +178,7 @@ mod tests {
                 host_key: Default::default(),
                 framing: None,
                 decoding: default_decoding(),
+                reroute_decode_errors: false,
                 fd: read_fd.into_raw_fd() as u32,
                 log_namespace: None,
             };
@@ -190,6 +220,7 @@ mod tests {
                 host_key: Default::default(),
                 framing: None,
                 decoding: default_decoding(),
+                reroute_decode_errors: false,
                 fd: read_fd.into_raw_fd() as u32,
                 log_namespace: Some(true),
             };
@@ -240,6 +271,7 @@ mod tests {
                 host_key: Default::default(),
                 framing: None,
                 decoding: default_decoding(),
+                reroute_decode_errors: false,
                 fd: write_fd.as_raw_fd() as u32, // intentionally giving the source a write-only fd
                 log_namespace: None,
             };
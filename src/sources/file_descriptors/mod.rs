@@ -9,7 +9,7 @@ use vector_lib::{
     EstimatedJsonEncodedSizeOf,
     codecs::{
         DecoderFramedRead, StreamDecodingError,
-        decoding::{DeserializerConfig, FramingConfig},
+        decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     },
     config::{LegacyKey, LogNamespace},
     configurable::NamedComponent,
@@ -36,8 +36,19 @@ pub trait FileDescriptorConfig: NamedComponent {
     fn host_key(&self) -> Option<OptionalValuePath>;
     fn framing(&self) -> Option<FramingConfig>;
     fn decoding(&self) -> DeserializerConfig;
+    fn reroute_decode_errors(&self) -> bool;
     fn description(&self) -> String;
 
+    fn decoder(&self, log_namespace: LogNamespace) -> crate::Result<Decoder> {
+        let decoding = self.decoding();
+        let framing = self
+            .framing()
+            .unwrap_or_else(|| decoding.default_stream_framing());
+        DecodingConfig::new(framing, decoding, log_namespace)
+            .with_decode_errors(self.reroute_decode_errors())
+            .build()
+    }
+
     fn source<R>(
         &self,
         reader: R,
@@ -56,11 +67,7 @@ pub trait FileDescriptorConfig: NamedComponent {
 
         let description = self.description();
 
-        let decoding = self.decoding();
-        let framing = self
-            .framing()
-            .unwrap_or_else(|| decoding.default_stream_framing());
-        let decoder = DecodingConfig::new(framing, decoding, log_namespace).build()?;
+        let decoder = self.decoder(log_namespace)?;
 
         let (sender, receiver) = mpsc::channel(1024);
 
@@ -204,6 +211,7 @@ fn outputs(
     log_namespace: LogNamespace,
     host_key: &Option<OptionalValuePath>,
     decoding: &DeserializerConfig,
+    reroute_decode_errors: bool,
     source_name: &'static str,
 ) -> Vec<SourceOutput> {
     let schema_definition = decoding
@@ -220,8 +228,12 @@ fn outputs(
         )
         .with_standard_vector_source_metadata();
 
-    vec![SourceOutput::new_maybe_logs(
+    let mut outputs = vec![SourceOutput::new_maybe_logs(
         decoding.output_type(),
         schema_definition,
-    )]
+    )];
+    if reroute_decode_errors {
+        outputs.push(decode_errors_output(log_namespace));
+    }
+    outputs
 }
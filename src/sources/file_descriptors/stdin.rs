@@ -9,7 +9,7 @@ use vector_lib::{
 
 use super::{FileDescriptorConfig, outputs};
 use crate::{
-    config::{Resource, SourceConfig, SourceContext, SourceOutput},
+    config::{Resource, SourceConfig, SourceContext, SourceOutput, TestInputDecoder},
     serde::default_decoding,
 };
 
@@ -40,6 +40,14 @@ pub struct StdinConfig {
     #[serde(default = "default_decoding")]
     pub decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub reroute_decode_errors: bool,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[configurable(metadata(docs::hidden))]
     #[serde(default)]
@@ -59,6 +67,10 @@ impl FileDescriptorConfig for StdinConfig {
         self.decoding.clone()
     }
 
+    fn reroute_decode_errors(&self) -> bool {
+        self.reroute_decode_errors
+    }
+
     fn description(&self) -> String {
         Self::NAME.to_string()
     }
@@ -71,6 +83,7 @@ impl Default for StdinConfig {
             host_key: Default::default(),
             framing: None,
             decoding: default_decoding(),
+            reroute_decode_errors: false,
             log_namespace: None,
         }
     }
@@ -94,7 +107,13 @@ impl SourceConfig for StdinConfig {
     fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
         let log_namespace = global_log_namespace.merge(self.log_namespace);
 
-        outputs(log_namespace, &self.host_key, &self.decoding, Self::NAME)
+        outputs(
+            log_namespace,
+            &self.host_key,
+            &self.decoding,
+            self.reroute_decode_errors,
+            Self::NAME,
+        )
     }
 
     fn resources(&self) -> Vec<Resource> {
@@ -104,6 +123,15 @@ impl SourceConfig for StdinConfig {
     fn can_acknowledge(&self) -> bool {
         false
     }
+
+    fn build_decoder(
+        &self,
+        global_log_namespace: LogNamespace,
+    ) -> crate::Result<Option<Box<dyn TestInputDecoder>>> {
+        let log_namespace = global_log_namespace.merge(self.log_namespace);
+        let decoder = self.decoder(log_namespace)?;
+        Ok(Some(Box::new(decoder)))
+    }
 }
 
 #[cfg(test)]
@@ -25,7 +25,7 @@ use tonic::{
 };
 use vector_lib::{
     byte_size_of::ByteSizeOf,
-    codecs::decoding::{DeserializerConfig, FramingConfig},
+    codecs::decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     config::{LegacyKey, LogNamespace},
     configurable::configurable_component,
     finalizer::UnorderedFinalizer,
@@ -220,6 +220,14 @@ pub struct PubsubConfig {
     #[derivative(Default(value = "default_decoding()"))]
     pub decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub reroute_decode_errors: bool,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     pub acknowledgements: SourceAcknowledgementsConfig,
@@ -321,6 +329,7 @@ impl SourceConfig for PubsubConfig {
                 self.decoding.clone(),
                 log_namespace,
             )
+            .with_decode_errors(self.reroute_decode_errors)
             .build()?,
             acknowledgements: cx.do_acknowledgements(self.acknowledgements),
             shutdown: cx.shutdown,
@@ -367,10 +376,14 @@ impl SourceConfig for PubsubConfig {
                 None,
             );
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             DataType::Log,
             schema_definition,
-        )]
+        )];
+        if self.reroute_decode_errors {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
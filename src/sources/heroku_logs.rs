@@ -12,7 +12,7 @@ use tokio_util::codec::Decoder as _;
 use vector_lib::{
     codecs::{
         StreamDecodingError,
-        decoding::{DeserializerConfig, FramingConfig},
+        decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     },
     config::{DataType, LegacyKey, LogNamespace},
     configurable::configurable_component,
@@ -83,6 +83,14 @@ pub struct LogplexConfig {
     #[serde(default = "default_decoding")]
     decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    reroute_decode_errors: bool,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: SourceAcknowledgementsConfig,
@@ -169,6 +177,7 @@ impl Default for LogplexConfig {
             auth: None,
             framing: default_framing_message_based(),
             decoding: default_decoding(),
+            reroute_decode_errors: false,
             acknowledgements: SourceAcknowledgementsConfig::default(),
             log_namespace: None,
             keepalive: KeepaliveConfig::default(),
@@ -190,6 +199,7 @@ impl SourceConfig for LogplexConfig {
 
         let decoder =
             DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .with_decode_errors(self.reroute_decode_errors)
                 .build()?;
 
         let source = LogplexSource {
@@ -218,8 +228,13 @@ impl SourceConfig for LogplexConfig {
     fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
         // There is a global and per-source `log_namespace` config.
         // The source config overrides the global setting and is merged here.
-        let schema_def = self.schema_definition(global_log_namespace.merge(self.log_namespace));
-        vec![SourceOutput::new_maybe_logs(DataType::Log, schema_def)]
+        let log_namespace = global_log_namespace.merge(self.log_namespace);
+        let schema_def = self.schema_definition(log_namespace);
+        let mut outputs = vec![SourceOutput::new_maybe_logs(DataType::Log, schema_def)];
+        if self.reroute_decode_errors {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn resources(&self) -> Vec<Resource> {
@@ -487,6 +502,7 @@ mod tests {
                 auth,
                 framing: default_framing_message_based(),
                 decoding: default_decoding(),
+                reroute_decode_errors: false,
                 acknowledgements: acknowledgements.into(),
                 log_namespace: None,
                 keepalive: Default::default(),
@@ -1,7 +1,7 @@
 //! Generalized HTTP client source.
 //! Calls an endpoint at an interval, decoding the HTTP responses into events.
 
-use std::{collections::HashMap, time::Duration};
+use std::{collections::HashMap, path::PathBuf, time::Duration};
 
 use bytes::{Bytes, BytesMut};
 use chrono::Utc;
@@ -14,7 +14,7 @@ use vector_lib::{
     TimeZone,
     codecs::{
         StreamDecodingError,
-        decoding::{DeserializerConfig, FramingConfig},
+        decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     },
     compile_vrl,
     config::{LogNamespace, SourceOutput, log_schema},
@@ -26,6 +26,7 @@ use vrl::{
     prelude::TypeState,
 };
 
+use super::pagination::{PaginationConfig, Paginator};
 use crate::{
     codecs::{Decoder, DecodingConfig},
     config::{SourceConfig, SourceContext},
@@ -100,6 +101,14 @@ pub struct HttpClientConfig {
     #[serde(default = "default_framing_message_based")]
     pub framing: FramingConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub reroute_decode_errors: bool,
+
     /// Headers to apply to the HTTP requests.
     ///
     /// One or more values for the same header can be provided.
@@ -131,6 +140,22 @@ pub struct HttpClientConfig {
     #[configurable(derived)]
     pub auth: Option<Auth>,
 
+    #[configurable(derived)]
+    pub pagination: Option<PaginationConfig>,
+
+    /// The directory used to persist the pagination checkpoint.
+    ///
+    /// By default, the [global `data_dir` option][global_data_dir] is used.
+    /// Make sure the running user has write permissions to this directory.
+    ///
+    /// This is only used when `pagination.checkpoint` is enabled.
+    ///
+    /// [global_data_dir]: https://vector.dev/docs/reference/configuration/global-options/#data_dir
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "/var/lib/vector"))]
+    #[configurable(metadata(docs::human_name = "Data Directory"))]
+    pub data_dir: Option<PathBuf>,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[configurable(metadata(docs::hidden))]
     #[serde(default)]
@@ -221,11 +246,14 @@ impl Default for HttpClientConfig {
             timeout: default_timeout(),
             decoding: default_decoding(),
             framing: default_framing_message_based(),
+            reroute_decode_errors: false,
             headers: HashMap::new(),
             method: default_http_method(),
             body: None,
             tls: None,
             auth: None,
+            pagination: None,
+            data_dir: None,
             log_namespace: None,
         }
     }
@@ -361,12 +389,29 @@ impl SourceConfig for HttpClientConfig {
 
         let content_type = self.decoding.content_type(&self.framing).to_string();
 
+        let paginator = self
+            .pagination
+            .as_ref()
+            .map(|pagination| -> crate::Result<Paginator> {
+                let data_dir = pagination
+                    .checkpoint
+                    .then(|| {
+                        cx.globals
+                            .resolve_and_make_data_subdir(self.data_dir.as_ref(), cx.key.id())
+                    })
+                    .transpose()?;
+                Ok(Paginator::new(pagination, data_dir))
+            })
+            .transpose()?;
+
         // Create context with the config for dynamic query parameter and body evaluation
         let context = HttpClientContext {
             decoder,
             log_namespace,
             query,
             body,
+            paginator,
+            pages: 0,
         };
 
         warn_if_interval_too_low(self.timeout, self.interval);
@@ -381,6 +426,8 @@ impl SourceConfig for HttpClientConfig {
             tls,
             proxy: cx.proxy.clone(),
             shutdown: cx.shutdown,
+            // Pages must be requested in order, so a scrape can't overlap with the previous one.
+            sequential: self.pagination.is_some(),
         };
 
         Ok(call(inputs, context, cx.out, self.method).boxed())
@@ -396,10 +443,14 @@ impl SourceConfig for HttpClientConfig {
             .schema_definition(log_namespace)
             .with_standard_vector_source_metadata();
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decode_errors {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -415,6 +466,7 @@ impl HttpClientConfig {
             log_namespace.unwrap_or_else(|| self.log_namespace.unwrap_or(false).into());
 
         DecodingConfig::new(framing, decoding, log_namespace)
+            .with_decode_errors(self.reroute_decode_errors)
     }
 }
 
@@ -425,6 +477,8 @@ pub struct HttpClientContext {
     pub log_namespace: LogNamespace,
     query: Query,
     body: Option<CompiledParam>,
+    paginator: Option<Paginator>,
+    pages: usize,
 }
 
 impl HttpClientContext {
@@ -449,6 +503,54 @@ impl HttpClientContext {
         }
         events
     }
+
+    /// Resolves the query parameters containing VRL expressions into `url`
+    fn resolve_query(&self, url: &Uri) -> Option<Uri> {
+        if !self.query.has_vrl {
+            return None;
+        }
+
+        // Resolve all query parameters with VRL expressions
+        let processed_query: Option<HashMap<_, _>> = self
+            .query
+            .compiled
+            .iter()
+            .map(|(name, value)| {
+                let resolved = match value {
+                    CompiledQueryParameterValue::SingleParam(param) => {
+                        let result = resolve_compiled_param(param)?;
+                        QueryParameterValue::SingleParam(ParameterValue::String(result))
+                    }
+                    CompiledQueryParameterValue::MultiParams(params) => {
+                        let results: Option<Vec<_>> = params
+                            .iter()
+                            .map(|p| resolve_compiled_param(p).map(ParameterValue::String))
+                            .collect();
+                        QueryParameterValue::MultiParams(results?)
+                    }
+                };
+                Some((name.clone(), resolved))
+            })
+            .collect();
+
+        // Build base URI and add query parameters
+        let base_uri = Uri::builder()
+            .scheme(
+                url.scheme()
+                    .cloned()
+                    .unwrap_or_else(|| http::uri::Scheme::try_from("http").unwrap()),
+            )
+            .authority(
+                url.authority()
+                    .cloned()
+                    .unwrap_or_else(|| http::uri::Authority::try_from("localhost").unwrap()),
+            )
+            .path_and_query(url.path().to_string())
+            .build()
+            .ok()?;
+
+        Some(build_url(&base_uri, &processed_query?))
+    }
 }
 
 impl HttpClientBuilder for HttpClientContext {
@@ -516,50 +618,24 @@ impl http_client::HttpClientContext for HttpClientContext {
 
     /// Process the URL dynamically before each request
     fn process_url(&self, url: &Uri) -> Option<Uri> {
-        if !self.query.has_vrl {
-            return None;
+        let resolved = self.resolve_query(url);
+        match &self.paginator {
+            Some(paginator) => Some(paginator.first_url(resolved.as_ref().unwrap_or(url))),
+            None => resolved,
         }
+    }
 
-        // Resolve all query parameters with VRL expressions
-        let processed_query: Option<HashMap<_, _>> = self
-            .query
-            .compiled
-            .iter()
-            .map(|(name, value)| {
-                let resolved = match value {
-                    CompiledQueryParameterValue::SingleParam(param) => {
-                        let result = resolve_compiled_param(param)?;
-                        QueryParameterValue::SingleParam(ParameterValue::String(result))
-                    }
-                    CompiledQueryParameterValue::MultiParams(params) => {
-                        let results: Option<Vec<_>> = params
-                            .iter()
-                            .map(|p| resolve_compiled_param(p).map(ParameterValue::String))
-                            .collect();
-                        QueryParameterValue::MultiParams(results?)
-                    }
-                };
-                Some((name.clone(), resolved))
-            })
-            .collect();
-
-        // Build base URI and add query parameters
-        let base_uri = Uri::builder()
-            .scheme(
-                url.scheme()
-                    .cloned()
-                    .unwrap_or_else(|| http::uri::Scheme::try_from("http").unwrap()),
-            )
-            .authority(
-                url.authority()
-                    .cloned()
-                    .unwrap_or_else(|| http::uri::Authority::try_from("localhost").unwrap()),
-            )
-            .path_and_query(url.path().to_string())
-            .build()
-            .ok()?;
-
-        Some(build_url(&base_uri, &processed_query?))
+    /// Get the URL of the next page, if pagination is enabled
+    fn next_page_url(
+        &mut self,
+        url: &Uri,
+        header: &Parts,
+        body: &Bytes,
+        events: &[Event],
+    ) -> Option<Uri> {
+        let paginator = self.paginator.as_ref()?;
+        self.pages += 1;
+        paginator.advance(url, header, body, events, self.pages)
     }
 
     /// Enriches events with source_type, timestamp
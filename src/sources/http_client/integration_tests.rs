@@ -54,11 +54,14 @@ async fn invalid_endpoint() {
         query: HashMap::new(),
         decoding: default_decoding(),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
         auth: None,
         tls: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -74,11 +77,14 @@ async fn collected_logs_bytes() {
         query: HashMap::new(),
         decoding: DeserializerConfig::Bytes,
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
         auth: None,
         tls: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -100,11 +106,14 @@ async fn collected_logs_json() {
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
         auth: None,
         tls: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -126,11 +135,14 @@ async fn collected_metrics_native_json() {
         query: HashMap::new(),
         decoding: DeserializerConfig::NativeJson(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
         auth: None,
         tls: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -157,11 +169,14 @@ async fn collected_trace_native_json() {
         query: HashMap::new(),
         decoding: DeserializerConfig::NativeJson(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
         auth: None,
         tls: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -183,11 +198,14 @@ async fn unauthorized_no_auth() {
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
         auth: None,
         tls: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -203,6 +221,7 @@ async fn unauthorized_wrong_auth() {
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
@@ -211,6 +230,8 @@ async fn unauthorized_wrong_auth() {
             user: "white_rabbit".to_string(),
             password: "morpheus".to_string().into(),
         }),
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -226,6 +247,7 @@ async fn authorized() {
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
@@ -234,6 +256,8 @@ async fn authorized() {
             user: "user".to_string(),
             password: "pass".to_string().into(),
         }),
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -249,6 +273,7 @@ async fn tls_invalid_ca() {
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
@@ -257,6 +282,8 @@ async fn tls_invalid_ca() {
             ..Default::default()
         }),
         auth: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -272,6 +299,7 @@ async fn tls_valid() {
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
@@ -280,6 +308,8 @@ async fn tls_valid() {
             ..Default::default()
         }),
         auth: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -296,11 +326,14 @@ async fn shutdown() {
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
         tls: None,
         auth: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     };
 
@@ -1,5 +1,7 @@
 #[cfg(feature = "sources-http_client")]
 pub mod client;
+#[cfg(feature = "sources-http_client")]
+pub mod pagination;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,573 @@
+//! Pagination support for the `http_client` source.
+//!
+//! A paginated scrape keeps requesting pages until the configured strategy reports that there is
+//! no next page, a page yields no events, or `max_pages` is reached. The position of the next page
+//! is kept across scrapes, and can optionally be checkpointed to disk so that a restarted source
+//! resumes where it left off.
+//!
+//! Positions are only ever taken from the server: the cursor from the response body, the URL of
+//! the `Link` header, or the offset following the events received. Once there is no next page, a
+//! cursor or link is forgotten and the next scrape starts from the configured URL, while an offset
+//! is kept so that the events already received aren't requested again.
+
+use std::{
+    fs, io,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use bytes::Bytes;
+use http::{Uri, response::Parts};
+use vector_lib::{
+    configurable::configurable_component, event::Event, lookup::lookup_v2::ConfigValuePath,
+};
+use vrl::path::OwnedSegment;
+
+use crate::{internal_events::HttpClientCheckpointError, sources::util::http_client};
+
+const CHECKPOINT_FILENAME: &str = "checkpoint.txt";
+
+/// Pagination configuration.
+///
+/// When set, each scrape keeps requesting the following pages until there are no more pages, a
+/// page yields no events, or `max_pages` is reached. Once there are no more pages, the next scrape
+/// starts again from the configured `endpoint`, except with the `offset` strategy, which continues
+/// from the offset following the events already received.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct PaginationConfig {
+    #[configurable(derived)]
+    #[serde(flatten)]
+    pub strategy: PaginationStrategy,
+
+    /// The maximum number of pages to request in a single scrape.
+    ///
+    /// When the limit is reached, the next scrape continues from the following page.
+    #[serde(default = "default_max_pages")]
+    pub max_pages: NonZeroUsize,
+
+    /// Whether to persist the position of the next page in the data directory.
+    ///
+    /// When enabled, a restarted source resumes from the last known page instead of starting
+    /// again from the configured `endpoint`.
+    #[serde(default)]
+    pub checkpoint: bool,
+}
+
+const fn default_max_pages() -> NonZeroUsize {
+    NonZeroUsize::new(100).unwrap()
+}
+
+/// The strategy used to find the next page of results.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "strategy", rename_all = "snake_case")]
+#[configurable(metadata(docs::enum_tag_description = "The pagination strategy to use."))]
+pub enum PaginationStrategy {
+    /// Follow a cursor found in the JSON response body.
+    ///
+    /// The cursor is passed to the next request in the `query_param` query parameter.
+    Cursor {
+        /// The path of the cursor in the JSON response body.
+        ///
+        /// A missing, `null`, or empty cursor indicates that there are no more pages.
+        #[configurable(metadata(docs::examples = "next_cursor"))]
+        #[configurable(metadata(docs::examples = "meta.pagination.next"))]
+        cursor_path: ConfigValuePath,
+
+        /// The query parameter used to pass the cursor to the next request.
+        #[configurable(metadata(docs::examples = "cursor"))]
+        query_param: String,
+    },
+
+    /// Follow the URL of the `Link` response header entry with `rel="next"`.
+    LinkHeader,
+
+    /// Advance an offset query parameter by the number of events received.
+    ///
+    /// A page with fewer than `limit` events indicates that there are no more pages.
+    Offset {
+        /// The query parameter used to pass the offset of the requested page.
+        #[serde(default = "default_offset_param")]
+        offset_param: String,
+
+        /// The query parameter used to pass the number of events requested per page.
+        #[serde(default = "default_limit_param")]
+        limit_param: String,
+
+        /// The number of events to request per page.
+        limit: NonZeroUsize,
+    },
+}
+
+fn default_offset_param() -> String {
+    "offset".to_string()
+}
+
+fn default_limit_param() -> String {
+    "limit".to_string()
+}
+
+/// Tracks the position of the next page across the scrapes of an endpoint.
+///
+/// The position is the cursor, `Link` URL, or offset of the next page, depending on the strategy.
+#[derive(Clone)]
+pub(super) struct Paginator {
+    strategy: PaginationStrategy,
+    max_pages: usize,
+    next: Arc<Mutex<Option<String>>>,
+    checkpoint_path: Option<PathBuf>,
+}
+
+impl Paginator {
+    pub(super) fn new(config: &PaginationConfig, data_dir: Option<PathBuf>) -> Self {
+        let checkpoint_path = data_dir.map(|dir| dir.join(CHECKPOINT_FILENAME));
+        let next = checkpoint_path.as_deref().and_then(read_checkpoint);
+
+        Self {
+            strategy: config.strategy.clone(),
+            max_pages: config.max_pages.get(),
+            next: Arc::new(Mutex::new(next)),
+            checkpoint_path,
+        }
+    }
+
+    /// Returns the URL of the first request of a scrape.
+    ///
+    /// This is `url`, which has its query parameters resolved, moved to the position of the next
+    /// page if there is one.
+    pub(super) fn first_url(&self, url: &Uri) -> Uri {
+        let next = self.next.lock().expect("mutex poisoned");
+        match &self.strategy {
+            PaginationStrategy::Cursor { query_param, .. } => match next.as_deref() {
+                Some(cursor) => with_query_params(url, &[(query_param, cursor)]),
+                None => url.clone(),
+            },
+            PaginationStrategy::LinkHeader => next
+                .as_deref()
+                .and_then(|link| Uri::try_from(link).ok())
+                .filter(|link| link.authority() == url.authority())
+                .unwrap_or_else(|| url.clone()),
+            PaginationStrategy::Offset {
+                offset_param,
+                limit_param,
+                limit,
+            } => with_query_params(
+                url,
+                &[
+                    (offset_param, next.as_deref().unwrap_or("0")),
+                    (limit_param, limit.to_string().as_str()),
+                ],
+            ),
+        }
+    }
+
+    /// Records the position following the response for `url`, and returns the URL of the next
+    /// page if the scrape should continue with it.
+    pub(super) fn advance(
+        &self,
+        url: &Uri,
+        header: &Parts,
+        body: &Bytes,
+        events: &[Event],
+        pages: usize,
+    ) -> Option<Uri> {
+        let (position, has_next) = self.next_position(url, header, body, events.len());
+        let next = has_next
+            .then(|| {
+                position
+                    .as_deref()
+                    .and_then(|position| self.url_at(url, position))
+            })
+            .flatten();
+        self.set_next(position);
+
+        next.filter(|_| !events.is_empty() && pages < self.max_pages)
+    }
+
+    /// Returns the position following the response for `url`, and whether there is a next page
+    /// at it.
+    fn next_position(
+        &self,
+        url: &Uri,
+        header: &Parts,
+        body: &Bytes,
+        count: usize,
+    ) -> (Option<String>, bool) {
+        match &self.strategy {
+            PaginationStrategy::Cursor { cursor_path, .. } => {
+                let cursor = serde_json::from_slice::<serde_json::Value>(body)
+                    .ok()
+                    .and_then(|body| match find_json_value(&body, cursor_path)? {
+                        serde_json::Value::String(cursor) if !cursor.is_empty() => {
+                            Some(cursor.clone())
+                        }
+                        serde_json::Value::Number(cursor) => Some(cursor.to_string()),
+                        _ => None,
+                    });
+                let has_next = cursor.is_some();
+                (cursor, has_next)
+            }
+            PaginationStrategy::LinkHeader => {
+                let link = header
+                    .headers
+                    .get_all(http::header::LINK)
+                    .iter()
+                    .filter_map(|value| value.to_str().ok())
+                    .find_map(http_client::find_rel_next_link)
+                    .and_then(|next| resolve_url(url, &next))
+                    .map(|next| next.to_string());
+                let has_next = link.is_some();
+                (link, has_next)
+            }
+            PaginationStrategy::Offset {
+                offset_param,
+                limit,
+                ..
+            } => {
+                // The offset moves past the events received even on the last page, as they're
+                // never requested again.
+                let offset = query_param(url, offset_param)
+                    .and_then(|offset| offset.parse::<usize>().ok())
+                    .unwrap_or(0);
+                (Some((offset + count).to_string()), count >= limit.get())
+            }
+        }
+    }
+
+    /// Returns the URL of the page at `position`, following the response for `url`.
+    fn url_at(&self, url: &Uri, position: &str) -> Option<Uri> {
+        match &self.strategy {
+            PaginationStrategy::Cursor { query_param, .. } => {
+                Some(with_query_params(url, &[(query_param, position)]))
+            }
+            PaginationStrategy::LinkHeader => Uri::try_from(position).ok(),
+            PaginationStrategy::Offset {
+                offset_param,
+                limit_param,
+                limit,
+            } => Some(with_query_params(
+                url,
+                &[
+                    (offset_param, position),
+                    (limit_param, limit.to_string().as_str()),
+                ],
+            )),
+        }
+    }
+
+    fn set_next(&self, next: Option<String>) {
+        let mut current = self.next.lock().expect("mutex poisoned");
+        if *current == next {
+            return;
+        }
+
+        if let Some(path) = &self.checkpoint_path
+            && let Err(error) = write_checkpoint(path, next.as_deref())
+        {
+            emit!(HttpClientCheckpointError {
+                error,
+                path: path.to_string_lossy().into_owned(),
+            });
+        }
+        *current = next;
+    }
+}
+
+fn read_checkpoint(path: &Path) -> Option<String> {
+    let contents = fs::read_to_string(path).ok()?;
+    let position = contents.lines().next()?.trim();
+    (!position.is_empty()).then(|| position.to_string())
+}
+
+fn write_checkpoint(path: &Path, next: Option<&str>) -> io::Result<()> {
+    match next {
+        Some(next) => {
+            let tmp_path = path.with_extension("tmp");
+            fs::write(&tmp_path, format!("{next}\n"))?;
+            fs::rename(tmp_path, path)
+        }
+        None => match fs::remove_file(path) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+            _ => Ok(()),
+        },
+    }
+}
+
+fn find_json_value<'a>(
+    value: &'a serde_json::Value,
+    path: &ConfigValuePath,
+) -> Option<&'a serde_json::Value> {
+    path.0
+        .segments
+        .iter()
+        .try_fold(value, |value, segment| match segment {
+            OwnedSegment::Field(field) => value.get(&**field),
+            OwnedSegment::Index(index) => {
+                let array = value.as_array()?;
+                let index = if *index < 0 {
+                    array.len().checked_sub(index.unsigned_abs())?
+                } else {
+                    *index as usize
+                };
+                array.get(index)
+            }
+        })
+}
+
+fn query_param(url: &Uri, name: &str) -> Option<String> {
+    url::form_urlencoded::parse(url.query()?.as_bytes())
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.into_owned())
+}
+
+/// Returns `url` with the given query parameters set, replacing any existing values.
+fn with_query_params(url: &Uri, params: &[(&String, &str)]) -> Uri {
+    let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+    if let Some(query) = url.query() {
+        serializer.extend_pairs(
+            url::form_urlencoded::parse(query.as_bytes())
+                .filter(|(key, _)| !params.iter().any(|(name, _)| key == name.as_str())),
+        );
+    }
+    for (name, value) in params {
+        serializer.append_pair(name, value);
+    }
+
+    let mut parts = url.clone().into_parts();
+    parts.path_and_query = Some(
+        format!("{}?{}", url.path(), serializer.finish())
+            .parse()
+            .expect("Failed to build URI from parsed arguments"),
+    );
+    Uri::from_parts(parts).expect("Failed to build URI from parsed arguments")
+}
+
+/// Resolves a possibly relative `Link` header URL against the URL of the request.
+fn resolve_url(base: &Uri, link: &str) -> Option<Uri> {
+    let link = Uri::try_from(link).ok()?;
+    if link.scheme().is_some() {
+        return Some(link);
+    }
+
+    let mut parts = base.clone().into_parts();
+    parts.path_and_query = link.into_parts().path_and_query;
+    Uri::from_parts(parts).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paginator(strategy: PaginationStrategy, max_pages: usize) -> Paginator {
+        Paginator::new(
+            &PaginationConfig {
+                strategy,
+                max_pages: NonZeroUsize::new(max_pages).unwrap(),
+                checkpoint: false,
+            },
+            None,
+        )
+    }
+
+    fn response(link: Option<&str>) -> Parts {
+        let mut builder = http::Response::builder();
+        if let Some(link) = link {
+            builder = builder.header(http::header::LINK, link);
+        }
+        builder.body(()).unwrap().into_parts().0
+    }
+
+    fn events(count: usize) -> Vec<Event> {
+        (0..count)
+            .map(|_| Event::Log(vector_lib::event::LogEvent::default()))
+            .collect()
+    }
+
+    #[test]
+    fn cursor_strategy_follows_cursor() {
+        let paginator = paginator(
+            PaginationStrategy::Cursor {
+                cursor_path: ConfigValuePath::try_from("meta.next".to_string()).unwrap(),
+                query_param: "cursor".to_string(),
+            },
+            10,
+        );
+        let url = Uri::from_static("http://localhost/logs?level=info");
+        let next_page = Uri::from_static("http://localhost/logs?level=info&cursor=def");
+
+        let next = paginator.advance(
+            &url,
+            &response(None),
+            &Bytes::from_static(br#"{"meta":{"next":"def"}}"#),
+            &events(1),
+            1,
+        );
+        assert_eq!(next.as_ref(), Some(&next_page));
+
+        let next = paginator.advance(
+            &next_page,
+            &response(None),
+            &Bytes::from_static(br#"{"meta":{"next":null}}"#),
+            &events(1),
+            2,
+        );
+        assert_eq!(next, None);
+        // Without a cursor to resume from, the next scrape starts over.
+        assert_eq!(paginator.first_url(&url), url);
+    }
+
+    #[test]
+    fn cursor_strategy_keeps_resolved_query_params() {
+        let paginator = paginator(
+            PaginationStrategy::Cursor {
+                cursor_path: ConfigValuePath::try_from("next".to_string()).unwrap(),
+                query_param: "cursor".to_string(),
+            },
+            1,
+        );
+
+        paginator.advance(
+            &Uri::from_static("http://localhost/logs?since=1"),
+            &response(None),
+            &Bytes::from_static(br#"{"next":"def"}"#),
+            &events(1),
+            1,
+        );
+
+        assert_eq!(
+            paginator.first_url(&Uri::from_static("http://localhost/logs?since=2")),
+            Uri::from_static("http://localhost/logs?since=2&cursor=def")
+        );
+    }
+
+    #[test]
+    fn link_header_strategy_starts_over_once_exhausted() {
+        let dir = tempfile::tempdir().unwrap();
+        let paginator = Paginator::new(
+            &PaginationConfig {
+                strategy: PaginationStrategy::LinkHeader,
+                max_pages: NonZeroUsize::new(1).unwrap(),
+                checkpoint: true,
+            },
+            Some(dir.path().to_path_buf()),
+        );
+        let url = Uri::from_static("http://localhost/logs");
+
+        paginator.advance(
+            &url,
+            &response(Some(r#"<http://localhost/logs?after=2>; rel="next""#)),
+            &Bytes::new(),
+            &events(1),
+            1,
+        );
+        assert!(dir.path().join(CHECKPOINT_FILENAME).exists());
+
+        let last_page = paginator.first_url(&url);
+        let next = paginator.advance(&last_page, &response(None), &Bytes::new(), &events(1), 1);
+
+        assert_eq!(next, None);
+        assert_eq!(paginator.first_url(&url), url);
+        assert!(!dir.path().join(CHECKPOINT_FILENAME).exists());
+    }
+
+    #[test]
+    fn link_header_strategy_resolves_relative_links() {
+        let paginator = paginator(PaginationStrategy::LinkHeader, 10);
+        let url = Uri::from_static("http://localhost/logs");
+
+        let next = paginator.advance(
+            &url,
+            &response(Some(r#"</logs?after=2>; rel="next", </logs>; rel="self""#)),
+            &Bytes::new(),
+            &events(1),
+            1,
+        );
+        assert_eq!(
+            next,
+            Some(Uri::from_static("http://localhost/logs?after=2"))
+        );
+    }
+
+    #[test]
+    fn offset_strategy_stops_on_short_page() {
+        let paginator = paginator(
+            PaginationStrategy::Offset {
+                offset_param: default_offset_param(),
+                limit_param: default_limit_param(),
+                limit: NonZeroUsize::new(2).unwrap(),
+            },
+            10,
+        );
+        let url = paginator.first_url(&Uri::from_static("http://localhost/logs"));
+        assert_eq!(
+            url,
+            Uri::from_static("http://localhost/logs?offset=0&limit=2")
+        );
+
+        let next = paginator.advance(&url, &response(None), &Bytes::new(), &events(2), 1);
+        assert_eq!(
+            next,
+            Some(Uri::from_static("http://localhost/logs?offset=2&limit=2"))
+        );
+
+        let next = paginator.advance(
+            &next.unwrap(),
+            &response(None),
+            &Bytes::new(),
+            &events(1),
+            2,
+        );
+        assert_eq!(next, None);
+        assert_eq!(
+            paginator.first_url(&url),
+            Uri::from_static("http://localhost/logs?offset=3&limit=2")
+        );
+    }
+
+    #[test]
+    fn max_pages_keeps_next_page_for_next_scrape() {
+        let paginator = paginator(PaginationStrategy::LinkHeader, 1);
+        let url = Uri::from_static("http://localhost/logs");
+
+        let next = paginator.advance(
+            &url,
+            &response(Some(r#"<http://localhost/logs?after=2>; rel="next""#)),
+            &Bytes::new(),
+            &events(1),
+            1,
+        );
+        assert_eq!(next, None);
+        assert_eq!(
+            paginator.first_url(&url),
+            Uri::from_static("http://localhost/logs?after=2")
+        );
+    }
+
+    #[test]
+    fn checkpoint_is_restored() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = PaginationConfig {
+            strategy: PaginationStrategy::LinkHeader,
+            max_pages: NonZeroUsize::new(1).unwrap(),
+            checkpoint: true,
+        };
+        let url = Uri::from_static("http://localhost/logs");
+
+        let paginator = Paginator::new(&config, Some(dir.path().to_path_buf()));
+        paginator.advance(
+            &url,
+            &response(Some(r#"<http://localhost/logs?after=2>; rel="next""#)),
+            &Bytes::new(),
+            &events(1),
+            1,
+        );
+
+        let paginator = Paginator::new(&config, Some(dir.path().to_path_buf()));
+        assert_eq!(
+            paginator.first_url(&url),
+            Uri::from_static("http://localhost/logs?after=2")
+        );
+    }
+}
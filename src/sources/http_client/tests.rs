@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, num::NonZeroUsize};
 
 use http::Uri;
 use tokio::time::Duration;
@@ -13,7 +13,10 @@ use vector_lib::{
 use vrl::event_path;
 use warp::{Filter, http::HeaderMap};
 
-use super::HttpClientConfig;
+use super::{
+    HttpClientConfig,
+    pagination::{PaginationConfig, PaginationStrategy},
+};
 use crate::{
     components::validation::prelude::*,
     http::{ParamType, ParameterValue, QueryParameterValue},
@@ -98,11 +101,14 @@ async fn bytes_decoding() {
         query: HashMap::new(),
         decoding: default_decoding(),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
         tls: None,
         auth: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -128,11 +134,14 @@ async fn json_decoding_newline_delimited() {
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: FramingConfig::NewlineDelimited(Default::default()),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
         tls: None,
         auth: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -164,11 +173,14 @@ async fn json_decoding_character_delimited() {
                 oversized_action: Default::default(),
             },
         }),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
         tls: None,
         auth: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -205,11 +217,14 @@ async fn request_query_applied() {
         ]),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
         tls: None,
         auth: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -319,11 +334,14 @@ async fn request_query_vrl_applied() {
         ]),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
         tls: None,
         auth: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -403,11 +421,14 @@ async fn request_query_vrl_dynamic_updates() {
         )]),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
         tls: None,
         auth: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -470,6 +491,7 @@ async fn headers_applied() {
         query: HashMap::new(),
         decoding: default_decoding(),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::from([(
             "f00".to_string(),
             vec!["bazz".to_string(), "bizz".to_string()],
@@ -478,6 +500,8 @@ async fn headers_applied() {
         body: None,
         auth: None,
         tls: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -503,11 +527,14 @@ async fn accept_header_override() {
         query: HashMap::new(),
         decoding: DeserializerConfig::Bytes,
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::from([("ACCEPT".to_string(), vec!["application/json".to_string()])]),
         method: HttpMethod::Get,
         body: None,
         auth: None,
         tls: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -540,11 +567,14 @@ async fn post_with_body() {
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Post,
         body: Some(ParameterValue::String(test_json.to_string())),
         tls: None,
         auth: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -584,11 +614,14 @@ async fn post_without_body() {
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Post,
         body: None,
         tls: None,
         auth: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -614,11 +647,14 @@ async fn post_with_custom_content_type() {
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::from([("Content-Type".to_string(), vec!["text/plain".to_string()])]),
         method: HttpMethod::Post,
         body: Some(ParameterValue::String("plain text body".to_string())),
         tls: None,
         auth: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -648,6 +684,7 @@ async fn post_with_vrl_body() {
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Post,
         body: Some(ParameterValue::Typed {
@@ -656,6 +693,8 @@ async fn post_with_vrl_body() {
         }),
         tls: None,
         auth: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     })
     .await;
@@ -695,11 +734,14 @@ async fn query_vrl_compilation_error() {
         )]),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Get,
         body: None,
         tls: None,
         auth: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     };
 
@@ -735,6 +777,7 @@ async fn body_vrl_compilation_error() {
         query: HashMap::new(),
         decoding: DeserializerConfig::Json(Default::default()),
         framing: default_framing_message_based(),
+        reroute_decode_errors: false,
         headers: HashMap::new(),
         method: HttpMethod::Post,
         body: Some(ParameterValue::Typed {
@@ -743,6 +786,8 @@ async fn body_vrl_compilation_error() {
         }),
         tls: None,
         auth: None,
+        pagination: None,
+        data_dir: None,
         log_namespace: None,
     };
 
@@ -764,3 +809,50 @@ async fn body_vrl_compilation_error() {
         Ok(_) => panic!("Expected build to fail with VRL compilation error, but it succeeded"),
     }
 }
+
+/// Pages linked with a `Link` header should all be requested as part of a scrape.
+#[tokio::test]
+async fn pagination_link_header() {
+    let (_guard, in_addr) = next_addr();
+
+    let dummy_endpoint = warp::path!("endpoint")
+        .and(warp::query::<HashMap<String, String>>())
+        .map(|query: HashMap<String, String>| match query.get("page") {
+            Some(_) => warp::reply::with_header(r#"{"data" : "second"}"#, "X-Page", "2"),
+            None => warp::reply::with_header(
+                r#"{"data" : "first"}"#,
+                "Link",
+                r#"</endpoint?page=2>; rel="next""#,
+            ),
+        });
+
+    tokio::spawn(warp::serve(dummy_endpoint).run(in_addr));
+    wait_for_tcp(in_addr).await;
+
+    let events = run_compliance(HttpClientConfig {
+        endpoint: format!("http://{in_addr}/endpoint"),
+        interval: INTERVAL,
+        timeout: TIMEOUT,
+        decoding: DeserializerConfig::Json(Default::default()),
+        pagination: Some(PaginationConfig {
+            strategy: PaginationStrategy::LinkHeader,
+            max_pages: NonZeroUsize::new(10).unwrap(),
+            checkpoint: false,
+        }),
+        ..Default::default()
+    })
+    .await;
+
+    let data = events
+        .iter()
+        .map(|event| {
+            event
+                .as_log()
+                .get(event_path!("data"))
+                .expect("data must be available")
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(&data[..2], ["first", "second"]);
+}
@@ -6,7 +6,7 @@ use http::StatusCode;
 use http_serde;
 use tokio_util::codec::Decoder as _;
 use vector_lib::{
-    codecs::decoding::{DeserializerConfig, FramingConfig},
+    codecs::decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     config::{DataType, LegacyKey, LogNamespace},
     configurable::configurable_component,
     lookup::{lookup_v2::OptionalValuePath, owned_value_path, path},
@@ -20,7 +20,7 @@ use crate::{
     common::http::{ErrorMessage, server_auth::HttpServerAuthConfig},
     config::{
         GenerateConfig, Resource, SourceAcknowledgementsConfig, SourceConfig, SourceContext,
-        SourceOutput,
+        SourceOutput, TestInputDecoder,
     },
     event::Event,
     http::KeepaliveConfig,
@@ -160,6 +160,14 @@ pub struct SimpleHttpConfig {
     #[configurable(derived)]
     decoding: Option<DeserializerConfig>,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    reroute_decode_errors: bool,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: SourceAcknowledgementsConfig,
@@ -260,6 +268,7 @@ impl Default for SimpleHttpConfig {
             strict_path: true,
             framing: None,
             decoding: Some(default_decoding()),
+            reroute_decode_errors: false,
             acknowledgements: SourceAcknowledgementsConfig::default(),
             log_namespace: None,
             keepalive: KeepaliveConfig::default(),
@@ -337,6 +346,7 @@ impl SourceConfig for SimpleHttpConfig {
         let log_namespace = cx.log_namespace(self.log_namespace);
         let decoder = self
             .get_decoding_config()?
+            .with_decode_errors(self.reroute_decode_errors)
             .build()?
             .with_log_namespace(log_namespace);
 
@@ -372,13 +382,17 @@ impl SourceConfig for SimpleHttpConfig {
 
         let schema_definition = self.schema_definition(log_namespace);
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding
                 .as_ref()
                 .map(|d| d.output_type())
                 .unwrap_or(DataType::Log),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decode_errors {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn resources(&self) -> Vec<Resource> {
@@ -388,6 +402,19 @@ impl SourceConfig for SimpleHttpConfig {
     fn can_acknowledge(&self) -> bool {
         true
     }
+
+    fn build_decoder(
+        &self,
+        global_log_namespace: LogNamespace,
+    ) -> crate::Result<Option<Box<dyn TestInputDecoder>>> {
+        let log_namespace = global_log_namespace.merge(self.log_namespace);
+        let decoder = self
+            .get_decoding_config()?
+            .with_decode_errors(self.reroute_decode_errors)
+            .build()?
+            .with_log_namespace(log_namespace);
+        Ok(Some(Box::new(decoder)))
+    }
 }
 
 #[derive(Clone)]
@@ -601,6 +628,7 @@ mod tests {
                 method,
                 framing,
                 decoding,
+                reroute_decode_errors: false,
                 acknowledgements: acknowledgements.into(),
                 log_namespace: None,
                 keepalive: Default::default(),
@@ -15,15 +15,16 @@ use chrono::{DateTime, TimeZone, Utc};
 use futures::{Stream, StreamExt};
 use futures_util::future::OptionFuture;
 use rdkafka::{
-    ClientConfig, ClientContext, Statistics, TopicPartitionList,
+    ClientConfig, ClientContext, Offset, Statistics, TopicPartitionList,
     consumer::{
         BaseConsumer, CommitMode, Consumer, ConsumerContext, Rebalance, StreamConsumer,
         stream_consumer::StreamPartitionQueue,
     },
-    error::KafkaError,
+    error::{KafkaError, KafkaResult},
     message::{BorrowedMessage, Headers as _, Message},
     types::RDKafkaErrorCode,
 };
+use regex::Regex;
 use serde_with::serde_as;
 use snafu::{ResultExt, Snafu};
 use tokio::{
@@ -40,7 +41,10 @@ use vector_lib::{
     EstimatedJsonEncodedSizeOf,
     codecs::{
         DecoderFramedRead, StreamDecodingError,
-        decoding::{DecompressionConfig, Decompressor, DeserializerConfig, FramingConfig},
+        decoding::{
+            DecompressionConfig, Decompressor, DeserializerConfig, FramingConfig,
+            decode_errors_output,
+        },
     },
     config::{LegacyKey, LogNamespace},
     configurable::configurable_component,
@@ -77,6 +81,10 @@ enum BuildError {
     CreateError { source: rdkafka::error::KafkaError },
     #[snafu(display("Could not subscribe to Kafka topics: {}", source))]
     SubscribeError { source: rdkafka::error::KafkaError },
+    #[snafu(display("Could not look up Kafka partition offsets: {}", source))]
+    OffsetLookupError { source: rdkafka::error::KafkaError },
+    #[snafu(display("Could not commit the start offsets of Kafka partitions: {}", source))]
+    OffsetCommitError { source: rdkafka::error::KafkaError },
 }
 
 /// Metrics (beta) configuration.
@@ -87,6 +95,67 @@ struct Metrics {
     pub topic_lag_metric: bool,
 }
 
+/// Where the source starts consuming partitions.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[configurable(metadata(docs::enum_tag_description = "How the start position is given."))]
+enum StartPosition {
+    /// Start at the first message of each partition with a timestamp at or after the given time.
+    ///
+    /// Partitions without such a message start at their end.
+    Timestamp {
+        /// The time to start at.
+        #[configurable(metadata(docs::examples = "2024-06-01T12:00:00Z"))]
+        timestamp: DateTime<Utc>,
+    },
+
+    /// Start at explicit offsets of individual partitions.
+    ///
+    /// Partitions that aren't listed start at the committed offset of the consumer group.
+    Offsets {
+        /// The offsets to start the partitions at.
+        offsets: Vec<PartitionOffset>,
+    },
+}
+
+/// The offset of a topic partition.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+struct PartitionOffset {
+    /// The topic of the partition.
+    #[configurable(metadata(docs::examples = "topic-1"))]
+    topic: String,
+
+    /// The partition number.
+    #[configurable(metadata(docs::examples = 0))]
+    partition: i32,
+
+    /// The offset of the next message to consume from the partition.
+    #[configurable(metadata(docs::examples = 1024))]
+    offset: i64,
+}
+
+/// Where the source stops consuming partitions.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[configurable(metadata(docs::enum_tag_description = "How the end position is given."))]
+enum EndPosition {
+    /// Stop before the first message of each partition with a timestamp at or after the given time.
+    ///
+    /// If the time has already passed, partitions without such a message stop at their end.
+    Timestamp {
+        /// The time to stop at.
+        #[configurable(metadata(docs::examples = "2024-06-01T13:00:00Z"))]
+        timestamp: DateTime<Utc>,
+    },
+
+    /// Stop at the end of each partition, as of when the source started.
+    HighWatermark,
+}
+
 /// Configuration for the `kafka` source.
 #[serde_as]
 #[configurable_component(source("kafka", "Collect logs from Apache Kafka."))]
@@ -124,6 +193,23 @@ pub struct KafkaSourceConfig {
     #[configurable(metadata(docs::examples = "example_auto_offset_reset_values()"))]
     auto_offset_reset: String,
 
+    /// Moves the consumer group to a new start position when the source starts.
+    ///
+    /// The start offsets are committed for the consumer group before the source subscribes to
+    /// `topics`, so no other consumers of the group may be active. As this happens each time the
+    /// source starts, this is meant for one-off replays of past events.
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    start_position: Option<StartPosition>,
+
+    /// Stops the source once it reaches this position in every partition.
+    ///
+    /// When all of its partitions reach the end position, the source shuts down, which shuts down
+    /// Vector once no other sources are running.
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    end_position: Option<EndPosition>,
+
     /// The Kafka session timeout.
     #[serde_as(as = "serde_with::DurationMilliSeconds<u64>")]
     #[configurable(metadata(docs::examples = 5000, docs::examples = 10000))]
@@ -245,6 +331,14 @@ pub struct KafkaSourceConfig {
     #[derivative(Default(value = "default_decoding()"))]
     decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    reroute_decode_errors: bool,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: SourceAcknowledgementsConfig,
@@ -335,6 +429,7 @@ impl SourceConfig for KafkaSourceConfig {
 
         let decoder =
             DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .with_decode_errors(self.reroute_decode_errors)
                 .build()?;
         let decompressor = self
             .decompression
@@ -419,10 +514,14 @@ impl SourceConfig for KafkaSourceConfig {
                 None,
             );
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decode_errors {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -451,8 +550,24 @@ async fn kafka_source(
         .set(Arc::downgrade(&consumer))
         .expect("Error setting up consumer context.");
 
-    // EOF signal allowing the coordination task to tell the kafka client task when all partitions have reached EOF
-    let (eof_tx, eof_rx) = eof.then(oneshot::channel::<()>).unzip();
+    // EOF signal allowing the coordination task to tell the kafka client task when all partitions have reached EOF,
+    // or their end position
+    let (eof_tx, eof_rx) = (eof || config.end_position.is_some())
+        .then(oneshot::channel::<()>)
+        .unzip();
+
+    if config.start_position.is_some() || config.end_position.is_some() {
+        let consumer = Arc::clone(&consumer);
+        let config = config.clone();
+        let positioned =
+            tokio::task::spawn_blocking(move || position_partitions(&consumer, &config))
+                .await
+                .expect("Positioning Kafka partitions panicked.");
+        if let Err(e) = positioned {
+            error!("{}", e);
+            return Err(());
+        }
+    }
 
     let topics: Vec<&str> = config.topics.iter().map(|s| s.as_str()).collect();
     if let Err(e) = consumer.subscribe(&topics).context(SubscribeSnafu) {
@@ -481,6 +596,7 @@ async fn kafka_source(
                 consumer_state,
                 drain_timeout_ms,
                 eof_tx,
+                eof,
             )
             .await;
         })
@@ -609,6 +725,7 @@ impl ConsumerStateInner<Consuming> {
         p: StreamPartitionQueue<KafkaSourceContext>,
         acknowledgements: bool,
         exit_eof: bool,
+        end: Option<PartitionEnd>,
     ) -> (oneshot::Sender<()>, tokio::task::AbortHandle) {
         let keys = self.config.keys();
         let decoder = self.decoder.clone();
@@ -660,13 +777,21 @@ impl ConsumerStateInner<Consuming> {
                     message = messages.next(), if finalizer.is_some() => match message {
                         None => unreachable!("MessageStream never calls Ready(None)"),
                         Some(Err(error)) => match error {
-                            rdkafka::error::KafkaError::PartitionEOF(partition) if exit_eof => {
+                            rdkafka::error::KafkaError::PartitionEOF(partition) if exit_eof || end.is_some_and(|end| end.offset.is_some()) => {
                                 debug!("EOF for partition {}.", partition);
                                 status = PartitionConsumerStatus::PartitionEOF;
                                 finalizer.take();
                             },
+                            // Partition EOF is only enabled for sources with an end position, which
+                            // don't always know the end offset of a partition.
+                            rdkafka::error::KafkaError::PartitionEOF(_) if end.is_some() => {},
                             _ => emit!(KafkaReadError { error }),
                         },
+                        Some(Ok(msg)) if end.is_some_and(|end| end.is_reached(&msg)) => {
+                            debug!("End position reached for partition {}:{}.", &tp.0, tp.1);
+                            status = PartitionConsumerStatus::PartitionEOF;
+                            finalizer.take();
+                        },
                         Some(Ok(msg)) => {
                             emit!(KafkaBytesReceived {
                                 byte_size: msg.payload_len(),
@@ -770,6 +895,7 @@ async fn coordinate_kafka_callbacks(
     consumer_state: ConsumerStateInner<Consuming>,
     max_drain_ms: Duration,
     mut eof: Option<oneshot::Sender<()>>,
+    exit_eof: bool,
 ) {
     let mut drain_deadline: OptionFuture<_> = None.into();
     let mut consumer_state = ConsumerState::Consuming(consumer_state);
@@ -788,8 +914,6 @@ async fn coordinate_kafka_callbacks(
     // Handles that will let us end any consumer task that exceeds a drain deadline
     let mut abort_handles: HashMap<TopicPartition, tokio::task::AbortHandle> = HashMap::new();
 
-    let exit_eof = eof.is_some();
-
     while let ConsumerState::Consuming(_) | ConsumerState::Draining(_) = consumer_state {
         tokio::select! {
             Some(Ok((finished_partition, status))) = partition_consumers.join_next(), if !partition_consumers.is_empty() => {
@@ -824,8 +948,9 @@ async fn coordinate_kafka_callbacks(
                 // PartitionConsumerStatus differentiates between a task that exited after
                 // being signaled to end, and one that reached the end of its partition and
                 // was configured to exit. After the last such task ends, we signal the kafka
-                // driver task to shut down the main consumer too. Note this is only used in tests.
-                if exit_eof && status == PartitionConsumerStatus::PartitionEOF && partition_consumers.is_empty() {
+                // driver task to shut down the main consumer too. Note this is only used in tests,
+                // and by sources with an end position.
+                if status == PartitionConsumerStatus::PartitionEOF && partition_consumers.is_empty() {
                     debug!("All partitions have exited or reached EOF.");
                     let _ = eof.take().map(|e| e.send(()));
                 }
@@ -836,12 +961,14 @@ async fn coordinate_kafka_callbacks(
                     ConsumerState::Draining(_) => error!("Partition assignment received while draining revoked partitions, maybe an invalid assignment."),
                     ConsumerState::Consuming(ref consumer_state) => {
                         let acks = consumer.context().acknowledgements;
+                        let ends = consumer.context().partition_ends.get();
                         for tp in assigned_partitions.drain(0..) {
                             let topic = tp.0.as_str();
                             let partition = tp.1;
                             match consumer.split_partition_queue(topic, partition) { Some(pq) => {
                                 debug!("Consuming partition {}:{}.", &tp.0, tp.1);
-                                let (end_tx, handle) = consumer_state.consume_partition(&mut partition_consumers, tp.clone(), Arc::clone(&consumer), pq, acks, exit_eof);
+                                let end = ends.map(|ends| ends.get(&tp));
+                                let (end_tx, handle) = consumer_state.consume_partition(&mut partition_consumers, tp.clone(), Arc::clone(&consumer), pq, acks, exit_eof, end);
                                 abort_handles.insert(tp.clone(), handle);
                                 end_signals.insert(tp, end_tx);
                             } _ => {
@@ -1256,7 +1383,10 @@ fn create_consumer(
             "fetch.wait.max.ms",
             config.fetch_wait_max_ms.as_millis().to_string(),
         )
-        .set("enable.partition.eof", "false")
+        .set(
+            "enable.partition.eof",
+            config.end_position.is_some().to_string(),
+        )
         .set("enable.auto.commit", "true")
         .set(
             "auto.commit.interval.ms",
@@ -1279,6 +1409,9 @@ fn create_consumer(
         .create_with_context::<_, StreamConsumer<_>>(KafkaSourceContext::new(
             config.metrics.topic_lag_metric,
             acknowledgements,
+            // Lets transactional `kafka` sinks check that the group they commit offsets for
+            // exists, for as long as the consumer does.
+            kafka::ConsumerGroupRegistration::new(&config.group_id, acknowledgements),
             callbacks,
             Span::current(),
         ))
@@ -1289,6 +1422,201 @@ fn create_consumer(
 
 type TopicPartition = (String, i32);
 
+/// Where consuming a partition ends.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PartitionEnd {
+    /// The offset to stop before, if it's known.
+    offset: Option<i64>,
+
+    /// The message timestamp, in milliseconds, to stop before.
+    timestamp_millis: Option<i64>,
+}
+
+impl PartitionEnd {
+    fn is_reached(&self, msg: &BorrowedMessage<'_>) -> bool {
+        self.offset.is_some_and(|offset| msg.offset() >= offset)
+            || self
+                .timestamp_millis
+                .zip(msg.timestamp().to_millis())
+                .is_some_and(|(end, timestamp)| timestamp >= end)
+    }
+}
+
+/// Where consuming the partitions of a source with an end position ends.
+#[derive(Debug)]
+struct PartitionEnds {
+    /// The end offsets of the partitions that existed when the source started.
+    offsets: HashMap<TopicPartition, i64>,
+
+    /// The end offset of partitions that were created after the source started.
+    new_partition_offset: Option<i64>,
+
+    /// The message timestamp, in milliseconds, to stop before.
+    timestamp_millis: Option<i64>,
+}
+
+impl PartitionEnds {
+    fn get(&self, tp: &TopicPartition) -> PartitionEnd {
+        PartitionEnd {
+            offset: self.offsets.get(tp).copied().or(self.new_partition_offset),
+            timestamp_millis: self.timestamp_millis,
+        }
+    }
+}
+
+/// Commits the start offsets of the source's partitions for its consumer group, and looks up where
+/// consuming each of them ends.
+fn position_partitions(
+    consumer: &StreamConsumer<KafkaSourceContext>,
+    config: &KafkaSourceConfig,
+) -> Result<(), BuildError> {
+    let timeout = config.socket_timeout_ms;
+    let partitions = match (&config.start_position, &config.end_position) {
+        (Some(StartPosition::Offsets { .. }) | None, None) => Vec::new(),
+        _ => topic_partitions(consumer, &config.topics, timeout).context(OffsetLookupSnafu)?,
+    };
+    let high_watermark = |(topic, partition): &TopicPartition| {
+        consumer
+            .fetch_watermarks(topic, *partition, timeout)
+            .map(|(_low, high)| high)
+    };
+
+    if let Some(start) = &config.start_position {
+        let mut offsets = TopicPartitionList::new();
+        match start {
+            StartPosition::Timestamp { timestamp } => {
+                for (tp, offset) in offsets_for_time(consumer, &partitions, timestamp, timeout)
+                    .context(OffsetLookupSnafu)?
+                {
+                    let offset = match offset {
+                        Some(offset) => offset,
+                        None => high_watermark(&tp).context(OffsetLookupSnafu)?,
+                    };
+                    offsets
+                        .add_partition_offset(&tp.0, tp.1, Offset::Offset(offset))
+                        .context(OffsetLookupSnafu)?;
+                }
+            }
+            StartPosition::Offsets { offsets: positions } => {
+                for position in positions {
+                    offsets
+                        .add_partition_offset(
+                            &position.topic,
+                            position.partition,
+                            Offset::Offset(position.offset),
+                        )
+                        .context(OffsetLookupSnafu)?;
+                }
+            }
+        }
+        consumer
+            .commit(&offsets, CommitMode::Sync)
+            .context(OffsetCommitSnafu)?;
+    }
+
+    if let Some(end) = &config.end_position {
+        let ends = match end {
+            EndPosition::Timestamp { timestamp } => {
+                let mut offsets = HashMap::new();
+                for (tp, offset) in offsets_for_time(consumer, &partitions, timestamp, timeout)
+                    .context(OffsetLookupSnafu)?
+                {
+                    // Partitions may still receive messages from before a time in the future, so
+                    // they can only stop at their end once the time has passed.
+                    let offset = match offset {
+                        Some(offset) => offset,
+                        None if *timestamp <= Utc::now() => {
+                            high_watermark(&tp).context(OffsetLookupSnafu)?
+                        }
+                        None => continue,
+                    };
+                    offsets.insert(tp, offset);
+                }
+                PartitionEnds {
+                    offsets,
+                    new_partition_offset: None,
+                    timestamp_millis: Some(timestamp.timestamp_millis()),
+                }
+            }
+            EndPosition::HighWatermark => PartitionEnds {
+                offsets: partitions
+                    .into_iter()
+                    .map(|tp| high_watermark(&tp).map(|offset| (tp, offset)))
+                    .collect::<KafkaResult<_>>()
+                    .context(OffsetLookupSnafu)?,
+                new_partition_offset: Some(0),
+                timestamp_millis: None,
+            },
+        };
+        _ = consumer.context().partition_ends.set(ends);
+    }
+
+    Ok(())
+}
+
+/// Lists the partitions of the topics that match the source's `topics`.
+fn topic_partitions(
+    consumer: &StreamConsumer<KafkaSourceContext>,
+    topics: &[String],
+    timeout: Duration,
+) -> KafkaResult<Vec<TopicPartition>> {
+    let patterns = topics
+        .iter()
+        .filter(|topic| topic.starts_with('^'))
+        .filter_map(|pattern| Regex::new(pattern).ok())
+        .collect::<Vec<_>>();
+    let metadata = consumer.fetch_metadata(None, timeout)?;
+
+    Ok(metadata
+        .topics()
+        .iter()
+        .filter(|topic| {
+            topics.iter().any(|name| name == topic.name())
+                || patterns
+                    .iter()
+                    .any(|pattern| pattern.is_match(topic.name()))
+        })
+        .flat_map(|topic| {
+            topic
+                .partitions()
+                .iter()
+                .map(|partition| (topic.name().to_string(), partition.id()))
+        })
+        .collect())
+}
+
+/// Looks up the offset of the first message of each partition with a timestamp at or after the
+/// given time, if there is one.
+fn offsets_for_time(
+    consumer: &StreamConsumer<KafkaSourceContext>,
+    partitions: &[TopicPartition],
+    timestamp: &DateTime<Utc>,
+    timeout: Duration,
+) -> KafkaResult<Vec<(TopicPartition, Option<i64>)>> {
+    let mut timestamps = TopicPartitionList::new();
+    for (topic, partition) in partitions {
+        timestamps.add_partition_offset(
+            topic,
+            *partition,
+            Offset::Offset(timestamp.timestamp_millis()),
+        )?;
+    }
+
+    consumer
+        .offsets_for_times(timestamps, timeout)?
+        .elements()
+        .iter()
+        .map(|element| {
+            element.error()?;
+            let offset = match element.offset() {
+                Offset::Offset(offset) => Some(offset),
+                _ => None,
+            };
+            Ok(((element.topic().to_string(), element.partition()), offset))
+        })
+        .collect()
+}
+
 /// Status returned by partition consumer tasks, allowing the coordination task
 /// to differentiate between a consumer exiting normally (after receiving an end
 /// signal) and exiting when it reaches the end of a partition
@@ -1308,17 +1636,24 @@ struct KafkaSourceContext {
     acknowledgements: bool,
     stats: kafka::KafkaStatisticsContext,
 
+    /// The registration of the consumer group, which holds the group metadata for `kafka` sinks.
+    consumer_group: kafka::ConsumerGroupRegistration,
+
     /// A callback channel used to coordinate between the main consumer task and the acknowledgement task
     callbacks: UnboundedSender<KafkaCallback>,
 
     /// A weak reference to the consumer, so that we can commit offsets during a rebalance operation
     consumer: OnceLock<Weak<StreamConsumer<KafkaSourceContext>>>,
+
+    /// Where consuming each partition ends, if the source has an end position
+    partition_ends: OnceLock<PartitionEnds>,
 }
 
 impl KafkaSourceContext {
     fn new(
         expose_lag_metrics: bool,
         acknowledgements: bool,
+        consumer_group: kafka::ConsumerGroupRegistration,
         callbacks: UnboundedSender<KafkaCallback>,
         span: Span,
     ) -> Self {
@@ -1328,7 +1663,9 @@ impl KafkaSourceContext {
                 span,
             },
             acknowledgements,
+            consumer_group,
             consumer: OnceLock::default(),
+            partition_ends: OnceLock::default(),
             callbacks,
         }
     }
@@ -1431,6 +1768,15 @@ impl ConsumerContext for KafkaSourceContext {
             }
         }
     }
+    fn post_rebalance(&self, base_consumer: &BaseConsumer<Self>, rebalance: &Rebalance) {
+        // Transactional `kafka` sinks commit offsets with the metadata of the group's current
+        // generation, which changes with every assignment.
+        if let Rebalance::Assign(_) = rebalance
+            && let Some(metadata) = base_consumer.group_metadata()
+        {
+            self.consumer_group.update(metadata);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1495,6 +1841,66 @@ mod test {
         assert!(config.decompression.is_none());
     }
 
+    #[test]
+    fn parses_start_and_end_positions() {
+        let config: KafkaSourceConfig = toml::from_str(
+            r#"
+            bootstrap_servers = "localhost:9092"
+            topics = ["topic"]
+            group_id = "group"
+
+            [start_position]
+            type = "offsets"
+            offsets = [{ topic = "topic", partition = 1, offset = 42 }]
+
+            [end_position]
+            type = "timestamp"
+            timestamp = "2024-06-01T13:00:00Z"
+            "#,
+        )
+        .unwrap();
+
+        let Some(StartPosition::Offsets { offsets }) = config.start_position else {
+            panic!("start_position should be offsets");
+        };
+        assert_eq!(
+            (
+                offsets[0].topic.as_str(),
+                offsets[0].partition,
+                offsets[0].offset
+            ),
+            ("topic", 1, 42)
+        );
+        let Some(EndPosition::Timestamp { timestamp }) = config.end_position else {
+            panic!("end_position should be a timestamp");
+        };
+        assert_eq!(timestamp.timestamp_millis(), 1_717_246_800_000);
+    }
+
+    #[test]
+    fn partition_ends_of_new_partitions() {
+        let ends = PartitionEnds {
+            offsets: HashMap::from([(("topic".to_string(), 0), 100)]),
+            new_partition_offset: Some(0),
+            timestamp_millis: None,
+        };
+
+        assert_eq!(
+            ends.get(&("topic".to_string(), 0)),
+            PartitionEnd {
+                offset: Some(100),
+                timestamp_millis: None,
+            }
+        );
+        assert_eq!(
+            ends.get(&("topic".to_string(), 1)),
+            PartitionEnd {
+                offset: Some(0),
+                timestamp_millis: None,
+            }
+        );
+    }
+
     pub(super) fn make_config(
         topic: &str,
         group: &str,
@@ -1787,6 +2193,34 @@ mod integration_test {
         send_receive(true, |n| n >= 2, 2, LogNamespace::Vector).await;
     }
 
+    #[tokio::test]
+    async fn replays_from_offsets_until_high_watermark() {
+        let (topic, group_id, _) = send_to_test_topic(1, 10).await;
+        let config = KafkaSourceConfig {
+            start_position: Some(StartPosition::Offsets {
+                offsets: vec![PartitionOffset {
+                    topic: topic.clone(),
+                    partition: 0,
+                    offset: 4,
+                }],
+            }),
+            end_position: Some(EndPosition::HighWatermark),
+            ..make_config(&topic, &group_id, LogNamespace::Legacy, None)
+        };
+
+        let (tx, rx) = SourceSender::new_test_errors(|_| false);
+        // The source shuts down on its own once it reaches the end position.
+        let (_trigger_shutdown, shutdown_done) =
+            spawn_kafka(tx, config, true, false, LogNamespace::Legacy);
+        let events = collect_n(rx, 6).await;
+        tokio::time::timeout(Duration::from_secs(30), shutdown_done)
+            .await
+            .expect("source should shut down at the end position");
+
+        assert_eq!(events.len(), 6);
+        assert_eq!(fetch_tpl_offset(&group_id, &topic, 0), Offset::from_raw(10));
+    }
+
     fn train_test_dictionary() -> Vec<u8> {
         let samples: Vec<Vec<u8>> = (0..100)
             .map(|i| format!("{TEXT} sample {i:04}").into_bytes())
@@ -30,6 +30,7 @@ fn generate_source_config(url: &str, subject: &str) -> NatsSourceConfig {
         subject: subject.to_string(),
         framing: default_framing_message_based(),
         decoding: default_decoding(),
+        reroute_decode_errors: false,
         subject_key_field: default_subject_key_field(),
         ..Default::default()
     }
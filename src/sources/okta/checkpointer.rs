@@ -0,0 +1,124 @@
+use std::{io::SeekFrom, path::PathBuf};
+
+use futures::StreamExt;
+use tokio::{
+    fs::{File, OpenOptions},
+    io::{self, AsyncReadExt, AsyncSeekExt, AsyncWriteExt},
+};
+use vector_lib::finalizer::OrderedFinalizer;
+
+use crate::{
+    event::{BatchStatus, BatchStatusReceiver},
+    internal_events::OktaCheckpointSetError,
+    shutdown::ShutdownSignal,
+};
+
+pub(super) const CHECKPOINT_FILENAME: &str = "checkpoint.txt";
+
+/// Persists the path and query of the next page of system logs to fetch.
+///
+/// The stored value carries the `after` cursor returned by Okta in the `rel=next` link, so the
+/// source can resume exactly where it left off after a restart.
+pub(super) struct Checkpointer {
+    file: File,
+    filename: PathBuf,
+}
+
+impl Checkpointer {
+    pub(super) async fn new(filename: PathBuf) -> Result<Self, io::Error> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&filename)
+            .await?;
+        Ok(Checkpointer { file, filename })
+    }
+
+    pub(super) async fn set(&mut self, cursor: &str) {
+        if let Err(error) = self.write(cursor).await {
+            emit!(OktaCheckpointSetError {
+                error,
+                filename: self.filename.to_str().unwrap_or("unknown").to_string(),
+            });
+        }
+    }
+
+    async fn write(&mut self, cursor: &str) -> Result<(), io::Error> {
+        let line = format!("{cursor}\n");
+        self.file.seek(SeekFrom::Start(0)).await?;
+        self.file.write_all(line.as_bytes()).await?;
+        self.file.set_len(line.len() as u64).await?;
+        self.file.sync_data().await
+    }
+
+    pub(super) async fn get(&mut self) -> Result<Option<String>, io::Error> {
+        let mut buf = Vec::<u8>::new();
+        self.file.seek(SeekFrom::Start(0)).await?;
+        self.file.read_to_end(&mut buf).await?;
+        let text = String::from_utf8_lossy(&buf);
+        Ok(text
+            .split_once('\n')
+            .map(|(line, _)| line.to_string())
+            .filter(|line| !line.is_empty()))
+    }
+}
+
+/// Advances the checkpoint once the events of a page have been handled.
+///
+/// The checkpoint only moves past a page after every event in it has been delivered, and pages are
+/// committed in the order they were fetched. Without acknowledgements, events count as delivered
+/// once the topology is done with them, rather than once a sink confirms them.
+pub(super) struct Finalizer(OrderedFinalizer<String>);
+
+impl Finalizer {
+    pub(super) fn new(mut checkpointer: Checkpointer, shutdown: ShutdownSignal) -> Self {
+        let (finalizer, mut ack_stream) = OrderedFinalizer::new(Some(shutdown));
+        crate::spawn_in_current_span(async move {
+            while let Some((status, cursor)) = ack_stream.next().await {
+                if status == BatchStatus::Delivered {
+                    checkpointer.set(&cursor).await;
+                }
+            }
+        });
+        Self(finalizer)
+    }
+
+    pub(super) fn finalize(&self, cursor: String, receiver: BatchStatusReceiver) {
+        self.0.add(cursor, receiver);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+    use tokio::fs::read_to_string;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn checkpointer_restarts_with_the_last_cursor() {
+        let tempdir = tempdir().unwrap();
+        let filename = tempdir.path().join(CHECKPOINT_FILENAME);
+
+        let mut checkpointer = Checkpointer::new(filename.clone()).await.unwrap();
+        assert_eq!(checkpointer.get().await.unwrap(), None);
+
+        checkpointer
+            .set("/api/v1/logs?since=2024-01-01T00%3A00%3A00Z&after=a-much-longer-cursor")
+            .await;
+        checkpointer.set("/api/v1/logs?after=short").await;
+        assert_eq!(
+            read_to_string(&filename).await.unwrap(),
+            "/api/v1/logs?after=short\n"
+        );
+
+        drop(checkpointer);
+        let mut checkpointer = Checkpointer::new(filename).await.unwrap();
+        assert_eq!(
+            checkpointer.get().await.unwrap().as_deref(),
+            Some("/api/v1/logs?after=short")
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::{sync::Arc, time::Duration};
+use std::{path::PathBuf, sync::Arc, time::Duration};
 
 use bytes::{Bytes, BytesMut};
 use chrono::Utc;
@@ -25,16 +25,21 @@ use vector_lib::{
     tls::TlsConfig,
 };
 
+use super::checkpointer::{CHECKPOINT_FILENAME, Checkpointer, Finalizer};
 use crate::{
     SourceSender,
-    config::{SourceConfig, SourceContext},
+    config::{SourceAcknowledgementsConfig, SourceConfig, SourceContext},
+    event::BatchNotifier,
     http::{HttpClient, HttpError},
     internal_events::{
         EndpointBytesReceived, HttpClientEventsReceived, HttpClientHttpError,
         HttpClientHttpResponseError, StreamClosedError,
     },
+    serde::bool_or_struct,
     sources,
-    sources::util::http_client::{default_interval, default_timeout, warn_if_interval_too_low},
+    sources::util::http_client::{
+        default_interval, default_timeout, find_rel_next_link, warn_if_interval_too_low,
+    },
     tls::TlsSettings,
 };
 use vector_lib::codecs::internal_events::DecoderDeserializeError;
@@ -70,6 +75,9 @@ pub struct OktaConfig {
 
     /// The time to look back for logs. This is used to determine the start time of the first request
     /// (that is, the earliest log to fetch)
+    ///
+    /// Only used when no checkpoint has been persisted yet. Once the source has fetched a page of
+    /// logs, it resumes from the stored cursor instead.
     #[configurable(metadata(docs::human_name = "Since (seconds before now)"))]
     pub since: Option<u64>,
 
@@ -77,6 +85,25 @@ pub struct OktaConfig {
     #[configurable(derived)]
     pub tls: Option<TlsConfig>,
 
+    /// The directory used to persist the cursor of the next page of logs to fetch.
+    ///
+    /// By default, the [global `data_dir` option][global_data_dir] is used, and the cursor isn't
+    /// persisted if Vector can't write to it.
+    /// Make sure the running user has write permissions to this directory.
+    ///
+    /// If this directory is specified, then Vector will attempt to create it, and fails to start
+    /// the source if it can't.
+    ///
+    /// [global_data_dir]: https://vector.dev/docs/reference/configuration/global-options/#data_dir
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = "/var/lib/vector"))]
+    #[configurable(metadata(docs::human_name = "Data Directory"))]
+    pub data_dir: Option<PathBuf>,
+
+    #[configurable(derived)]
+    #[serde(default, deserialize_with = "bool_or_struct")]
+    pub acknowledgements: SourceAcknowledgementsConfig,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[configurable(metadata(docs::hidden))]
     #[serde(default)]
@@ -92,6 +119,8 @@ impl Default for OktaConfig {
             timeout: default_timeout(),
             since: None,
             tls: None,
+            data_dir: None,
+            acknowledgements: Default::default(),
             log_namespace: None,
         }
     }
@@ -99,33 +128,43 @@ impl Default for OktaConfig {
 
 impl_generate_config_from_default!(OktaConfig);
 
-fn find_rel_next_link(header: &str) -> Option<String> {
-    for part in header.split(',') {
-        let relpart: Vec<_> = part.split(';').collect();
-        if let Some(url) = relpart
-            .first()
-            .map(|s| s.trim().trim_matches(|c| c == '<' || c == '>'))
-            && part.contains("rel=\"next\"")
-        {
-            return Some(url.to_string());
-        }
-    }
-    None
-}
-
 #[async_trait::async_trait]
 #[typetag::serde(name = "okta")]
 impl SourceConfig for OktaConfig {
     async fn build(&self, cx: SourceContext) -> crate::Result<sources::Source> {
-        let since = match self.since {
-            Some(since) => Utc::now() - Duration::from_secs(since),
-            _ => Utc::now(),
+        // Without a data directory of its own, the source runs without a checkpoint when the
+        // global one can't be used.
+        let mut checkpointer = match self.checkpointer(&cx).await {
+            Ok(checkpointer) => Some(checkpointer),
+            Err(error) if self.data_dir.is_none() => {
+                warn!(
+                    message = "Unable to use the data directory, the cursor won't be checkpointed.",
+                    %error,
+                );
+                None
+            }
+            Err(error) => return Err(error),
         };
 
-        let path_and_query = format!(
-            "/api/v1/logs?since={}",
-            utf8_percent_encode(&since.to_rfc3339(), percent_encoding::NON_ALPHANUMERIC)
-        );
+        // Resume from the persisted cursor, falling back to `since` on the first run.
+        let cursor = match &mut checkpointer {
+            Some(checkpointer) => checkpointer.get().await?,
+            None => None,
+        };
+        let path_and_query = match cursor {
+            Some(cursor) => cursor,
+            None => {
+                let since = match self.since {
+                    Some(since) => Utc::now() - Duration::from_secs(since),
+                    _ => Utc::now(),
+                };
+
+                format!(
+                    "/api/v1/logs?since={}",
+                    utf8_percent_encode(&since.to_rfc3339(), percent_encoding::NON_ALPHANUMERIC)
+                )
+            }
+        };
 
         let mut url_parts = Uri::try_from(&self.domain)
             .map_err(|_| {
@@ -151,6 +190,8 @@ impl SourceConfig for OktaConfig {
         let tls = TlsSettings::from_options(self.tls.as_ref())?;
 
         let log_namespace = cx.log_namespace(self.log_namespace);
+        let finalizer =
+            checkpointer.map(|checkpointer| Finalizer::new(checkpointer, cx.shutdown.clone()));
 
         warn_if_interval_too_low(self.timeout, self.interval);
 
@@ -162,6 +203,7 @@ impl SourceConfig for OktaConfig {
             self.interval,
             self.timeout,
             log_namespace,
+            finalizer,
             cx.shutdown,
             cx.out,
         )
@@ -180,7 +222,16 @@ impl SourceConfig for OktaConfig {
     }
 
     fn can_acknowledge(&self) -> bool {
-        false
+        true
+    }
+}
+
+impl OktaConfig {
+    async fn checkpointer(&self, cx: &SourceContext) -> crate::Result<Checkpointer> {
+        let data_dir = cx
+            .globals
+            .resolve_and_make_data_subdir(self.data_dir.as_ref(), cx.key.id())?;
+        Ok(Checkpointer::new(data_dir.join(CHECKPOINT_FILENAME)).await?)
     }
 }
 
@@ -236,6 +287,7 @@ fn handle_response(
     response: OktaRunResult,
     decoder: Decoder,
     log_namespace: LogNamespace,
+    batch: Option<BatchNotifier>,
     url: String,
 ) -> Option<impl Stream<Item = Event> + Send + use<>> {
     match response {
@@ -261,7 +313,7 @@ fn handle_response(
 
             enrich_events(&mut events, log_namespace);
 
-            Some(stream::iter(events))
+            Some(stream::iter(events).map(move |event| event.with_batch_notifier_option(&batch)))
         }
         Ok((header, _, _)) => {
             emit!(HttpClientHttpResponseError {
@@ -283,6 +335,8 @@ fn handle_response(
 /// and will always return a `rel=next` link regardless of whether there are more results.
 /// This function fetches all pages until there are no more results (an empty JSON array) and finishes until
 /// the next interval
+/// Each time a page is fetched, the link to the following page is handed to the `finalizer`, if
+/// any, which persists it as the checkpoint once the page's events are delivered.
 /// The function will run until the `shutdown` signal is received.
 #[allow(clippy::too_many_arguments)] // internal function
 async fn run(
@@ -293,10 +347,12 @@ async fn run(
     interval: Duration,
     timeout: Duration,
     log_namespace: LogNamespace,
+    finalizer: Option<Finalizer>,
     shutdown: ShutdownSignal,
     mut out: SourceSender,
 ) -> Result<(), ()> {
     let url_mutex = Arc::new(Mutex::new(url.clone()));
+    let finalizer = finalizer.map(Arc::new);
     let decoder = DecodingConfig::new(
         FramingConfig::Bytes,
         DeserializerConfig::Json(JsonDeserializerConfig::default()),
@@ -321,6 +377,7 @@ async fn run(
             let url_mutex = Arc::clone(&url_mutex);
             let token = token.clone();
             let decoder = decoder.clone();
+            let finalizer = finalizer.clone();
 
             async move {
                 stream::unfold((), move |_| {
@@ -328,9 +385,10 @@ async fn run(
                     let token = token.clone();
                     let decoder = decoder.clone();
                     let client = client.clone();
+                    let finalizer = finalizer.clone();
 
                     async move {
-                        let (run_url, response): (String, OktaRunResult) = {
+                        let (run_url, response, batch): (String, OktaRunResult, _) = {
                             // We update the actual URL based on the response the API returns
                             // so the critical section is between here & when the request finishes
                             let mut url_lock = url_mutex.lock().await;
@@ -367,15 +425,26 @@ async fn run(
                                 })
                                 .await;
 
+                            let mut batch = None;
                             if let Ok((_, _, Some(ref next))) = response {
                                 *url_lock = next.clone();
+
+                                // The checkpoint only needs the path and query, which carry the
+                                // cursor; the domain always comes from the configuration.
+                                if let (Some(finalizer), Some(cursor)) =
+                                    (&finalizer, next.path_and_query())
+                                {
+                                    let (notifier, receiver) = BatchNotifier::new_with_receiver();
+                                    finalizer.finalize(cursor.to_string(), receiver);
+                                    batch = Some(notifier);
+                                }
                             }
                             let new_url = url_lock.to_string();
 
-                            (new_url, response)
+                            (new_url, response, batch)
                         };
 
-                        handle_response(response, decoder, log_namespace, run_url)
+                        handle_response(response, decoder, log_namespace, batch, run_url)
                             .map(|events| (events, ()))
                     }
                 })
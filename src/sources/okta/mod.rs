@@ -1,4 +1,6 @@
 #[cfg(feature = "sources-okta")]
+mod checkpointer;
+#[cfg(feature = "sources-okta")]
 pub mod client;
 
 #[cfg(test)]
@@ -1,6 +1,9 @@
-use std::sync::{
-    Arc,
-    atomic::{AtomicBool, Ordering},
+use std::{
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
 };
 
 use tokio::time::Duration;
@@ -14,7 +17,7 @@ use crate::{
     test_util::{
         addr::next_addr,
         components::{HTTP_PULL_SOURCE_TAGS, run_and_assert_source_compliance},
-        test_generate_config, wait_for_tcp,
+        temp_dir, test_generate_config, wait_for_tcp,
     },
 };
 
@@ -22,6 +25,13 @@ pub(crate) const INTERVAL: Duration = Duration::from_secs(10);
 
 pub(crate) const TIMEOUT: Duration = Duration::from_secs(1);
 
+/// Creates an empty data directory for the checkpoint.
+pub(crate) fn data_dir() -> PathBuf {
+    let data_dir = temp_dir();
+    std::fs::create_dir_all(&data_dir).unwrap();
+    data_dir
+}
+
 /// The happy path should yield at least one event and must emit the required internal events for sources.
 pub(crate) async fn run_compliance(config: OktaConfig) -> Vec<Event> {
     let events =
@@ -45,6 +55,7 @@ impl ValidatableComponent for OktaConfig {
             token: "token".to_string(),
             interval: Duration::from_secs(1),
             timeout: Duration::from_secs(1),
+            data_dir: Some(data_dir()),
             ..Default::default()
         };
         let log_namespace: LogNamespace = config.log_namespace.unwrap_or_default().into();
@@ -95,6 +106,7 @@ async fn okta_compliance() {
         interval: INTERVAL,
         timeout: TIMEOUT,
         log_namespace: None,
+        data_dir: Some(data_dir()),
         ..Default::default()
     })
     .await;
@@ -163,6 +175,7 @@ async fn okta_follows_rel() {
         interval: INTERVAL,
         timeout: TIMEOUT,
         log_namespace: None,
+        data_dir: Some(data_dir()),
         ..Default::default()
     })
     .await;
@@ -238,9 +251,104 @@ async fn okta_persists_rel() {
         token: "token".to_string(),
         interval: Duration::from_secs(1),
         timeout: Duration::from_millis(100),
+        data_dir: Some(data_dir()),
         ..Default::default()
     })
     .await;
 
     assert_eq!(events.len(), 2);
 }
+
+#[tokio::test]
+async fn okta_resumes_from_checkpoint() {
+    // after a restart, the source must continue from the persisted cursor instead of starting
+    // over from `since`
+    let (_guard, addr) = next_addr();
+
+    let restarted: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+
+    let dummy_endpoint = warp::path!("api" / "v1" / "logs")
+        .and(warp::query::<std::collections::HashMap<String, String>>())
+        .map({
+            let restarted = Arc::clone(&restarted);
+            move |q: std::collections::HashMap<String, String>| {
+                let restarted = restarted.load(Ordering::Relaxed);
+                let (body, next) = match q.get("after").map(String::as_str) {
+                    None if restarted => panic!("restarted without the checkpoint"),
+                    None => (r#"[{"data":"foo"}]"#, "bar"),
+                    Some("bar") => (r#"[{"data":"bar"}]"#, "baz"),
+                    Some("baz") if restarted => (r#"[{"data":"baz"}]"#, "quux"),
+                    Some(after) => ("[]", after),
+                };
+                warp::http::Response::builder()
+                    .header("Content-Type", "application/json")
+                    .header(
+                        "link",
+                        format!("<http://{addr}/api/v1/logs?after={next}>; rel=\"next\""),
+                    )
+                    .body(body)
+                    .unwrap()
+            }
+        });
+
+    tokio::spawn(warp::serve(dummy_endpoint).run(addr));
+    wait_for_tcp(addr).await;
+
+    let config = OktaConfig {
+        domain: format!("http://{addr}"),
+        token: "token".to_string(),
+        interval: INTERVAL,
+        timeout: TIMEOUT,
+        data_dir: Some(data_dir()),
+        ..Default::default()
+    };
+
+    let events = run_compliance(config.clone()).await;
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].as_log()["data"].as_str().unwrap(), "foo");
+    assert_eq!(events[1].as_log()["data"].as_str().unwrap(), "bar");
+
+    restarted.store(true, Ordering::Relaxed);
+
+    let events = run_compliance(config).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].as_log()["data"].as_str().unwrap(), "baz");
+}
+
+#[tokio::test]
+async fn okta_runs_without_a_data_dir() {
+    // without a usable data directory, the source runs without a checkpoint
+    let (_guard, addr) = next_addr();
+
+    let dummy_endpoint = warp::path!("api" / "v1" / "logs")
+        .and(warp::query::<std::collections::HashMap<String, String>>())
+        .map(move |q: std::collections::HashMap<String, String>| {
+            let body = match q.get("after") {
+                None => r#"[{"data":"foo"}]"#,
+                Some(_) => "[]",
+            };
+            warp::http::Response::builder()
+                .header("Content-Type", "application/json")
+                .header(
+                    "link",
+                    format!("<http://{addr}/api/v1/logs?after=bar>; rel=\"next\""),
+                )
+                .body(body)
+                .unwrap()
+        });
+
+    tokio::spawn(warp::serve(dummy_endpoint).run(addr));
+    wait_for_tcp(addr).await;
+
+    let events = run_compliance(OktaConfig {
+        domain: format!("http://{addr}"),
+        token: "token".to_string(),
+        interval: INTERVAL,
+        timeout: TIMEOUT,
+        data_dir: None,
+        ..Default::default()
+    })
+    .await;
+
+    assert_eq!(events.len(), 1);
+}
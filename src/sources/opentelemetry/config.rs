@@ -3,7 +3,7 @@ use std::net::SocketAddr;
 use crate::{
     config::{
         DataType, GenerateConfig, Resource, SourceAcknowledgementsConfig, SourceConfig,
-        SourceContext, SourceOutput,
+        SourceContext, SourceOutput, TestInputDecoder,
     },
     http::KeepaliveConfig,
     serde::bool_or_struct,
@@ -13,6 +13,7 @@ use crate::{
         opentelemetry::{
             grpc::Service,
             http::{build_warp_filter, run_http_server},
+            test_input::OtlpTestInputDecoder,
         },
         util::{
             decompression::max_decompressed_size_bytes,
@@ -505,4 +506,15 @@ impl SourceConfig for OpentelemetryConfig {
     fn can_acknowledge(&self) -> bool {
         true
     }
+
+    fn build_decoder(
+        &self,
+        global_log_namespace: LogNamespace,
+    ) -> crate::Result<Option<Box<dyn TestInputDecoder>>> {
+        let log_namespace = global_log_namespace.merge(self.log_namespace);
+        Ok(Some(Box::new(OtlpTestInputDecoder::new(
+            self,
+            log_namespace,
+        )?)))
+    }
 }
@@ -8,6 +8,7 @@ mod grpc;
 mod http;
 mod reply;
 mod status;
+mod test_input;
 
 use vector_lib::{
     event::Event,
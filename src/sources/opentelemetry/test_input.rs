@@ -0,0 +1,253 @@
+//! Decoding of the input that unit tests insert at the `opentelemetry` source.
+//!
+//! Unit test inputs are text, so they are read as OTLP/JSON export requests, which are converted
+//! to their protobuf encoding and then decoded the same way as the requests the source receives.
+
+use base64::prelude::{BASE64_STANDARD, Engine as _};
+use bytes::Bytes;
+use prost::Message as _;
+use prost_reflect::{DescriptorPool, DynamicMessage};
+use vector_lib::{
+    codecs::decoding::{OtlpDeserializer, OtlpSignalType, format::Deserializer as _},
+    config::LogNamespace,
+    opentelemetry::proto::{
+        DESCRIPTOR_BYTES, LOGS_REQUEST_MESSAGE_TYPE, METRICS_REQUEST_MESSAGE_TYPE,
+        RESOURCE_LOGS_JSON_FIELD, RESOURCE_METRICS_JSON_FIELD, RESOURCE_SPANS_JSON_FIELD,
+        TRACES_REQUEST_MESSAGE_TYPE,
+        collector::{
+            logs::v1::ExportLogsServiceRequest, metrics::v1::ExportMetricsServiceRequest,
+            trace::v1::ExportTraceServiceRequest,
+        },
+    },
+};
+
+use super::config::{LOGS, METRICS, OpentelemetryConfig, TRACES};
+use crate::{config::TestInputDecoder, event::Event};
+
+/// The fields that OTLP/JSON encodes as hex strings rather than as base64 like other bytes fields.
+const HEX_ID_FIELDS: [&str; 3] = ["traceId", "spanId", "parentSpanId"];
+
+pub(super) struct OtlpTestInputDecoder {
+    descriptors: DescriptorPool,
+    log_namespace: LogNamespace,
+    logs_deserializer: Option<OtlpDeserializer>,
+    metrics_deserializer: Option<OtlpDeserializer>,
+    traces_deserializer: Option<OtlpDeserializer>,
+}
+
+impl OtlpTestInputDecoder {
+    pub(super) fn new(
+        config: &OpentelemetryConfig,
+        log_namespace: LogNamespace,
+    ) -> crate::Result<Self> {
+        Ok(Self {
+            descriptors: DescriptorPool::decode(DESCRIPTOR_BYTES)?,
+            log_namespace,
+            logs_deserializer: config.get_signal_deserializer(OtlpSignalType::Logs)?,
+            metrics_deserializer: config.get_signal_deserializer(OtlpSignalType::Metrics)?,
+            traces_deserializer: config.get_signal_deserializer(OtlpSignalType::Traces)?,
+        })
+    }
+
+    /// Converts an OTLP/JSON export request to its protobuf encoding.
+    fn encode(&self, message_type: &str, mut request: serde_json::Value) -> crate::Result<Bytes> {
+        hex_ids_to_base64(&mut request)?;
+        let descriptor = self
+            .descriptors
+            .get_message_by_name(message_type)
+            .ok_or_else(|| format!("missing descriptor of `{message_type}`"))?;
+        let message = DynamicMessage::deserialize(descriptor, request)?;
+        Ok(prost_reflect::prost::Message::encode_to_vec(&message).into())
+    }
+}
+
+impl TestInputDecoder for OtlpTestInputDecoder {
+    fn decode(&self, input: Bytes) -> crate::Result<Vec<(Option<String>, Event)>> {
+        let request = serde_json::from_slice::<serde_json::Value>(&input)?;
+
+        let (output, events) = if request.get(RESOURCE_LOGS_JSON_FIELD).is_some() {
+            let body = self.encode(LOGS_REQUEST_MESSAGE_TYPE, request)?;
+            let events = match &self.logs_deserializer {
+                Some(deserializer) => deserializer.parse(body, self.log_namespace)?.into_vec(),
+                None => ExportLogsServiceRequest::decode(body)?
+                    .resource_logs
+                    .into_iter()
+                    .flat_map(|v| v.into_event_iter(self.log_namespace))
+                    .collect(),
+            };
+            (LOGS, events)
+        } else if request.get(RESOURCE_METRICS_JSON_FIELD).is_some() {
+            let body = self.encode(METRICS_REQUEST_MESSAGE_TYPE, request)?;
+            let events = match &self.metrics_deserializer {
+                Some(deserializer) => deserializer.parse(body, self.log_namespace)?.into_vec(),
+                None => ExportMetricsServiceRequest::decode(body)?
+                    .resource_metrics
+                    .into_iter()
+                    .flat_map(|v| v.into_event_iter())
+                    .collect(),
+            };
+            (METRICS, events)
+        } else if request.get(RESOURCE_SPANS_JSON_FIELD).is_some() {
+            let body = self.encode(TRACES_REQUEST_MESSAGE_TYPE, request)?;
+            let events = match &self.traces_deserializer {
+                Some(deserializer) => deserializer
+                    .parse(body, LogNamespace::default())?
+                    .into_vec(),
+                None => ExportTraceServiceRequest::decode(body)?
+                    .resource_spans
+                    .into_iter()
+                    .flat_map(|v| v.into_event_iter())
+                    .collect(),
+            };
+            (TRACES, events)
+        } else {
+            return Err(format!(
+                "expected an OTLP/JSON export request with a `{RESOURCE_LOGS_JSON_FIELD}`, \
+                `{RESOURCE_METRICS_JSON_FIELD}`, or `{RESOURCE_SPANS_JSON_FIELD}` field"
+            )
+            .into());
+        };
+
+        Ok(events
+            .into_iter()
+            .map(|event| (Some(output.to_owned()), event))
+            .collect())
+    }
+}
+
+/// Re-encodes the hex trace and span IDs of an OTLP/JSON request as base64, which is how the
+/// protobuf JSON mapping encodes bytes fields.
+fn hex_ids_to_base64(value: &mut serde_json::Value) -> crate::Result<()> {
+    match value {
+        serde_json::Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                match value {
+                    serde_json::Value::String(id) if HEX_ID_FIELDS.contains(&key.as_str()) => {
+                        *id = BASE64_STANDARD.encode(hex::decode(&*id)?);
+                    }
+                    value => hex_ids_to_base64(value)?,
+                }
+            }
+        }
+        serde_json::Value::Array(array) => {
+            for value in array {
+                hex_ids_to_base64(value)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use vrl::value;
+
+    use super::*;
+
+    fn decoder(use_otlp_decoding: bool) -> OtlpTestInputDecoder {
+        let config = toml::from_str::<OpentelemetryConfig>(&format!(
+            indoc! {r#"
+                use_otlp_decoding = {}
+
+                [grpc]
+                address = "0.0.0.0:4317"
+
+                [http]
+                address = "0.0.0.0:4318"
+            "#},
+            use_otlp_decoding
+        ))
+        .unwrap();
+        OtlpTestInputDecoder::new(&config, LogNamespace::Legacy).unwrap()
+    }
+
+    const LOGS_REQUEST: &str = indoc! {r#"
+        {
+          "resourceLogs": [{
+            "resource": {
+              "attributes": [{"key": "service.name", "value": {"stringValue": "checkout"}}]
+            },
+            "scopeLogs": [{
+              "logRecords": [{
+                "timeUnixNano": "1700000000000000000",
+                "severityText": "INFO",
+                "traceId": "5b8efff798038103d269b633813fc60c",
+                "spanId": "eee19b7ec3c1b174",
+                "body": {"stringValue": "order placed"}
+              }]
+            }]
+          }]
+        }
+    "#};
+
+    #[test]
+    fn decodes_logs_request() {
+        let events = decoder(false).decode(Bytes::from(LOGS_REQUEST)).unwrap();
+
+        assert_eq!(events.len(), 1);
+        let (output, event) = &events[0];
+        assert_eq!(output.as_deref(), Some(LOGS));
+        let log = event.as_log();
+        assert_eq!(log["message"], "order placed".into());
+        assert_eq!(log["severity_text"], "INFO".into());
+        assert_eq!(log["trace_id"], "5b8efff798038103d269b633813fc60c".into());
+        assert_eq!(log["span_id"], "eee19b7ec3c1b174".into());
+        assert_eq!(
+            log["resources"],
+            value!({"service.name": "checkout"}).into()
+        );
+    }
+
+    #[test]
+    fn decodes_logs_request_with_otlp_decoding() {
+        let events = decoder(true).decode(Bytes::from(LOGS_REQUEST)).unwrap();
+
+        assert_eq!(events.len(), 1);
+        let (output, event) = &events[0];
+        assert_eq!(output.as_deref(), Some(LOGS));
+        assert!(event.as_log().get(RESOURCE_LOGS_JSON_FIELD).is_some());
+    }
+
+    #[test]
+    fn decodes_traces_request() {
+        let events = decoder(false)
+            .decode(Bytes::from(indoc! {r#"
+                {
+                  "resourceSpans": [{
+                    "scopeSpans": [{
+                      "spans": [{
+                        "traceId": "5b8efff798038103d269b633813fc60c",
+                        "spanId": "eee19b7ec3c1b174",
+                        "name": "GET /orders",
+                        "kind": 2
+                      }]
+                    }]
+                  }]
+                }
+            "#}))
+            .unwrap();
+
+        assert_eq!(events.len(), 1);
+        let (output, event) = &events[0];
+        assert_eq!(output.as_deref(), Some(TRACES));
+        let trace = event.as_trace();
+        assert_eq!(
+            trace.get(vrl::event_path!("name")),
+            Some(&"GET /orders".into())
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_request() {
+        let error = decoder(false)
+            .decode(Bytes::from(r#"{"resourceProfiles": []}"#))
+            .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("expected an OTLP/JSON export request")
+        );
+    }
+}
@@ -155,6 +155,7 @@ impl SourceConfig for PrometheusScrapeConfig {
             tls,
             proxy: cx.proxy.clone(),
             shutdown: cx.shutdown,
+            sequential: false,
         };
 
         Ok(call(inputs, builder, cx.out, HttpMethod::Get).boxed())
@@ -16,7 +16,7 @@ use vector_lib::{
     EstimatedJsonEncodedSizeOf,
     codecs::{
         Decoder, DecoderFramedRead, DecodingConfig, StreamDecodingError,
-        decoding::{DeserializerConfig, FramingConfig},
+        decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     },
     config::{LegacyKey, LogNamespace, SourceAcknowledgementsConfig, SourceOutput},
     configurable::configurable_component,
@@ -91,6 +91,14 @@ pub struct PulsarSourceConfig {
     #[derivative(Default(value = "default_decoding()"))]
     decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    reroute_decode_errors: bool,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: SourceAcknowledgementsConfig,
@@ -211,6 +219,7 @@ impl SourceConfig for PulsarSourceConfig {
         let consumer = self.create_consumer().await?;
         let decoder =
             DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .with_decode_errors(self.reroute_decode_errors)
                 .build()?;
         let acknowledgements = cx.do_acknowledgements(self.acknowledgements);
 
@@ -252,10 +261,14 @@ impl SourceConfig for PulsarSourceConfig {
                 Kind::bytes(),
                 Some("producer_name"),
             );
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decode_errors {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -645,6 +658,7 @@ mod integration_tests {
             dead_letter_queue_policy: None,
             framing: FramingConfig::Bytes,
             decoding: DeserializerConfig::Bytes,
+            reroute_decode_errors: false,
             acknowledgements: acknowledgements.into(),
             log_namespace: None,
             tls: tls.clone(),
@@ -6,7 +6,7 @@ use vector_lib::{
     EstimatedJsonEncodedSizeOf,
     codecs::{
         Decoder, DecoderFramedRead, DecodingConfig, StreamDecodingError,
-        decoding::{DeserializerConfig, FramingConfig},
+        decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     },
     config::{LegacyKey, LogNamespace},
     configurable::configurable_component,
@@ -127,6 +127,14 @@ pub struct RedisSourceConfig {
     #[derivative(Default(value = "default_decoding()"))]
     decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    reroute_decode_errors: bool,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[configurable(metadata(docs::hidden))]
     #[serde(default)]
@@ -165,6 +173,7 @@ impl SourceConfig for RedisSourceConfig {
         let connection_info = ConnectionInfo::from(client.get_connection_info());
         let decoder =
             DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .with_decode_errors(self.reroute_decode_errors)
                 .build()?;
 
         let bytes_received = register!(BytesReceived::from(Protocol::from(
@@ -212,10 +221,14 @@ impl SourceConfig for RedisSourceConfig {
             )
             .with_standard_vector_source_metadata();
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decode_errors {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -345,6 +358,7 @@ mod integration_test {
             redis_key: None,
             framing: default_framing_message_based(),
             decoding: default_decoding(),
+            reroute_decode_errors: false,
             log_namespace: Some(false),
         };
 
@@ -386,6 +400,7 @@ mod integration_test {
             redis_key: Some(OptionalValuePath::from(owned_value_path!("remapped_key"))),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
+            reroute_decode_errors: false,
             log_namespace: Some(true),
         };
 
@@ -427,6 +442,7 @@ mod integration_test {
             redis_key: None,
             framing: default_framing_message_based(),
             decoding: default_decoding(),
+            reroute_decode_errors: false,
             log_namespace: Some(false),
         };
 
@@ -460,6 +476,7 @@ mod integration_test {
             redis_key: None,
             framing: default_framing_message_based(),
             decoding: default_decoding(),
+            reroute_decode_errors: false,
             log_namespace: Some(false),
         };
 
@@ -4,7 +4,7 @@ pub mod udp;
 mod unix;
 
 use vector_lib::{
-    codecs::decoding::DeserializerConfig,
+    codecs::decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     config::{LegacyKey, LogNamespace, log_schema},
     configurable::configurable_component,
     lookup::{lookup_v2::OptionalValuePath, owned_value_path},
@@ -13,7 +13,9 @@ use vrl::value::{Kind, kind::Collection};
 
 use crate::{
     codecs::DecodingConfig,
-    config::{GenerateConfig, Resource, SourceConfig, SourceContext, SourceOutput},
+    config::{
+        GenerateConfig, Resource, SourceConfig, SourceContext, SourceOutput, TestInputDecoder,
+    },
     sources::util::net::TcpSource,
     tls::MaybeTlsSettings,
 };
@@ -69,6 +71,41 @@ impl SocketConfig {
         }
     }
 
+    fn framing(&self) -> FramingConfig {
+        let decoding = self.decoding();
+        match &self.mode {
+            Mode::Tcp(config) => config
+                .framing
+                .clone()
+                .unwrap_or_else(|| decoding.default_stream_framing()),
+            Mode::Udp(config) => config
+                .framing()
+                .clone()
+                .unwrap_or_else(|| decoding.default_message_based_framing()),
+            #[cfg(unix)]
+            Mode::UnixDatagram(config) => config
+                .framing
+                .clone()
+                .unwrap_or_else(|| decoding.default_message_based_framing()),
+            #[cfg(unix)]
+            Mode::UnixStream(config) => config
+                .framing
+                .clone()
+                .unwrap_or_else(|| decoding.default_stream_framing()),
+        }
+    }
+
+    const fn reroute_decode_errors(&self) -> bool {
+        match &self.mode {
+            Mode::Tcp(config) => config.reroute_decode_errors,
+            Mode::Udp(config) => config.reroute_decode_errors,
+            #[cfg(unix)]
+            Mode::UnixDatagram(config) => config.reroute_decode_errors,
+            #[cfg(unix)]
+            Mode::UnixStream(config) => config.reroute_decode_errors,
+        }
+    }
+
     fn log_namespace(&self, global_log_namespace: LogNamespace) -> LogNamespace {
         match &self.mode {
             Mode::Tcp(config) => global_log_namespace.merge(config.log_namespace),
@@ -124,6 +161,7 @@ impl SourceConfig for SocketConfig {
                     decoding,
                     log_namespace,
                 )
+                .with_decode_errors(config.reroute_decode_errors)
                 .build()?;
 
                 let tcp = tcp::RawTcpSource::new(config.clone(), decoder, log_namespace);
@@ -159,7 +197,9 @@ impl SourceConfig for SocketConfig {
                     .framing()
                     .clone()
                     .unwrap_or_else(|| decoding.default_message_based_framing());
-                let decoder = DecodingConfig::new(framing, decoding, log_namespace).build()?;
+                let decoder = DecodingConfig::new(framing, decoding, log_namespace)
+                    .with_decode_errors(config.reroute_decode_errors)
+                    .build()?;
                 Ok(udp::udp(
                     config,
                     decoder,
@@ -176,7 +216,9 @@ impl SourceConfig for SocketConfig {
                     .framing
                     .clone()
                     .unwrap_or_else(|| decoding.default_message_based_framing());
-                let decoder = DecodingConfig::new(framing, decoding, log_namespace).build()?;
+                let decoder = DecodingConfig::new(framing, decoding, log_namespace)
+                    .with_decode_errors(config.reroute_decode_errors)
+                    .build()?;
 
                 unix::unix_datagram(config, decoder, cx.shutdown, cx.out, log_namespace)
             }
@@ -193,6 +235,7 @@ impl SourceConfig for SocketConfig {
                     decoding,
                     log_namespace,
                 )
+                .with_decode_errors(config.reroute_decode_errors)
                 .build()?;
 
                 unix::unix_stream(config, decoder, cx.shutdown, cx.out, log_namespace)
@@ -292,10 +335,14 @@ impl SourceConfig for SocketConfig {
             }
         };
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding().output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decode_errors() {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn resources(&self) -> Vec<Resource> {
@@ -312,6 +359,17 @@ impl SourceConfig for SocketConfig {
     fn can_acknowledge(&self) -> bool {
         false
     }
+
+    fn build_decoder(
+        &self,
+        global_log_namespace: LogNamespace,
+    ) -> crate::Result<Option<Box<dyn TestInputDecoder>>> {
+        let log_namespace = self.log_namespace(global_log_namespace);
+        let decoder = DecodingConfig::new(self.framing(), self.decoding(), log_namespace)
+            .build()?
+            .with_decode_errors(self.reroute_decode_errors());
+        Ok(Some(Box::new(decoder)))
+    }
 }
 
 pub(crate) fn default_host_key() -> OptionalValuePath {
@@ -347,9 +405,10 @@ mod test {
         CharacterDelimitedDecoderConfig, decoding::CharacterDelimitedDecoderOptions,
     };
     use vector_lib::{
-        codecs::{GelfDeserializerConfig, NewlineDelimitedDecoderConfig},
-        event::EventContainer,
+        codecs::{GelfDeserializerConfig, JsonDeserializerConfig, NewlineDelimitedDecoderConfig},
+        event::{EventContainer, EventStatus, into_event_stream},
         lookup::{lookup_v2::OptionalValuePath, owned_value_path, path},
+        source_sender::DECODE_ERRORS_OUTPUT,
     };
     use vrl::{btreemap, value, value::ObjectMap};
     #[cfg(unix)]
@@ -565,6 +624,41 @@ mod test {
         .await;
     }
 
+    #[tokio::test]
+    async fn tcp_reroutes_decode_errors() {
+        let (mut tx, rx) = SourceSender::new_test();
+        let errors = tx.add_outputs(EventStatus::Delivered, DECODE_ERRORS_OUTPUT.to_owned());
+        let (guard, addr) = next_addr();
+        let mut config = TcpConfig::from_address(addr.into());
+        config.set_decoding(JsonDeserializerConfig::default().into());
+        config.reroute_decode_errors = true;
+
+        let server = SocketConfig::from(config)
+            .build(SourceContext::new_test(tx, None))
+            .await
+            .unwrap();
+        tokio::spawn(server);
+
+        wait_for_tcp_and_release(guard, addr).await;
+
+        let client_addr = send_lines(
+            addr,
+            vec![r#"{"foo":"bar"}"#.to_owned(), "{invalid".to_owned()].into_iter(),
+        )
+        .await
+        .unwrap();
+
+        let events = collect_n(rx, 1).await;
+        assert_eq!(events[0].as_log()["foo"], "bar".into());
+
+        let errors = collect_n(errors.flat_map(into_event_stream), 1).await;
+        let error = errors[0].as_log();
+        assert_eq!(error["message"], "{invalid".into());
+        assert_eq!(error["framing"], "newline_delimited".into());
+        assert_eq!(error["codec"], "json".into());
+        assert_eq!(error["port"], client_addr.port().into());
+    }
+
     #[tokio::test]
     async fn tcp_it_includes_source_type() {
         assert_source_compliance(&SOCKET_PUSH_SOURCE_TAGS, async {
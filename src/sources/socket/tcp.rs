@@ -94,6 +94,14 @@ pub struct TcpConfig {
     #[serde(default = "default_decoding")]
     pub(super) decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub(super) reroute_decode_errors: bool,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[serde(default)]
     #[configurable(metadata(docs::hidden))]
@@ -123,6 +131,7 @@ impl TcpConfig {
             tls_handshake_timeout_secs: None,
             framing: None,
             decoding: default_decoding(),
+            reroute_decode_errors: false,
             connection_limit: None,
             log_namespace: None,
         }
@@ -62,6 +62,14 @@ pub struct UnixConfig {
     #[serde(default = "default_decoding")]
     pub decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub reroute_decode_errors: bool,
+
     /// The namespace to use for logs. This overrides the global setting.
     #[serde(default)]
     #[configurable(metadata(docs::hidden))]
@@ -76,6 +84,7 @@ impl UnixConfig {
             host_key: default_host_key(),
             framing: None,
             decoding: default_decoding(),
+            reroute_decode_errors: false,
             log_namespace: None,
         }
     }
@@ -25,7 +25,7 @@ use vector_lib::{
     EstimatedJsonEncodedSizeOf,
     codecs::{
         Decoder, StreamDecodingError,
-        decoding::{DeserializerConfig, FramingConfig},
+        decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     },
     config::{LegacyKey, LogNamespace},
     configurable::configurable_component,
@@ -161,6 +161,14 @@ pub struct SplunkConfig {
     #[configurable(derived)]
     #[serde(default)]
     pub raw: CodecConfig,
+
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// This applies to the endpoints that have `decoding` set. Each of these events holds the raw
+    /// bytes that failed to decode in the `message` field, along with the `error`, and the
+    /// `framing` method and `codec` that were used.
+    #[configurable(metadata(docs::advanced))]
+    pub reroute_decode_errors: bool,
 }
 
 /// Codec configuration applied to one of the `splunk_hec` endpoints.
@@ -188,7 +196,11 @@ pub struct CodecConfig {
 }
 
 impl CodecConfig {
-    fn build_decoder(&self, log_namespace: LogNamespace) -> crate::Result<Option<Decoder>> {
+    fn build_decoder(
+        &self,
+        log_namespace: LogNamespace,
+        decode_errors: bool,
+    ) -> crate::Result<Option<Decoder>> {
         match &self.decoding {
             Some(decoding) => {
                 let framing = self
@@ -196,7 +208,9 @@ impl CodecConfig {
                     .clone()
                     .unwrap_or_else(|| decoding.default_message_based_framing());
                 Ok(Some(
-                    DecodingConfig::new(framing, decoding.clone(), log_namespace).build()?,
+                    DecodingConfig::new(framing, decoding.clone(), log_namespace)
+                        .with_decode_errors(decode_errors)
+                        .build()?,
                 ))
             }
             None => Ok(None),
@@ -217,6 +231,7 @@ impl Default for SplunkConfig {
             store_hec_token: false,
             log_namespace: None,
             keepalive: Default::default(),
+            reroute_decode_errors: false,
             event: CodecConfig::default(),
             raw: CodecConfig::default(),
         }
@@ -244,8 +259,12 @@ impl SplunkConfig {
         let shutdown = cx.shutdown.clone();
         let out = cx.out.clone();
         let log_namespace = cx.log_namespace(self.log_namespace);
-        let event_decoder = self.event.build_decoder(log_namespace)?;
-        let raw_decoder = self.raw.build_decoder(log_namespace)?;
+        let event_decoder = self
+            .event
+            .build_decoder(log_namespace, self.reroute_decode_errors)?;
+        let raw_decoder = self
+            .raw
+            .build_decoder(log_namespace, self.reroute_decode_errors)?;
         let source = SplunkSource::new(
             self,
             tls.http_protocol_name(),
@@ -447,7 +466,11 @@ impl SourceConfig for SplunkConfig {
             (Some(d), None) | (None, Some(d)) => d.output_type() | DataType::Log,
             (Some(de), Some(dr)) => de.output_type() | dr.output_type(),
         };
-        vec![SourceOutput::new_maybe_logs(output_type, schema_definition)]
+        let mut outputs = vec![SourceOutput::new_maybe_logs(output_type, schema_definition)];
+        if self.reroute_decode_errors {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn resources(&self) -> Vec<Resource> {
@@ -2151,6 +2174,7 @@ mod tests {
                 store_hec_token,
                 log_namespace: None,
                 keepalive: Default::default(),
+                reroute_decode_errors: false,
                 event: CodecConfig::default(),
                 raw: CodecConfig::default(),
             }
@@ -3421,6 +3445,7 @@ mod tests {
                 store_hec_token: false,
                 log_namespace: None,
                 keepalive: Default::default(),
+                reroute_decode_errors: false,
                 event,
                 raw,
             }
@@ -3807,6 +3832,7 @@ mod tests {
                 store_hec_token: false,
                 log_namespace: None,
                 keepalive: Default::default(),
+                reroute_decode_errors: false,
                 event: CodecConfig::default(),
                 raw: codec_decoding(vector_lib::codecs::JsonDeserializerConfig::default().into()),
             }
@@ -3860,6 +3886,7 @@ mod tests {
                 store_hec_token: false,
                 log_namespace: None,
                 keepalive: Default::default(),
+                reroute_decode_errors: false,
                 event: CodecConfig::default(),
                 raw: codec_full(
                     Some(FramingConfig::NewlineDelimited(Default::default())),
@@ -28,7 +28,8 @@ use crate::{
     SourceSender,
     codecs::Decoder,
     config::{
-        DataType, GenerateConfig, Resource, SourceConfig, SourceContext, SourceOutput, log_schema,
+        DataType, GenerateConfig, Resource, SourceConfig, SourceContext, SourceOutput,
+        TestInputDecoder, log_schema,
     },
     event::Event,
     internal_events::{
@@ -291,6 +292,23 @@ impl SourceConfig for SyslogConfig {
     fn can_acknowledge(&self) -> bool {
         false
     }
+
+    fn build_decoder(
+        &self,
+        global_log_namespace: LogNamespace,
+    ) -> crate::Result<Option<Box<dyn TestInputDecoder>>> {
+        let log_namespace = global_log_namespace.merge(self.log_namespace);
+        let framer = match self.mode {
+            Mode::Udp { .. } => Framer::Bytes(BytesDecoder::new()),
+            _ => Framer::OctetCounting(OctetCountingDecoder::new_with_max_length(self.max_length)),
+        };
+        let decoder = Decoder::new(
+            framer,
+            Deserializer::Syslog(SyslogDeserializerConfig::from_source(SyslogConfig::NAME).build()),
+        )
+        .with_log_namespace(log_namespace);
+        Ok(Some(Box::new(decoder)))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -11,12 +11,13 @@
 // Okta source only imports defaults but doesn't use the rest of the client
 #![cfg_attr(feature = "sources-okta", allow(dead_code))]
 
-use std::{collections::HashMap, future::ready, time::Duration};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
 use bytes::Bytes;
-use futures_util::{FutureExt, StreamExt, TryFutureExt, stream};
+use futures_util::{Stream, StreamExt, stream};
 use http::{Uri, response::Parts};
 use hyper::{Body, Request};
+use tokio::time::MissedTickBehavior;
 use tokio_stream::wrappers::IntervalStream;
 use vector_lib::{
     EstimatedJsonEncodedSizeOf, config::proxy::ProxyConfig, event::Event, json_size::JsonSize,
@@ -50,6 +51,8 @@ pub(crate) struct GenericHttpClientInputs {
     pub tls: TlsSettings,
     pub proxy: ProxyConfig,
     pub shutdown: ShutdownSignal,
+    /// Whether a scrape waits for the previous one to complete before starting.
+    pub sequential: bool,
 }
 
 /// The default interval to call the HTTP endpoint if none is configured.
@@ -96,6 +99,19 @@ pub(crate) trait HttpClientContext {
     // metadata. This function should be used rather than internal enrichment so
     // that accurate byte count metrics can be emitted.
     fn enrich_events(&mut self, _events: &mut Vec<Event>) {}
+
+    /// (Optional) Called after a successful response to get the URL of the next page to request
+    /// as part of the same scrape.
+    /// Returns None once there are no more pages to request.
+    fn next_page_url(
+        &mut self,
+        _url: &Uri,
+        _header: &Parts,
+        _body: &Bytes,
+        _events: &[Event],
+    ) -> Option<Uri> {
+        None
+    }
 }
 
 /// Builds a url for the HTTP requests.
@@ -132,6 +148,21 @@ pub(crate) fn build_url(uri: &Uri, query: &QueryParameters) -> Uri {
         .expect("Failed to build URI from parsed arguments")
 }
 
+/// Finds the URL of the `rel="next"` entry in a `Link` header value.
+pub(crate) fn find_rel_next_link(header: &str) -> Option<String> {
+    for part in header.split(',') {
+        let relpart: Vec<_> = part.split(';').collect();
+        if let Some(url) = relpart
+            .first()
+            .map(|s| s.trim().trim_matches(|c| c == '<' || c == '>'))
+            && part.contains("rel=\"next\"")
+        {
+            return Some(url.to_string());
+        }
+    }
+    None
+}
+
 /// Warns if the scrape timeout is greater than the scrape interval.
 pub(crate) fn warn_if_interval_too_low(timeout: Duration, interval: Duration) {
     if timeout > interval {
@@ -143,13 +174,173 @@ pub(crate) fn warn_if_interval_too_low(timeout: Duration, interval: Duration) {
     }
 }
 
+/// The options used to build each HTTP request of a scrape.
+struct RequestSettings {
+    timeout: Duration,
+    headers: HashMap<String, Vec<String>>,
+    content_type: String,
+    auth: Option<Auth>,
+    http_method: HttpMethod,
+}
+
+impl RequestSettings {
+    fn build_request<C: HttpClientContext>(&self, url: &Uri, context: &C) -> Request<Body> {
+        let mut builder = match self.http_method {
+            HttpMethod::Head => Request::head(url),
+            HttpMethod::Get => Request::get(url),
+            HttpMethod::Post => Request::post(url),
+            HttpMethod::Put => Request::put(url),
+            HttpMethod::Patch => Request::patch(url),
+            HttpMethod::Delete => Request::delete(url),
+            HttpMethod::Options => Request::options(url),
+        };
+
+        // add user specified headers
+        for (header, values) in &self.headers {
+            for value in values {
+                builder = builder.header(header, value);
+            }
+        }
+
+        // set ACCEPT header if not user specified
+        if !self.headers.contains_key(http::header::ACCEPT.as_str()) {
+            builder = builder.header(http::header::ACCEPT, &self.content_type);
+        }
+
+        // Get the request body from the context (if any)
+        let body = match context.get_request_body() {
+            Some(body_str) => {
+                // Set Content-Type header if not already set
+                if !self
+                    .headers
+                    .contains_key(http::header::CONTENT_TYPE.as_str())
+                {
+                    builder = builder.header(http::header::CONTENT_TYPE, "application/json");
+                }
+                Body::from(body_str)
+            }
+            None => Body::empty(),
+        };
+
+        // building the request should be infallible
+        let mut request = builder.body(body).expect("error creating request");
+
+        if let Some(auth) = &self.auth {
+            auth.apply(&mut request);
+        }
+
+        request
+    }
+}
+
+/// Sends a single request and collects the response body.
+async fn fetch(
+    client: &HttpClient,
+    timeout: Duration,
+    request: Request<Body>,
+    url: &Uri,
+) -> crate::Result<(Parts, Bytes)> {
+    let response = match tokio::time::timeout(timeout, client.send(request)).await {
+        Ok(Ok(response)) => response,
+        Ok(Err(error)) => return Err(error.into()),
+        Err(_) => {
+            return Err(
+                format!("Timeout error: request exceeded {}s", timeout.as_secs_f64()).into(),
+            );
+        }
+    };
+
+    let (header, body) = response.into_parts();
+    let body = http_body::Body::collect(body).await?.to_bytes();
+    emit!(EndpointBytesReceived {
+        byte_size: body.len(),
+        protocol: "http",
+        endpoint: url.to_string().as_str(),
+    });
+    Ok((header, body))
+}
+
+/// Scrapes a single url, following any subsequent pages reported by the context.
+fn scrape<C: HttpClientContext + Send + 'static>(
+    client: HttpClient,
+    settings: Arc<RequestSettings>,
+    context: C,
+    url: Uri,
+) -> impl Stream<Item = Event> + Send {
+    stream::unfold(Some((context, url)), move |state| {
+        let client = client.clone();
+        let settings = Arc::clone(&settings);
+        async move {
+            let (mut context, url) = state?;
+            let request = settings.build_request(&url, &context);
+            let (events, next) = match fetch(&client, settings.timeout, request, &url).await {
+                Ok((header, body)) if header.status == hyper::StatusCode::OK => {
+                    let events = context.on_response(&url, &header, &body).map(|mut events| {
+                        let byte_size = if events.is_empty() {
+                            // We need to explicitly set the byte size
+                            // to 0 since
+                            // `estimated_json_encoded_size_of` returns
+                            // at least 1 for an empty collection. For
+                            // the purposes of the
+                            // HttpClientEventsReceived event, we should
+                            // emit 0 when there aren't any usable
+                            // metrics.
+                            JsonSize::zero()
+                        } else {
+                            events.estimated_json_encoded_size_of()
+                        };
+
+                        emit!(HttpClientEventsReceived {
+                            byte_size,
+                            count: events.len(),
+                            url: url.to_string()
+                        });
+
+                        // We'll enrich after receiving the events so
+                        // that the byte sizes are accurate.
+                        context.enrich_events(&mut events);
+
+                        events
+                    });
+                    let next = context.next_page_url(
+                        &url,
+                        &header,
+                        &body,
+                        events.as_deref().unwrap_or_default(),
+                    );
+                    (events.unwrap_or_default(), next)
+                }
+                Ok((header, _)) => {
+                    context.on_http_response_error(&url, &header);
+                    emit!(HttpClientHttpResponseError {
+                        code: header.status,
+                        url: url.to_string(),
+                    });
+                    (Vec::new(), None)
+                }
+                Err(error) => {
+                    emit!(HttpClientHttpError {
+                        error,
+                        url: url.to_string()
+                    });
+                    (Vec::new(), None)
+                }
+            };
+
+            Some((stream::iter(events), next.map(|next| (context, next))))
+        }
+    })
+    .flatten()
+}
+
 /// Calls one or more urls at an interval.
 ///   - The HTTP request is built per the options in provided generic inputs.
 ///   - The HTTP response is decoded/parsed into events by the specific context.
+///   - If the context reports a next page, it is requested as part of the same scrape.
 ///   - The events are then sent to the output stream.
 pub(crate) async fn call<
     B: HttpClientBuilder<Context = C> + Send + Clone,
-    C: HttpClientContext + Send,
+    C: HttpClientContext + Send + 'static,
 >(
     inputs: GenericHttpClientInputs,
     context_builder: B,
@@ -160,139 +351,41 @@ pub(crate) async fn call<
     // proxy and tls settings.
     let client =
         HttpClient::new(inputs.tls.clone(), &inputs.proxy).expect("Building HTTP client failed");
-    let mut stream = IntervalStream::new(tokio::time::interval(inputs.interval))
+    let settings = Arc::new(RequestSettings {
+        timeout: inputs.timeout,
+        headers: inputs.headers,
+        content_type: inputs.content_type,
+        auth: inputs.auth,
+        http_method,
+    });
+
+    let mut interval = tokio::time::interval(inputs.interval);
+    let concurrency_limit = if inputs.sequential {
+        // Ticks missed while the previous scrape was running shouldn't result in a burst of scrapes.
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        Some(1)
+    } else {
+        None
+    };
+
+    let urls = inputs.urls;
+    let mut stream = IntervalStream::new(interval)
         .take_until(inputs.shutdown)
-        .map(move |_| stream::iter(inputs.urls.clone()))
+        .map(move |_| stream::iter(urls.clone()))
         .flatten()
         .map(move |base_url| {
             let client = client.clone();
-            let endpoint = base_url.to_string();
+            let settings = Arc::clone(&settings);
 
             let context_builder = context_builder.clone();
-            let mut context = context_builder.build(&base_url);
+            let context = context_builder.build(&base_url);
 
             // Check if we need to process the URL dynamically (for updating VRL expressions)
             let url = context.process_url(&base_url).unwrap_or(base_url);
 
-            let mut builder = match http_method {
-                HttpMethod::Head => Request::head(&url),
-                HttpMethod::Get => Request::get(&url),
-                HttpMethod::Post => Request::post(&url),
-                HttpMethod::Put => Request::put(&url),
-                HttpMethod::Patch => Request::patch(&url),
-                HttpMethod::Delete => Request::delete(&url),
-                HttpMethod::Options => Request::options(&url),
-            };
-
-            // add user specified headers
-            for (header, values) in &inputs.headers {
-                for value in values {
-                    builder = builder.header(header, value);
-                }
-            }
-
-            // set ACCEPT header if not user specified
-            if !inputs.headers.contains_key(http::header::ACCEPT.as_str()) {
-                builder = builder.header(http::header::ACCEPT, &inputs.content_type);
-            }
-
-            // Get the request body from the context (if any)
-            let body = match context.get_request_body() {
-                Some(body_str) => {
-                    // Set Content-Type header if not already set
-                    if !inputs
-                        .headers
-                        .contains_key(http::header::CONTENT_TYPE.as_str())
-                    {
-                        builder = builder.header(http::header::CONTENT_TYPE, "application/json");
-                    }
-                    Body::from(body_str)
-                }
-                None => Body::empty(),
-            };
-
-            // building the request should be infallible
-            let mut request = builder.body(body).expect("error creating request");
-
-            if let Some(auth) = &inputs.auth {
-                auth.apply(&mut request);
-            }
-
-            tokio::time::timeout(inputs.timeout, client.send(request))
-                .then(move |result| async move {
-                    match result {
-                        Ok(Ok(response)) => Ok(response),
-                        Ok(Err(error)) => Err(error.into()),
-                        Err(_) => Err(format!(
-                            "Timeout error: request exceeded {}s",
-                            inputs.timeout.as_secs_f64()
-                        )
-                        .into()),
-                    }
-                })
-                .and_then(|response| async move {
-                    let (header, body) = response.into_parts();
-                    let body = http_body::Body::collect(body).await?.to_bytes();
-                    emit!(EndpointBytesReceived {
-                        byte_size: body.len(),
-                        protocol: "http",
-                        endpoint: endpoint.as_str(),
-                    });
-                    Ok((header, body))
-                })
-                .into_stream()
-                .filter_map(move |response| {
-                    ready(match response {
-                        Ok((header, body)) if header.status == hyper::StatusCode::OK => {
-                            context.on_response(&url, &header, &body).map(|mut events| {
-                                let byte_size = if events.is_empty() {
-                                    // We need to explicitly set the byte size
-                                    // to 0 since
-                                    // `estimated_json_encoded_size_of` returns
-                                    // at least 1 for an empty collection. For
-                                    // the purposes of the
-                                    // HttpClientEventsReceived event, we should
-                                    // emit 0 when there aren't any usable
-                                    // metrics.
-                                    JsonSize::zero()
-                                } else {
-                                    events.estimated_json_encoded_size_of()
-                                };
-
-                                emit!(HttpClientEventsReceived {
-                                    byte_size,
-                                    count: events.len(),
-                                    url: url.to_string()
-                                });
-
-                                // We'll enrich after receiving the events so
-                                // that the byte sizes are accurate.
-                                context.enrich_events(&mut events);
-
-                                stream::iter(events)
-                            })
-                        }
-                        Ok((header, _)) => {
-                            context.on_http_response_error(&url, &header);
-                            emit!(HttpClientHttpResponseError {
-                                code: header.status,
-                                url: url.to_string(),
-                            });
-                            None
-                        }
-                        Err(error) => {
-                            emit!(HttpClientHttpError {
-                                error,
-                                url: url.to_string()
-                            });
-                            None
-                        }
-                    })
-                })
-                .flatten()
-                .boxed()
+            scrape(client, settings, context, url).boxed()
         })
-        .flatten_unordered(None)
+        .flatten_unordered(concurrency_limit)
         .boxed();
 
     match out.send_event_stream(&mut stream).await {
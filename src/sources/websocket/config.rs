@@ -5,7 +5,7 @@ use serde_with::serde_as;
 use snafu::ResultExt;
 use vector_config::configurable_component;
 use vector_lib::{
-    codecs::decoding::{DeserializerConfig, FramingConfig},
+    codecs::decoding::{DeserializerConfig, FramingConfig, decode_errors_output},
     config::{LogNamespace, SourceOutput},
 };
 
@@ -86,6 +86,14 @@ pub struct WebSocketConfig {
     #[serde(default = "default_decoding")]
     pub decoding: DeserializerConfig,
 
+    /// Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+    ///
+    /// Each of these events holds the raw bytes that failed to decode in the `message` field, along
+    /// with the `error`, and the `framing` method and `codec` that were used.
+    #[serde(default)]
+    #[configurable(metadata(docs::advanced))]
+    pub reroute_decode_errors: bool,
+
     /// Framing to use in the decoding.
     #[configurable(derived)]
     #[serde(default = "default_framing_message_based")]
@@ -139,6 +147,7 @@ impl Default for WebSocketConfig {
         Self {
             common: WebSocketCommonConfig::default(),
             decoding: default_decoding(),
+            reroute_decode_errors: false,
             framing: default_framing_message_based(),
             connect_timeout_secs: default_connect_timeout_secs(),
             initial_message: None,
@@ -164,6 +173,7 @@ impl SourceConfig for WebSocketConfig {
         let log_namespace = cx.log_namespace(self.log_namespace);
         let decoder =
             DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace)
+                .with_decode_errors(self.reroute_decode_errors)
                 .build()?;
 
         let params = WebSocketSourceParams {
@@ -185,10 +195,14 @@ impl SourceConfig for WebSocketConfig {
             .schema_definition(log_namespace)
             .with_standard_vector_source_metadata();
 
-        vec![SourceOutput::new_maybe_logs(
+        let mut outputs = vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
-        )]
+        )];
+        if self.reroute_decode_errors {
+            outputs.push(decode_errors_output(log_namespace));
+        }
+        outputs
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -61,7 +61,10 @@ use crate::{
     shutdown::SourceShutdownCoordinator,
     spawn_named,
     topology::task::TaskError,
-    transforms::{SyncTransform, TaskTransform, Transform, TransformOutputs, TransformOutputsBuf},
+    transforms::{
+        MultiOutputTaskTransform, SyncTransform, TaskTransform, Transform, TransformOutputs,
+        TransformOutputsBuf,
+    },
     utilization::{
         OutputUtilization, Utilization, UtilizationComponentSender, UtilizationEmitter,
         UtilizationRegistry,
@@ -718,6 +721,8 @@ impl<'a> Builder<'a> {
             schema: self.config.schema,
             app_name: crate::get_app_name().to_string(),
             app_name_slug: crate::get_slugified_app_name(),
+            is_new: self.diff.sinks.is_added(key)
+                || self.diff.enrichment_tables.sinks.is_added(key),
             extra_context: self.extra_context.clone(),
         };
 
@@ -832,6 +837,9 @@ impl<'a> Builder<'a> {
             Transform::Function(t) => self.build_sync_transform(Box::new(t), node, input_rx),
             Transform::Synchronous(t) => self.build_sync_transform(t, node, input_rx),
             Transform::Task(t) => self.build_task_transform(t, node, input_rx),
+            Transform::MultiOutputTask(t) => {
+                self.build_multi_output_task_transform(t, node, input_rx)
+            }
         }
     }
 
@@ -1004,6 +1012,77 @@ impl<'a> Builder<'a> {
 
         (task, outputs)
     }
+
+    fn build_multi_output_task_transform(
+        &self,
+        t: Box<dyn MultiOutputTaskTransform>,
+        node: TransformNode,
+        input_rx: BufferReceiver<EventArray>,
+    ) -> (Task, HashMap<OutputId, fanout::ControlChannel>) {
+        let TransformNode {
+            key,
+            typetag,
+            input_details,
+            outputs,
+            cpu_ns,
+            ..
+        } = node;
+        let input_type = input_details.data_type();
+
+        let (mut outputs, controls) = TransformOutputs::new(outputs, &key);
+
+        let sender = self
+            .utilization_registry
+            .add_component(key.clone(), gauge!(GaugeName::Utilization));
+        let output_sender = sender.clone();
+        let input_rx = Utilization::new(sender, key.clone(), input_rx.into_stream());
+
+        let events_received = register!(EventsReceived);
+        let filtered = input_rx
+            .filter(move |events| ready(filter_events_type(events, input_type)))
+            .inspect(move |events| {
+                events_received.emit(CountByteSize(
+                    events.len(),
+                    events.estimated_json_encoded_size_of(),
+                ))
+            });
+        let latency_recorder = LatencyRecorder::new(self.config.global.latency_ewma_alpha);
+
+        let mut stream = OutputUtilization::new(output_sender, t.transform(Box::pin(filtered)));
+        let transform = async move {
+            debug!("Task transform starting.");
+
+            while let Some(mut outputs_buf) = stream.next().await {
+                let now = Instant::now();
+                outputs_buf.for_each_array_mut(|array| latency_recorder.on_send(array, now));
+                if let Err(error) = outputs.send(&mut outputs_buf).await {
+                    debug!("Task transform finished with an error.");
+                    return Err(TaskError::wrapped(error));
+                }
+            }
+
+            debug!("Task transform finished normally.");
+            Ok(TaskOutput::Transform)
+        };
+
+        let transform = if let Some(cpu_ns) = cpu_ns {
+            transform.cpu_timed(cpu_ns).boxed()
+        } else {
+            transform.boxed()
+        };
+
+        let mut output_controls = HashMap::new();
+        for (name, control) in controls {
+            let id = name
+                .map(|name| OutputId::from((&key, name)))
+                .unwrap_or_else(|| OutputId::from(&key));
+            output_controls.insert(id, control);
+        }
+
+        let task = Task::new(key, typetag, transform);
+
+        (task, output_controls)
+    }
 }
 
 async fn run_source_output_pump(
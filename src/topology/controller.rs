@@ -1,18 +1,25 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use futures_util::FutureExt as _;
-use tokio::sync::{Mutex, MutexGuard};
+use snafu::Snafu;
+use tokio::sync::{Mutex, MutexGuard, mpsc, oneshot};
 
 #[cfg(feature = "api")]
 use crate::api;
 use crate::{
-    config,
+    config::{self, ComponentKey, ConfigDiff},
     extra_context::ExtraContext,
     internal_events::{VectorRecoveryError, VectorReloadError, VectorReloaded},
     signal::ShutdownError,
     topology::{ReloadError, RunningTopology},
 };
 
+/// The number of control requests that can be queued before senders have to wait.
+pub const CONTROL_CHANNEL_CAPACITY: usize = 16;
+
+pub type ControlSender = mpsc::Sender<ControlRequest>;
+pub type ControlReceiver = mpsc::Receiver<ControlRequest>;
+
 #[derive(Clone, Debug)]
 pub struct SharedTopologyController(Arc<Mutex<TopologyController>>);
 
@@ -40,6 +47,8 @@ pub struct TopologyController {
     pub require_healthy: Option<bool>,
     #[cfg(feature = "api")]
     pub api_server: Option<api::GrpcServer>,
+    /// Handed to the API server so it can submit control requests to the application.
+    pub control_tx: ControlSender,
     pub extra_context: ExtraContext,
 }
 
@@ -60,6 +69,79 @@ pub enum ReloadOutcome {
     FatalError(ShutdownError),
 }
 
+/// A change to the running topology requested through the API.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ControlCommand {
+    /// Reload the configuration from disk.
+    Reload,
+    /// Stop forwarding events from a source, applying backpressure to it.
+    PauseSource(ComponentKey),
+    /// Resume forwarding events from a paused source.
+    ResumeSource(ComponentKey),
+    /// Remove a sink from the running topology once it has processed all of its buffered events.
+    DrainSink(ComponentKey),
+    /// Remove a sink from the running topology without waiting for it to finish.
+    ShutdownSink(ComponentKey),
+}
+
+/// A [`ControlCommand`] along with the channel its result is sent back on.
+#[derive(Debug)]
+pub struct ControlRequest {
+    pub command: ControlCommand,
+    pub reply: oneshot::Sender<ControlResult>,
+}
+
+pub type ControlResult = Result<ComponentChanges, ControlError>;
+
+#[derive(Clone, Debug, Snafu, PartialEq, Eq)]
+pub enum ControlError {
+    #[snafu(display("the configuration is invalid: {}", errors.join("; ")))]
+    InvalidConfig { errors: Vec<String> },
+    #[snafu(display("the reload failed and the previous configuration was restored"))]
+    RolledBack,
+    #[snafu(display("the reload failed and Vector is shutting down: {error}"))]
+    Fatal { error: ShutdownError },
+    #[snafu(display(r#"source "{key}" does not exist"#))]
+    SourceNotFound { key: ComponentKey },
+    #[snafu(display(r#"sink "{key}" does not exist"#))]
+    SinkNotFound { key: ComponentKey },
+    #[snafu(display(r#"source "{key}" is already paused"#))]
+    SourceAlreadyPaused { key: ComponentKey },
+    #[snafu(display(r#"source "{key}" is not paused"#))]
+    SourceNotPaused { key: ComponentKey },
+}
+
+/// The components that were added, changed, or removed by a change to the running topology.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ComponentChanges {
+    pub added: Vec<ComponentKey>,
+    pub changed: Vec<ComponentKey>,
+    pub removed: Vec<ComponentKey>,
+}
+
+impl From<&ConfigDiff> for ComponentChanges {
+    fn from(diff: &ConfigDiff) -> Self {
+        fn sorted<'a>(keys: impl Iterator<Item = &'a ComponentKey>) -> Vec<ComponentKey> {
+            let mut keys = keys.cloned().collect::<Vec<_>>();
+            keys.sort();
+            keys.dedup();
+            keys
+        }
+
+        let differences = [
+            &diff.sources,
+            &diff.transforms,
+            &diff.sinks,
+            &diff.enrichment_tables.tables,
+        ];
+        Self {
+            added: sorted(differences.into_iter().flat_map(|d| &d.to_add)),
+            changed: sorted(differences.into_iter().flat_map(|d| &d.to_change)),
+            removed: sorted(differences.into_iter().flat_map(|d| &d.to_remove)),
+        }
+    }
+}
+
 impl TopologyController {
     pub async fn reload(&mut self, mut new_config: config::Config) -> ReloadOutcome {
         new_config
@@ -76,7 +158,13 @@ impl TopologyController {
         } else if self.api_server.is_none() {
             debug!("Starting gRPC API server.");
 
-            match api::GrpcServer::start(self.topology.config(), self.topology.watch()).await {
+            match api::GrpcServer::start(
+                self.topology.config(),
+                self.topology.watch(),
+                self.control_tx.clone(),
+            )
+            .await
+            {
                 Ok(api_server) => {
                     let addr = api_server.addr();
                     info!(
@@ -150,6 +238,36 @@ impl TopologyController {
         }
     }
 
+    /// Reloads the topology like [`Self::reload`], reporting the components that were changed.
+    pub async fn reload_with_changes(&mut self, new_config: config::Config) -> ControlResult {
+        let changes = ComponentChanges::from(&ConfigDiff::new(
+            self.topology.config(),
+            &new_config,
+            HashSet::new(),
+        ));
+        match self.reload(new_config).await {
+            ReloadOutcome::Success => Ok(changes),
+            ReloadOutcome::MissingApiKey | ReloadOutcome::RolledBack => {
+                Err(ControlError::RolledBack)
+            }
+            ReloadOutcome::FatalError(error) => Err(ControlError::Fatal { error }),
+        }
+    }
+
+    /// Removes a sink from the running topology.
+    ///
+    /// When `drain` is set, this waits for the sink to process all of its buffered events, up to
+    /// the graceful shutdown duration. The sink is added back on the next configuration reload.
+    pub async fn remove_sink(&mut self, key: &ComponentKey, drain: bool) -> ControlResult {
+        let Some(new_config) = self.topology.config().without_sink(key) else {
+            return Err(ControlError::SinkNotFound { key: key.clone() });
+        };
+        if drain {
+            self.topology.drain_sink_on_removal(key.clone());
+        }
+        self.reload_with_changes(new_config).await
+    }
+
     /// Stops the topology. Returns `true` if every component finished on its own before the
     /// graceful shutdown deadline, or `false` if any component had to be forcefully killed.
     #[cfg_attr(not(feature = "api"), allow(unused_mut))]
@@ -31,7 +31,11 @@ use vector_lib::buffers::topology::channel::{BufferReceiverStream, BufferSender}
 use self::task::{Task, TaskError, TaskResult};
 pub use self::{
     builder::TopologyPieces,
-    controller::{ReloadOutcome, SharedTopologyController, TopologyController},
+    controller::{
+        CONTROL_CHANNEL_CAPACITY, ComponentChanges, ControlCommand, ControlError, ControlReceiver,
+        ControlRequest, ControlResult, ControlSender, ReloadOutcome, SharedTopologyController,
+        TopologyController,
+    },
     running::{ReloadError, RunningTopology, ShutdownErrorReceiver},
 };
 use crate::{
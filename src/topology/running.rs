@@ -24,6 +24,7 @@ use vector_lib::{
 use super::{
     BuiltBuffer, TaskHandle, TaskResult,
     builder::{self, TopologyPieces, TopologyPiecesBuilder, reload_enrichment_tables},
+    controller::ControlError,
     fanout::{ControlChannel, ControlMessage},
     handle_errors, retain, take_healthchecks,
     task::{Task, TaskOutput},
@@ -73,6 +74,8 @@ pub struct RunningTopology {
     metrics_task: Option<TaskHandle>,
     metrics_task_shutdown_trigger: Option<Trigger>,
     pending_reload: Option<HashSet<ComponentKey>>,
+    paused_sources: HashSet<ComponentKey>,
+    sinks_to_drain: HashSet<ComponentKey>,
     sink_confinement_gauges: HashMap<ComponentKey, Gauge>,
 }
 
@@ -98,6 +101,8 @@ impl RunningTopology {
             metrics_task: None,
             metrics_task_shutdown_trigger: None,
             pending_reload: None,
+            paused_sources: HashSet::new(),
+            sinks_to_drain: HashSet::new(),
             sink_confinement_gauges: HashMap::new(),
         }
     }
@@ -116,6 +121,96 @@ impl RunningTopology {
         }
     }
 
+    /// Marks a sink to be waited on until it has processed all of its buffered events when it is
+    /// removed by the next reload, instead of being detached. The mark is cleared by that reload
+    /// whether or not it succeeds.
+    pub fn drain_sink_on_removal(&mut self, key: ComponentKey) {
+        self.sinks_to_drain.insert(key);
+    }
+
+    /// Pauses a source by pausing its downstream components in each of its output fanouts, which
+    /// applies backpressure to the source until it is resumed.
+    ///
+    /// Paused sources stay paused across reloads that keep them, and are resumed when the
+    /// topology is stopped.
+    pub fn pause_source(&mut self, key: &ComponentKey) -> Result<(), ControlError> {
+        if self.config.source(key).is_none() {
+            return Err(ControlError::SourceNotFound { key: key.clone() });
+        }
+        if !self.paused_sources.insert(key.clone()) {
+            return Err(ControlError::SourceAlreadyPaused { key: key.clone() });
+        }
+
+        for (output_id, consumer) in self.source_consumers(key) {
+            if let Some(output) = self.outputs.get(&output_id) {
+                debug!(component_id = %consumer, fanout_id = %output_id, "Pausing component input in fanout.");
+                _ = output.send(ControlMessage::Pause(consumer));
+            }
+        }
+        Ok(())
+    }
+
+    /// Resumes a source paused by [`Self::pause_source`].
+    pub fn resume_source(&mut self, key: &ComponentKey) -> Result<(), ControlError> {
+        if self.config.source(key).is_none() {
+            return Err(ControlError::SourceNotFound { key: key.clone() });
+        }
+        if !self.paused_sources.remove(key) {
+            return Err(ControlError::SourceNotPaused { key: key.clone() });
+        }
+
+        self.replace_source_consumers(key);
+        Ok(())
+    }
+
+    /// Resumes all paused sources, as reloading relies on pausing and replacing fanout inputs
+    /// itself, returning the sources that were paused.
+    fn resume_paused_sources(&mut self) -> HashSet<ComponentKey> {
+        let paused = std::mem::take(&mut self.paused_sources);
+        for key in &paused {
+            self.replace_source_consumers(key);
+        }
+        paused
+    }
+
+    /// Pauses the given sources again after a reload, forgetting those it removed.
+    fn repause_sources(&mut self, keys: HashSet<ComponentKey>) {
+        for key in keys {
+            if self.config.source(&key).is_some() {
+                _ = self.pause_source(&key);
+            }
+        }
+    }
+
+    fn replace_source_consumers(&self, key: &ComponentKey) {
+        for (output_id, consumer) in self.source_consumers(key) {
+            if let (Some(output), Some(input)) =
+                (self.outputs.get(&output_id), self.inputs.get(&consumer))
+            {
+                debug!(component_id = %consumer, fanout_id = %output_id, "Replacing component input in fanout.");
+                _ = output.send(ControlMessage::Replace(consumer, input.clone()));
+            }
+        }
+    }
+
+    /// Returns each output of the given source along with the components consuming it.
+    fn source_consumers(&self, key: &ComponentKey) -> Vec<(OutputId, ComponentKey)> {
+        self.inputs
+            .keys()
+            .filter_map(|consumer| {
+                self.config
+                    .inputs_for_node(consumer)
+                    .map(|inputs| (consumer, inputs))
+            })
+            .flat_map(|(consumer, inputs)| {
+                inputs
+                    .iter()
+                    .filter(|input| &input.component == key)
+                    .map(|input| (input.clone(), consumer.clone()))
+            })
+            .collect()
+    }
+
     /// Creates a subscription to topology changes.
     ///
     /// This is used by the tap API to observe configuration changes, and re-wire tap sinks.
@@ -150,7 +245,10 @@ impl RunningTopology {
     ///
     /// The returned future resolves to `true` if every component finished on its own before
     /// the graceful shutdown deadline, or `false` if any component had to be forcefully killed.
-    pub fn stop(self) -> impl Future<Output = bool> {
+    pub fn stop(mut self) -> impl Future<Output = bool> {
+        // Paused sources would otherwise be unable to flush their in-flight events.
+        self.resume_paused_sources();
+
         // Create handy handles collections of all tasks for the subsequent
         // operations.
         let mut wait_handles = Vec::new();
@@ -299,6 +397,9 @@ impl RunningTopology {
     ) -> Result<(), ReloadError> {
         info!("Reloading running topology with new configuration.");
 
+        // Drain marks only apply to this reload, so they must not outlive a failed one.
+        let sinks_to_drain = std::mem::take(&mut self.sinks_to_drain);
+
         if self.config.global != new_config.global {
             return match self.config.global.diff(&new_config.global) {
                 Ok(changed_fields) => Err(ReloadError::GlobalOptionsChanged { changed_fields }),
@@ -306,6 +407,8 @@ impl RunningTopology {
             };
         }
 
+        let paused_sources = self.resume_paused_sources();
+
         // Calculate the change between the current configuration and the new configuration, and
         // shutdown any components that are changing so that we can reclaim their buffers before
         // spawning the new version of the component.
@@ -316,7 +419,9 @@ impl RunningTopology {
         } else {
             ConfigDiff::new(&self.config, &new_config, HashSet::new())
         };
-        let buffers = self.shutdown_diff(&diff, &new_config).await;
+        let buffers = self
+            .shutdown_diff(&diff, &new_config, &sinks_to_drain)
+            .await;
 
         // Gives windows some time to make available any port
         // released by shutdown components.
@@ -347,6 +452,7 @@ impl RunningTopology {
                 self.spawn_diff(&diff, new_pieces);
                 self.config = new_config;
                 self.refresh_confinement_gauges();
+                self.repause_sources(paused_sources);
 
                 info!("New configuration loaded successfully.");
 
@@ -375,6 +481,7 @@ impl RunningTopology {
             // `self.config` still holds the old config on the rollback path, so
             // this restores the gauges for the re-spawned old sinks.
             self.refresh_confinement_gauges();
+            self.repause_sources(paused_sources);
 
             info!("Old configuration restored successfully.");
 
@@ -436,6 +543,7 @@ impl RunningTopology {
         &mut self,
         diff: &ConfigDiff,
         new_config: &Config,
+        sinks_to_drain: &HashSet<ComponentKey>,
     ) -> HashMap<ComponentKey, BuiltBuffer> {
         // First, we shutdown any changed/removed sources. This ensures that we can allow downstream
         // components to terminate naturally by virtue of the flow of events stopping.
@@ -701,6 +809,21 @@ impl RunningTopology {
             if wait_for_sinks.contains(key) {
                 debug!(message = "Waiting for sink to shutdown.", component_id = %key);
                 previous.await.unwrap().unwrap();
+            } else if sinks_to_drain.contains(*key) {
+                debug!(message = "Waiting for sink to drain.", component_id = %key);
+                match self.graceful_shutdown_duration {
+                    Some(duration) => {
+                        if tokio::time::timeout(duration, previous).await.is_err() {
+                            warn!(
+                                message = "Sink did not finish draining before the graceful shutdown deadline, detaching it.",
+                                component_id = %key,
+                            );
+                        }
+                    }
+                    None => {
+                        _ = previous.await;
+                    }
+                }
             } else {
                 drop(previous); // detach and forget
             }
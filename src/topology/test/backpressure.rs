@@ -110,6 +110,7 @@ async fn buffer_drop_fan_out() {
     sink_outer.buffer = BufferConfig::Single(BufferType::Memory {
         size: MemoryBufferSize::MaxEvents(MEMORY_BUFFER_DEFAULT_MAX_EVENTS),
         when_full: WhenFull::DropNewest,
+        max_age: None,
     });
     config.add_sink_outer("out2", sink_outer);
 
@@ -44,6 +44,7 @@ async fn create_topology(
         "in",
         UnitTestSourceConfig {
             events: vec![event],
+            ..Default::default()
         },
     );
     builder.add_transform(
@@ -0,0 +1,126 @@
+use futures::StreamExt;
+use tokio::time::{Duration, timeout};
+
+use super::into_message_stream;
+use crate::{
+    config::{ComponentKey, Config},
+    event::{Event, LogEvent},
+    test_util::{
+        mock::{basic_sink, basic_source},
+        start_topology, trace_init,
+    },
+    topology::ControlError,
+};
+
+#[tokio::test]
+async fn pause_and_resume_source() {
+    trace_init();
+
+    let (mut in1, source1) = basic_source();
+    let (out1, sink1) = basic_sink(10);
+    let mut out1 = Box::pin(out1);
+
+    let mut config = Config::builder();
+    config.add_source("in1", source1);
+    config.add_sink("out1", &["in1"], sink1);
+
+    let (mut topology, _) = start_topology(config.build().unwrap(), false).await;
+
+    let key = ComponentKey::from("in1");
+    topology.pause_source(&key).unwrap();
+    assert_eq!(
+        topology.pause_source(&key),
+        Err(ControlError::SourceAlreadyPaused { key: key.clone() })
+    );
+
+    in1.send_event(Event::Log(LogEvent::from("test")))
+        .await
+        .unwrap();
+    assert!(
+        timeout(Duration::from_millis(100), out1.next())
+            .await
+            .is_err()
+    );
+
+    topology.resume_source(&key).unwrap();
+    let item = out1.next().await.unwrap();
+    assert_eq!(
+        into_message_stream(item).collect::<Vec<_>>().await,
+        vec!["test"]
+    );
+    assert_eq!(
+        topology.resume_source(&key),
+        Err(ControlError::SourceNotPaused { key })
+    );
+
+    topology.stop().await;
+}
+
+#[tokio::test]
+async fn pause_unknown_source() {
+    trace_init();
+
+    let mut config = Config::builder();
+    config.add_source("in1", basic_source().1);
+    config.add_sink("out1", &["in1"], basic_sink(10).1);
+
+    let (mut topology, _) = start_topology(config.build().unwrap(), false).await;
+
+    let key = ComponentKey::from("out1");
+    assert_eq!(
+        topology.pause_source(&key),
+        Err(ControlError::SourceNotFound { key })
+    );
+
+    topology.stop().await;
+}
+
+#[tokio::test]
+async fn drain_sink_keeps_sources_paused() {
+    trace_init();
+
+    let (mut in1, source1) = basic_source();
+    let (out1, sink1) = basic_sink(10);
+    let (out2, sink2) = basic_sink(10);
+
+    let mut config = Config::builder();
+    config.add_source("in1", source1);
+    config.add_sink("out1", &["in1"], sink1);
+    config.add_sink("out2", &["in1"], sink2);
+
+    let (mut topology, _) = start_topology(config.build().unwrap(), false).await;
+    topology.pause_source(&ComponentKey::from("in1")).unwrap();
+
+    let sink_key = ComponentKey::from("out2");
+    let new_config = topology.config().without_sink(&sink_key).unwrap();
+    topology.drain_sink_on_removal(sink_key.clone());
+    topology
+        .reload_config_and_respawn(new_config, Default::default())
+        .await
+        .unwrap();
+    assert!(topology.config().sink(&sink_key).is_none());
+
+    // The removed sink's input is closed once it has drained.
+    assert!(out2.collect::<Vec<_>>().await.is_empty());
+
+    // The source is still paused after the reload.
+    let source_key = ComponentKey::from("in1");
+    in1.send_event(Event::Log(LogEvent::from("test")))
+        .await
+        .unwrap();
+    let mut out1 = Box::pin(out1);
+    assert!(
+        timeout(Duration::from_millis(100), out1.next())
+            .await
+            .is_err()
+    );
+
+    topology.resume_source(&source_key).unwrap();
+    let item = out1.next().await.unwrap();
+    assert_eq!(
+        into_message_stream(item).collect::<Vec<_>>().await,
+        vec!["test"]
+    );
+
+    topology.stop().await;
+}
@@ -12,7 +12,7 @@ use tokio::{
     time::{Duration, sleep},
 };
 use vector_lib::{
-    buffers::{BufferConfig, BufferType, WhenFull},
+    buffers::{BufferConfig, BufferType, DiskBufferCompression, WhenFull},
     config::{ComponentKey, OutputId},
     source_sender::SourceSenderItem,
 };
@@ -35,6 +35,7 @@ use crate::{
 mod backpressure;
 mod compliance;
 mod confinement_gauge;
+mod control;
 #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
 mod cpu_metrics;
 #[cfg(all(feature = "sinks-socket", feature = "sources-socket"))]
@@ -845,6 +846,9 @@ async fn topology_disk_buffer_flushes_on_idle() {
     sink1_outer.buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: std::num::NonZeroU64::new(268435488).unwrap(),
         when_full: WhenFull::DropNewest,
+        compression: DiskBufferCompression::None,
+        encryption: None,
+        max_age: None,
     });
     config.add_sink_outer("out1", sink1_outer);
 
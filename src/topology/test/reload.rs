@@ -9,7 +9,7 @@ use futures::StreamExt;
 use tokio::{sync::oneshot::channel, time::sleep};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use vector_lib::{
-    buffers::{BufferConfig, BufferType, MemoryBufferSize, WhenFull},
+    buffers::{BufferConfig, BufferType, DiskBufferCompression, MemoryBufferSize, WhenFull},
     config::ComponentKey,
     event::{Event, EventContainer, LogEvent},
 };
@@ -204,6 +204,9 @@ async fn topology_disk_buffer_conflict() {
     old_config.sinks[&sink_key].buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: NonZeroU64::new(268435488).unwrap(),
         when_full: WhenFull::Block,
+        compression: DiskBufferCompression::None,
+        encryption: None,
+        max_age: None,
     });
 
     let mut new_config = old_config.clone();
@@ -211,6 +214,9 @@ async fn topology_disk_buffer_conflict() {
     new_config.sinks[&sink_key].buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: NonZeroU64::new(268435488).unwrap(),
         when_full: WhenFull::Block,
+        compression: DiskBufferCompression::None,
+        encryption: None,
+        max_age: None,
     });
 
     reload_sink_test(
@@ -352,6 +358,9 @@ async fn topology_disk_buffer_config_change_does_not_stall() {
     old_config.sinks[&sink_key].buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: NonZeroU64::new(268435488).unwrap(),
         when_full: WhenFull::Block,
+        compression: DiskBufferCompression::None,
+        encryption: None,
+        max_age: None,
     });
 
     // Change only the disk buffer's max_size.
@@ -359,6 +368,9 @@ async fn topology_disk_buffer_config_change_does_not_stall() {
     new_config.sinks[&sink_key].buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: NonZeroU64::new(536870912).unwrap(),
         when_full: WhenFull::Block,
+        compression: DiskBufferCompression::None,
+        encryption: None,
+        max_age: None,
     });
 
     let (mut topology, crash) = start_topology(old_config.build().unwrap(), true).await;
@@ -406,6 +418,7 @@ async fn topology_disk_buffer_config_change_chained_does_not_stall() {
     let memory_stage = BufferType::Memory {
         size: MemoryBufferSize::MaxEvents(NonZeroUsize::new(100).unwrap()),
         when_full: WhenFull::Overflow,
+        max_age: None,
     };
 
     let mut old_config = Config::builder();
@@ -419,6 +432,9 @@ async fn topology_disk_buffer_config_change_chained_does_not_stall() {
         BufferType::DiskV2 {
             max_size: NonZeroU64::new(268435488).unwrap(),
             when_full: WhenFull::Block,
+            compression: DiskBufferCompression::None,
+            encryption: None,
+            max_age: None,
         },
     ]);
 
@@ -429,6 +445,9 @@ async fn topology_disk_buffer_config_change_chained_does_not_stall() {
         BufferType::DiskV2 {
             max_size: NonZeroU64::new(536870912).unwrap(),
             when_full: WhenFull::Block,
+            compression: DiskBufferCompression::None,
+            encryption: None,
+            max_age: None,
         },
     ]);
 
@@ -489,6 +508,7 @@ async fn topology_reload_preserves_enrichment_table_state() {
         "in",
         UnitTestSourceConfig {
             events: vec![source_event.clone()],
+            ..Default::default()
         },
     );
     old_config.add_sink("out", &["memory_test_source"], oneshot_sink(old_tx));
@@ -511,7 +531,7 @@ async fn topology_reload_preserves_enrichment_table_state() {
         &[],
         EnrichmentTables::Memory(new_memory_config),
     );
-    new_config.add_source("in_2", UnitTestSourceConfig { events: vec![] });
+    new_config.add_source("in_2", UnitTestSourceConfig::default());
     new_config.add_sink("out_2", &["memory_test_source"], oneshot_sink(new_tx));
 
     let (mut topology, crash) = start_topology(old_config.build().unwrap(), false).await;
@@ -578,6 +598,7 @@ async fn topology_reload_reuses_removed_enrichment_table_source_key() {
         "in",
         UnitTestSourceConfig {
             events: vec![Event::Log(LogEvent::from("old"))],
+            ..Default::default()
         },
     );
 
@@ -607,6 +628,7 @@ async fn topology_reload_reuses_removed_enrichment_table_source_key() {
         table_source_key,
         UnitTestSourceConfig {
             events: vec![Event::Log(LogEvent::from("new"))],
+            ..Default::default()
         },
     );
     new_config.add_sink("new_out", &[table_source_key], oneshot_sink(new_tx));
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use vector_lib::configurable::configurable_component;
 
-use super::Aggregate;
+use super::{Aggregate, EventTimeAggregate, LATE_OUTPUT};
 use crate::{
     config::{DataType, Input, OutputId, TransformConfig, TransformContext, TransformOutput},
     schema,
@@ -10,8 +10,9 @@ use crate::{
 };
 
 /// Configuration for the `aggregate` transform.
+// Not `Copy` or `Eq`, as `histogram_buckets` holds a list of floats.
 #[configurable_component(transform("aggregate", "Aggregate metrics passing through a topology."))]
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct AggregateConfig {
     /// The interval between flushes, in milliseconds.
@@ -26,6 +27,64 @@ pub struct AggregateConfig {
     #[serde(default = "default_mode")]
     #[configurable(derived)]
     pub mode: AggregationMode,
+
+    /// The upper limits of the buckets of the histograms created by the `Histogram` mode.
+    ///
+    /// The limits must be in ascending order. Values greater than the last limit are only
+    /// accounted for in the count and sum of the histogram.
+    #[serde(default = "default_histogram_buckets")]
+    pub histogram_buckets: Vec<f64>,
+
+    /// Event-time windowing configuration.
+    ///
+    /// When set, metrics are aggregated into windows of `interval_ms` based on their own
+    /// timestamp, instead of the time they arrive at the transform. Windows are aligned to the
+    /// Unix epoch, and the aggregated metrics are timestamped with the start of their window.
+    /// Metrics without a timestamp are assigned to a window based on their arrival time.
+    #[configurable(derived)]
+    #[serde(default)]
+    pub event_time: Option<EventTimeConfig>,
+}
+
+/// Event-time windowing configuration.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct EventTimeConfig {
+    /// How long to wait for late metrics after the end of a window, in milliseconds.
+    ///
+    /// The watermark of the transform is the most recent metric timestamp seen, minus the
+    /// allowed lateness. A window is flushed once the watermark passes its end, and metrics for
+    /// windows that have already been flushed are handled according to `late_policy`.
+    ///
+    /// Metrics timestamped in the future only advance the watermark up to the current time. If no
+    /// metrics are received during a whole interval, the watermark moves forward by one interval
+    /// per idle interval instead, until the window of the most recent metric timestamp is flushed.
+    #[serde(default)]
+    #[configurable(metadata(docs::human_name = "Allowed Lateness"))]
+    pub allowed_lateness_ms: u64,
+
+    /// What to do with metrics that arrive after their window has been flushed.
+    #[serde(default)]
+    #[configurable(derived)]
+    pub late_policy: LatePolicy,
+}
+
+/// What to do with metrics that arrive after their window has been flushed.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LatePolicy {
+    /// Drop late metrics.
+    #[default]
+    Drop,
+
+    /// Send late metrics, unmodified, to the `late` output of the transform.
+    Output,
+
+    /// Aggregate late metrics into the current window, the window of the most recent metric
+    /// timestamp seen.
+    CurrentWindow,
 }
 
 #[configurable_component]
@@ -59,22 +118,71 @@ pub enum AggregationMode {
 
     /// Stdev value of absolute metric; incremental metrics pass through unchanged.
     Stdev,
+
+    /// Folds the values of absolute gauges and incremental distributions into an incremental
+    /// [DDSketch][ddsketch]; other metrics pass through unchanged.
+    ///
+    /// [ddsketch]: https://www.vldb.org/pvldb/vol12/p2195-masson.pdf
+    Sketch,
+
+    /// Folds the values of absolute gauges and incremental distributions into an incremental
+    /// aggregated histogram with the buckets set by `histogram_buckets`; other metrics pass through
+    /// unchanged.
+    Histogram,
 }
 const fn default_mode() -> AggregationMode {
     AggregationMode::Auto
 }
 
+fn default_histogram_buckets() -> Vec<f64> {
+    vec![
+        0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+    ]
+}
+
 const fn default_interval_ms() -> u64 {
     10 * 1000
 }
 
+impl Default for AggregateConfig {
+    fn default() -> Self {
+        Self {
+            interval_ms: default_interval_ms(),
+            mode: default_mode(),
+            histogram_buckets: default_histogram_buckets(),
+            event_time: None,
+        }
+    }
+}
+
 impl_generate_config_from_default!(AggregateConfig);
 
+impl AggregateConfig {
+    pub(super) fn transform_outputs(&self) -> Vec<TransformOutput> {
+        let mut outputs = vec![TransformOutput::new(DataType::Metric, HashMap::new())];
+        if matches!(
+            self.event_time,
+            Some(EventTimeConfig {
+                late_policy: LatePolicy::Output,
+                ..
+            })
+        ) {
+            outputs.push(
+                TransformOutput::new(DataType::Metric, HashMap::new()).with_port(LATE_OUTPUT),
+            );
+        }
+        outputs
+    }
+}
+
 #[async_trait::async_trait]
 #[typetag::serde(name = "aggregate")]
 impl TransformConfig for AggregateConfig {
     async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
-        Aggregate::new(self).map(Transform::event_task)
+        match self.event_time {
+            Some(_) => EventTimeAggregate::new(self).map(Transform::multi_output_task),
+            None => Aggregate::new(self).map(Transform::event_task),
+        }
     }
 
     fn input(&self) -> Input {
@@ -86,6 +194,6 @@ impl TransformConfig for AggregateConfig {
         _: &TransformContext,
         _: &[(OutputId, schema::Definition)],
     ) -> Vec<TransformOutput> {
-        vec![TransformOutput::new(DataType::Metric, HashMap::new())]
+        self.transform_outputs()
     }
 }
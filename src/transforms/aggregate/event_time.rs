@@ -0,0 +1,243 @@
+use super::{Aggregate, AggregateConfig, LATE_OUTPUT, LatePolicy, transform::MetricEntry};
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    pin::Pin,
+    time::Duration,
+};
+
+use async_stream::stream;
+use chrono::{DateTime, TimeZone, Utc};
+use futures::{Stream, StreamExt};
+use vector_lib::internal_event::{ComponentEventsDropped, INTENTIONAL};
+
+use crate::{
+    config::TransformOutput,
+    event::{Event, EventArray, EventContainer, metric::MetricSeries},
+    internal_events::AggregateLateEvent,
+    transforms::{MultiOutputTaskTransform, TransformOutputsBuf},
+};
+
+/// Aggregates metrics into windows based on their own timestamp.
+///
+/// Each window is aggregated by its own [`Aggregate`], and is flushed once the watermark passes
+/// its end.
+#[derive(Debug)]
+pub struct EventTimeAggregate {
+    /// An empty aggregate that new windows are created from.
+    empty: Aggregate,
+    interval_ms: i64,
+    allowed_lateness_ms: i64,
+    late_policy: LatePolicy,
+    outputs: Vec<TransformOutput>,
+    /// The open windows, by their start time in milliseconds since the Unix epoch.
+    windows: BTreeMap<i64, Aggregate>,
+    /// The most recent metric timestamp seen, in milliseconds since the Unix epoch.
+    max_timestamp: Option<i64>,
+    /// All windows ending at or before the watermark have been flushed.
+    watermark: Option<i64>,
+    /// Whether any metric was recorded since the last flush interval.
+    active: bool,
+    /// The number of flush intervals in a row during which no metric was recorded.
+    idle_intervals: i64,
+    /// The values of the last flushed window, kept by the `diff` mode.
+    previous: HashMap<MetricSeries, MetricEntry>,
+}
+
+impl EventTimeAggregate {
+    pub fn new(config: &AggregateConfig) -> crate::Result<Self> {
+        let event_time = config.event_time.unwrap_or_default();
+        if config.interval_ms == 0 {
+            return Err("`interval_ms` must be greater than zero when `event_time` is set".into());
+        }
+
+        Ok(Self {
+            empty: Aggregate::new(config)?,
+            interval_ms: i64::try_from(config.interval_ms)?,
+            allowed_lateness_ms: i64::try_from(event_time.allowed_lateness_ms)?,
+            late_policy: event_time.late_policy,
+            outputs: config.transform_outputs(),
+            windows: BTreeMap::new(),
+            max_timestamp: None,
+            watermark: None,
+            active: false,
+            idle_intervals: 0,
+            previous: HashMap::new(),
+        })
+    }
+
+    fn window_start(&self, timestamp: i64) -> i64 {
+        timestamp - timestamp.rem_euclid(self.interval_ms)
+    }
+
+    /// Records a metric received at `now`.
+    ///
+    /// Metrics that aren't aggregated and windows that are closed by the metric are written to
+    /// `output`, and late metrics are written to `late` when they are sent to the `late` output.
+    ///
+    /// Metrics timestamped in the future only advance the watermark up to `now`, so that a single
+    /// one of them doesn't make every other metric late.
+    pub fn record(
+        &mut self,
+        event: Event,
+        now: DateTime<Utc>,
+        output: &mut Vec<Event>,
+        late: &mut Vec<Event>,
+    ) {
+        let timestamp = event
+            .as_metric()
+            .timestamp()
+            .unwrap_or(now)
+            .timestamp_millis();
+        let mut start = self.window_start(timestamp);
+
+        if let Some(watermark) = self.watermark
+            && start + self.interval_ms <= watermark
+        {
+            emit!(AggregateLateEvent);
+            match self.late_policy {
+                LatePolicy::Drop => {
+                    emit!(ComponentEventsDropped::<INTENTIONAL> {
+                        count: 1,
+                        reason: "Metric arrived after its window was flushed.",
+                    });
+                    return;
+                }
+                LatePolicy::Output => {
+                    late.push(event);
+                    return;
+                }
+                LatePolicy::CurrentWindow => {
+                    // The watermark is only set once a metric has been seen.
+                    let current = self.max_timestamp.unwrap_or(timestamp);
+                    start = self.window_start(current);
+                }
+            }
+        }
+
+        self.active = true;
+        let window = self
+            .windows
+            .entry(start)
+            .or_insert_with(|| self.empty.clone());
+        if let Some(passthrough) = window.record(event) {
+            output.push(passthrough);
+        }
+        self.advance(timestamp.min(now.timestamp_millis()), output);
+    }
+
+    /// Called every flush interval to flush the open windows of an idle input.
+    ///
+    /// The watermark moves forward by one interval for every interval without metrics, starting
+    /// from the most recent metric timestamp seen rather than the current time, until the window of
+    /// that timestamp is flushed. A backlog of old metrics therefore isn't made late by the time
+    /// it takes to arrive.
+    pub fn tick(&mut self, output: &mut Vec<Event>) {
+        if std::mem::take(&mut self.active) {
+            self.idle_intervals = 0;
+            return;
+        }
+        self.idle_intervals += 1;
+
+        if let Some(max_timestamp) = self.max_timestamp {
+            let watermark = (max_timestamp - self.allowed_lateness_ms
+                + self.idle_intervals * self.interval_ms)
+                .min(self.window_start(max_timestamp) + self.interval_ms);
+            self.advance_watermark(watermark, output);
+        }
+    }
+
+    /// Flushes all of the open windows.
+    pub fn flush_all_into(&mut self, output: &mut Vec<Event>) {
+        while let Some((start, window)) = self.windows.pop_first() {
+            self.flush_window(start, window, output);
+        }
+    }
+
+    /// Advances the watermark according to the given timestamp, flushing the windows it closes.
+    fn advance(&mut self, timestamp: i64, output: &mut Vec<Event>) {
+        let max_timestamp = self
+            .max_timestamp
+            .map_or(timestamp, |max| max.max(timestamp));
+        self.max_timestamp = Some(max_timestamp);
+        self.advance_watermark(max_timestamp - self.allowed_lateness_ms, output);
+    }
+
+    /// Moves the watermark forward to the given time, flushing the windows it closes.
+    fn advance_watermark(&mut self, watermark: i64, output: &mut Vec<Event>) {
+        if self.watermark.is_some_and(|current| current >= watermark) {
+            return;
+        }
+        self.watermark = Some(watermark);
+
+        while let Some(entry) = self.windows.first_entry()
+            && *entry.key() + self.interval_ms <= watermark
+        {
+            let (start, window) = entry.remove_entry();
+            self.flush_window(start, window, output);
+        }
+    }
+
+    fn flush_window(&mut self, start: i64, mut window: Aggregate, output: &mut Vec<Event>) {
+        // The values kept by the `diff` mode are carried over from one window to the next.
+        window.set_previous(std::mem::take(&mut self.previous));
+
+        let flushed = output.len();
+        window.flush_into(output);
+
+        let timestamp = Utc.timestamp_millis_opt(start).single();
+        for event in &mut output[flushed..] {
+            event.as_mut_metric().data_mut().time.timestamp = timestamp;
+        }
+
+        self.previous = window.take_previous();
+    }
+}
+
+impl MultiOutputTaskTransform for EventTimeAggregate {
+    fn transform(
+        mut self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = EventArray> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = TransformOutputsBuf> + Send>> {
+        let period = Duration::from_millis(self.interval_ms as u64);
+        let mut flush_stream =
+            tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+
+        Box::pin(stream! {
+            let mut output = Vec::new();
+            let mut late = Vec::new();
+            let mut done = false;
+            while !done {
+                tokio::select! {
+                    _ = flush_stream.tick() => {
+                        self.tick(&mut output);
+                    },
+                    maybe_events = input_rx.next() => {
+                        match maybe_events {
+                            None => {
+                                self.flush_all_into(&mut output);
+                                done = true;
+                            }
+                            Some(events) => {
+                                for event in events.into_events() {
+                                    self.record(event, Utc::now(), &mut output, &mut late);
+                                }
+                            }
+                        }
+                    }
+                };
+                if !output.is_empty() || !late.is_empty() {
+                    let mut outputs_buf =
+                        TransformOutputsBuf::new_with_capacity(self.outputs.clone(), output.len());
+                    for event in output.drain(..) {
+                        outputs_buf.push(None, event);
+                    }
+                    for event in late.drain(..) {
+                        outputs_buf.push(Some(LATE_OUTPUT), event);
+                    }
+                    yield outputs_buf;
+                }
+            }
+        })
+    }
+}
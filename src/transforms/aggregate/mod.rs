@@ -1,8 +1,13 @@
 mod config;
+mod event_time;
 mod transform;
 
-pub use config::{AggregateConfig, AggregationMode};
+pub use config::{AggregateConfig, AggregationMode, EventTimeConfig, LatePolicy};
+pub use event_time::EventTimeAggregate;
 pub use transform::Aggregate;
 
+/// The name of the output that late metrics are sent to.
+const LATE_OUTPUT: &str = "late";
+
 #[cfg(test)]
 mod tests;
@@ -1,5 +1,6 @@
 use std::{collections::BTreeSet, sync::Arc, task::Poll, time::Duration};
 
+use chrono::{TimeZone, Utc};
 use futures::{StreamExt, stream};
 use indoc::indoc;
 use tokio::sync::mpsc;
@@ -11,12 +12,12 @@ use super::*;
 use crate::{
     config::{OutputId, TransformConfig, TransformContext},
     event::{
-        Event, Metric,
-        metric::{MetricKind, MetricValue},
+        Event, EventArray, Metric,
+        metric::{MetricKind, MetricSketch, MetricValue, StatisticKind},
     },
     schema::Definition,
     test_util::components::assert_transform_compliance,
-    transforms::test::create_topology,
+    transforms::{Transform, test::create_topology},
 };
 
 #[test]
@@ -45,6 +46,7 @@ fn incremental_auto() {
     let mut agg = Aggregate::new(&AggregateConfig {
         interval_ms: 1000_u64,
         mode: AggregationMode::Auto,
+        ..Default::default()
     })
     .unwrap();
 
@@ -116,6 +118,7 @@ fn absolute_auto() {
     let mut agg = Aggregate::new(&AggregateConfig {
         interval_ms: 1000_u64,
         mode: AggregationMode::Auto,
+        ..Default::default()
     })
     .unwrap();
 
@@ -182,6 +185,7 @@ fn count_agg() {
     let mut agg = Aggregate::new(&AggregateConfig {
         interval_ms: 1000_u64,
         mode: AggregationMode::Count,
+        ..Default::default()
     })
     .unwrap();
 
@@ -238,6 +242,7 @@ fn absolute_max() {
     let mut agg = Aggregate::new(&AggregateConfig {
         interval_ms: 1000_u64,
         mode: AggregationMode::Max,
+        ..Default::default()
     })
     .unwrap();
 
@@ -284,6 +289,7 @@ fn absolute_min() {
     let mut agg = Aggregate::new(&AggregateConfig {
         interval_ms: 1000_u64,
         mode: AggregationMode::Min,
+        ..Default::default()
     })
     .unwrap();
 
@@ -330,6 +336,7 @@ fn absolute_diff() {
     let mut agg = Aggregate::new(&AggregateConfig {
         interval_ms: 1000_u64,
         mode: AggregationMode::Diff,
+        ..Default::default()
     })
     .unwrap();
 
@@ -386,6 +393,7 @@ fn absolute_diff_conflicting_type() {
     let mut agg = Aggregate::new(&AggregateConfig {
         interval_ms: 1000_u64,
         mode: AggregationMode::Diff,
+        ..Default::default()
     })
     .unwrap();
 
@@ -421,6 +429,7 @@ fn absolute_mean() {
     let mut agg = Aggregate::new(&AggregateConfig {
         interval_ms: 1000_u64,
         mode: AggregationMode::Mean,
+        ..Default::default()
     })
     .unwrap();
 
@@ -478,6 +487,7 @@ fn absolute_stdev() {
     let mut agg = Aggregate::new(&AggregateConfig {
         interval_ms: 1000_u64,
         mode: AggregationMode::Stdev,
+        ..Default::default()
     })
     .unwrap();
 
@@ -533,12 +543,124 @@ fn absolute_stdev() {
     assert_eq!(&stdev_result, &out[0]);
 }
 
+#[test]
+fn absolute_sketch() {
+    let mut agg = Aggregate::new(&AggregateConfig {
+        interval_ms: 1000_u64,
+        mode: AggregationMode::Sketch,
+        ..Default::default()
+    })
+    .unwrap();
+
+    for value in 1..=100 {
+        let gauge = make_metric(
+            "gauge_a",
+            MetricKind::Absolute,
+            MetricValue::Gauge {
+                value: f64::from(value),
+            },
+        );
+        assert_eq!(agg.record(gauge), None);
+    }
+    let distribution = make_metric(
+        "gauge_a",
+        MetricKind::Incremental,
+        MetricValue::Distribution {
+            samples: vector_lib::samples![1000.0 => 1],
+            statistic: StatisticKind::Histogram,
+        },
+    );
+    assert_eq!(agg.record(distribution), None);
+
+    let mut out = vec![];
+    agg.flush_into(&mut out);
+    assert_eq!(1, out.len());
+    assert_eq!(MetricKind::Incremental, out[0].as_metric().kind());
+    let MetricValue::Sketch {
+        sketch: MetricSketch::AgentDDSketch(sketch),
+    } = out[0].as_metric().value()
+    else {
+        panic!("Expected a sketch, got {:?}", out[0].as_metric().value());
+    };
+    assert_eq!(101, sketch.count());
+    assert_eq!(Some(1000.0), sketch.max());
+    let p50 = sketch.quantile(0.5).unwrap();
+    assert!((p50 - 51.0).abs() / 51.0 < 0.02, "p50 was {p50}");
+}
+
+#[test]
+fn absolute_histogram() {
+    let mut agg = Aggregate::new(&AggregateConfig {
+        interval_ms: 1000_u64,
+        mode: AggregationMode::Histogram,
+        histogram_buckets: vec![1.0, 5.0, 10.0],
+        ..Default::default()
+    })
+    .unwrap();
+
+    for value in [0.5, 2.0, 3.0, 7.0, 20.0] {
+        let gauge = make_metric(
+            "gauge_a",
+            MetricKind::Absolute,
+            MetricValue::Gauge { value },
+        );
+        assert_eq!(agg.record(gauge), None);
+    }
+
+    // Other metrics pass through unchanged.
+    let counter = make_metric(
+        "counter_a",
+        MetricKind::Incremental,
+        MetricValue::Counter { value: 1.0 },
+    );
+    assert_eq!(agg.record(counter.clone()), Some(counter));
+
+    let mut out = vec![];
+    agg.flush_into(&mut out);
+    assert_eq!(1, out.len());
+    assert_eq!(MetricKind::Incremental, out[0].as_metric().kind());
+    assert_eq!(
+        &MetricValue::AggregatedHistogram {
+            buckets: vector_lib::buckets![1.0 => 1, 5.0 => 2, 10.0 => 1],
+            count: 5,
+            sum: 32.5,
+        },
+        out[0].as_metric().value()
+    );
+}
+
+#[test]
+fn histogram_buckets_validation() {
+    for buckets in [
+        vec![],
+        vec![1.0, 1.0],
+        vec![5.0, 1.0],
+        vec![1.0, f64::INFINITY],
+    ] {
+        let config = AggregateConfig {
+            interval_ms: 1000_u64,
+            mode: AggregationMode::Histogram,
+            histogram_buckets: buckets.clone(),
+            ..Default::default()
+        };
+        assert!(Aggregate::new(&config).is_err(), "{buckets:?}");
+    }
+
+    // The default buckets are valid.
+    let config = AggregateConfig {
+        mode: AggregationMode::Histogram,
+        ..Default::default()
+    };
+    assert!(Aggregate::new(&config).is_ok());
+}
+
 #[test]
 fn passes_through_ignored_kind() {
     // Sum mode aggregates incremental, passes through absolute without collapsing.
     let mut agg = Aggregate::new(&AggregateConfig {
         interval_ms: 1000_u64,
         mode: AggregationMode::Sum,
+        ..Default::default()
     })
     .unwrap();
 
@@ -588,6 +710,7 @@ fn conflicting_value_type() {
     let mut agg = Aggregate::new(&AggregateConfig {
         interval_ms: 1000_u64,
         mode: AggregationMode::Auto,
+        ..Default::default()
     })
     .unwrap();
 
@@ -646,6 +769,7 @@ fn conflicting_kinds() {
     let mut agg = Aggregate::new(&AggregateConfig {
         interval_ms: 1000_u64,
         mode: AggregationMode::Auto,
+        ..Default::default()
     })
     .unwrap();
 
@@ -833,3 +957,295 @@ async fn transform_interval() {
     })
     .await;
 }
+
+fn make_timed_metric(name: &'static str, value: f64, timestamp_ms: i64) -> Event {
+    let metric = make_metric(
+        name,
+        MetricKind::Incremental,
+        MetricValue::Counter { value },
+    )
+    .into_metric()
+    .with_timestamp(Utc.timestamp_millis_opt(timestamp_ms).single());
+    Event::Metric(metric)
+}
+
+fn event_time_config(allowed_lateness_ms: u64, late_policy: LatePolicy) -> AggregateConfig {
+    AggregateConfig {
+        interval_ms: 10_000,
+        mode: AggregationMode::Auto,
+        event_time: Some(EventTimeConfig {
+            allowed_lateness_ms,
+            late_policy,
+        }),
+        ..Default::default()
+    }
+}
+
+fn counter_value(event: &Event) -> f64 {
+    match event.as_metric().value() {
+        MetricValue::Counter { value } => *value,
+        value => panic!("Unexpected metric value {value:?}"),
+    }
+}
+
+// Aligned to the 10 second windows used by these tests.
+const BASE_MS: i64 = 1_700_000_000_000;
+
+#[test]
+fn event_time_windows() {
+    let mut agg = EventTimeAggregate::new(&event_time_config(0, LatePolicy::Drop)).unwrap();
+    let now = Utc::now();
+    let (mut out, mut late) = (vec![], vec![]);
+
+    // Arrival order doesn't matter, only the metric timestamps do.
+    agg.record(
+        make_timed_metric("counter_a", 1.0, BASE_MS + 5_000),
+        now,
+        &mut out,
+        &mut late,
+    );
+    agg.record(
+        make_timed_metric("counter_a", 2.0, BASE_MS + 1_000),
+        now,
+        &mut out,
+        &mut late,
+    );
+    agg.record(
+        make_timed_metric("counter_a", 4.0, BASE_MS + 9_999),
+        now,
+        &mut out,
+        &mut late,
+    );
+    assert!(out.is_empty());
+
+    // A metric in the next window moves the watermark past the end of the first one.
+    agg.record(
+        make_timed_metric("counter_a", 8.0, BASE_MS + 10_000),
+        now,
+        &mut out,
+        &mut late,
+    );
+    assert_eq!(1, out.len());
+    assert_eq!(7.0, counter_value(&out[0]));
+    assert_eq!(
+        Utc.timestamp_millis_opt(BASE_MS).single(),
+        out[0].as_metric().timestamp()
+    );
+
+    out.clear();
+    agg.flush_all_into(&mut out);
+    assert_eq!(1, out.len());
+    assert_eq!(8.0, counter_value(&out[0]));
+    assert_eq!(
+        Utc.timestamp_millis_opt(BASE_MS + 10_000).single(),
+        out[0].as_metric().timestamp()
+    );
+    assert!(late.is_empty());
+}
+
+#[test]
+fn event_time_allowed_lateness() {
+    let mut agg = EventTimeAggregate::new(&event_time_config(5_000, LatePolicy::Drop)).unwrap();
+    let now = Utc::now();
+    let (mut out, mut late) = (vec![], vec![]);
+
+    agg.record(
+        make_timed_metric("counter_a", 1.0, BASE_MS + 1_000),
+        now,
+        &mut out,
+        &mut late,
+    );
+    agg.record(
+        make_timed_metric("counter_a", 2.0, BASE_MS + 14_000),
+        now,
+        &mut out,
+        &mut late,
+    );
+    // The first window is still open, as the watermark trails the latest metric by 5 seconds.
+    assert!(out.is_empty());
+    agg.record(
+        make_timed_metric("counter_a", 4.0, BASE_MS + 9_000),
+        now,
+        &mut out,
+        &mut late,
+    );
+    assert!(out.is_empty());
+
+    agg.record(
+        make_timed_metric("counter_a", 8.0, BASE_MS + 15_000),
+        now,
+        &mut out,
+        &mut late,
+    );
+    assert_eq!(1, out.len());
+    assert_eq!(5.0, counter_value(&out[0]));
+    assert!(late.is_empty());
+}
+
+#[test]
+fn event_time_late_policies() {
+    for (late_policy, expected_out, expected_late) in [
+        (LatePolicy::Drop, vec![2.0], vec![]),
+        (LatePolicy::Output, vec![2.0], vec![4.0]),
+        (LatePolicy::CurrentWindow, vec![6.0], vec![]),
+    ] {
+        let mut agg = EventTimeAggregate::new(&event_time_config(0, late_policy)).unwrap();
+        let now = Utc::now();
+        let (mut out, mut late) = (vec![], vec![]);
+
+        agg.record(
+            make_timed_metric("counter_a", 1.0, BASE_MS),
+            now,
+            &mut out,
+            &mut late,
+        );
+        agg.record(
+            make_timed_metric("counter_a", 2.0, BASE_MS + 10_000),
+            now,
+            &mut out,
+            &mut late,
+        );
+        out.clear();
+
+        // The first window has already been flushed.
+        agg.record(
+            make_timed_metric("counter_a", 4.0, BASE_MS + 5_000),
+            now,
+            &mut out,
+            &mut late,
+        );
+        agg.flush_all_into(&mut out);
+
+        assert_eq!(
+            expected_out,
+            out.iter().map(counter_value).collect::<Vec<_>>(),
+            "{late_policy:?}"
+        );
+        assert_eq!(
+            expected_late,
+            late.iter().map(counter_value).collect::<Vec<_>>(),
+            "{late_policy:?}"
+        );
+    }
+}
+
+#[test]
+fn event_time_idle_flush() {
+    let mut agg = EventTimeAggregate::new(&event_time_config(15_000, LatePolicy::Drop)).unwrap();
+    let now = Utc::now();
+    let (mut out, mut late) = (vec![], vec![]);
+
+    agg.record(
+        make_timed_metric("counter_a", 1.0, BASE_MS + 5_000),
+        now,
+        &mut out,
+        &mut late,
+    );
+
+    // Metrics were received during this interval, so the windows are left open.
+    agg.tick(&mut out);
+    assert!(out.is_empty());
+
+    // The watermark moves forward by one interval per idle interval from the latest metric
+    // timestamp, so the window stays open for as long as the allowed lateness.
+    agg.tick(&mut out);
+    assert!(out.is_empty());
+    agg.tick(&mut out);
+    assert_eq!(1, out.len());
+    assert_eq!(1.0, counter_value(&out[0]));
+
+    // Idling doesn't move the watermark past the window of the latest metric, so an old
+    // backlog arriving later isn't late.
+    out.clear();
+    for _ in 0..5 {
+        agg.tick(&mut out);
+    }
+    agg.record(
+        make_timed_metric("counter_a", 2.0, BASE_MS + 10_000),
+        now,
+        &mut out,
+        &mut late,
+    );
+    agg.flush_all_into(&mut out);
+    assert_eq!(vec![2.0], out.iter().map(counter_value).collect::<Vec<_>>());
+    assert!(late.is_empty());
+}
+
+#[test]
+fn event_time_future_metric() {
+    let mut agg = EventTimeAggregate::new(&event_time_config(0, LatePolicy::Output)).unwrap();
+    let now = Utc.timestamp_millis_opt(BASE_MS + 20_000).unwrap();
+    let (mut out, mut late) = (vec![], vec![]);
+
+    // A metric far in the future only moves the watermark up to the current time.
+    agg.record(
+        make_timed_metric("counter_a", 1.0, BASE_MS + 3_600_000),
+        now,
+        &mut out,
+        &mut late,
+    );
+    agg.record(
+        make_timed_metric("counter_a", 2.0, BASE_MS + 25_000),
+        now,
+        &mut out,
+        &mut late,
+    );
+    assert!(out.is_empty());
+    assert!(late.is_empty());
+
+    agg.flush_all_into(&mut out);
+    assert_eq!(
+        vec![2.0, 1.0],
+        out.iter().map(counter_value).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn event_time_late_output() {
+    let outputs = |config: AggregateConfig| {
+        config
+            .outputs(&TransformContext::default(), &[])
+            .into_iter()
+            .map(|output| output.port)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(vec![None], outputs(event_time_config(0, LatePolicy::Drop)));
+    assert_eq!(
+        vec![None, Some("late".to_string())],
+        outputs(event_time_config(0, LatePolicy::Output))
+    );
+}
+
+#[tokio::test]
+async fn event_time_transform() {
+    let agg = event_time_config(0, LatePolicy::Output)
+        .build(&TransformContext::default())
+        .await
+        .unwrap();
+    let Transform::MultiOutputTask(agg) = agg else {
+        panic!("Expected a multi-output task transform");
+    };
+
+    let inputs = vec![
+        make_timed_metric("counter_a", 1.0, BASE_MS),
+        make_timed_metric("counter_a", 2.0, BASE_MS + 10_000),
+        make_timed_metric("counter_a", 4.0, BASE_MS + 5_000),
+        make_timed_metric("counter_a", 8.0, BASE_MS + 15_000),
+    ];
+    let in_stream = Box::pin(stream::iter(inputs.into_iter().map(EventArray::from)));
+    let mut out_stream = agg.transform(in_stream);
+
+    let (mut out, mut late) = (vec![], vec![]);
+    while let Some(mut outputs_buf) = out_stream.next().await {
+        out.extend(outputs_buf.drain().map(|event| counter_value(&event)));
+        late.extend(
+            outputs_buf
+                .drain_named("late")
+                .map(|event| counter_value(&event)),
+        );
+    }
+
+    assert_eq!(vec![1.0, 10.0], out);
+    assert_eq!(vec![4.0], late);
+}
@@ -8,9 +8,12 @@ use std::{
 
 use async_stream::stream;
 use futures::{Stream, StreamExt};
-use vector_lib::event::{
-    MetricValue,
-    metric::{Metric, MetricData, MetricKind, MetricSeries},
+use vector_lib::{
+    event::{
+        MetricValue,
+        metric::{Metric, MetricData, MetricKind, MetricSeries, Sample, samples_to_buckets},
+    },
+    metrics::AgentDDSketch,
 };
 
 use crate::{
@@ -54,6 +57,13 @@ enum InnerMode {
     Stdev {
         multi_map: HashMap<MetricSeries, Vec<MetricEntry>>,
     },
+
+    /// Folds gauges and distributions into a sketch; other metrics pass through unchanged.
+    Sketch,
+
+    /// Folds gauges and distributions into an aggregated histogram; other metrics pass through
+    /// unchanged.
+    Histogram { buckets: Vec<f64> },
 }
 
 impl From<AggregationMode> for InnerMode {
@@ -74,13 +84,17 @@ impl From<AggregationMode> for InnerMode {
             AggregationMode::Stdev => InnerMode::Stdev {
                 multi_map: HashMap::default(),
             },
+            AggregationMode::Sketch => InnerMode::Sketch,
+            AggregationMode::Histogram => InnerMode::Histogram {
+                buckets: Vec::new(),
+            },
         }
     }
 }
 
-type MetricEntry = (MetricData, EventMetadata);
+pub(super) type MetricEntry = (MetricData, EventMetadata);
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Aggregate {
     interval: Duration,
     map: HashMap<MetricSeries, MetricEntry>,
@@ -89,10 +103,30 @@ pub struct Aggregate {
 
 impl Aggregate {
     pub fn new(config: &AggregateConfig) -> crate::Result<Self> {
+        let mode = match config.mode {
+            AggregationMode::Histogram => {
+                let buckets = &config.histogram_buckets;
+                if buckets.is_empty() {
+                    return Err("`histogram_buckets` must not be empty".into());
+                }
+                if buckets.iter().any(|limit| !limit.is_finite())
+                    || buckets.windows(2).any(|pair| pair[0] >= pair[1])
+                {
+                    return Err(
+                        "`histogram_buckets` must be finite numbers in ascending order".into(),
+                    );
+                }
+                InnerMode::Histogram {
+                    buckets: buckets.clone(),
+                }
+            }
+            mode => mode.into(),
+        };
+
         Ok(Self {
             interval: Duration::from_millis(config.interval_ms),
             map: Default::default(),
-            mode: config.mode.into(),
+            mode,
         })
     }
 
@@ -100,6 +134,12 @@ impl Aggregate {
         let (series, data, metadata) = event.into_metric().into_parts();
 
         match (&mut self.mode, data.kind) {
+            (InnerMode::Sketch | InnerMode::Histogram { .. }, _) => match self.fold(data) {
+                Ok(data) => self.record_sum(series, data, metadata),
+                Err(data) => {
+                    return Some(Event::Metric(Metric::from_parts(series, data, metadata)));
+                }
+            },
             (InnerMode::Sum, MetricKind::Absolute)
             | (InnerMode::Latest | InnerMode::Diff { .. }, MetricKind::Incremental)
             | (InnerMode::Max | InnerMode::Min, MetricKind::Incremental)
@@ -137,6 +177,42 @@ impl Aggregate {
         None
     }
 
+    /// Converts the values of an absolute gauge or an incremental distribution into the sketch or
+    /// histogram they are folded into, returning back any other metric.
+    fn fold(&self, mut data: MetricData) -> Result<MetricData, MetricData> {
+        let samples = match (data.kind, &mut data.value) {
+            (MetricKind::Absolute, MetricValue::Gauge { value }) => vec![Sample {
+                value: *value,
+                rate: 1,
+            }],
+            (MetricKind::Incremental, MetricValue::Distribution { samples, .. }) => {
+                std::mem::take(samples)
+            }
+            _ => return Err(data),
+        };
+
+        data.value = match &self.mode {
+            InnerMode::Sketch => {
+                let mut sketch = AgentDDSketch::with_agent_defaults();
+                for sample in &samples {
+                    sketch.insert_n(sample.value, sample.rate);
+                }
+                sketch.into()
+            }
+            InnerMode::Histogram { buckets } => {
+                let (buckets, count, sum) = samples_to_buckets(&samples, buckets);
+                MetricValue::AggregatedHistogram {
+                    buckets,
+                    count,
+                    sum,
+                }
+            }
+            _ => unreachable!("only the sketch and histogram modes fold values"),
+        };
+        data.kind = MetricKind::Incremental;
+        Ok(data)
+    }
+
     fn record_count(
         &mut self,
         series: MetricSeries,
@@ -210,6 +286,21 @@ impl Aggregate {
         }
     }
 
+    /// Takes the values of the previous flush that are kept by the `diff` mode.
+    pub(super) fn take_previous(&mut self) -> HashMap<MetricSeries, MetricEntry> {
+        match &mut self.mode {
+            InnerMode::Diff { prev_map } => std::mem::take(prev_map),
+            _ => HashMap::default(),
+        }
+    }
+
+    /// Sets the values of the previous flush that are kept by the `diff` mode.
+    pub(super) fn set_previous(&mut self, previous: HashMap<MetricSeries, MetricEntry>) {
+        if let InnerMode::Diff { prev_map } = &mut self.mode {
+            *prev_map = previous;
+        }
+    }
+
     pub fn flush_into(&mut self, output: &mut Vec<Event>) {
         let map = std::mem::take(&mut self.map);
         for (series, entry) in map.clone().into_iter() {
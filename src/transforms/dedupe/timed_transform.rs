@@ -1,7 +1,8 @@
-use std::{future::ready, num::NonZeroUsize, pin::Pin, time::Instant};
+use std::{future::ready, num::NonZeroUsize, pin::Pin};
 
 use futures::{Stream, StreamExt};
 use lru::LruCache;
+use tokio::time::Instant;
 
 use super::{
     common::{FieldMatchConfig, TimedCacheConfig},
@@ -1,11 +1,12 @@
 use std::{
     collections::{HashMap, hash_map::Entry},
     pin::Pin,
-    time::{Duration, Instant},
+    time::Duration,
 };
 
 use futures::Stream;
 use indexmap::IndexMap;
+use tokio::time::Instant;
 use vector_lib::stream::expiration_map::{Emitter, map_with_expiration};
 use vector_vrl_metrics::MetricsStorage;
 use vrl::{
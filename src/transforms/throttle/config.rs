@@ -1,10 +1,9 @@
 use std::time::Duration;
 
-use governor::clock;
 use serde_with::serde_as;
 use vector_lib::{config::clone_input_definitions, configurable::configurable_component};
 
-use super::transform::Throttle;
+use super::{rate_limiter::TokioClock, transform::Throttle};
 use crate::{
     conditions::AnyCondition,
     config::{DataType, Input, OutputId, TransformConfig, TransformContext, TransformOutput},
@@ -68,7 +67,7 @@ impl_generate_config_from_default!(ThrottleConfig);
 #[typetag::serde(name = "throttle")]
 impl TransformConfig for ThrottleConfig {
     async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
-        Throttle::new(self, context, clock::MonotonicClock).map(Transform::event_task)
+        Throttle::new(self, context, TokioClock::default()).map(Transform::event_task)
     }
 
     fn input(&self) -> Input {
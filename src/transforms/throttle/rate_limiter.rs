@@ -1,11 +1,38 @@
 use std::{hash::Hash, sync::Arc};
 
-use governor::{RateLimiter, clock, middleware::NoOpMiddleware, state::keyed::DashMapStateStore};
-use tokio;
+use governor::{
+    RateLimiter, clock, middleware::NoOpMiddleware, nanos::Nanos, state::keyed::DashMapStateStore,
+};
+use tokio::time::Instant;
 
 use super::transform::Throttle;
 use crate::cpu_time::spawn_timed;
 
+/// A clock following the time of the Tokio runtime.
+///
+/// Unlike the clocks of the governor crate, this clock follows the virtual time of a paused
+/// runtime, such as the one `vector test` uses for time-dependent tests.
+#[derive(Clone, Debug)]
+pub struct TokioClock {
+    start: Instant,
+}
+
+impl Default for TokioClock {
+    fn default() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl clock::Clock for TokioClock {
+    type Instant = Nanos;
+
+    fn now(&self) -> Self::Instant {
+        Nanos::from(self.start.elapsed())
+    }
+}
+
 /// Re-usable wrapper around the structs/type from the governor crate.
 /// Spawns a background task that periodically flushes keys that haven't been accessed recently.
 pub struct RateLimiterRunner<K, C>
@@ -1,3 +1,6 @@
+use std::{num::NonZeroUsize, time::Duration};
+
+use serde_with::serde_as;
 use vector_lib::{config::clone_input_definitions, configurable::configurable_component};
 
 use super::transform::Window;
@@ -8,10 +11,12 @@ use crate::{
         TransformOutput,
     },
     schema,
+    template::UnconfinedTemplate,
     transforms::Transform,
 };
 
 /// Configuration for the `window` transform.
+#[serde_as]
 #[configurable_component(transform(
     "window",
     "Apply a buffered sliding window over the stream of events and flush it based on supplied criteria"
@@ -39,6 +44,29 @@ pub struct WindowConfig {
     /// The maximum number of events to keep after the event matched by the `flush_when` condition.
     #[serde(default = "default_events_after")]
     pub num_events_after: usize,
+
+    /// The value to group events into separate windows.
+    ///
+    /// Each unique key has its own buffer of `num_events_before` events, and a flush triggered by
+    /// an event only flushes the window of that event's key. If left unspecified, or if the
+    /// template fails to render for an event, the event is placed in a single shared window.
+    #[configurable(metadata(docs::examples = "{{ service }}", docs::examples = "{{ host }}",))]
+    pub group_by: Option<UnconfinedTemplate>,
+
+    /// The maximum period of time a key's window is kept after its last event is received, in
+    /// milliseconds.
+    ///
+    /// When a window expires, any events still buffered in it are dropped. Windows are checked for
+    /// expiration as new events arrive. If left unspecified, windows never expire.
+    #[serde_as(as = "Option<serde_with::DurationMilliSeconds<u64>>")]
+    #[configurable(metadata(docs::human_name = "Expire After"))]
+    pub expire_after_ms: Option<Duration>,
+
+    /// The maximum number of keys to track windows for.
+    ///
+    /// When a new key is seen and this limit is reached, the least recently active window is
+    /// evicted and its buffered events are dropped.
+    pub max_keys: Option<NonZeroUsize>,
 }
 
 impl GenerateConfig for WindowConfig {
@@ -59,21 +87,7 @@ const fn default_events_after() -> usize {
 #[typetag::serde(name = "window")]
 impl TransformConfig for WindowConfig {
     async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
-        Ok(Transform::function(
-            Window::new(
-                self.forward_when
-                    .as_ref()
-                    .map(|condition| {
-                        condition.build(&context.enrichment_tables, &context.metrics_storage)
-                    })
-                    .transpose()?,
-                self.flush_when
-                    .build(&context.enrichment_tables, &context.metrics_storage)?,
-                self.num_events_before,
-                self.num_events_after,
-            )
-            .unwrap(),
-        ))
+        Window::new(self, context).map(Transform::function)
     }
 
     fn input(&self) -> Input {
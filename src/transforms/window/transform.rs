@@ -1,14 +1,42 @@
-use std::collections::VecDeque;
+use std::{
+    collections::{HashMap, VecDeque},
+    num::NonZeroUsize,
+    time::Duration,
+};
 
+use tokio::time::Instant;
 use vector_lib::internal_event::{Count, InternalEventHandle as _, Registered};
 
+use super::config::WindowConfig;
 use crate::{
     conditions::Condition,
+    config::TransformContext,
     event::Event,
-    internal_events::WindowEventsDropped,
+    internal_events::{TemplateRenderingError, WindowEventsDropped, WindowStaleEventsDropped},
+    template::UnconfinedTemplate,
     transforms::{FunctionTransform, OutputBuffer},
 };
 
+/// The sliding window state of a single key.
+#[derive(Clone)]
+struct WindowState {
+    buffer: VecDeque<Event>,
+    events_counter: usize,
+    is_flushing: bool,
+    last_seen: Instant,
+}
+
+impl WindowState {
+    fn new(num_events_before: usize, now: Instant) -> Self {
+        Self {
+            buffer: VecDeque::with_capacity(num_events_before),
+            events_counter: 0,
+            is_flushing: false,
+            last_seen: now,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Window {
     // Configuration parameters
@@ -16,34 +44,98 @@ pub struct Window {
     flush_when: Condition,
     num_events_before: usize,
     num_events_after: usize,
+    group_by: Option<UnconfinedTemplate>,
+    expire_after: Option<Duration>,
+    max_keys: Option<NonZeroUsize>,
 
     // Internal variables
-    buffer: VecDeque<Event>,
-    events_counter: usize,
+    states: HashMap<Option<String>, WindowState>,
+    last_expiration_check: Instant,
     events_dropped: Registered<WindowEventsDropped>,
-    is_flushing: bool,
+    stale_events_dropped: Registered<WindowStaleEventsDropped>,
 }
 
 impl Window {
-    pub fn new(
-        forward_when: Option<Condition>,
-        flush_when: Condition,
-        num_events_before: usize,
-        num_events_after: usize,
-    ) -> crate::Result<Self> {
-        let buffer = VecDeque::with_capacity(num_events_before);
+    pub fn new(config: &WindowConfig, context: &TransformContext) -> crate::Result<Self> {
+        let forward_when = config
+            .forward_when
+            .as_ref()
+            .map(|condition| condition.build(&context.enrichment_tables, &context.metrics_storage))
+            .transpose()?;
+        let flush_when = config
+            .flush_when
+            .build(&context.enrichment_tables, &context.metrics_storage)?;
 
         Ok(Window {
             forward_when,
             flush_when,
-            num_events_before,
-            num_events_after,
+            num_events_before: config.num_events_before,
+            num_events_after: config.num_events_after,
+            group_by: config.group_by.clone(),
+            expire_after: config.expire_after_ms,
+            max_keys: config.max_keys,
+            states: HashMap::new(),
+            last_expiration_check: Instant::now(),
             events_dropped: register!(WindowEventsDropped),
-            buffer,
-            events_counter: 0,
-            is_flushing: false,
+            stale_events_dropped: register!(WindowStaleEventsDropped),
         })
     }
+
+    fn key_for(&self, event: &Event) -> Option<String> {
+        self.group_by.as_ref().and_then(|template| {
+            template
+                .render_string(event)
+                .map_err(|error| {
+                    emit!(TemplateRenderingError {
+                        error,
+                        field: Some("group_by"),
+                        drop_event: false,
+                    })
+                })
+                .ok()
+        })
+    }
+
+    /// Drops the windows of all keys that have not seen an event within `expire_after`.
+    fn expire_states(&mut self, now: Instant) {
+        let Some(expire_after) = self.expire_after else {
+            return;
+        };
+        if now.duration_since(self.last_expiration_check) < expire_after {
+            return;
+        }
+        self.last_expiration_check = now;
+
+        let stale_events_dropped = &self.stale_events_dropped;
+        self.states.retain(|_, state| {
+            let expired = now.duration_since(state.last_seen) >= expire_after;
+            if expired && !state.buffer.is_empty() {
+                stale_events_dropped.emit(Count(state.buffer.len()));
+            }
+            !expired
+        });
+    }
+
+    /// Evicts the least recently active window if the number of tracked keys is at its limit.
+    fn evict_if_full(&mut self) {
+        let Some(max_keys) = self.max_keys else {
+            return;
+        };
+        if self.states.len() < max_keys.get() {
+            return;
+        }
+
+        let oldest = self
+            .states
+            .iter()
+            .min_by_key(|(_, state)| state.last_seen)
+            .map(|(key, _)| key.clone());
+        if let Some(state) = oldest.and_then(|key| self.states.remove(&key))
+            && !state.buffer.is_empty()
+        {
+            self.stale_events_dropped.emit(Count(state.buffer.len()));
+        }
+    }
 }
 
 impl FunctionTransform for Window {
@@ -58,36 +150,48 @@ impl FunctionTransform for Window {
 
         let (flush, event) = self.flush_when.check(event);
 
-        if self.buffer.capacity() < self.num_events_before {
-            self.buffer.reserve(self.num_events_before);
-        }
-
         if pass {
             output.push(event);
-        } else if flush {
+            return;
+        }
+
+        let now = Instant::now();
+        self.expire_states(now);
+
+        let key = self.key_for(&event);
+        if !self.states.contains_key(&key) {
+            self.evict_if_full();
+        }
+        let state = self
+            .states
+            .entry(key)
+            .or_insert_with(|| WindowState::new(self.num_events_before, now));
+        state.last_seen = now;
+
+        if flush {
             if self.num_events_before > 0 {
-                self.buffer.drain(..).for_each(|evt| output.push(evt));
+                state.buffer.drain(..).for_each(|evt| output.push(evt));
             }
 
-            self.events_counter = 0;
-            self.is_flushing = true;
+            state.events_counter = 0;
+            state.is_flushing = true;
             output.push(event);
-        } else if self.is_flushing {
-            self.events_counter += 1;
+        } else if state.is_flushing {
+            state.events_counter += 1;
 
-            if self.events_counter > self.num_events_after {
-                self.events_counter = 0;
-                self.is_flushing = false;
+            if state.events_counter > self.num_events_after {
+                state.events_counter = 0;
+                state.is_flushing = false;
                 self.events_dropped.emit(Count(1));
             } else {
                 output.push(event);
             }
-        } else if self.buffer.len() >= self.num_events_before {
-            self.buffer.pop_front();
-            self.buffer.push_back(event);
+        } else if state.buffer.len() >= self.num_events_before {
+            state.buffer.pop_front();
+            state.buffer.push_back(event);
             self.events_dropped.emit(Count(1));
         } else if self.num_events_before > 0 {
-            self.buffer.push_back(event);
+            state.buffer.push_back(event);
         } else {
             self.events_dropped.emit(Count(1));
         }
@@ -96,7 +200,7 @@ impl FunctionTransform for Window {
 
 #[cfg(test)]
 mod test {
-    use std::ops::RangeInclusive;
+    use std::{num::NonZeroUsize, ops::RangeInclusive, time::Duration};
 
     use tokio::sync::{
         mpsc,
@@ -105,11 +209,15 @@ mod test {
     use tokio_stream::wrappers::ReceiverStream;
     use vrl::{core::Value, event_path};
 
+    use super::Window;
     use crate::{
         conditions::{AnyCondition, ConditionConfig, VrlConfig},
+        config::TransformContext,
         event::{Event, LogEvent},
         test_util::components::assert_transform_compliance,
-        transforms::{test::create_topology, window::config::WindowConfig},
+        transforms::{
+            FunctionTransform, OutputBuffer, test::create_topology, window::config::WindowConfig,
+        },
     };
 
     #[tokio::test]
@@ -354,6 +462,69 @@ mod test {
         .await;
     }
 
+    #[test]
+    fn test_group_by_flushes_only_matching_key() {
+        let mut window = get_grouped_window(10, None, None);
+
+        let output = transform_messages(
+            &mut window,
+            vec![
+                service_event("a", "A01"),
+                service_event("b", "B01"),
+                service_event("a", "A02"),
+                service_event("b", "B02"),
+                service_event("a", "flush"),
+            ],
+        );
+        assert_eq!(
+            output,
+            vec![Value::from("A01"), Value::from("A02"), Value::from("flush")]
+        );
+
+        let output = transform_messages(&mut window, vec![service_event("b", "flush")]);
+        assert_eq!(
+            output,
+            vec![Value::from("B01"), Value::from("B02"), Value::from("flush")]
+        );
+    }
+
+    #[test]
+    fn test_group_by_max_keys_evicts_least_recent() {
+        let mut window = get_grouped_window(10, None, Some(2));
+
+        let output = transform_messages(
+            &mut window,
+            vec![
+                service_event("a", "A01"),
+                service_event("b", "B01"),
+                service_event("a", "A02"),
+                service_event("c", "C01"),
+                service_event("a", "flush"),
+            ],
+        );
+        assert_eq!(
+            output,
+            vec![Value::from("A01"), Value::from("A02"), Value::from("flush")]
+        );
+
+        // The window for `b` was evicted when `c` was first seen.
+        let output = transform_messages(&mut window, vec![service_event("b", "flush")]);
+        assert_eq!(output, vec![Value::from("flush")]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_group_by_expire_after() {
+        let mut window = get_grouped_window(10, Some(10), None);
+
+        let output = transform_messages(&mut window, vec![service_event("a", "A01")]);
+        assert!(output.is_empty());
+
+        tokio::time::advance(Duration::from_millis(20)).await;
+
+        let output = transform_messages(&mut window, vec![service_event("a", "flush")]);
+        assert_eq!(output, vec![Value::from("flush")]);
+    }
+
     const fn get_transform_config(
         flush_when: AnyCondition,
         forward_when: Option<AnyCondition>,
@@ -365,7 +536,39 @@ mod test {
             forward_when,
             num_events_before,
             num_events_after,
+            group_by: None,
+            expire_after_ms: None,
+            max_keys: None,
+        }
+    }
+
+    fn get_grouped_window(
+        num_events_before: usize,
+        expire_after_ms: Option<u64>,
+        max_keys: Option<usize>,
+    ) -> Window {
+        let mut config = get_transform_config(get_condition("flush"), None, num_events_before, 0);
+        config.group_by = Some("{{ service }}".try_into().unwrap());
+        config.expire_after_ms = expire_after_ms.map(Duration::from_millis);
+        config.max_keys = max_keys.and_then(NonZeroUsize::new);
+        Window::new(&config, &TransformContext::default()).unwrap()
+    }
+
+    fn service_event(service: &str, message: &str) -> Event {
+        let mut log = LogEvent::from(message);
+        log.insert("service", service);
+        Event::from(log)
+    }
+
+    fn transform_messages(window: &mut Window, events: Vec<Event>) -> Vec<Value> {
+        let mut output = OutputBuffer::with_capacity(events.len());
+        for event in events {
+            window.transform(&mut output, event);
         }
+        output
+            .into_events()
+            .map(|event| event.as_log().get(event_path!("message")).unwrap().clone())
+            .collect()
     }
 
     fn get_condition(message: &str) -> AnyCondition {
@@ -232,6 +232,61 @@ cli: {
 	options: _core_options
 
 	commands: {
+		"buffer": {
+			description: """
+				Inspect, dump, or replay the unread events in a disk buffer that is not in use by a
+				running Vector process. The buffer is only read from, so its contents are left as they
+				were. This makes it possible to recover the data in a disk buffer on another machine.
+
+				The `inspect` subcommand reports the number of records and events in the buffer, their
+				size, and the timestamps of the oldest and newest events. The `dump` subcommand writes
+				the events as native JSON, one event per line. The `replay` subcommand sends the events
+				to a sink from the given configuration, which defaults to the sink the buffer belonged to.
+
+				As the buffer is left as it was, replayed events are sent again by a Vector process that
+				starts with the data directory of the buffer. Move the buffer directory out of the data
+				directory once the replay has succeeded to avoid that.
+				"""
+
+			example: "vector buffer replay /var/lib/vector/buffer/v2/my_sink --config /etc/vector/vector.yaml"
+
+			flags: _default_flags
+
+			options: {
+				"encryption-key": {
+					description: "The key the buffer is encrypted with, encoded as base64"
+					type:        "string"
+					env_var:     "VECTOR_BUFFER_ENCRYPTION_KEY"
+				}
+				"previous-encryption-keys": {
+					description: "Keys that were previously used to encrypt the buffer, encoded as base64"
+					type:        "string"
+					env_var:     "VECTOR_BUFFER_PREVIOUS_ENCRYPTION_KEYS"
+				}
+				"output": {
+					_short:      "o"
+					description: "Write the events to this file instead of stdout (`dump` only)"
+					type:        "string"
+				}
+				"config": {
+					_short:      "c"
+					description: "The configuration containing the sink to replay the events into (`replay` only)"
+					type:        "string"
+				}
+				"sink": {
+					description: "The ID of the sink to replay the events into (`replay` only)"
+					type:        "string"
+				}
+			}
+
+			args: {
+				path: {
+					description: "The disk buffer directory, found at `<data_dir>/buffer/v2/<sink id>`"
+					type:        "string"
+					required:    true
+				}
+			}
+		}
 		"graph": {
 			description: """
 				Generate a visual representation of topologies. The output is in the [DOT format](\(urls.dot_format)),
@@ -491,6 +491,16 @@ components: {
 		description: "Default output stream of the component. Use this component's ID as an input to downstream transforms and sinks."
 	}
 
+	_decode_errors_output: #Output & {
+		name: "decode_errors"
+		description: """
+			Events that failed to decode, if `reroute_decode_errors` is set to `true`. Each event holds
+			the raw bytes in the `message` field, along with the `error`, and the `framing` method and
+			`codec` that were used. Use `<component_id>.decode_errors` as an input to downstream
+			transforms and sinks.
+			"""
+	}
+
 	#Outputs: *[_default_output] | [#Output, ...#Output]
 
 	#IAM: {
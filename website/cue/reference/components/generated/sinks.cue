@@ -12,6 +12,79 @@ generated: components: sinks: configuration: {
 			"""
 		required: false
 		type: object: options: {
+			compression: {
+				description:   "Compression applied to records written to a disk buffer."
+				relevant_when: "type = \"disk\""
+				required:      false
+				type: string: {
+					default: "none"
+					enum: {
+						lz4: """
+														Records are compressed with [LZ4][lz4].
+
+														This is faster than `zstd`, but compresses less.
+
+														[lz4]: https://lz4.org/
+														"""
+						none: "Records are not compressed."
+						zstd: """
+														Records are compressed with [Zstandard][zstd].
+
+														This provides a better compression ratio than `lz4`, at the cost of more CPU time.
+
+														[zstd]: https://facebook.github.io/zstd/
+														"""
+					}
+				}
+			}
+			encryption: {
+				description: """
+					Encryption at rest for a disk buffer.
+
+					Records are encrypted with XChaCha20-Poly1305 before being written to disk.
+					"""
+				relevant_when: "type = \"disk\""
+				required:      false
+				type: object: options: {
+					key: {
+						description: """
+							The key used to encrypt records written to the buffer.
+
+							Must be a 32-byte key, encoded as base64. Rather than writing the key into the
+							configuration, it should be retrieved with [secrets management][secrets].
+
+							[secrets]: https://vector.dev/docs/reference/configuration/secrets/
+							"""
+						required: true
+						type: string: examples: ["SECRET[backend.buffer_key]"]
+					}
+					previous_keys: {
+						description: """
+							Keys that were previously used to encrypt records in the buffer.
+
+							New records are always encrypted with `key`, but records encrypted with any of these keys
+							can still be read. When rotating keys, move the previous key here, and only remove it once
+							the records written with it have been read from the buffer.
+							"""
+						required: false
+						type: array: {
+							default: []
+							items: type: string: examples: ["SECRET[backend.previous_buffer_key]"]
+						}
+					}
+				}
+			}
+			max_age: {
+				description: """
+					The maximum age of events in the buffer, in seconds.
+
+					Events that have been in the buffer for longer than this are skipped when read, and are
+					counted as discarded rather than being sent to the sink. Data files whose events have all
+					expired are deleted without being read.
+					"""
+				required: false
+				type: uint: unit: "seconds"
+			}
 			max_events: {
 				description:   "The maximum number of events allowed in the buffer."
 				relevant_when: "type = \"memory\""
@@ -70,6 +143,18 @@ generated: components: sinks: configuration: {
 														highest priority, and it is preferable to temporarily lose events rather than cause a
 														slowdown in the acceptance/consumption of events.
 														"""
+						drop_oldest: """
+														Drops the oldest events in the buffer to make room for the event.
+
+														The evicted events will be intentionally dropped. This mode is typically used when the most
+														recent data is the most valuable, such as during a prolonged outage of the downstream
+														service, and it is preferable to lose older events rather than cause a slowdown in the
+														acceptance/consumption of events.
+
+														Disk buffers evict whole data files at a time, so `max_size` should be several times larger
+														than the size of a single data file (128 MiB) for this mode to be effective. If no data
+														file can be evicted, the event itself is dropped instead.
+														"""
 					}
 				}
 			}
@@ -79,6 +79,23 @@ components: sinks: file: {
 				disk before acknowledging the events.
 				"""
 		}
+
+		rotation: {
+			title: "File Rotation"
+			body:  """
+				When `rotation.max_bytes` or `rotation.interval_secs` is set, a file that
+				is due for rotation is closed and renamed before the next event is
+				written to its path, and a new file is opened in its place. Rotated
+				files are named with a numbered suffix (`app.log.1` being the most
+				recent) or with the time of the rotation, and can be compressed with
+				`rotation.compression`.
+
+				When `rotation.max_files` is set, the oldest rotated files of a path
+				are deleted. Rotation only renames and deletes regular files in the
+				directory of the rendered path, after verifying that the directory is
+				inside `base_dir`, and never follows symbolic links.
+				"""
+		}
 	}
 
 	telemetry: metrics: {
@@ -428,10 +428,219 @@ generated: components: sinks: aws_s3: configuration: {
 				description:   "Apache Avro-specific encoder options."
 				relevant_when: "codec = \"avro\""
 				required:      true
-				type: object: options: schema: {
-					description: "The Avro schema."
-					required:    true
-					type: string: examples: ["{ \"type\": \"record\", \"name\": \"log\", \"fields\": [{ \"name\": \"message\", \"type\": \"string\" }] }"]
+				type: object: options: {
+					schema: {
+						description: "The Avro schema."
+						required:    true
+						type: string: examples: ["{ \"type\": \"record\", \"name\": \"log\", \"fields\": [{ \"name\": \"message\", \"type\": \"string\" }] }"]
+					}
+					schema_registry: {
+						description: """
+																Prefixes each datum with the ID of the schema in a schema registry.
+
+																The output is in the [Confluent wire format][wire_format], which is what consumers that
+																read schemas from the registry expect. The schema's ID is looked up, or registered, when
+																the component starts.
+
+																[wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+																"""
+						required: false
+						type: object: options: {
+							auth: {
+								description: "Basic authentication credentials for the schema registry."
+								required:    false
+								type: object: options: {
+									password: {
+										description: "The basic authentication password."
+										required:    true
+										type: string: examples: ["${SCHEMA_REGISTRY_PASSWORD}"]
+									}
+									user: {
+										description: "The basic authentication username."
+										required:    true
+										type: string: examples: ["${SCHEMA_REGISTRY_USERNAME}"]
+									}
+								}
+							}
+							auto_register: {
+								description: """
+																				Registers the schema under the subject if it isn't registered yet.
+
+																				When disabled, the schema must already be registered under the subject.
+																				"""
+								required: false
+								type: bool: default: false
+							}
+							proxy: {
+								description: """
+																				Proxy options for connecting to the schema registry.
+
+																				These are merged with the `HTTP_PROXY`, `HTTPS_PROXY`, and `NO_PROXY` environment variables.
+																				"""
+								required: false
+								type: object: options: {
+									enabled: {
+										description: "Enables proxying support."
+										required:    false
+										type: bool: default: true
+									}
+									http: {
+										description: """
+																								Proxy endpoint to use when proxying HTTP traffic.
+
+																								Must be a valid URI string.
+																								"""
+										required: false
+										type: string: examples: ["http://foo.bar:3128"]
+									}
+									https: {
+										description: """
+																								Proxy endpoint to use when proxying HTTPS traffic.
+
+																								Must be a valid URI string.
+																								"""
+										required: false
+										type: string: examples: ["http://foo.bar:3128"]
+									}
+									no_proxy: {
+										description: """
+																								A list of hosts to avoid proxying.
+
+																								Multiple patterns are allowed:
+
+																								| Pattern             | Example match                                                               |
+																								| ------------------- | --------------------------------------------------------------------------- |
+																								| Domain names        | `example.com` matches requests to `example.com`                     |
+																								| Wildcard domains    | `.example.com` matches requests to `example.com` and its subdomains |
+																								| IP addresses        | `127.0.0.1` matches requests to `127.0.0.1`                         |
+																								| [CIDR][cidr] blocks | `192.168.0.0/16` matches requests to any IP addresses in this range     |
+																								| Splat               | `*` matches all hosts                                                   |
+
+																								[cidr]: https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing
+																								"""
+										required: false
+										type: array: {
+											default: []
+											items: type: string: examples: ["localhost", ".foo.bar", "*"]
+										}
+									}
+								}
+							}
+							subject: {
+								description: """
+																				The subject that the schema is registered under.
+
+																				With the registry's default topic name strategy, this is the name of the Kafka topic
+																				followed by `-value`.
+																				"""
+								required: true
+								type: string: examples: ["logs-value"]
+							}
+							tls: {
+								description: """
+																				TLS options for connecting to the schema registry.
+
+																				`alpn_protocols` and `server_name` aren't supported.
+																				"""
+								required: false
+								type: object: options: {
+									alpn_protocols: {
+										description: """
+																								Sets the list of supported ALPN protocols.
+
+																								Declare the supported ALPN protocols, which are used during negotiation with a peer. They are prioritized in the order
+																								that they are defined.
+																								"""
+										required: false
+										type: array: items: type: string: examples: ["h2"]
+									}
+									ca_file: {
+										description: """
+																								Absolute path to an additional CA certificate file.
+
+																								The certificate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
+																								"""
+										required: false
+										type: string: examples: ["/path/to/certificate_authority.crt"]
+									}
+									crt_file: {
+										description: """
+																								Absolute path to a certificate file used to identify this server.
+
+																								The certificate must be in DER, PEM (X.509), or PKCS#12 format. Additionally, the certificate can be provided as
+																								an inline string in PEM format.
+
+																								If this is set _and_ is not a PKCS#12 archive, `key_file` must also be set.
+																								"""
+										required: false
+										type: string: examples: ["/path/to/host_certificate.crt"]
+									}
+									key_file: {
+										description: """
+																								Absolute path to a private key file used to identify this server.
+
+																								The key must be in DER or PEM (PKCS#8) format. Additionally, the key can be provided as an inline string in PEM format.
+																								"""
+										required: false
+										type: string: examples: ["/path/to/host_certificate.key"]
+									}
+									key_pass: {
+										description: """
+																								Passphrase used to unlock the encrypted key file.
+
+																								This has no effect unless `key_file` is set.
+																								"""
+										required: false
+										type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
+									}
+									server_name: {
+										description: """
+																								Server name to use when using Server Name Indication (SNI).
+
+																								Only relevant for outgoing connections.
+																								"""
+										required: false
+										type: string: examples: ["www.example.com"]
+									}
+									verify_certificate: {
+										description: """
+																								Enables certificate verification. For components that create a server, this requires that the
+																								client connections have a valid client certificate. For components that initiate requests,
+																								this validates that the upstream has a valid certificate.
+
+																								If enabled, certificates must not be expired and must be issued by a trusted
+																								issuer. This verification operates in a hierarchical manner, checking that the leaf certificate (the
+																								certificate presented by the client/server) is not only valid, but that the issuer of that certificate is also valid, and
+																								so on, until the verification process reaches a root certificate.
+
+																								Do NOT set this to `false` unless you understand the risks of not verifying the validity of certificates.
+																								"""
+										required: false
+										type: bool: {}
+									}
+									verify_hostname: {
+										description: """
+																								Enables hostname verification.
+
+																								If enabled, the hostname used to connect to the remote host must be present in the TLS certificate presented by
+																								the remote host, either as the Common Name or as an entry in the Subject Alternative Name extension.
+
+																								Only relevant for outgoing connections.
+
+																								Do NOT set this to `false` unless you understand the risks of not verifying the remote hostname.
+																								"""
+										required: false
+										type: bool: {}
+									}
+								}
+							}
+							url: {
+								description: "The URL of the schema registry."
+								required:    true
+								type: string: examples: ["http://localhost:8081"]
+							}
+						}
+					}
 				}
 			}
 			cef: {
@@ -561,6 +770,14 @@ generated: components: sinks: aws_s3: configuration: {
 
 						[logfmt]: https://brandur.org/logfmt
 						"""
+					msgpack: """
+						Encodes an event as [MessagePack][msgpack].
+
+						Bytes that aren't valid UTF-8 are encoded as binary values, and timestamps are encoded
+						using the timestamp extension type.
+
+						[msgpack]: https://msgpack.org/
+						"""
 					native: """
 						Encodes an event in the [native Protocol Buffers format][vector_native_protobuf].
 
@@ -780,6 +997,214 @@ generated: components: sinks: aws_s3: configuration: {
 						required:    true
 						type: string: examples: ["package.Message"]
 					}
+					schema_registry: {
+						description: """
+																Prefixes each message with the ID of its schema in a schema registry.
+
+																The output is in the [Confluent wire format][wire_format], which is what consumers that
+																read schemas from the registry expect. The ID of the latest version of the schema
+																registered under the subject is looked up when the component starts. Protobuf schemas
+																must be registered ahead of time, so `auto_register` isn't supported.
+
+																[wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+																"""
+						required: false
+						type: object: options: {
+							auth: {
+								description: "Basic authentication credentials for the schema registry."
+								required:    false
+								type: object: options: {
+									password: {
+										description: "The basic authentication password."
+										required:    true
+										type: string: examples: ["${SCHEMA_REGISTRY_PASSWORD}"]
+									}
+									user: {
+										description: "The basic authentication username."
+										required:    true
+										type: string: examples: ["${SCHEMA_REGISTRY_USERNAME}"]
+									}
+								}
+							}
+							auto_register: {
+								description: """
+																				Registers the schema under the subject if it isn't registered yet.
+
+																				When disabled, the schema must already be registered under the subject.
+																				"""
+								required: false
+								type: bool: default: false
+							}
+							proxy: {
+								description: """
+																				Proxy options for connecting to the schema registry.
+
+																				These are merged with the `HTTP_PROXY`, `HTTPS_PROXY`, and `NO_PROXY` environment variables.
+																				"""
+								required: false
+								type: object: options: {
+									enabled: {
+										description: "Enables proxying support."
+										required:    false
+										type: bool: default: true
+									}
+									http: {
+										description: """
+																								Proxy endpoint to use when proxying HTTP traffic.
+
+																								Must be a valid URI string.
+																								"""
+										required: false
+										type: string: examples: ["http://foo.bar:3128"]
+									}
+									https: {
+										description: """
+																								Proxy endpoint to use when proxying HTTPS traffic.
+
+																								Must be a valid URI string.
+																								"""
+										required: false
+										type: string: examples: ["http://foo.bar:3128"]
+									}
+									no_proxy: {
+										description: """
+																								A list of hosts to avoid proxying.
+
+																								Multiple patterns are allowed:
+
+																								| Pattern             | Example match                                                               |
+																								| ------------------- | --------------------------------------------------------------------------- |
+																								| Domain names        | `example.com` matches requests to `example.com`                     |
+																								| Wildcard domains    | `.example.com` matches requests to `example.com` and its subdomains |
+																								| IP addresses        | `127.0.0.1` matches requests to `127.0.0.1`                         |
+																								| [CIDR][cidr] blocks | `192.168.0.0/16` matches requests to any IP addresses in this range     |
+																								| Splat               | `*` matches all hosts                                                   |
+
+																								[cidr]: https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing
+																								"""
+										required: false
+										type: array: {
+											default: []
+											items: type: string: examples: ["localhost", ".foo.bar", "*"]
+										}
+									}
+								}
+							}
+							subject: {
+								description: """
+																				The subject that the schema is registered under.
+
+																				With the registry's default topic name strategy, this is the name of the Kafka topic
+																				followed by `-value`.
+																				"""
+								required: true
+								type: string: examples: ["logs-value"]
+							}
+							tls: {
+								description: """
+																				TLS options for connecting to the schema registry.
+
+																				`alpn_protocols` and `server_name` aren't supported.
+																				"""
+								required: false
+								type: object: options: {
+									alpn_protocols: {
+										description: """
+																								Sets the list of supported ALPN protocols.
+
+																								Declare the supported ALPN protocols, which are used during negotiation with a peer. They are prioritized in the order
+																								that they are defined.
+																								"""
+										required: false
+										type: array: items: type: string: examples: ["h2"]
+									}
+									ca_file: {
+										description: """
+																								Absolute path to an additional CA certificate file.
+
+																								The certificate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
+																								"""
+										required: false
+										type: string: examples: ["/path/to/certificate_authority.crt"]
+									}
+									crt_file: {
+										description: """
+																								Absolute path to a certificate file used to identify this server.
+
+																								The certificate must be in DER, PEM (X.509), or PKCS#12 format. Additionally, the certificate can be provided as
+																								an inline string in PEM format.
+
+																								If this is set _and_ is not a PKCS#12 archive, `key_file` must also be set.
+																								"""
+										required: false
+										type: string: examples: ["/path/to/host_certificate.crt"]
+									}
+									key_file: {
+										description: """
+																								Absolute path to a private key file used to identify this server.
+
+																								The key must be in DER or PEM (PKCS#8) format. Additionally, the key can be provided as an inline string in PEM format.
+																								"""
+										required: false
+										type: string: examples: ["/path/to/host_certificate.key"]
+									}
+									key_pass: {
+										description: """
+																								Passphrase used to unlock the encrypted key file.
+
+																								This has no effect unless `key_file` is set.
+																								"""
+										required: false
+										type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
+									}
+									server_name: {
+										description: """
+																								Server name to use when using Server Name Indication (SNI).
+
+																								Only relevant for outgoing connections.
+																								"""
+										required: false
+										type: string: examples: ["www.example.com"]
+									}
+									verify_certificate: {
+										description: """
+																								Enables certificate verification. For components that create a server, this requires that the
+																								client connections have a valid client certificate. For components that initiate requests,
+																								this validates that the upstream has a valid certificate.
+
+																								If enabled, certificates must not be expired and must be issued by a trusted
+																								issuer. This verification operates in a hierarchical manner, checking that the leaf certificate (the
+																								certificate presented by the client/server) is not only valid, but that the issuer of that certificate is also valid, and
+																								so on, until the verification process reaches a root certificate.
+
+																								Do NOT set this to `false` unless you understand the risks of not verifying the validity of certificates.
+																								"""
+										required: false
+										type: bool: {}
+									}
+									verify_hostname: {
+										description: """
+																								Enables hostname verification.
+
+																								If enabled, the hostname used to connect to the remote host must be present in the TLS certificate presented by
+																								the remote host, either as the Common Name or as an entry in the Subject Alternative Name extension.
+
+																								Only relevant for outgoing connections.
+
+																								Do NOT set this to `false` unless you understand the risks of not verifying the remote hostname.
+																								"""
+										required: false
+										type: bool: {}
+									}
+								}
+							}
+							url: {
+								description: "The URL of the schema registry."
+								required:    true
+								type: string: examples: ["http://localhost:8081"]
+							}
+						}
+					}
 					use_json_names: {
 						description: """
 																Use JSON field names (camelCase) instead of protobuf field names (snake_case).
@@ -70,6 +70,22 @@ generated: components: sinks: postgres: configuration: {
 			}
 		}
 	}
+	dangerously_allow_unconfined_template_resolution: {
+		description: """
+			Disable all template confinement checks for this sink.
+
+			**DANGEROUS — disables a security control.**
+
+			Bypasses both startup validation and runtime confinement for every
+			templated field on this sink. When enabled, a log producer that
+			controls any field used in a template can write to arbitrary keys,
+			paths, or routing destinations. This flag is a full opt-out: it
+			disables confinement even for templates that have a usable static
+			prefix.
+			"""
+		required: false
+		type: bool: default: false
+	}
 	endpoint: {
 		description: """
 			The PostgreSQL server connection string. It can contain the username and password.
@@ -275,12 +291,89 @@ generated: components: sinks: postgres: configuration: {
 	}
 	table: {
 		description: """
-			The table that data is inserted into. This table parameter is vulnerable
-			to SQL injection attacks as Vector does not validate or sanitize it, you must not use untrusted input.
-			This parameter will be directly interpolated in the SQL query statement,
-			as table names as parameters in prepared statements are not allowed in PostgreSQL.
+			The table that data is inserted into.
+
+			The table name is directly interpolated in the SQL statement, as table names can't be
+			parameters of prepared statements in PostgreSQL. Vector doesn't validate or sanitize the
+			static parts of this template, so they must not come from untrusted input. The parts that
+			are rendered from the event may only contain ASCII letters, digits, and underscores, and
+			events whose table name doesn't meet this requirement are dropped.
 			"""
 		required: true
-		type: string: {}
+		type: string: {
+			examples: ["mytable", "logs_{{ service }}"]
+			syntax: "template"
+		}
+	}
+	write: {
+		description: "How events are written to the table."
+		required:    false
+		type: object: options: {
+			conflict_columns: {
+				description:   "The columns of the unique constraint or index that identifies a row."
+				relevant_when: "mode = \"upsert\""
+				required:      true
+				type: array: items: type: string: examples: ["id"]
+			}
+			format: {
+				description:   "The format that events are sent to `COPY` in."
+				relevant_when: "mode = \"copy\""
+				required:      false
+				type: string: {
+					default: "csv"
+					enum: {
+						binary: """
+							Sends events in PostgreSQL's binary format, which saves the server from parsing them.
+
+							Only columns of the `bool`, `int2`, `int4`, `int8`, `float4`, `float8`, `text`,
+							`varchar`, `bpchar`, `json`, `jsonb`, `timestamp`, `timestamptz`, `uuid`, and `bytea`
+							types are supported.
+							"""
+						csv: """
+							Sends events as CSV, which PostgreSQL parses into the column types of the table.
+
+							Objects and arrays are sent as JSON, so they can only be written to `json` and `jsonb`
+							columns.
+							"""
+					}
+				}
+			}
+			mode: {
+				description: "The statement used to write events to the table."
+				required:    false
+				type: string: {
+					default: "insert"
+					enum: {
+						copy: """
+							Write events with `COPY ... FROM STDIN`, which has much higher throughput than `INSERT`
+							for bulk ingestion.
+
+							Only the columns that have a field in at least one event of a batch are written, so the
+							other columns get their default values. Events that have no field for any column of the
+							table aren't written.
+							"""
+						insert: "Insert events with `INSERT INTO ... SELECT * FROM jsonb_populate_recordset(...)`."
+						upsert: """
+							Insert events with `INSERT ... ON CONFLICT (...) DO UPDATE`, updating the existing row when
+							an event conflicts with one.
+
+							This suits tables that hold the latest state of an entity keyed by its ID. When a batch
+							holds several events with the same values for `conflict_columns`, only the last of them is
+							written.
+							"""
+					}
+				}
+			}
+			update_columns: {
+				description: """
+					The columns that are updated when a row already exists.
+
+					If not set, every column of the table other than `conflict_columns` is updated.
+					"""
+				relevant_when: "mode = \"upsert\""
+				required:      false
+				type: array: items: type: string: examples: ["status"]
+			}
+		}
 	}
 }
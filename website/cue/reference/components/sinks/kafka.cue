@@ -64,7 +64,23 @@ components: sinks: kafka: {
 		traces: false
 	}
 
-	how_it_works: components._kafka.how_it_works
+	how_it_works: components._kafka.how_it_works & {
+		transactions: {
+			title: "Exactly-once delivery"
+			body:  """
+				When `transaction` is set, the sink produces events within Kafka transactions, each holding
+				up to `transaction.max_events` events. A transaction is aborted and retried if any of its
+				events fails to be produced, so consumers that read with `isolation.level` set to
+				`read_committed` see each event exactly once. Events that Kafka can never accept, such as
+				ones that exceed the maximum message size, are rejected and left out of the retried
+				transaction.
+
+				With `transaction.source_offsets`, the sink also commits the offsets of a `kafka` source
+				in the same Vector instance within each transaction. Consuming and producing an event then
+				succeed or fail together, which makes a Kafka-to-Kafka pipeline exactly-once end to end.
+				"""
+		}
+	}
 
 	telemetry: metrics: {
 		kafka_queue_messages:                components.sources.internal_metrics.output.metrics.kafka_queue_messages
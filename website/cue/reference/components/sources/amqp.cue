@@ -41,6 +41,11 @@ components: sources: amqp: {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.amqp.configuration
 
 	output: {
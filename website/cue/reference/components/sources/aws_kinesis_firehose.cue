@@ -57,6 +57,11 @@ components: sources: aws_kinesis_firehose: {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.aws_kinesis_firehose.configuration
 
 	output: {
@@ -44,6 +44,11 @@ components: sources: aws_s3: components._aws & {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.aws_s3.configuration & {
 		_aws_include: false
 	}
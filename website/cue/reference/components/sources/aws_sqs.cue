@@ -52,6 +52,11 @@ components: sources: aws_sqs: components._aws & {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.aws_sqs.configuration & {
 		_aws_include: false
 	}
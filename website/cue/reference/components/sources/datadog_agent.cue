@@ -82,6 +82,7 @@ components: sources: datadog_agent: {
 				If [multiple_outputs](#multiple_outputs) is enabled, received trace events will go to this output stream. Use `<component_id>.traces` as an input to downstream transforms and sinks.
 				"""
 		},
+		components._decode_errors_output,
 	]
 
 	output: {
@@ -36,6 +36,11 @@ components: sources: demo_logs: {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.demo_logs.configuration
 
 	output: {
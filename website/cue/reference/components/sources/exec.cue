@@ -38,6 +38,11 @@ components: sources: exec: {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.exec.configuration
 
 	output: {
@@ -39,6 +39,11 @@ components: sources: file_descriptor: {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.file_descriptor.configuration
 
 	output: {
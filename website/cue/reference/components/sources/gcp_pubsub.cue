@@ -56,6 +56,11 @@ components: sources: gcp_pubsub: {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.gcp_pubsub.configuration
 
 	output: logs: record: {
@@ -179,6 +179,11 @@ generated: components: sources: aws_s3: configuration: {
 					schema: {
 						description: """
 																The Avro schema definition.
+
+																When `schema_registry` is set, this is optional. If it's set, data written with any
+																compatible version of the schema is resolved to it, following Avro's schema evolution
+																rules. Otherwise, data is decoded with the schema it was written with.
+
 																**Note**: The following [`apache_avro::types::Value`] variants are *not* supported:
 																* `Date`
 																* `Decimal`
@@ -186,13 +191,204 @@ generated: components: sources: aws_s3: configuration: {
 																* `Fixed`
 																* `TimeMillis`
 																"""
-						required: true
-						type: string: examples: ["{ \"type\": \"record\", \"name\": \"log\", \"fields\": [{ \"name\": \"message\", \"type\": \"string\" }] }"]
+						required: false
+						type: string: {
+							default: ""
+							examples: ["{ \"type\": \"record\", \"name\": \"log\", \"fields\": [{ \"name\": \"message\", \"type\": \"string\" }] }"]
+						}
+					}
+					schema_registry: {
+						description: """
+																Fetches the schema that each datum was written with from a schema registry.
+
+																Each datum must be in the [Confluent wire format][wire_format], which prefixes it with the
+																ID of its schema. Schemas are fetched the first time their ID is seen and cached after
+																that.
+
+																[wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+																"""
+						required: false
+						type: object: options: {
+							auth: {
+								description: "Basic authentication credentials for the schema registry."
+								required:    false
+								type: object: options: {
+									password: {
+										description: "The basic authentication password."
+										required:    true
+										type: string: examples: ["${SCHEMA_REGISTRY_PASSWORD}"]
+									}
+									user: {
+										description: "The basic authentication username."
+										required:    true
+										type: string: examples: ["${SCHEMA_REGISTRY_USERNAME}"]
+									}
+								}
+							}
+							proxy: {
+								description: """
+																				Proxy options for connecting to the schema registry.
+
+																				These are merged with the `HTTP_PROXY`, `HTTPS_PROXY`, and `NO_PROXY` environment variables.
+																				"""
+								required: false
+								type: object: options: {
+									enabled: {
+										description: "Enables proxying support."
+										required:    false
+										type: bool: default: true
+									}
+									http: {
+										description: """
+																								Proxy endpoint to use when proxying HTTP traffic.
+
+																								Must be a valid URI string.
+																								"""
+										required: false
+										type: string: examples: ["http://foo.bar:3128"]
+									}
+									https: {
+										description: """
+																								Proxy endpoint to use when proxying HTTPS traffic.
+
+																								Must be a valid URI string.
+																								"""
+										required: false
+										type: string: examples: ["http://foo.bar:3128"]
+									}
+									no_proxy: {
+										description: """
+																								A list of hosts to avoid proxying.
+
+																								Multiple patterns are allowed:
+
+																								| Pattern             | Example match                                                               |
+																								| ------------------- | --------------------------------------------------------------------------- |
+																								| Domain names        | `example.com` matches requests to `example.com`                     |
+																								| Wildcard domains    | `.example.com` matches requests to `example.com` and its subdomains |
+																								| IP addresses        | `127.0.0.1` matches requests to `127.0.0.1`                         |
+																								| [CIDR][cidr] blocks | `192.168.0.0/16` matches requests to any IP addresses in this range     |
+																								| Splat               | `*` matches all hosts                                                   |
+
+																								[cidr]: https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing
+																								"""
+										required: false
+										type: array: {
+											default: []
+											items: type: string: examples: ["localhost", ".foo.bar", "*"]
+										}
+									}
+								}
+							}
+							tls: {
+								description: """
+																				TLS options for connecting to the schema registry.
+
+																				`alpn_protocols` and `server_name` aren't supported.
+																				"""
+								required: false
+								type: object: options: {
+									alpn_protocols: {
+										description: """
+																								Sets the list of supported ALPN protocols.
+
+																								Declare the supported ALPN protocols, which are used during negotiation with a peer. They are prioritized in the order
+																								that they are defined.
+																								"""
+										required: false
+										type: array: items: type: string: examples: ["h2"]
+									}
+									ca_file: {
+										description: """
+																								Absolute path to an additional CA certificate file.
+
+																								The certificate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
+																								"""
+										required: false
+										type: string: examples: ["/path/to/certificate_authority.crt"]
+									}
+									crt_file: {
+										description: """
+																								Absolute path to a certificate file used to identify this server.
+
+																								The certificate must be in DER, PEM (X.509), or PKCS#12 format. Additionally, the certificate can be provided as
+																								an inline string in PEM format.
+
+																								If this is set _and_ is not a PKCS#12 archive, `key_file` must also be set.
+																								"""
+										required: false
+										type: string: examples: ["/path/to/host_certificate.crt"]
+									}
+									key_file: {
+										description: """
+																								Absolute path to a private key file used to identify this server.
+
+																								The key must be in DER or PEM (PKCS#8) format. Additionally, the key can be provided as an inline string in PEM format.
+																								"""
+										required: false
+										type: string: examples: ["/path/to/host_certificate.key"]
+									}
+									key_pass: {
+										description: """
+																								Passphrase used to unlock the encrypted key file.
+
+																								This has no effect unless `key_file` is set.
+																								"""
+										required: false
+										type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
+									}
+									server_name: {
+										description: """
+																								Server name to use when using Server Name Indication (SNI).
+
+																								Only relevant for outgoing connections.
+																								"""
+										required: false
+										type: string: examples: ["www.example.com"]
+									}
+									verify_certificate: {
+										description: """
+																								Enables certificate verification. For components that create a server, this requires that the
+																								client connections have a valid client certificate. For components that initiate requests,
+																								this validates that the upstream has a valid certificate.
+
+																								If enabled, certificates must not be expired and must be issued by a trusted
+																								issuer. This verification operates in a hierarchical manner, checking that the leaf certificate (the
+																								certificate presented by the client/server) is not only valid, but that the issuer of that certificate is also valid, and
+																								so on, until the verification process reaches a root certificate.
+
+																								Do NOT set this to `false` unless you understand the risks of not verifying the validity of certificates.
+																								"""
+										required: false
+										type: bool: {}
+									}
+									verify_hostname: {
+										description: """
+																								Enables hostname verification.
+
+																								If enabled, the hostname used to connect to the remote host must be present in the TLS certificate presented by
+																								the remote host, either as the Common Name or as an entry in the Subject Alternative Name extension.
+
+																								Only relevant for outgoing connections.
+
+																								Do NOT set this to `false` unless you understand the risks of not verifying the remote hostname.
+																								"""
+										required: false
+										type: bool: {}
+									}
+								}
+							}
+							url: {
+								description: "The URL of the schema registry."
+								required:    true
+								type: string: examples: ["http://localhost:8081"]
+							}
+						}
 					}
 					strip_schema_id_prefix: {
 						description: "For Avro datum encoded in Kafka messages, the bytes are prefixed with the schema ID.  Set this to `true` to strip the schema ID prefix, as described in [Confluent Kafka's documentation](https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format)."
-						required:    true
-						type: bool: {}
+						required:    false
+						type: bool: default: false
 					}
 				}
 			}
@@ -208,6 +404,21 @@ generated: components: sources: aws_s3: configuration: {
 															[apache_avro]: https://avro.apache.org/
 															"""
 						bytes: "Uses the raw bytes as-is."
+						cef: """
+															Decodes the raw bytes as a [CEF (Common Event Format)][cef] message.
+
+															The header fields are decoded into the `cef` object, and the extensions into the root of
+															the event. Any text before `CEF:`, such as a syslog header, is ignored.
+
+															[cef]: https://www.microfocus.com/documentation/arcsight/arcsight-smartconnectors-8.3/cef-implementation-standard/Content/CEF/Chapter%201%20What%20is%20CEF.htm
+															"""
+						csv: """
+															Decodes the raw bytes as [CSV (Comma Separated Values)][csv].
+
+															Each row is decoded into a log event, with a field for each column.
+
+															[csv]: https://datatracker.ietf.org/doc/html/rfc4180
+															"""
 						gelf: """
 															Decodes the raw bytes as a [GELF][gelf] message.
 
@@ -236,6 +447,22 @@ generated: components: sources: aws_s3: configuration: {
 
 															[json]: https://www.json.org/
 															"""
+						logfmt: """
+															Decodes the raw bytes as [logfmt][logfmt].
+
+															Each key becomes a field of the event. Keys without a value are decoded as `true`.
+
+															[logfmt]: https://brandur.org/logfmt
+															"""
+						msgpack: """
+															Decodes the raw bytes as [MessagePack][msgpack].
+
+															Binary values are decoded as bytes, and the timestamp extension type is decoded as a
+															timestamp. Other extension types are decoded as an object with `msgpack_extension_code` and
+															`bytes` fields.
+
+															[msgpack]: https://msgpack.org/
+															"""
 						native: """
 															Decodes the raw bytes as [native Protocol Buffers format][vector_native_protobuf].
 
@@ -286,6 +513,121 @@ generated: components: sources: aws_s3: configuration: {
 					}
 				}
 			}
+			cef: {
+				description:   "CEF-specific decoding options."
+				relevant_when: "codec = \"cef\""
+				required:      false
+				type: object: options: lossy: {
+					description: """
+						Determines whether to replace invalid UTF-8 sequences instead of failing.
+
+						When true, invalid UTF-8 sequences are replaced with the [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+
+						[U+FFFD]: https://en.wikipedia.org/wiki/Specials_(Unicode_block)#Replacement_character
+						"""
+					required: false
+					type: bool: default: true
+				}
+			}
+			csv: {
+				description:   "CSV-specific decoding options."
+				relevant_when: "codec = \"csv\""
+				required:      false
+				type: object: options: {
+					columns: {
+						description: """
+																The names of the columns, in the order they appear in each row.
+
+																When empty, the first row of each frame is treated as a header row holding the column
+																names, and every following row in the frame is decoded into an event. As the header row
+																must be in the same frame as the rows it describes, this requires a framing method that
+																keeps a whole CSV document in a single frame, such as `bytes`.
+
+																When set, every row is decoded into an event, so a framing method that splits the input
+																into lines, such as `newline_delimited`, can be used. A header row in the input is then
+																decoded like any other row.
+																"""
+						required: false
+						type: array: {
+							default: []
+							items: type: string: examples: ["timestamp", "message"]
+						}
+					}
+					delimiter: {
+						description: "The field delimiter to use when reading CSV."
+						required:    false
+						type: ascii_char: default: ","
+					}
+					double_quote: {
+						description: """
+																Enables double quote escapes.
+
+																This is enabled by default, so two consecutive quotes within a quoted field are read as
+																a single quote. When disabled, quotes in field data are expected to be escaped with the
+																`escape` character instead.
+																"""
+						required: false
+						type: bool: default: true
+					}
+					escape: {
+						description: """
+																The escape character to use when reading CSV.
+
+																In some variants of CSV, quotes are escaped using a special escape character
+																like \\ (instead of escaping quotes by doubling them).
+
+																To use this, `double_quote` needs to be disabled as well; otherwise, this setting is ignored.
+																"""
+						required: false
+						type: ascii_char: default: "\""
+					}
+					quote: {
+						description: "The quote character to use when reading CSV."
+						required:    false
+						type: ascii_char: default: "\""
+					}
+					timezone: {
+						description: """
+																The name of the timezone to apply to timestamp columns that do not contain an explicit
+																time zone. The time zone name may be any name in the [TZ database][tz_database], or `local`
+																to indicate system local time.
+
+																If not set, `local` is used.
+
+																[tz_database]: https://en.wikipedia.org/wiki/List_of_tz_database_time_zones
+																"""
+						required: false
+						type: string: examples: ["local", "America/New_York", "EST5EDT"]
+					}
+					types: {
+						description: """
+																The types to convert the values of columns to.
+
+																Columns without a type are decoded as strings. The value of a typed column that is
+																empty is decoded as `null`.
+
+																The supported types are `string`, `integer`, `float`, `boolean`, `timestamp` (parsed as
+																RFC 3339), and `timestamp|<format>` (parsed with the given [`strptime` format][strptime]).
+
+																[strptime]: https://docs.rs/chrono/latest/chrono/format/strftime/index.html#specifiers
+																"""
+						required: false
+						type: object: {
+							examples: [{
+								duration:  "float"
+								status:    "integer"
+								success:   "boolean"
+								timestamp: "timestamp|%F %T"
+							}]
+							options: "*": {
+								description: "The type to convert the column's values to."
+								required:    true
+								type: string: {}
+							}
+						}
+					}
+				}
+			}
 			gelf: {
 				description:   "GELF-specific decoding options."
 				relevant_when: "codec = \"gelf\""
@@ -352,6 +694,22 @@ generated: components: sources: aws_s3: configuration: {
 					type: bool: default: true
 				}
 			}
+			logfmt: {
+				description:   "Logfmt-specific decoding options."
+				relevant_when: "codec = \"logfmt\""
+				required:      false
+				type: object: options: lossy: {
+					description: """
+						Determines whether to replace invalid UTF-8 sequences instead of failing.
+
+						When true, invalid UTF-8 sequences are replaced with the [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+
+						[U+FFFD]: https://en.wikipedia.org/wiki/Specials_(Unicode_block)#Replacement_character
+						"""
+					required: false
+					type: bool: default: true
+				}
+			}
 			native_json: {
 				description:   "Vector's native JSON-specific decoding options."
 				relevant_when: "codec = \"native_json\""
@@ -380,18 +738,213 @@ generated: components: sources: aws_s3: configuration: {
 																This file is the output of `protoc -I <include path> -o <desc output path> <proto>`.
 
 																For more information, see [How Buf images work](https://buf.build/docs/reference/images/#how-buf-images-work).
+
+																Not used when `schema_registry` is set.
 																"""
 						required: false
 						type: string: default: ""
 					}
 					message_type: {
-						description: "The name of the message type to use for serializing."
-						required:    false
+						description: """
+																The name of the message type to use for serializing.
+
+																Not used when `schema_registry` is set, as each message names its type in its header.
+																"""
+						required: false
 						type: string: {
 							default: ""
 							examples: ["package.Message"]
 						}
 					}
+					schema_registry: {
+						description: """
+																Fetches the schema that each message was written with from a schema registry.
+
+																Each message must be in the [Confluent wire format][wire_format], which prefixes it with
+																the ID of its schema and the indexes of its message type within the schema. Schemas are
+																fetched the first time their ID is seen and cached after that, so messages written with
+																newer versions of a schema are decoded with the fields of that version.
+
+																[wire_format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+																"""
+						required: false
+						type: object: options: {
+							auth: {
+								description: "Basic authentication credentials for the schema registry."
+								required:    false
+								type: object: options: {
+									password: {
+										description: "The basic authentication password."
+										required:    true
+										type: string: examples: ["${SCHEMA_REGISTRY_PASSWORD}"]
+									}
+									user: {
+										description: "The basic authentication username."
+										required:    true
+										type: string: examples: ["${SCHEMA_REGISTRY_USERNAME}"]
+									}
+								}
+							}
+							proxy: {
+								description: """
+																				Proxy options for connecting to the schema registry.
+
+																				These are merged with the `HTTP_PROXY`, `HTTPS_PROXY`, and `NO_PROXY` environment variables.
+																				"""
+								required: false
+								type: object: options: {
+									enabled: {
+										description: "Enables proxying support."
+										required:    false
+										type: bool: default: true
+									}
+									http: {
+										description: """
+																								Proxy endpoint to use when proxying HTTP traffic.
+
+																								Must be a valid URI string.
+																								"""
+										required: false
+										type: string: examples: ["http://foo.bar:3128"]
+									}
+									https: {
+										description: """
+																								Proxy endpoint to use when proxying HTTPS traffic.
+
+																								Must be a valid URI string.
+																								"""
+										required: false
+										type: string: examples: ["http://foo.bar:3128"]
+									}
+									no_proxy: {
+										description: """
+																								A list of hosts to avoid proxying.
+
+																								Multiple patterns are allowed:
+
+																								| Pattern             | Example match                                                               |
+																								| ------------------- | --------------------------------------------------------------------------- |
+																								| Domain names        | `example.com` matches requests to `example.com`                     |
+																								| Wildcard domains    | `.example.com` matches requests to `example.com` and its subdomains |
+																								| IP addresses        | `127.0.0.1` matches requests to `127.0.0.1`                         |
+																								| [CIDR][cidr] blocks | `192.168.0.0/16` matches requests to any IP addresses in this range     |
+																								| Splat               | `*` matches all hosts                                                   |
+
+																								[cidr]: https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing
+																								"""
+										required: false
+										type: array: {
+											default: []
+											items: type: string: examples: ["localhost", ".foo.bar", "*"]
+										}
+									}
+								}
+							}
+							tls: {
+								description: """
+																				TLS options for connecting to the schema registry.
+
+																				`alpn_protocols` and `server_name` aren't supported.
+																				"""
+								required: false
+								type: object: options: {
+									alpn_protocols: {
+										description: """
+																								Sets the list of supported ALPN protocols.
+
+																								Declare the supported ALPN protocols, which are used during negotiation with a peer. They are prioritized in the order
+																								that they are defined.
+																								"""
+										required: false
+										type: array: items: type: string: examples: ["h2"]
+									}
+									ca_file: {
+										description: """
+																								Absolute path to an additional CA certificate file.
+
+																								The certificate must be in the DER or PEM (X.509) format. Additionally, the certificate can be provided as an inline string in PEM format.
+																								"""
+										required: false
+										type: string: examples: ["/path/to/certificate_authority.crt"]
+									}
+									crt_file: {
+										description: """
+																								Absolute path to a certificate file used to identify this server.
+
+																								The certificate must be in DER, PEM (X.509), or PKCS#12 format. Additionally, the certificate can be provided as
+																								an inline string in PEM format.
+
+																								If this is set _and_ is not a PKCS#12 archive, `key_file` must also be set.
+																								"""
+										required: false
+										type: string: examples: ["/path/to/host_certificate.crt"]
+									}
+									key_file: {
+										description: """
+																								Absolute path to a private key file used to identify this server.
+
+																								The key must be in DER or PEM (PKCS#8) format. Additionally, the key can be provided as an inline string in PEM format.
+																								"""
+										required: false
+										type: string: examples: ["/path/to/host_certificate.key"]
+									}
+									key_pass: {
+										description: """
+																								Passphrase used to unlock the encrypted key file.
+
+																								This has no effect unless `key_file` is set.
+																								"""
+										required: false
+										type: string: examples: ["${KEY_PASS_ENV_VAR}", "PassWord1"]
+									}
+									server_name: {
+										description: """
+																								Server name to use when using Server Name Indication (SNI).
+
+																								Only relevant for outgoing connections.
+																								"""
+										required: false
+										type: string: examples: ["www.example.com"]
+									}
+									verify_certificate: {
+										description: """
+																								Enables certificate verification. For components that create a server, this requires that the
+																								client connections have a valid client certificate. For components that initiate requests,
+																								this validates that the upstream has a valid certificate.
+
+																								If enabled, certificates must not be expired and must be issued by a trusted
+																								issuer. This verification operates in a hierarchical manner, checking that the leaf certificate (the
+																								certificate presented by the client/server) is not only valid, but that the issuer of that certificate is also valid, and
+																								so on, until the verification process reaches a root certificate.
+
+																								Do NOT set this to `false` unless you understand the risks of not verifying the validity of certificates.
+																								"""
+										required: false
+										type: bool: {}
+									}
+									verify_hostname: {
+										description: """
+																								Enables hostname verification.
+
+																								If enabled, the hostname used to connect to the remote host must be present in the TLS certificate presented by
+																								the remote host, either as the Common Name or as an entry in the Subject Alternative Name extension.
+
+																								Only relevant for outgoing connections.
+
+																								Do NOT set this to `false` unless you understand the risks of not verifying the remote hostname.
+																								"""
+										required: false
+										type: bool: {}
+									}
+								}
+							}
+							url: {
+								description: "The URL of the schema registry."
+								required:    true
+								type: string: examples: ["http://localhost:8081"]
+							}
+						}
+					}
 					use_json_names: {
 						description: """
 																Use JSON field names (camelCase) instead of protobuf field names (snake_case).
@@ -805,6 +1358,16 @@ generated: components: sources: aws_s3: configuration: {
 		required: false
 		type: string: examples: ["us-east-1"]
 	}
+	reroute_decode_errors: {
+		description: """
+			Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+
+			Each of these events holds the raw bytes that failed to decode in the `message` field, along
+			with the `error`, and the `framing` method and `codec` that were used.
+			"""
+		required: false
+		type: bool: default: false
+	}
 	sqs: {
 		description: "Configuration options for SQS."
 		required:    false
@@ -1,6 +1,43 @@
 package metadata
 
 generated: components: sources: okta: configuration: {
+	acknowledgements: {
+		deprecated: true
+		description: """
+			Controls how acknowledgements are handled by this source.
+
+			This setting is **deprecated** in favor of enabling `acknowledgements` at the [global][global_acks] or sink level.
+
+			Enabling or disabling acknowledgements at the source level has **no effect** on acknowledgement behavior.
+
+			See [End-to-end Acknowledgements][e2e_acks] for more information on how event acknowledgement is handled.
+
+			[global_acks]: https://vector.dev/docs/reference/configuration/global-options/#acknowledgements
+			[e2e_acks]: https://vector.dev/docs/architecture/end-to-end-acknowledgements/
+			"""
+		required: false
+		type: object: options: enabled: {
+			description: "Whether or not end-to-end acknowledgements are enabled for this source."
+			required:    false
+			type: bool: {}
+		}
+	}
+	data_dir: {
+		description: """
+			The directory used to persist the cursor of the next page of logs to fetch.
+
+			By default, the [global `data_dir` option][global_data_dir] is used, and the cursor isn't
+			persisted if Vector can't write to it.
+			Make sure the running user has write permissions to this directory.
+
+			If this directory is specified, then Vector will attempt to create it, and fails to start
+			the source if it can't.
+
+			[global_data_dir]: https://vector.dev/docs/reference/configuration/global-options/#data_dir
+			"""
+		required: false
+		type: string: examples: ["/var/lib/vector"]
+	}
 	domain: {
 		description: "The Okta subdomain to scrape"
 		required:    true
@@ -30,6 +67,9 @@ generated: components: sources: okta: configuration: {
 		description: """
 			The time to look back for logs. This is used to determine the start time of the first request
 			(that is, the earliest log to fetch)
+
+			Only used when no checkpoint has been persisted yet. Once the source has fetched a page of
+			logs, it resumes from the stored cursor instead.
 			"""
 		required: false
 		type: uint: {}
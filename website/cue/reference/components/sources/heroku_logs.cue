@@ -60,6 +60,11 @@ components: sources: heroku_logs: {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.heroku_logs.configuration
 
 	output: logs: line: {
@@ -51,6 +51,11 @@ components: sources: http_client: {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.http_client.configuration & {
 		endpoint: warnings: ["You must explicitly add the path to your endpoint."]
 	}
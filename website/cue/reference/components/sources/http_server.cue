@@ -54,6 +54,11 @@ components: sources: http_server: {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.http_server.configuration
 
 	output: {
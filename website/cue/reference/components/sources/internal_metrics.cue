@@ -83,6 +83,12 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		aggregate_late_events_total: {
+			description:       "The number of metrics received by the aggregate transform after their event-time window was flushed."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		api_started_total: {
 			description:       "The number of times the Vector API has been started."
 			type:              "counter"
@@ -38,6 +38,11 @@ components: sources: kafka: {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.kafka.configuration
 
 	output: {
@@ -107,5 +112,21 @@ components: sources: kafka: {
 		kafka_consumer_lag:                  components.sources.internal_metrics.output.metrics.kafka_consumer_lag
 	}
 
-	how_it_works: components._kafka.how_it_works
+	how_it_works: components._kafka.how_it_works & {
+		replay: {
+			title: "Replaying past events"
+			body:  """
+				To backfill events after an incident, the source can run as a one-off replay job. With
+				`start_position`, it commits new start offsets for its consumer group before subscribing,
+				either the offsets of the first messages at or after a timestamp, or explicit offsets of
+				individual partitions. Use a dedicated `group_id` for replays, as the group can't have
+				other active consumers at that point.
+
+				With `end_position`, the source stops consuming each partition at the first message at or
+				after a timestamp, or at the end of the partition as of when the source started. Once all
+				partitions reach the end position, the source shuts down, so Vector exits when it has no
+				other sources.
+				"""
+		}
+	}
 }
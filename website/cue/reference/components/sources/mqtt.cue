@@ -50,6 +50,11 @@ components: sources: mqtt: {
 		notices: []
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.mqtt.configuration
 
 	installation: {
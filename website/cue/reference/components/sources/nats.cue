@@ -38,6 +38,11 @@ components: sources: nats: {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.nats.configuration
 
 	output: {
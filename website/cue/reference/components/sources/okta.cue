@@ -5,11 +5,11 @@ components: sources: okta: {
 	title: "Okta"
 
 	classes: {
-		delivery: "best_effort"
+		delivery: "at_least_once"
 		deployment_roles: ["aggregator"]
 		development:   "beta"
 		egress_method: "stream"
-		stateful:      false
+		stateful:      true
 	}
 
 	features: {
@@ -175,5 +175,16 @@ components: sources: okta: {
 				The `since` parameter begins fetching logs generated prior to Vector's startup
 				"""
 		}
+		checkpointing: {
+			title: "Checkpointing"
+			body: """
+				After each page of logs, the `okta` source stores the link to the next page, which
+				contains Okta's `after` cursor, in a checkpoint file within the `data_dir`. On restart,
+				Vector resumes from this cursor instead of starting over from `since`.
+
+				When [end-to-end acknowledgements](/docs/architecture/end-to-end-acknowledgements/) are enabled, the
+				checkpoint only advances once all of the events of a page have been delivered to the sinks.
+				"""
+		}
 	}
 }
@@ -32,6 +32,11 @@ components: sources: pulsar: {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: {
 		auth: {
 			description: "Options for the authentication strategy."
@@ -104,6 +109,16 @@ components: sources: pulsar: {
 				examples: ["pulsar://127.0.0.1:6650"]
 			}
 		}
+		reroute_decode_errors: {
+			description: """
+				Routes events that fail to decode to the `decode_errors` output instead of dropping them.
+
+				Each of these events holds the raw bytes that failed to decode in the `message` field, along
+				with the `error`, and the `framing` method and `codec` that were used.
+				"""
+			required: false
+			type: bool: default: false
+		}
 		topics: {
 			description: "The Pulsar topic names to read events from."
 			required:    true
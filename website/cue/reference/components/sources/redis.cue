@@ -56,6 +56,11 @@ components: sources: redis: {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.redis.configuration
 
 	output: {
@@ -55,6 +55,11 @@ components: sources: socket: {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.socket.configuration
 
 	output: {
@@ -55,6 +55,11 @@ components: sources: splunk_hec: {
 		platform_name: null
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.splunk_hec.configuration
 
 	output: logs: event: {
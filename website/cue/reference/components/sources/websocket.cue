@@ -60,6 +60,11 @@ components: sources: websocket: {
 		notices: []
 	}
 
+	outputs: [
+		components._default_output,
+		components._decode_errors_output,
+	]
+
 	configuration: generated.components.sources.websocket.configuration & {
 		ping_timeout: warnings: ["This option is ignored if the `ping_interval` option is not set."]
 	}
@@ -44,6 +44,12 @@ components: transforms: aggregate: {
 		metrics: "": {
 			description: "The modified input `metric` event."
 		}
+		metrics: late: {
+			description: """
+				Metrics that arrived after their window was flushed, when `event_time.late_policy`
+				is set to `output`.
+				"""
+		}
 	}
 
 	examples: [
@@ -178,6 +184,21 @@ components: transforms: aggregate: {
 				"""
 		}
 
+		event_time: {
+			title: "Event-time Windows"
+			body: """
+				By default, metrics are aggregated by the time they arrive at the transform, and all
+				of them are flushed every `interval_ms`. When `event_time` is set, metrics are instead
+				aggregated into windows based on their own timestamp, so that metrics replayed from a
+				buffer after an outage are aggregated with the other metrics of their interval.
+
+				A window is flushed once the watermark, the most recent metric timestamp seen minus
+				`event_time.allowed_lateness_ms`, passes its end. Metrics for windows that have already
+				been flushed are dropped, sent to the `late` output, or aggregated into the current
+				window, depending on `event_time.late_policy`.
+				"""
+		}
+
 		advantages: {
 			title: "Advantages of Use"
 			body: """
@@ -195,5 +216,6 @@ components: transforms: aggregate: {
 		aggregate_events_recorded_total: components.sources.internal_metrics.output.metrics.aggregate_events_recorded_total
 		aggregate_failed_updates:        components.sources.internal_metrics.output.metrics.aggregate_failed_updates
 		aggregate_flushes_total:         components.sources.internal_metrics.output.metrics.aggregate_flushes_total
+		aggregate_late_events_total:     components.sources.internal_metrics.output.metrics.aggregate_late_events_total
 	}
 }
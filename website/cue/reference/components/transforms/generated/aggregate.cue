@@ -1,6 +1,62 @@
 package metadata
 
 generated: components: transforms: aggregate: configuration: {
+	event_time: {
+		description: """
+			Event-time windowing configuration.
+
+			When set, metrics are aggregated into windows of `interval_ms` based on their own
+			timestamp, instead of the time they arrive at the transform. Windows are aligned to the
+			Unix epoch, and the aggregated metrics are timestamped with the start of their window.
+			Metrics without a timestamp are assigned to a window based on their arrival time.
+			"""
+		required: false
+		type: object: options: {
+			allowed_lateness_ms: {
+				description: """
+					How long to wait for late metrics after the end of a window, in milliseconds.
+
+					The watermark of the transform is the most recent metric timestamp seen, minus the
+					allowed lateness. A window is flushed once the watermark passes its end, and metrics for
+					windows that have already been flushed are handled according to `late_policy`.
+
+					Metrics timestamped in the future only advance the watermark up to the current time. If no
+					metrics are received during a whole interval, the watermark moves forward by one interval
+					per idle interval instead, until the window of the most recent metric timestamp is flushed.
+					"""
+				required: false
+				type: uint: default: 0
+			}
+			late_policy: {
+				description: "What to do with metrics that arrive after their window has been flushed."
+				required:    false
+				type: string: {
+					default: "drop"
+					enum: {
+						current_window: """
+							Aggregate late metrics into the current window, the window of the most recent metric
+							timestamp seen.
+							"""
+						drop:   "Drop late metrics."
+						output: "Send late metrics, unmodified, to the `late` output of the transform."
+					}
+				}
+			}
+		}
+	}
+	histogram_buckets: {
+		description: """
+			The upper limits of the buckets of the histograms created by the `Histogram` mode.
+
+			The limits must be in ascending order. Values greater than the last limit are only
+			accounted for in the count and sum of the histogram.
+			"""
+		required: false
+		type: array: {
+			default: [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]
+			items: type: float: {}
+		}
+	}
 	interval_ms: {
 		description: """
 			The interval between flushes, in milliseconds.
@@ -23,12 +79,23 @@ generated: components: transforms: aggregate: configuration: {
 				Auto:   "Default mode. Sums incremental metrics and uses the latest value for absolute metrics."
 				Count:  "Counts metrics for incremental and absolute metrics"
 				Diff:   "Returns difference between latest value for absolute; incremental metrics pass through unchanged."
+				Histogram: """
+					Folds the values of absolute gauges and incremental distributions into an incremental
+					aggregated histogram with the buckets set by `histogram_buckets`; other metrics pass through
+					unchanged.
+					"""
 				Latest: "Returns the latest value for absolute metrics; incremental metrics pass through unchanged."
 				Max:    "Max value of absolute metric; incremental metrics pass through unchanged."
 				Mean:   "Mean value of absolute metric; incremental metrics pass through unchanged."
 				Min:    "Min value of absolute metric; incremental metrics pass through unchanged."
-				Stdev:  "Stdev value of absolute metric; incremental metrics pass through unchanged."
-				Sum:    "Sums incremental metrics; absolute metrics pass through unchanged."
+				Sketch: """
+					Folds the values of absolute gauges and incremental distributions into an incremental
+					[DDSketch][ddsketch]; other metrics pass through unchanged.
+
+					[ddsketch]: https://www.vldb.org/pvldb/vol12/p2195-masson.pdf
+					"""
+				Stdev: "Stdev value of absolute metric; incremental metrics pass through unchanged."
+				Sum:   "Sums incremental metrics; absolute metrics pass through unchanged."
 			}
 		}
 	}
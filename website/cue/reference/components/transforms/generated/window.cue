@@ -11,6 +11,17 @@ generated: components: transforms: window: configuration: {
 		required: true
 		type: condition: {}
 	}
+	expire_after_ms: {
+		description: """
+			The maximum period of time a key's window is kept after its last event is received, in
+			milliseconds.
+
+			When a window expires, any events still buffered in it are dropped. Windows are checked for
+			expiration as new events arrive. If left unspecified, windows never expire.
+			"""
+		required: false
+		type: uint: unit: "milliseconds"
+	}
 	forward_when: {
 		description: """
 			A condition used to pass events through the transform without buffering.
@@ -22,6 +33,30 @@ generated: components: transforms: window: configuration: {
 		required: false
 		type: condition: {}
 	}
+	group_by: {
+		description: """
+			The value to group events into separate windows.
+
+			Each unique key has its own buffer of `num_events_before` events, and a flush triggered by
+			an event only flushes the window of that event's key. If left unspecified, or if the
+			template fails to render for an event, the event is placed in a single shared window.
+			"""
+		required: false
+		type: string: {
+			examples: ["{{ service }}", "{{ host }}"]
+			syntax: "template"
+		}
+	}
+	max_keys: {
+		description: """
+			The maximum number of keys to track windows for.
+
+			When a new key is seen and this limit is reached, the least recently active window is
+			evicted and its buffered events are dropped.
+			"""
+		required: false
+		type: uint: {}
+	}
 	num_events_after: {
 		description: "The maximum number of events to keep after the event matched by the `flush_when` condition."
 		required:    false
@@ -141,6 +141,20 @@ components: transforms: window: {
 				that are relatively uncommon.
 				"""
 		}
+
+		grouping: {
+			title: "Grouping"
+			body: """
+				By default, all events share a single window. When `group_by` is set, each unique value of the
+				template gets its own window, so a `flush_when` match only flushes the events of the same key. This
+				is useful for multi-tenant streams, where an error in one service should not flush the debug logs
+				of another.
+
+				Each key keeps up to `num_events_before` events in memory. Use `expire_after_ms` to drop the windows
+				of keys that have gone idle, and `max_keys` to bound the number of windows tracked at once. Events
+				buffered in an expired or evicted window are dropped.
+				"""
+		}
 	}
 
 	telemetry: metrics: {
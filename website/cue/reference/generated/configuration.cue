@@ -16,6 +16,18 @@ generated: configuration: {
 						"""
 					required: false
 				}
+				control_token: {
+					type: string: examples: ["${VECTOR_API_CONTROL_TOKEN}"]
+					description: """
+						The token required to use the API's topology control RPCs, such as reloading the
+						configuration or pausing a source.
+
+						Clients must send it in the `authorization` metadata as `Bearer <token>`. If left
+						unspecified, the control RPCs are disabled. Changes to this option take effect when the
+						API is restarted.
+						"""
+					required: false
+				}
 				enabled: {
 					type: bool: default: false
 					description: "Whether the API is enabled for this Vector instance."
@@ -23,7 +35,7 @@ generated: configuration: {
 				}
 			}
 			description: "API options."
-			warnings: ["The API currently does not support authentication. Only enable it in isolated environments or for debugging. It must not be exposed to untrusted clients."]
+			warnings: ["The read-only parts of the API do not support authentication. Only enable it in isolated environments or for debugging. It must not be exposed to untrusted clients."]
 			group: "api"
 		}
 		enrichment_tables: {
@@ -116,28 +128,58 @@ generated: configuration: {
 								type: object: options: {
 									delimiter: {
 										type: string: default: ","
-										description: "The delimiter used to separate fields in each row of the CSV file."
-										required:    false
+										description:   "The delimiter used to separate fields in each row of the CSV file."
+										relevant_when: "type = \"csv\""
+										required:      false
 									}
 									include_headers: {
 										type: bool: default: true
 										description: """
 																						Whether or not the file contains column headers.
 
-																						When set to `true`, the first row of the CSV file will be read as the header row, and
+																						When set to `true`, the first row of the file will be read as the header row, and
 																						the values will be used for the names of each column. This is the default behavior.
 
 																						When set to `false`, columns are referred to by their numerical index.
 																						"""
-										required: false
+										relevant_when: "type = \"csv\" or type = \"tsv\""
+										required:      false
 									}
 									type: {
 										required: true
-										type: string: enum: csv: """
-																									Decodes the file as a [CSV][csv] (comma-separated values) file.
-
-																									[csv]: https://wikipedia.org/wiki/Comma-separated_values
-																									"""
+										type: string: enum: {
+											csv: """
+													Decodes the file as a [CSV][csv] (comma-separated values) file.
+
+													[csv]: https://wikipedia.org/wiki/Comma-separated_values
+													"""
+											json_lines: """
+													Decodes the file as [JSON Lines][jsonl], with one JSON object per line.
+
+													The columns of the table are the union of the keys of all objects. Rows that do not
+													contain a given key have a `null` value for that column. Values keep their JSON types, including
+													nested objects and arrays.
+
+													[jsonl]: https://jsonlines.org/
+													"""
+											tsv: """
+													Decodes the file as a [TSV][tsv] (tab-separated values) file.
+
+													Fields are separated by tabs and are never quoted, so quote characters are read as part of
+													the value. Columns are coerced into their types by `schema`, as for CSV files.
+
+													[tsv]: https://www.iana.org/assignments/media-types/text/tab-separated-values
+													"""
+											parquet: """
+													Decodes the file as an [Apache Parquet][apache_parquet] file.
+
+													The columns of the table are the columns of the Parquet schema. Values keep their Parquet
+													types: integers, floats, booleans, strings, binary, timestamps and dates, as well as
+													nested lists, maps, and structs.
+
+													[apache_parquet]: https://parquet.apache.org/
+													"""
+										}
 										description: "File encoding type."
 									}
 								}
@@ -149,9 +191,7 @@ generated: configuration: {
 								description: """
 																		The path of the enrichment table file.
 
-																		Currently, only [CSV][csv] files are supported.
-
-																		[csv]: https://en.wikipedia.org/wiki/Comma-separated_values
+																		The format of the file is set by `encoding`.
 																		"""
 								required: true
 							}
@@ -171,6 +211,9 @@ generated: configuration: {
 
 														This is used to coerce log fields from strings into their proper types. The available types are listed in the `Types` list below.
 
+														For the `json_lines` and `parquet` encodings, only string values are coerced. Values that already have a
+														native type are left unchanged.
+
 														Timestamp coercions need to be prefaced with `timestamp|`, for example `"timestamp|%F"`. Timestamp specifiers can use either of the following:
 
 														1. One of the built-in-formats listed in the `Timestamp Formats` table below.
@@ -480,6 +523,35 @@ generated: configuration: {
 						required:      false
 						relevant_when: "type = \"memory\""
 					}
+					snapshot: {
+						type: object: options: {
+							data_dir: {
+								type: string: examples: ["/var/lib/vector"]
+								description: """
+																		The directory used to persist the table snapshot.
+
+																		By default, the [global `data_dir` option][global_data_dir] is used.
+																		Make sure the running user has write permissions to this directory.
+
+																		If this directory is specified, then Vector will attempt to create it.
+
+																		[global_data_dir]: https://vector.dev/docs/reference/configuration/global-options/#data_dir
+																		"""
+								required: false
+							}
+							interval: {
+								type: uint: {
+									default: 60
+									unit:    "seconds"
+								}
+								description: "The interval, in seconds, between writes of the table snapshot to disk."
+								required:    false
+							}
+						}
+						description:   "Configuration for persisting the table contents to disk."
+						required:      false
+						relevant_when: "type = \"memory\""
+					}
 					source_config: {
 						type: object: options: {
 							export_batch_size: {
@@ -1029,6 +1101,18 @@ generated: configuration: {
 																					highest priority, and it is preferable to temporarily lose events rather than cause a
 																					slowdown in the acceptance/consumption of events.
 																					"""
+										drop_oldest: """
+																					Drops the oldest events in the buffer to make room for the event.
+
+																					The evicted events will be intentionally dropped. This mode is typically used when the most
+																					recent data is the most valuable, such as during a prolonged outage of the downstream
+																					service, and it is preferable to lose older events rather than cause a slowdown in the
+																					acceptance/consumption of events.
+
+																					Disk buffers evict whole data files at a time, so `max_size` should be several times larger
+																					than the size of a single data file (128 MiB) for this mode to be effective. If no data
+																					file can be evicted, the event itself is dropped instead.
+																					"""
 									}
 									default: "block"
 								}
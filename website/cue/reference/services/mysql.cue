@@ -0,0 +1,10 @@
+package metadata
+
+services: mysql: {
+	name:     "MySQL"
+	thing:    "a \(name) database"
+	url:      urls.mysql
+	versions: null
+
+	description: "[MySQL](\(urls.mysql)) is a widely used open source relational database. [MariaDB](\(urls.mariadb)) is a community-developed fork of MySQL that remains compatible with its protocol."
+}
@@ -360,6 +360,7 @@ urls: {
 	macos:                                      "\(wikipedia)/wiki/MacOS"
 	magic_bytes:                                "\(wikipedia)/wiki/List_of_file_signatures"
 	mailing_list:                               "/community/"
+	mariadb:                                    "https://mariadb.org/"
 	maxmind:                                    "https://www.maxmind.com/en/home"
 	maxmind_db_file_format:                     "https://maxmind.github.io/MaxMind-DB/"
 	maxmind_geoip2:                             "https://dev.maxmind.com/geoip/geoip2/downloadable"
@@ -376,6 +377,7 @@ urls: {
 	mongodb_command_server_status:              "https://docs.mongodb.com/manual/reference/command/serverStatus/"
 	mongodb_connection_string_uri_format:       "https://docs.mongodb.com/manual/reference/connection-string/"
 	mqtt:                                       "https://mqtt.org/"
+	mysql:                                      "https://www.mysql.com/"
 	musl_builder_docker_image:                  "\(vector_repo)/blob/master/scripts/ci-docker-images/builder-x86_64-unknown-linux-musl/Dockerfile"
 	native_proto_schema:                        "\(vector_repo)/blob/master/lib/vector-core/proto/event.proto"
 	native_json_schema:                         "\(vector_repo)/blob/master/lib/codecs/tests/data/native_encoding/schema.cue"
@@ -508,6 +510,7 @@ urls: {
 	splunk_hec_raw_endpoint:                    "https://docs.splunk.com/Documentation/Splunk/8.0.0/RESTREF/RESTinput#services.2Fcollector.2Fraw"
 	splunk_hec_setup:                           "https://docs.splunk.com/Documentation/Splunk/latest/Data/UsetheHTTPEventCollector"
 	splunk_hec_metadata:                        "https://docs.splunk.com/Documentation/Splunk/latest/Data/FormateventsforHTTPEventCollector#Event_metadata"
+	sqlite:                                     "https://www.sqlite.org/"
 	specs_instrumentation:                      "\(vector_repo)/blob/master/docs/specs/instrumentation.md)"
 	standard_streams:                           "\(wikipedia)/wiki/Standard_streams"
 	statsd:                                     "\(github)/statsd/statsd"